@@ -1,285 +1,959 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.11.1.
 
-import 'package:flutter/foundation.dart';
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
-import 'package:loom/src/rust/frb_generated.dart';
-
 part 'blox_api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `convert_to_internal_block`, `convert_to_internal_document`
+            // These functions are ignored because they are not marked as `pub`: `convert_inline`, `convert_to_internal_block`, `convert_to_internal_document`, `find_block`, `load_flashcards`, `merge_attributes`, `new`, `restore_attributes`, `review_date`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BloxListType`, `ParseProgress`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 // These functions have error during generation (see debug logs or enable `stop_on_error: true` for more details): `parse_blox_file_with_progress`
 
-/// Parse a Blox document from string content
-BloxDocument parseBloxString({required String content}) =>
-    RustLib.instance.api.crateApiBloxApiParseBloxString(content: content);
+
+            /// Parse a Blox document from string content
+BloxDocument  parseBloxString({required String content }) => RustLib.instance.api.crateApiBloxApiParseBloxString(content: content);
+
+/// Parse a Blox document, reporting every problem instead of failing.
+/// The returned document is always usable for rendering; duplicate ids and
+/// unresolved references are reported along with parse problems.
+BloxParseResult  parseBloxStringWithDiagnostics({required String content , required BloxParserOptions options }) => RustLib.instance.api.crateApiBloxApiParseBloxStringWithDiagnostics(content: content, options: options);
 
 /// Parse a Blox document from file
-Future<BloxDocument> parseBloxFile({required String filePath}) =>
-    RustLib.instance.api.crateApiBloxApiParseBloxFile(filePath: filePath);
-
-/// Encode a Blox document back to string format
-String encodeBloxDocument({
-  required BloxDocument document,
-  required bool useShorthand,
-}) =>
-    RustLib.instance.api.crateApiBloxApiEncodeBloxDocument(
-      document: document,
-      useShorthand: useShorthand,
-    );
-
-/// Decode a Blox document to specified output format
-String decodeBloxDocument({
-  required BloxDocument document,
-  required BloxOutputFormat format,
-}) =>
-    RustLib.instance.api
-        .crateApiBloxApiDecodeBloxDocument(document: document, format: format);
-
-/// Validate Blox syntax without full parsing
-List<String> validateBloxSyntax({required String content}) =>
-    RustLib.instance.api.crateApiBloxApiValidateBloxSyntax(content: content);
-
-@immutable
-class BloxBlock {
-  const BloxBlock({
-    required this.blockType,
-    required this.level,
-    required this.attributes,
-    required this.content,
-    required this.children,
-    required this.lineNumber,
-    required this.inlineElements,
-    required this.listItems,
-    this.table,
-  });
-  final String blockType;
-  final BigInt level;
-  final Map<String, String> attributes;
-  final String content;
-  final List<BloxBlock> children;
-  final BigInt lineNumber;
-  final List<BloxInlineElement> inlineElements;
-  final List<BloxListItem> listItems;
-  final BloxTable? table;
-
-  @override
-  int get hashCode =>
-      blockType.hashCode ^
-      level.hashCode ^
-      attributes.hashCode ^
-      content.hashCode ^
-      children.hashCode ^
-      lineNumber.hashCode ^
-      inlineElements.hashCode ^
-      listItems.hashCode ^
-      table.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxBlock &&
-          runtimeType == other.runtimeType &&
-          blockType == other.blockType &&
-          level == other.level &&
-          attributes == other.attributes &&
-          content == other.content &&
-          children == other.children &&
-          lineNumber == other.lineNumber &&
-          inlineElements == other.inlineElements &&
-          listItems == other.listItems &&
-          table == other.table;
-}
-
-@immutable
-class BloxDocument {
-  const BloxDocument({
-    required this.blocks,
-    required this.metadata,
-  });
-  final List<BloxBlock> blocks;
-  final Map<String, String> metadata;
-
-  @override
-  int get hashCode => blocks.hashCode ^ metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxDocument &&
-          runtimeType == other.runtimeType &&
-          blocks == other.blocks &&
-          metadata == other.metadata;
-}
+Future<BloxDocument>  parseBloxFile({required String filePath }) => RustLib.instance.api.crateApiBloxApiParseBloxFile(filePath: filePath);
+
+/// Encode a Blox document back to string format. Metadata is written at
+/// the top; `encode_blox_document_preserving` keeps it where it was written.
+/// Fails for a document that cannot be read back as written, such as one
+/// whose content has `\r\n` line breaks or whose attribute values hold
+/// line breaks.
+String  encodeBloxDocument({required BloxDocument document , required bool useShorthand }) => RustLib.instance.api.crateApiBloxApiEncodeBloxDocument(document: document, useShorthand: useShorthand);
+
+/// Encode a document edited through its list items, tables or inline
+/// elements, writing the content of those blocks from the edited models
+String  encodeBloxDocumentFromModels({required BloxDocument document , required bool useShorthand }) => RustLib.instance.api.crateApiBloxApiEncodeBloxDocumentFromModels(document: document, useShorthand: useShorthand);
+
+/// Encode an edited document as a change to `source`, the text it was
+/// parsed from, keeping the formatting of everything that was not edited.
+/// Blocks edited only through their structured models are written from them.
+String  encodeBloxDocumentPreserving({required String source , required BloxDocument document , required bool useShorthand }) => RustLib.instance.api.crateApiBloxApiEncodeBloxDocumentPreserving(source: source, document: document, useShorthand: useShorthand);
+
+/// Format Blox source in `style`, keeping its comments. Fails rather
+/// than return text that would parse as a different document.
+String  formatBloxString({required String content , required BloxFormatStyle style }) => RustLib.instance.api.crateApiBloxApiFormatBloxString(content: content, style: style);
+
+/// Whether Blox source is already formatted in `style`
+bool  checkBloxFormat({required String content , required BloxFormatStyle style }) => RustLib.instance.api.crateApiBloxApiCheckBloxFormat(content: content, style: style);
+
+/// Decode a Blox document to specified output format; GIFT and QTI are
+/// decoded with `decode_blox_package`
+String  decodeBloxDocument({required BloxDocument document , required BloxOutputFormat format }) => RustLib.instance.api.crateApiBloxApiDecodeBloxDocument(document: document, format: format);
+
+/// Decode a document to the files of `format`, such as the QTI package of its quizzes
+BloxPackage  decodeBloxPackage({required BloxDocument document , required BloxOutputFormat format }) => RustLib.instance.api.crateApiBloxApiDecodeBloxPackage(document: document, format: format);
+
+/// Render a document as HTML with quiz answers marked and explanations shown
+String  decodeBloxAnswerKey({required BloxDocument document }) => RustLib.instance.api.crateApiBloxApiDecodeBloxAnswerKey(document: document);
+
+/// Replace `{{!name}}` variables with their values.
+/// `variables` take precedence over `#var` definitions in the document;
+/// each variable without a value is reported as a diagnostic.
+BloxParseResult  interpolateBloxVariables({required BloxDocument document , required Map<String, String> variables }) => RustLib.instance.api.crateApiBloxApiInterpolateBloxVariables(document: document, variables: variables);
+
+/// Load the rows of `#table src=...` blocks from files relative to `base_dir`,
+/// the directory the document was read from
+BloxParseResult  loadBloxTables({required BloxDocument document , required String baseDir }) => RustLib.instance.api.crateApiBloxApiLoadBloxTables(document: document, baseDir: baseDir);
+
+/// Find the block that `id` refers to, including slugs made for headings without an id
+BloxReferenceTarget?  resolveReference({required BloxDocument document , required String id }) => RustLib.instance.api.crateApiBloxApiResolveReference(document: document, id: id);
+
+/// Change the table block at `path` in `content`, returning the source edits that make the change
+BloxTableEditResult  editBloxTable({required String content , required Uint64List path , required BloxTableOp op }) => RustLib.instance.api.crateApiBloxApiEditBloxTable(content: content, path: path, op: op);
+
+/// Keep the body rows of the table at `path` whose text contains `query`,
+/// ignoring case, in the cell at index `column` or in any cell
+BloxTableEditResult  filterBloxTableRows({required String content , required Uint64List path , BigInt? column , required String query }) => RustLib.instance.api.crateApiBloxApiFilterBloxTableRows(content: content, path: path, column: column, query: query);
+
+/// Every task in the `.blox` files at `paths`, searching directories for them
+List<BloxTask>  scanBloxTasks({required List<String> paths }) => RustLib.instance.api.crateApiBloxApiScanBloxTasks(paths: paths);
+
+/// Check or uncheck the task at `span` in `file`, rewriting only its line on disk
+BloxTask  toggleBloxTask({required String file , required BloxSpan span }) => RustLib.instance.api.crateApiBloxApiToggleBloxTask(file: file, span: span);
+
+/// The flashcards of the file at `file_path`, with their review schedules
+List<BloxFlashcard>  bloxFlashcards({required String filePath }) => RustLib.instance.api.crateApiBloxApiBloxFlashcards(filePath: filePath);
+
+/// The flashcards of the file at `file_path` to review on `today`
+/// (`YYYY-MM-DD`, or the current date), due cards before new ones
+List<BloxFlashcard>  dueBloxFlashcards({required String filePath , String? today }) => RustLib.instance.api.crateApiBloxApiDueBloxFlashcards(filePath: filePath, today: today);
+
+/// Record a review of the card `card_id`, graded from 0 (forgotten) to 5
+/// (perfect recall), in the review log next to the file
+BloxFlashcard  reviewBloxFlashcard({required String filePath , required String cardId , required int grade , String? today }) => RustLib.instance.api.crateApiBloxApiReviewBloxFlashcard(filePath: filePath, cardId: cardId, grade: grade, today: today);
+
+/// Validate Blox syntax without full parsing.
+/// Nesting is checked strictly; unknown block types are not reported.
+List<String>  validateBloxSyntax({required String content }) => RustLib.instance.api.crateApiBloxApiValidateBloxSyntax(content: content);
+
+            enum BloxAttributeOrder {
+                    source,
+sorted,
+                    ;
+                    
+                }
+
+class BloxAttributeSpan  {
+                final String key;
+final BloxSpan keySpan;
+final BloxSpan valueSpan;
+
+                const BloxAttributeSpan({required this.key ,required this.keySpan ,required this.valueSpan ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^keySpan.hashCode^valueSpan.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxAttributeSpan &&
+                runtimeType == other.runtimeType
+                && key == other.key&& keySpan == other.keySpan&& valueSpan == other.valueSpan;
+        
+            }
+
+class BloxBlock  {
+                final String blockType;
+final BigInt level;
+final Map<String, String> attributes;
+final String content;
+final List<BloxBlock> children;
+final BigInt lineNumber;
+final BloxSpan headerSpan;
+final BloxSpan contentSpan;
+final List<BloxAttributeSpan> attributeSpans;
+final List<BloxInlineElement> inlineElements;
+/// Spans of `inline_elements`, in the same order
+final List<BloxSpan> inlineSpans;
+final List<BloxListItem> listItems;
+final BloxTable? table;
+final BloxQuiz? quiz;
+
+                const BloxBlock({required this.blockType ,required this.level ,required this.attributes ,required this.content ,required this.children ,required this.lineNumber ,required this.headerSpan ,required this.contentSpan ,required this.attributeSpans ,required this.inlineElements ,required this.inlineSpans ,required this.listItems ,this.table ,this.quiz ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => blockType.hashCode^level.hashCode^attributes.hashCode^content.hashCode^children.hashCode^lineNumber.hashCode^headerSpan.hashCode^contentSpan.hashCode^attributeSpans.hashCode^inlineElements.hashCode^inlineSpans.hashCode^listItems.hashCode^table.hashCode^quiz.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxBlock &&
+                runtimeType == other.runtimeType
+                && blockType == other.blockType&& level == other.level&& attributes == other.attributes&& content == other.content&& children == other.children&& lineNumber == other.lineNumber&& headerSpan == other.headerSpan&& contentSpan == other.contentSpan&& attributeSpans == other.attributeSpans&& inlineElements == other.inlineElements&& inlineSpans == other.inlineSpans&& listItems == other.listItems&& table == other.table&& quiz == other.quiz;
+        
+            }
 
 @freezed
-sealed class BloxInlineElement with _$BloxInlineElement {
-  const BloxInlineElement._();
-
-  const factory BloxInlineElement.text(
-    String field0,
-  ) = BloxInlineElement_Text;
-  const factory BloxInlineElement.link({
-    required String text,
-    required String url,
-  }) = BloxInlineElement_Link;
-  const factory BloxInlineElement.bold(
-    String field0,
-  ) = BloxInlineElement_Bold;
-  const factory BloxInlineElement.italic(
-    String field0,
-  ) = BloxInlineElement_Italic;
-  const factory BloxInlineElement.code(
-    String field0,
-  ) = BloxInlineElement_Code;
-  const factory BloxInlineElement.math(
-    String field0,
-  ) = BloxInlineElement_Math;
-  const factory BloxInlineElement.strikethrough(
-    String field0,
-  ) = BloxInlineElement_Strikethrough;
-  const factory BloxInlineElement.highlight(
-    String field0,
-  ) = BloxInlineElement_Highlight;
-  const factory BloxInlineElement.subscript(
-    String field0,
-  ) = BloxInlineElement_Subscript;
-  const factory BloxInlineElement.superscript(
-    String field0,
-  ) = BloxInlineElement_Superscript;
-  const factory BloxInlineElement.reference(
-    String field0,
-  ) = BloxInlineElement_Reference;
-  const factory BloxInlineElement.footnote({
-    required String id,
-    required String text,
-  }) = BloxInlineElement_Footnote;
-  const factory BloxInlineElement.custom({
-    required String elementType,
-    required Map<String, String> attributes,
-    required String content,
-  }) = BloxInlineElement_Custom;
-}
-
-@immutable
-class BloxListItem {
-  const BloxListItem({
-    required this.itemType,
-    required this.content,
-    required this.children,
-    required this.level,
-  });
-  final BloxListItemType itemType;
-  final String content;
-  final List<BloxListItem> children;
-  final BigInt level;
-
-  @override
-  int get hashCode =>
-      itemType.hashCode ^ content.hashCode ^ children.hashCode ^ level.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxListItem &&
-          runtimeType == other.runtimeType &&
-          itemType == other.itemType &&
-          content == other.content &&
-          children == other.children &&
-          level == other.level;
-}
+                sealed class BloxCellValue with _$BloxCellValue  {
+                    const BloxCellValue._();
+
+                     const factory BloxCellValue.empty() = BloxCellValue_Empty;
+ const factory BloxCellValue.text(  String field0,) = BloxCellValue_Text;
+ const factory BloxCellValue.number(  double field0,) = BloxCellValue_Number;
+ /// `YYYY-MM-DD`
+const factory BloxCellValue.date(  String field0,) = BloxCellValue_Date;
+ const factory BloxCellValue.currency({   required double amount ,  String? symbol , }) = BloxCellValue_Currency;
+ /// A formula that could not be evaluated, as a code such as `#DIV/0!`
+const factory BloxCellValue.error(  String field0,) = BloxCellValue_Error;
+
+                    
+
+                    
+                }
+
+class BloxColumnSpec  {
+                /// `left`, `center` or `right`
+final String align;
+final String? width;
+/// `text`, `number`, `date` or `currency`
+final String columnType;
+final BigInt? decimals;
+
+                const BloxColumnSpec({required this.align ,this.width ,required this.columnType ,this.decimals ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => align.hashCode^width.hashCode^columnType.hashCode^decimals.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxColumnSpec &&
+                runtimeType == other.runtimeType
+                && align == other.align&& width == other.width&& columnType == other.columnType&& decimals == other.decimals;
+        
+            }
+
+class BloxDiagnostic  {
+                final BloxSeverity severity;
+final String code;
+final BloxSpan span;
+final String message;
+final BloxFix? fix;
+
+                const BloxDiagnostic({required this.severity ,required this.code ,required this.span ,required this.message ,this.fix ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => severity.hashCode^code.hashCode^span.hashCode^message.hashCode^fix.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxDiagnostic &&
+                runtimeType == other.runtimeType
+                && severity == other.severity&& code == other.code&& span == other.span&& message == other.message&& fix == other.fix;
+        
+            }
+
+class BloxDocument  {
+                final List<BloxBlock> blocks;
+/// The attributes of the document's `#meta` and `#document` blocks
+final BloxMetadata metadata;
+
+                const BloxDocument({required this.blocks ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => blocks.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxDocument &&
+                runtimeType == other.runtimeType
+                && blocks == other.blocks&& metadata == other.metadata;
+        
+            }
+
+class BloxFix  {
+                final BloxSpan span;
+final String replacement;
+final String message;
+
+                const BloxFix({required this.span ,required this.replacement ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => span.hashCode^replacement.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxFix &&
+                runtimeType == other.runtimeType
+                && span == other.span&& replacement == other.replacement&& message == other.message;
+        
+            }
+
+/// A flashcard and its review schedule
+class BloxFlashcard  {
+                final String id;
+/// `definition` or `question`
+final String kind;
+final String front;
+final String back;
+final BloxSpan span;
+/// `YYYY-MM-DD`; `None` for a card never reviewed
+final String? due;
+final int repetitions;
+final int intervalDays;
+final double ease;
+
+                const BloxFlashcard({required this.id ,required this.kind ,required this.front ,required this.back ,required this.span ,this.due ,required this.repetitions ,required this.intervalDays ,required this.ease ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^kind.hashCode^front.hashCode^back.hashCode^span.hashCode^due.hashCode^repetitions.hashCode^intervalDays.hashCode^ease.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxFlashcard &&
+                runtimeType == other.runtimeType
+                && id == other.id&& kind == other.kind&& front == other.front&& back == other.back&& span == other.span&& due == other.due&& repetitions == other.repetitions&& intervalDays == other.intervalDays&& ease == other.ease;
+        
+            }
+
+/// How `format_blox_string` lays out a document
+class BloxFormatStyle  {
+                final bool useShorthand;
+final BloxAttributeOrder attributeOrder;
+final BloxQuoteStyle quoteStyle;
+final BigInt blankLines;
+final bool alignTables;
+final bool normalizeLists;
+final BigInt? wrapWidth;
+
+                const BloxFormatStyle({required this.useShorthand ,required this.attributeOrder ,required this.quoteStyle ,required this.blankLines ,required this.alignTables ,required this.normalizeLists ,this.wrapWidth ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => useShorthand.hashCode^attributeOrder.hashCode^quoteStyle.hashCode^blankLines.hashCode^alignTables.hashCode^normalizeLists.hashCode^wrapWidth.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxFormatStyle &&
+                runtimeType == other.runtimeType
+                && useShorthand == other.useShorthand&& attributeOrder == other.attributeOrder&& quoteStyle == other.quoteStyle&& blankLines == other.blankLines&& alignTables == other.alignTables&& normalizeLists == other.normalizeLists&& wrapWidth == other.wrapWidth;
+        
+            }
+
+/// How the parser reads indentation in front of headers and content
+enum BloxIndentPolicy {
+                    flat,
+header,
+common,
+                    ;
+                    static Future<BloxIndentPolicy>  default_()=>RustLib.instance.api.crateApiBloxApiBloxIndentPolicyDefault();
+
+
+                }
+
+@freezed
+                sealed class BloxInlineElement with _$BloxInlineElement  {
+                    const BloxInlineElement._();
+
+                     const factory BloxInlineElement.text(  String field0,) = BloxInlineElement_Text;
+ const factory BloxInlineElement.link({   required List<BloxInlineElement> content ,  required String url , }) = BloxInlineElement_Link;
+ const factory BloxInlineElement.bold(  List<BloxInlineElement> field0,) = BloxInlineElement_Bold;
+ const factory BloxInlineElement.italic(  List<BloxInlineElement> field0,) = BloxInlineElement_Italic;
+ const factory BloxInlineElement.code({   required String code ,  String? lang , }) = BloxInlineElement_Code;
+ const factory BloxInlineElement.math(  String field0,) = BloxInlineElement_Math;
+ const factory BloxInlineElement.strikethrough(  List<BloxInlineElement> field0,) = BloxInlineElement_Strikethrough;
+ const factory BloxInlineElement.highlight(  List<BloxInlineElement> field0,) = BloxInlineElement_Highlight;
+ const factory BloxInlineElement.subscript(  List<BloxInlineElement> field0,) = BloxInlineElement_Subscript;
+ const factory BloxInlineElement.superscript(  List<BloxInlineElement> field0,) = BloxInlineElement_Superscript;
+ const factory BloxInlineElement.reference({   required String id ,  String? relation , }) = BloxInlineElement_Reference;
+ const factory BloxInlineElement.footnote({   required String id ,  required List<BloxInlineElement> content , }) = BloxInlineElement_Footnote;
+ const factory BloxInlineElement.variable(  String field0,) = BloxInlineElement_Variable;
+ const factory BloxInlineElement.custom({   required String elementType ,  required Map<String, String> attributes ,  required String content , }) = BloxInlineElement_Custom;
+
+                    
+
+                    
+                }
+
+class BloxListItem  {
+                final BloxListItemType itemType;
+final String content;
+final List<BloxListItem> children;
+final BigInt level;
+/// The item's number in an ordered list
+final BigInt? number;
+final List<BloxInlineElement> inlineElements;
+final Map<String, String> attributes;
+/// Span of the item's first line, or of its `item` block header
+final BloxSpan span;
+
+                const BloxListItem({required this.itemType ,required this.content ,required this.children ,required this.level ,this.number ,required this.inlineElements ,required this.attributes ,required this.span ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => itemType.hashCode^content.hashCode^children.hashCode^level.hashCode^number.hashCode^inlineElements.hashCode^attributes.hashCode^span.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxListItem &&
+                runtimeType == other.runtimeType
+                && itemType == other.itemType&& content == other.content&& children == other.children&& level == other.level&& number == other.number&& inlineElements == other.inlineElements&& attributes == other.attributes&& span == other.span;
+        
+            }
 
 @freezed
-sealed class BloxListItemType with _$BloxListItemType {
-  const BloxListItemType._();
+                sealed class BloxListItemType with _$BloxListItemType  {
+                    const BloxListItemType._();
+
+                     const factory BloxListItemType.unchecked() = BloxListItemType_Unchecked;
+ const factory BloxListItemType.checked() = BloxListItemType_Checked;
+ const factory BloxListItemType.definition({   required String term , }) = BloxListItemType_Definition;
+
+                    
+
+                    
+                }
+
+/// Document metadata. The typed fields are read from `entries`, which is
+/// the only part used when a document is converted back.
+class BloxMetadata  {
+                final String? title;
+final List<String> authors;
+/// `YYYY-MM-DD`
+final String? created;
+/// `YYYY-MM-DD`
+final String? modified;
+final List<String> tags;
+final List<BloxMetadataEntry> entries;
+
+                const BloxMetadata({this.title ,required this.authors ,this.created ,this.modified ,required this.tags ,required this.entries ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^authors.hashCode^created.hashCode^modified.hashCode^tags.hashCode^entries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxMetadata &&
+                runtimeType == other.runtimeType
+                && title == other.title&& authors == other.authors&& created == other.created&& modified == other.modified&& tags == other.tags&& entries == other.entries;
+        
+            }
+
+class BloxMetadataEntry  {
+                /// `meta` or `document`
+final String block;
+final String key;
+final String value;
+
+                const BloxMetadataEntry({required this.block ,required this.key ,required this.value ,});
+
+                
+                
 
-  const factory BloxListItemType.unchecked() = BloxListItemType_Unchecked;
-  const factory BloxListItemType.checked() = BloxListItemType_Checked;
-  const factory BloxListItemType.definition({
-    required String term,
-  }) = BloxListItemType_Definition;
-}
+                
+        @override
+        int get hashCode => block.hashCode^key.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxMetadataEntry &&
+                runtimeType == other.runtimeType
+                && block == other.block&& key == other.key&& value == other.value;
+        
+            }
 
 enum BloxOutputFormat {
-  html,
-  markdown,
-  json,
-  plainText,
-  ;
-}
-
-@immutable
-class BloxTable {
-  const BloxTable({
-    required this.rows,
-    this.caption,
-    this.header,
-  });
-  final String? caption;
-  final BloxTableRow? header;
-  final List<BloxTableRow> rows;
-
-  @override
-  int get hashCode => caption.hashCode ^ header.hashCode ^ rows.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxTable &&
-          runtimeType == other.runtimeType &&
-          caption == other.caption &&
-          header == other.header &&
-          rows == other.rows;
-}
-
-@immutable
-class BloxTableCell {
-  const BloxTableCell({
-    required this.content,
-    required this.colspan,
-    required this.rowspan,
-    required this.isHeader,
-  });
-  final String content;
-  final BigInt colspan;
-  final BigInt rowspan;
-  final bool isHeader;
-
-  @override
-  int get hashCode =>
-      content.hashCode ^
-      colspan.hashCode ^
-      rowspan.hashCode ^
-      isHeader.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxTableCell &&
-          runtimeType == other.runtimeType &&
-          content == other.content &&
-          colspan == other.colspan &&
-          rowspan == other.rowspan &&
-          isHeader == other.isHeader;
-}
-
-@immutable
-class BloxTableRow {
-  const BloxTableRow({
-    required this.cells,
-  });
-  final List<BloxTableCell> cells;
-
-  @override
-  int get hashCode => cells.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BloxTableRow &&
-          runtimeType == other.runtimeType &&
-          cells == other.cells;
-}
+                    html,
+markdown,
+json,
+plainText,
+gift,
+qti,
+                    ;
+                    
+                }
+
+/// The files of a decoded document, with problems found while writing them
+class BloxPackage  {
+                final List<BloxPackageFile> files;
+/// The files as a zip archive, as LMS imports expect for QTI
+final Uint8List archive;
+final List<BloxDiagnostic> diagnostics;
+
+                const BloxPackage({required this.files ,required this.archive ,required this.diagnostics ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => files.hashCode^archive.hashCode^diagnostics.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxPackage &&
+                runtimeType == other.runtimeType
+                && files == other.files&& archive == other.archive&& diagnostics == other.diagnostics;
+        
+            }
+
+class BloxPackageFile  {
+                final String path;
+final String content;
+
+                const BloxPackageFile({required this.path ,required this.content ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^content.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxPackageFile &&
+                runtimeType == other.runtimeType
+                && path == other.path&& content == other.content;
+        
+            }
+
+class BloxParseResult  {
+                final BloxDocument document;
+final List<BloxDiagnostic> diagnostics;
+
+                const BloxParseResult({required this.document ,required this.diagnostics ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => document.hashCode^diagnostics.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxParseResult &&
+                runtimeType == other.runtimeType
+                && document == other.document&& diagnostics == other.diagnostics;
+        
+            }
+
+class BloxParserOptions  {
+                final bool strictNesting;
+final BloxIndentPolicy indent;
+
+                const BloxParserOptions({required this.strictNesting ,required this.indent ,});
+
+                static Future<BloxParserOptions>  default_()=>RustLib.instance.api.crateApiBloxApiBloxParserOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => strictNesting.hashCode^indent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxParserOptions &&
+                runtimeType == other.runtimeType
+                && strictNesting == other.strictNesting&& indent == other.indent;
+        
+            }
+
+class BloxPosition  {
+                final BigInt line;
+final BigInt column;
+final BigInt offset;
+
+                const BloxPosition({required this.line ,required this.column ,required this.offset ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => line.hashCode^column.hashCode^offset.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxPosition &&
+                runtimeType == other.runtimeType
+                && line == other.line&& column == other.column&& offset == other.offset;
+        
+            }
+
+class BloxQuiz  {
+                final String? title;
+/// Text before the first question
+final String description;
+final List<BloxQuizQuestion> questions;
+
+                const BloxQuiz({this.title ,required this.description ,required this.questions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^description.hashCode^questions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxQuiz &&
+                runtimeType == other.runtimeType
+                && title == other.title&& description == other.description&& questions == other.questions;
+        
+            }
+
+class BloxQuizChoice  {
+                final String content;
+final bool correct;
+
+                const BloxQuizChoice({required this.content ,required this.correct ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => content.hashCode^correct.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxQuizChoice &&
+                runtimeType == other.runtimeType
+                && content == other.content&& correct == other.correct;
+        
+            }
+
+class BloxQuizQuestion  {
+                /// `single`, `multiple`, `truefalse` or `short`
+final String questionType;
+final String prompt;
+final List<BloxQuizChoice> choices;
+/// Accepted answers of short answer questions; `true` or `false` for true/false ones
+final List<String> answers;
+final String? explanation;
+final BigInt points;
+final String? id;
+/// Span of the question's `?` line, or of its `question` block header
+final BloxSpan span;
+
+                const BloxQuizQuestion({required this.questionType ,required this.prompt ,required this.choices ,required this.answers ,this.explanation ,required this.points ,this.id ,required this.span ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => questionType.hashCode^prompt.hashCode^choices.hashCode^answers.hashCode^explanation.hashCode^points.hashCode^id.hashCode^span.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxQuizQuestion &&
+                runtimeType == other.runtimeType
+                && questionType == other.questionType&& prompt == other.prompt&& choices == other.choices&& answers == other.answers&& explanation == other.explanation&& points == other.points&& id == other.id&& span == other.span;
+        
+            }
+
+enum BloxQuoteStyle {
+                    minimal,
+double,
+single,
+                    ;
+                    
+                }
+
+/// The block an id refers to, for jumping to it in the editor
+class BloxReferenceTarget  {
+                /// Indexes from `BloxDocument.blocks` down through `children`
+final Uint64List path;
+final BigInt lineNumber;
+final BloxSpan headerSpan;
+/// Whether the id is a slug made for a heading rather than written in the source
+final bool generated;
+
+                const BloxReferenceTarget({required this.path ,required this.lineNumber ,required this.headerSpan ,required this.generated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^lineNumber.hashCode^headerSpan.hashCode^generated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxReferenceTarget &&
+                runtimeType == other.runtimeType
+                && path == other.path&& lineNumber == other.lineNumber&& headerSpan == other.headerSpan&& generated == other.generated;
+        
+            }
+
+enum BloxSeverity {
+                    error,
+warning,
+info,
+hint,
+                    ;
+                    
+                }
+
+class BloxSpan  {
+                final BloxPosition start;
+final BloxPosition end;
+
+                const BloxSpan({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxSpan &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+class BloxTable  {
+                final String? caption;
+final List<BloxColumnSpec> columns;
+final BloxTableRow? header;
+final List<BloxTableRow> rows;
+
+                const BloxTable({this.caption ,required this.columns ,this.header ,required this.rows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => caption.hashCode^columns.hashCode^header.hashCode^rows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTable &&
+                runtimeType == other.runtimeType
+                && caption == other.caption&& columns == other.columns&& header == other.header&& rows == other.rows;
+        
+            }
+
+class BloxTableCell  {
+                final String content;
+final BigInt colspan;
+final BigInt rowspan;
+final bool isHeader;
+final BloxCellValue value;
+
+                const BloxTableCell({required this.content ,required this.colspan ,required this.rowspan ,required this.isHeader ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => content.hashCode^colspan.hashCode^rowspan.hashCode^isHeader.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTableCell &&
+                runtimeType == other.runtimeType
+                && content == other.content&& colspan == other.colspan&& rowspan == other.rowspan&& isHeader == other.isHeader&& value == other.value;
+        
+            }
+
+class BloxTableEditResult  {
+                /// The table block, parsed from the edited source
+final BloxBlock block;
+/// Edits to apply to the source, in source order
+final List<BloxTextEdit> edits;
+
+                const BloxTableEditResult({required this.block ,required this.edits ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => block.hashCode^edits.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTableEditResult &&
+                runtimeType == other.runtimeType
+                && block == other.block&& edits == other.edits;
+        
+            }
+
+@freezed
+                sealed class BloxTableOp with _$BloxTableOp  {
+                    const BloxTableOp._();
+
+                     const factory BloxTableOp.insertRow({   required BigInt index ,  required List<String> cells , }) = BloxTableOp_InsertRow;
+ const factory BloxTableOp.deleteRow({   required BigInt index , }) = BloxTableOp_DeleteRow;
+ const factory BloxTableOp.moveRow({   required BigInt from ,  required BigInt to , }) = BloxTableOp_MoveRow;
+ const factory BloxTableOp.insertColumn({   required BigInt index ,  String? header , }) = BloxTableOp_InsertColumn;
+ const factory BloxTableOp.deleteColumn({   required BigInt index , }) = BloxTableOp_DeleteColumn;
+ const factory BloxTableOp.moveColumn({   required BigInt from ,  required BigInt to , }) = BloxTableOp_MoveColumn;
+ const factory BloxTableOp.sort({   required BigInt column ,  required bool descending , }) = BloxTableOp_Sort;
+
+                    
+
+                    
+                }
+
+class BloxTableRow  {
+                final List<BloxTableCell> cells;
+
+                const BloxTableRow({required this.cells ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cells.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTableRow &&
+                runtimeType == other.runtimeType
+                && cells == other.cells;
+        
+            }
+
+/// A check list item in one of the files scanned
+class BloxTask  {
+                final String file;
+final String text;
+final bool checked;
+/// Span of the item's line, or of its `item` block header
+final BloxSpan span;
+/// Titles of the sections around the list, outermost first
+final List<String> sectionPath;
+final String? due;
+final String? assignee;
+
+                const BloxTask({required this.file ,required this.text ,required this.checked ,required this.span ,required this.sectionPath ,this.due ,this.assignee ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => file.hashCode^text.hashCode^checked.hashCode^span.hashCode^sectionPath.hashCode^due.hashCode^assignee.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTask &&
+                runtimeType == other.runtimeType
+                && file == other.file&& text == other.text&& checked == other.checked&& span == other.span&& sectionPath == other.sectionPath&& due == other.due&& assignee == other.assignee;
+        
+            }
+
+class BloxTextEdit  {
+                final BloxSpan span;
+final String replacement;
+
+                const BloxTextEdit({required this.span ,required this.replacement ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => span.hashCode^replacement.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BloxTextEdit &&
+                runtimeType == other.runtimeType
+                && span == other.span&& replacement == other.replacement;
+        
+            }
+            
\ No newline at end of file
@@ -1,1355 +1,2284 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.11.1.
 
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+
+import 'api/blox_api.dart';
+import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
-
+import 'frb_generated.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-import 'package:loom/src/rust/api/blox_api.dart';
-import 'package:loom/src/rust/api/simple.dart';
-import 'package:loom/src/rust/frb_generated.dart';
-import 'package:loom/src/rust/frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
-
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  RustLib._();
-  @internal
-  static final instance = RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1149422544;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_loom',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateApiBloxApiDecodeBloxDocument({
-    required BloxDocument document,
-    required BloxOutputFormat format,
-  });
-
-  String crateApiBloxApiEncodeBloxDocument({
-    required BloxDocument document,
-    required bool useShorthand,
-  });
-
-  String crateApiSimpleGreet({required String name});
-
-  Future<void> crateApiSimpleInitApp();
-
-  Future<BloxDocument> crateApiBloxApiParseBloxFile({required String filePath});
-
-  BloxDocument crateApiBloxApiParseBloxString({required String content});
-
-  List<String> crateApiBloxApiValidateBloxSyntax({required String content});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateApiBloxApiDecodeBloxDocument({
-    required BloxDocument document,
-    required BloxOutputFormat format,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_blox_document(document, serializer);
-          sse_encode_blox_output_format(format, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 1065454388;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_loom',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  List<BloxFlashcard> crateApiBloxApiBloxFlashcards({required String filePath });
+
+Future<BloxIndentPolicy> crateApiBloxApiBloxIndentPolicyDefault();
+
+Future<BloxParserOptions> crateApiBloxApiBloxParserOptionsDefault();
+
+bool crateApiBloxApiCheckBloxFormat({required String content , required BloxFormatStyle style });
+
+String crateApiBloxApiDecodeBloxAnswerKey({required BloxDocument document });
+
+String crateApiBloxApiDecodeBloxDocument({required BloxDocument document , required BloxOutputFormat format });
+
+BloxPackage crateApiBloxApiDecodeBloxPackage({required BloxDocument document , required BloxOutputFormat format });
+
+List<BloxFlashcard> crateApiBloxApiDueBloxFlashcards({required String filePath , String? today });
+
+BloxTableEditResult crateApiBloxApiEditBloxTable({required String content , required Uint64List path , required BloxTableOp op });
+
+String crateApiBloxApiEncodeBloxDocument({required BloxDocument document , required bool useShorthand });
+
+String crateApiBloxApiEncodeBloxDocumentFromModels({required BloxDocument document , required bool useShorthand });
+
+String crateApiBloxApiEncodeBloxDocumentPreserving({required String source , required BloxDocument document , required bool useShorthand });
+
+BloxTableEditResult crateApiBloxApiFilterBloxTableRows({required String content , required Uint64List path , BigInt? column , required String query });
+
+String crateApiBloxApiFormatBloxString({required String content , required BloxFormatStyle style });
+
+String crateApiSimpleGreet({required String name });
+
+Future<void> crateApiSimpleInitApp();
+
+BloxParseResult crateApiBloxApiInterpolateBloxVariables({required BloxDocument document , required Map<String, String> variables });
+
+BloxParseResult crateApiBloxApiLoadBloxTables({required BloxDocument document , required String baseDir });
+
+Future<BloxDocument> crateApiBloxApiParseBloxFile({required String filePath });
+
+BloxDocument crateApiBloxApiParseBloxString({required String content });
+
+BloxParseResult crateApiBloxApiParseBloxStringWithDiagnostics({required String content , required BloxParserOptions options });
+
+BloxReferenceTarget? crateApiBloxApiResolveReference({required BloxDocument document , required String id });
+
+BloxFlashcard crateApiBloxApiReviewBloxFlashcard({required String filePath , required String cardId , required int grade , String? today });
+
+List<BloxTask> crateApiBloxApiScanBloxTasks({required List<String> paths });
+
+BloxTask crateApiBloxApiToggleBloxTask({required String file , required BloxSpan span });
+
+List<String> crateApiBloxApiValidateBloxSyntax({required String content });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override List<BloxFlashcard> crateApiBloxApiBloxFlashcards({required String filePath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_blox_flashcard,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiBloxFlashcardsConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiBloxFlashcardsConstMeta => const TaskConstMeta(
+            debugName: "blox_flashcards",
+            argNames: ["filePath"],
+        );
+        
+
+@override Future<BloxIndentPolicy> crateApiBloxApiBloxIndentPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_indent_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiBloxIndentPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiBloxIndentPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "blox_indent_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<BloxParserOptions> crateApiBloxApiBloxParserOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_parser_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiBloxParserOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiBloxParserOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "blox_parser_options_default",
+            argNames: [],
+        );
+        
+
+@override bool crateApiBloxApiCheckBloxFormat({required String content , required BloxFormatStyle style })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_box_autoadd_blox_format_style(style, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiCheckBloxFormatConstMeta,
+            argValues: [content, style],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiCheckBloxFormatConstMeta => const TaskConstMeta(
+            debugName: "check_blox_format",
+            argNames: ["content", "style"],
+        );
+        
+
+@override String crateApiBloxApiDecodeBloxAnswerKey({required BloxDocument document })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiBloxApiDecodeBloxDocumentConstMeta,
-        argValues: [document, format],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBloxApiDecodeBloxDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: 'decode_blox_document',
-        argNames: ['document', 'format'],
-      );
-
-  @override
-  String crateApiBloxApiEncodeBloxDocument({
-    required BloxDocument document,
-    required bool useShorthand,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_blox_document(document, serializer);
-          sse_encode_bool(useShorthand, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBloxApiDecodeBloxAnswerKeyConstMeta,
+            argValues: [document],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiDecodeBloxAnswerKeyConstMeta => const TaskConstMeta(
+            debugName: "decode_blox_answer_key",
+            argNames: ["document"],
+        );
+        
+
+@override String crateApiBloxApiDecodeBloxDocument({required BloxDocument document , required BloxOutputFormat format })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_blox_output_format(format, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiBloxApiEncodeBloxDocumentConstMeta,
-        argValues: [document, useShorthand],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBloxApiEncodeBloxDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: 'encode_blox_document',
-        argNames: ['document', 'useShorthand'],
-      );
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiBloxApiDecodeBloxDocumentConstMeta,
+            argValues: [document, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiDecodeBloxDocumentConstMeta => const TaskConstMeta(
+            debugName: "decode_blox_document",
+            argNames: ["document", "format"],
+        );
+        
+
+@override BloxPackage crateApiBloxApiDecodeBloxPackage({required BloxDocument document , required BloxOutputFormat format })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_blox_output_format(format, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_package,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiDecodeBloxPackageConstMeta,
+            argValues: [document, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiDecodeBloxPackageConstMeta => const TaskConstMeta(
+            debugName: "decode_blox_package",
+            argNames: ["document", "format"],
+        );
+        
+
+@override List<BloxFlashcard> crateApiBloxApiDueBloxFlashcards({required String filePath , String? today })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+sse_encode_opt_String(today, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_blox_flashcard,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiDueBloxFlashcardsConstMeta,
+            argValues: [filePath, today],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiDueBloxFlashcardsConstMeta => const TaskConstMeta(
+            debugName: "due_blox_flashcards",
+            argNames: ["filePath", "today"],
+        );
+        
+
+@override BloxTableEditResult crateApiBloxApiEditBloxTable({required String content , required Uint64List path , required BloxTableOp op })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_list_prim_usize_strict(path, serializer);
+sse_encode_box_autoadd_blox_table_op(op, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_table_edit_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiEditBloxTableConstMeta,
+            argValues: [content, path, op],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiEditBloxTableConstMeta => const TaskConstMeta(
+            debugName: "edit_blox_table",
+            argNames: ["content", "path", "op"],
+        );
+        
+
+@override String crateApiBloxApiEncodeBloxDocument({required BloxDocument document , required bool useShorthand })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_bool(useShorthand, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
-        debugName: 'greet',
-        argNames: ['name'],
-      );
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
-        debugName: 'init_app',
-        argNames: [],
-      );
-
-  @override
-  Future<BloxDocument> crateApiBloxApiParseBloxFile({
-    required String filePath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_blox_document,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiBloxApiParseBloxFileConstMeta,
-        argValues: [filePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBloxApiParseBloxFileConstMeta =>
-      const TaskConstMeta(
-        debugName: 'parse_blox_file',
-        argNames: ['filePath'],
-      );
-
-  @override
-  BloxDocument crateApiBloxApiParseBloxString({required String content}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_blox_document,
+        )
+        ,
+            constMeta: kCrateApiBloxApiEncodeBloxDocumentConstMeta,
+            argValues: [document, useShorthand],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiEncodeBloxDocumentConstMeta => const TaskConstMeta(
+            debugName: "encode_blox_document",
+            argNames: ["document", "useShorthand"],
+        );
+        
+
+@override String crateApiBloxApiEncodeBloxDocumentFromModels({required BloxDocument document , required bool useShorthand })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_bool(useShorthand, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiBloxApiParseBloxStringConstMeta,
-        argValues: [content],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBloxApiParseBloxStringConstMeta =>
-      const TaskConstMeta(
-        debugName: 'parse_blox_string',
-        argNames: ['content'],
-      );
-
-  @override
-  List<String> crateApiBloxApiValidateBloxSyntax({required String content}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiEncodeBloxDocumentFromModelsConstMeta,
+            argValues: [document, useShorthand],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiEncodeBloxDocumentFromModelsConstMeta => const TaskConstMeta(
+            debugName: "encode_blox_document_from_models",
+            argNames: ["document", "useShorthand"],
+        );
+        
+
+@override String crateApiBloxApiEncodeBloxDocumentPreserving({required String source , required BloxDocument document , required bool useShorthand })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(source, serializer);
+sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_bool(useShorthand, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiBloxApiValidateBloxSyntaxConstMeta,
-        argValues: [content],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiBloxApiValidateBloxSyntaxConstMeta =>
-      const TaskConstMeta(
-        debugName: 'validate_blox_syntax',
-        argNames: ['content'],
-      );
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(raw)
-          .map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  BloxBlock dco_decode_blox_block(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return BloxBlock(
-      blockType: dco_decode_String(arr[0]),
-      level: dco_decode_usize(arr[1]),
-      attributes: dco_decode_Map_String_String_None(arr[2]),
-      content: dco_decode_String(arr[3]),
-      children: dco_decode_list_blox_block(arr[4]),
-      lineNumber: dco_decode_usize(arr[5]),
-      inlineElements: dco_decode_list_blox_inline_element(arr[6]),
-      listItems: dco_decode_list_blox_list_item(arr[7]),
-      table: dco_decode_opt_box_autoadd_blox_table(arr[8]),
-    );
-  }
-
-  @protected
-  BloxDocument dco_decode_blox_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return BloxDocument(
-      blocks: dco_decode_list_blox_block(arr[0]),
-      metadata: dco_decode_Map_String_String_None(arr[1]),
-    );
-  }
-
-  @protected
-  BloxInlineElement dco_decode_blox_inline_element(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return BloxInlineElement_Text(
-          dco_decode_String(raw[1]),
+        )
+        ,
+            constMeta: kCrateApiBloxApiEncodeBloxDocumentPreservingConstMeta,
+            argValues: [source, document, useShorthand],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiEncodeBloxDocumentPreservingConstMeta => const TaskConstMeta(
+            debugName: "encode_blox_document_preserving",
+            argNames: ["source", "document", "useShorthand"],
         );
-      case 1:
-        return BloxInlineElement_Link(
-          text: dco_decode_String(raw[1]),
-          url: dco_decode_String(raw[2]),
+        
+
+@override BloxTableEditResult crateApiBloxApiFilterBloxTableRows({required String content , required Uint64List path , BigInt? column , required String query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_list_prim_usize_strict(path, serializer);
+sse_encode_opt_box_autoadd_usize(column, serializer);
+sse_encode_String(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_table_edit_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiFilterBloxTableRowsConstMeta,
+            argValues: [content, path, column, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiFilterBloxTableRowsConstMeta => const TaskConstMeta(
+            debugName: "filter_blox_table_rows",
+            argNames: ["content", "path", "column", "query"],
         );
-      case 2:
-        return BloxInlineElement_Bold(
-          dco_decode_String(raw[1]),
+        
+
+@override String crateApiBloxApiFormatBloxString({required String content , required BloxFormatStyle style })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_box_autoadd_blox_format_style(style, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiFormatBloxStringConstMeta,
+            argValues: [content, style],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiFormatBloxStringConstMeta => const TaskConstMeta(
+            debugName: "format_blox_string",
+            argNames: ["content", "style"],
         );
-      case 3:
-        return BloxInlineElement_Italic(
-          dco_decode_String(raw[1]),
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
         );
-      case 4:
-        return BloxInlineElement_Code(
-          dco_decode_String(raw[1]),
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
         );
-      case 5:
-        return BloxInlineElement_Math(
-          dco_decode_String(raw[1]),
+        
+
+@override BloxParseResult crateApiBloxApiInterpolateBloxVariables({required BloxDocument document , required Map<String, String> variables })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_Map_String_String_None(variables, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_parse_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiInterpolateBloxVariablesConstMeta,
+            argValues: [document, variables],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiInterpolateBloxVariablesConstMeta => const TaskConstMeta(
+            debugName: "interpolate_blox_variables",
+            argNames: ["document", "variables"],
         );
-      case 6:
-        return BloxInlineElement_Strikethrough(
-          dco_decode_String(raw[1]),
+        
+
+@override BloxParseResult crateApiBloxApiLoadBloxTables({required BloxDocument document , required String baseDir })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_String(baseDir, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_parse_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiLoadBloxTablesConstMeta,
+            argValues: [document, baseDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiLoadBloxTablesConstMeta => const TaskConstMeta(
+            debugName: "load_blox_tables",
+            argNames: ["document", "baseDir"],
         );
-      case 7:
-        return BloxInlineElement_Highlight(
-          dco_decode_String(raw[1]),
+        
+
+@override Future<BloxDocument> crateApiBloxApiParseBloxFile({required String filePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_document,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiParseBloxFileConstMeta,
+            argValues: [filePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiParseBloxFileConstMeta => const TaskConstMeta(
+            debugName: "parse_blox_file",
+            argNames: ["filePath"],
         );
-      case 8:
-        return BloxInlineElement_Subscript(
-          dco_decode_String(raw[1]),
+        
+
+@override BloxDocument crateApiBloxApiParseBloxString({required String content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_document,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiParseBloxStringConstMeta,
+            argValues: [content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiParseBloxStringConstMeta => const TaskConstMeta(
+            debugName: "parse_blox_string",
+            argNames: ["content"],
         );
-      case 9:
-        return BloxInlineElement_Superscript(
-          dco_decode_String(raw[1]),
+        
+
+@override BloxParseResult crateApiBloxApiParseBloxStringWithDiagnostics({required String content , required BloxParserOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+sse_encode_box_autoadd_blox_parser_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_parse_result,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiParseBloxStringWithDiagnosticsConstMeta,
+            argValues: [content, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiParseBloxStringWithDiagnosticsConstMeta => const TaskConstMeta(
+            debugName: "parse_blox_string_with_diagnostics",
+            argNames: ["content", "options"],
         );
-      case 10:
-        return BloxInlineElement_Reference(
-          dco_decode_String(raw[1]),
+        
+
+@override BloxReferenceTarget? crateApiBloxApiResolveReference({required BloxDocument document , required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_blox_document(document, serializer);
+sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_blox_reference_target,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBloxApiResolveReferenceConstMeta,
+            argValues: [document, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiResolveReferenceConstMeta => const TaskConstMeta(
+            debugName: "resolve_reference",
+            argNames: ["document", "id"],
         );
-      case 11:
-        return BloxInlineElement_Footnote(
-          id: dco_decode_String(raw[1]),
-          text: dco_decode_String(raw[2]),
+        
+
+@override BloxFlashcard crateApiBloxApiReviewBloxFlashcard({required String filePath , required String cardId , required int grade , String? today })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(filePath, serializer);
+sse_encode_String(cardId, serializer);
+sse_encode_u_8(grade, serializer);
+sse_encode_opt_String(today, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_flashcard,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiReviewBloxFlashcardConstMeta,
+            argValues: [filePath, cardId, grade, today],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiReviewBloxFlashcardConstMeta => const TaskConstMeta(
+            debugName: "review_blox_flashcard",
+            argNames: ["filePath", "cardId", "grade", "today"],
         );
-      case 12:
-        return BloxInlineElement_Custom(
-          elementType: dco_decode_String(raw[1]),
-          attributes: dco_decode_Map_String_String_None(raw[2]),
-          content: dco_decode_String(raw[3]),
+        
+
+@override List<BloxTask> crateApiBloxApiScanBloxTasks({required List<String> paths })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_blox_task,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiScanBloxTasksConstMeta,
+            argValues: [paths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiScanBloxTasksConstMeta => const TaskConstMeta(
+            debugName: "scan_blox_tasks",
+            argNames: ["paths"],
         );
-      default:
-        throw Exception('unreachable');
-    }
-  }
-
-  @protected
-  BloxListItem dco_decode_blox_list_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return BloxListItem(
-      itemType: dco_decode_blox_list_item_type(arr[0]),
-      content: dco_decode_String(arr[1]),
-      children: dco_decode_list_blox_list_item(arr[2]),
-      level: dco_decode_usize(arr[3]),
-    );
-  }
-
-  @protected
-  BloxListItemType dco_decode_blox_list_item_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return const BloxListItemType_Unchecked();
-      case 1:
-        return const BloxListItemType_Checked();
-      case 2:
-        return BloxListItemType_Definition(
-          term: dco_decode_String(raw[1]),
+        
+
+@override BloxTask crateApiBloxApiToggleBloxTask({required String file , required BloxSpan span })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(file, serializer);
+sse_encode_box_autoadd_blox_span(span, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blox_task,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiToggleBloxTaskConstMeta,
+            argValues: [file, span],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiToggleBloxTaskConstMeta => const TaskConstMeta(
+            debugName: "toggle_blox_task",
+            argNames: ["file", "span"],
         );
-      default:
-        throw Exception('unreachable');
-    }
-  }
-
-  @protected
-  BloxOutputFormat dco_decode_blox_output_format(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BloxOutputFormat.values[raw as int];
-  }
-
-  @protected
-  BloxTable dco_decode_blox_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return BloxTable(
-      caption: dco_decode_opt_String(arr[0]),
-      header: dco_decode_opt_box_autoadd_blox_table_row(arr[1]),
-      rows: dco_decode_list_blox_table_row(arr[2]),
-    );
-  }
-
-  @protected
-  BloxTableCell dco_decode_blox_table_cell(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return BloxTableCell(
-      content: dco_decode_String(arr[0]),
-      colspan: dco_decode_usize(arr[1]),
-      rowspan: dco_decode_usize(arr[2]),
-      isHeader: dco_decode_bool(arr[3]),
-    );
-  }
-
-  @protected
-  BloxTableRow dco_decode_blox_table_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return BloxTableRow(
-      cells: dco_decode_list_blox_table_cell(arr[0]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  BloxDocument dco_decode_box_autoadd_blox_document(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_blox_document(raw);
-  }
-
-  @protected
-  BloxTable dco_decode_box_autoadd_blox_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_blox_table(raw);
-  }
-
-  @protected
-  BloxTableRow dco_decode_box_autoadd_blox_table_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_blox_table_row(raw);
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<BloxBlock> dco_decode_list_blox_block(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_blox_block).toList();
-  }
-
-  @protected
-  List<BloxInlineElement> dco_decode_list_blox_inline_element(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_blox_inline_element).toList();
-  }
-
-  @protected
-  List<BloxListItem> dco_decode_list_blox_list_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_blox_list_item).toList();
-  }
-
-  @protected
-  List<BloxTableCell> dco_decode_list_blox_table_cell(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_blox_table_cell).toList();
-  }
-
-  @protected
-  List<BloxTableRow> dco_decode_list_blox_table_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_blox_table_row).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  BloxTable? dco_decode_opt_box_autoadd_blox_table(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_blox_table(raw);
-  }
-
-  @protected
-  BloxTableRow? dco_decode_opt_box_autoadd_blox_table_row(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_blox_table_row(raw);
-  }
-
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_String(arr[1]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  BloxBlock sse_decode_blox_block(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_blockType = sse_decode_String(deserializer);
-    final var_level = sse_decode_usize(deserializer);
-    final var_attributes = sse_decode_Map_String_String_None(deserializer);
-    final var_content = sse_decode_String(deserializer);
-    final var_children = sse_decode_list_blox_block(deserializer);
-    final var_lineNumber = sse_decode_usize(deserializer);
-    final var_inlineElements =
-        sse_decode_list_blox_inline_element(deserializer);
-    final var_listItems = sse_decode_list_blox_list_item(deserializer);
-    final var_table = sse_decode_opt_box_autoadd_blox_table(deserializer);
-    return BloxBlock(
-      blockType: var_blockType,
-      level: var_level,
-      attributes: var_attributes,
-      content: var_content,
-      children: var_children,
-      lineNumber: var_lineNumber,
-      inlineElements: var_inlineElements,
-      listItems: var_listItems,
-      table: var_table,
-    );
-  }
-
-  @protected
-  BloxDocument sse_decode_blox_document(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_blocks = sse_decode_list_blox_block(deserializer);
-    final var_metadata = sse_decode_Map_String_String_None(deserializer);
-    return BloxDocument(blocks: var_blocks, metadata: var_metadata);
-  }
-
-  @protected
-  BloxInlineElement sse_decode_blox_inline_element(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Text(var_field0);
-      case 1:
-        final var_text = sse_decode_String(deserializer);
-        final var_url = sse_decode_String(deserializer);
-        return BloxInlineElement_Link(text: var_text, url: var_url);
-      case 2:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Bold(var_field0);
-      case 3:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Italic(var_field0);
-      case 4:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Code(var_field0);
-      case 5:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Math(var_field0);
-      case 6:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Strikethrough(var_field0);
-      case 7:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Highlight(var_field0);
-      case 8:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Subscript(var_field0);
-      case 9:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Superscript(var_field0);
-      case 10:
-        final var_field0 = sse_decode_String(deserializer);
-        return BloxInlineElement_Reference(var_field0);
-      case 11:
-        final var_id = sse_decode_String(deserializer);
-        final var_text = sse_decode_String(deserializer);
-        return BloxInlineElement_Footnote(id: var_id, text: var_text);
-      case 12:
-        final var_elementType = sse_decode_String(deserializer);
-        final var_attributes = sse_decode_Map_String_String_None(deserializer);
-        final var_content = sse_decode_String(deserializer);
-        return BloxInlineElement_Custom(
-          elementType: var_elementType,
-          attributes: var_attributes,
-          content: var_content,
+        
+
+@override List<String> crateApiBloxApiValidateBloxSyntax({required String content })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(content, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiBloxApiValidateBloxSyntaxConstMeta,
+            argValues: [content],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBloxApiValidateBloxSyntaxConstMeta => const TaskConstMeta(
+            debugName: "validate_blox_syntax",
+            argNames: ["content"],
         );
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  BloxListItem sse_decode_blox_list_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_itemType = sse_decode_blox_list_item_type(deserializer);
-    final var_content = sse_decode_String(deserializer);
-    final var_children = sse_decode_list_blox_list_item(deserializer);
-    final var_level = sse_decode_usize(deserializer);
-    return BloxListItem(
-      itemType: var_itemType,
-      content: var_content,
-      children: var_children,
-      level: var_level,
-    );
-  }
-
-  @protected
-  BloxListItemType sse_decode_blox_list_item_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return const BloxListItemType_Unchecked();
-      case 1:
-        return const BloxListItemType_Checked();
-      case 2:
-        final var_term = sse_decode_String(deserializer);
-        return BloxListItemType_Definition(term: var_term);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  BloxOutputFormat sse_decode_blox_output_format(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return BloxOutputFormat.values[inner];
-  }
-
-  @protected
-  BloxTable sse_decode_blox_table(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_caption = sse_decode_opt_String(deserializer);
-    final var_header = sse_decode_opt_box_autoadd_blox_table_row(deserializer);
-    final var_rows = sse_decode_list_blox_table_row(deserializer);
-    return BloxTable(caption: var_caption, header: var_header, rows: var_rows);
-  }
-
-  @protected
-  BloxTableCell sse_decode_blox_table_cell(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_content = sse_decode_String(deserializer);
-    final var_colspan = sse_decode_usize(deserializer);
-    final var_rowspan = sse_decode_usize(deserializer);
-    final var_isHeader = sse_decode_bool(deserializer);
-    return BloxTableCell(
-      content: var_content,
-      colspan: var_colspan,
-      rowspan: var_rowspan,
-      isHeader: var_isHeader,
-    );
-  }
-
-  @protected
-  BloxTableRow sse_decode_blox_table_row(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_cells = sse_decode_list_blox_table_cell(deserializer);
-    return BloxTableRow(cells: var_cells);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  BloxDocument sse_decode_box_autoadd_blox_document(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return sse_decode_blox_document(deserializer);
-  }
-
-  @protected
-  BloxTable sse_decode_box_autoadd_blox_table(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return sse_decode_blox_table(deserializer);
-  }
-
-  @protected
-  BloxTableRow sse_decode_box_autoadd_blox_table_row(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return sse_decode_blox_table_row(deserializer);
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<BloxBlock> sse_decode_list_blox_block(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <BloxBlock>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_blox_block(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<BloxInlineElement> sse_decode_list_blox_inline_element(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <BloxInlineElement>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_blox_inline_element(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<BloxListItem> sse_decode_list_blox_list_item(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <BloxListItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_blox_list_item(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<BloxTableCell> sse_decode_list_blox_table_cell(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <BloxTableCell>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_blox_table_cell(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<BloxTableRow> sse_decode_list_blox_table_row(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <BloxTableRow>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_blox_table_row(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return sse_decode_String(deserializer);
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BloxTable? sse_decode_opt_box_autoadd_blox_table(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return sse_decode_box_autoadd_blox_table(deserializer);
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BloxTableRow? sse_decode_opt_box_autoadd_blox_table_row(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return sse_decode_box_autoadd_blox_table_row(deserializer);
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 = sse_decode_String(deserializer);
-    final var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_blox_block(BloxBlock self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.blockType, serializer);
-    sse_encode_usize(self.level, serializer);
-    sse_encode_Map_String_String_None(self.attributes, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_list_blox_block(self.children, serializer);
-    sse_encode_usize(self.lineNumber, serializer);
-    sse_encode_list_blox_inline_element(self.inlineElements, serializer);
-    sse_encode_list_blox_list_item(self.listItems, serializer);
-    sse_encode_opt_box_autoadd_blox_table(self.table, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_document(BloxDocument self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_blox_block(self.blocks, serializer);
-    sse_encode_Map_String_String_None(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_inline_element(
-    BloxInlineElement self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case BloxInlineElement_Text(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Link(text: final text, url: final url):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(text, serializer);
-        sse_encode_String(url, serializer);
-      case BloxInlineElement_Bold(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Italic(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Code(field0: final field0):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Math(field0: final field0):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Strikethrough(field0: final field0):
-        sse_encode_i_32(6, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Highlight(field0: final field0):
-        sse_encode_i_32(7, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Subscript(field0: final field0):
-        sse_encode_i_32(8, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Superscript(field0: final field0):
-        sse_encode_i_32(9, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Reference(field0: final field0):
-        sse_encode_i_32(10, serializer);
-        sse_encode_String(field0, serializer);
-      case BloxInlineElement_Footnote(id: final id, text: final text):
-        sse_encode_i_32(11, serializer);
-        sse_encode_String(id, serializer);
-        sse_encode_String(text, serializer);
-      case BloxInlineElement_Custom(
-          elementType: final elementType,
-          attributes: final attributes,
-          content: final content
-        ):
-        sse_encode_i_32(12, serializer);
-        sse_encode_String(elementType, serializer);
-        sse_encode_Map_String_String_None(attributes, serializer);
-        sse_encode_String(content, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_blox_list_item(BloxListItem self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_blox_list_item_type(self.itemType, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_list_blox_list_item(self.children, serializer);
-    sse_encode_usize(self.level, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_list_item_type(
-    BloxListItemType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case BloxListItemType_Unchecked():
-        sse_encode_i_32(0, serializer);
-      case BloxListItemType_Checked():
-        sse_encode_i_32(1, serializer);
-      case BloxListItemType_Definition(term: final term):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(term, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_blox_output_format(
-    BloxOutputFormat self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_table(BloxTable self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.caption, serializer);
-    sse_encode_opt_box_autoadd_blox_table_row(self.header, serializer);
-    sse_encode_list_blox_table_row(self.rows, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_table_cell(
-    BloxTableCell self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.content, serializer);
-    sse_encode_usize(self.colspan, serializer);
-    sse_encode_usize(self.rowspan, serializer);
-    sse_encode_bool(self.isHeader, serializer);
-  }
-
-  @protected
-  void sse_encode_blox_table_row(BloxTableRow self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_blox_table_cell(self.cells, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_blox_document(
-    BloxDocument self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_blox_document(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_blox_table(
-    BloxTable self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_blox_table(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_blox_table_row(
-    BloxTableRow self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_blox_table_row(self, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_blox_block(
-    List<BloxBlock> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_blox_block(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_blox_inline_element(
-    List<BloxInlineElement> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_blox_inline_element(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_blox_list_item(
-    List<BloxListItem> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_blox_list_item(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_blox_table_cell(
-    List<BloxTableCell> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_blox_table_cell(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_blox_table_row(
-    List<BloxTableRow> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_blox_table_row(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_blox_table(
-    BloxTable? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_blox_table(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_blox_table_row(
-    BloxTableRow? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_blox_table_row(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
+        
+
+
+
+                  @protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected BloxAttributeOrder dco_decode_blox_attribute_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BloxAttributeOrder.values[raw as int]; }
+
+@protected BloxAttributeSpan dco_decode_blox_attribute_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxAttributeSpan(key: dco_decode_String(arr[0]),
+keySpan: dco_decode_blox_span(arr[1]),
+valueSpan: dco_decode_blox_span(arr[2]),); }
+
+@protected BloxBlock dco_decode_blox_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return BloxBlock(blockType: dco_decode_String(arr[0]),
+level: dco_decode_usize(arr[1]),
+attributes: dco_decode_Map_String_String_None(arr[2]),
+content: dco_decode_String(arr[3]),
+children: dco_decode_list_blox_block(arr[4]),
+lineNumber: dco_decode_usize(arr[5]),
+headerSpan: dco_decode_blox_span(arr[6]),
+contentSpan: dco_decode_blox_span(arr[7]),
+attributeSpans: dco_decode_list_blox_attribute_span(arr[8]),
+inlineElements: dco_decode_list_blox_inline_element(arr[9]),
+inlineSpans: dco_decode_list_blox_span(arr[10]),
+listItems: dco_decode_list_blox_list_item(arr[11]),
+table: dco_decode_opt_box_autoadd_blox_table(arr[12]),
+quiz: dco_decode_opt_box_autoadd_blox_quiz(arr[13]),); }
+
+@protected BloxCellValue dco_decode_blox_cell_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return BloxCellValue_Empty();
+case 1: return BloxCellValue_Text(dco_decode_String(raw[1]),);
+case 2: return BloxCellValue_Number(dco_decode_f_64(raw[1]),);
+case 3: return BloxCellValue_Date(dco_decode_String(raw[1]),);
+case 4: return BloxCellValue_Currency(amount: dco_decode_f_64(raw[1]),symbol: dco_decode_opt_String(raw[2]),);
+case 5: return BloxCellValue_Error(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BloxColumnSpec dco_decode_blox_column_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BloxColumnSpec(align: dco_decode_String(arr[0]),
+width: dco_decode_opt_String(arr[1]),
+columnType: dco_decode_String(arr[2]),
+decimals: dco_decode_opt_box_autoadd_usize(arr[3]),); }
+
+@protected BloxDiagnostic dco_decode_blox_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return BloxDiagnostic(severity: dco_decode_blox_severity(arr[0]),
+code: dco_decode_String(arr[1]),
+span: dco_decode_blox_span(arr[2]),
+message: dco_decode_String(arr[3]),
+fix: dco_decode_opt_box_autoadd_blox_fix(arr[4]),); }
+
+@protected BloxDocument dco_decode_blox_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxDocument(blocks: dco_decode_list_blox_block(arr[0]),
+metadata: dco_decode_blox_metadata(arr[1]),); }
+
+@protected BloxFix dco_decode_blox_fix(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxFix(span: dco_decode_blox_span(arr[0]),
+replacement: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),); }
+
+@protected BloxFlashcard dco_decode_blox_flashcard(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return BloxFlashcard(id: dco_decode_String(arr[0]),
+kind: dco_decode_String(arr[1]),
+front: dco_decode_String(arr[2]),
+back: dco_decode_String(arr[3]),
+span: dco_decode_blox_span(arr[4]),
+due: dco_decode_opt_String(arr[5]),
+repetitions: dco_decode_u_32(arr[6]),
+intervalDays: dco_decode_u_32(arr[7]),
+ease: dco_decode_f_64(arr[8]),); }
+
+@protected BloxFormatStyle dco_decode_blox_format_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return BloxFormatStyle(useShorthand: dco_decode_bool(arr[0]),
+attributeOrder: dco_decode_blox_attribute_order(arr[1]),
+quoteStyle: dco_decode_blox_quote_style(arr[2]),
+blankLines: dco_decode_usize(arr[3]),
+alignTables: dco_decode_bool(arr[4]),
+normalizeLists: dco_decode_bool(arr[5]),
+wrapWidth: dco_decode_opt_box_autoadd_usize(arr[6]),); }
+
+@protected BloxIndentPolicy dco_decode_blox_indent_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BloxIndentPolicy.values[raw as int]; }
+
+@protected BloxInlineElement dco_decode_blox_inline_element(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return BloxInlineElement_Text(dco_decode_String(raw[1]),);
+case 1: return BloxInlineElement_Link(content: dco_decode_list_blox_inline_element(raw[1]),url: dco_decode_String(raw[2]),);
+case 2: return BloxInlineElement_Bold(dco_decode_list_blox_inline_element(raw[1]),);
+case 3: return BloxInlineElement_Italic(dco_decode_list_blox_inline_element(raw[1]),);
+case 4: return BloxInlineElement_Code(code: dco_decode_String(raw[1]),lang: dco_decode_opt_String(raw[2]),);
+case 5: return BloxInlineElement_Math(dco_decode_String(raw[1]),);
+case 6: return BloxInlineElement_Strikethrough(dco_decode_list_blox_inline_element(raw[1]),);
+case 7: return BloxInlineElement_Highlight(dco_decode_list_blox_inline_element(raw[1]),);
+case 8: return BloxInlineElement_Subscript(dco_decode_list_blox_inline_element(raw[1]),);
+case 9: return BloxInlineElement_Superscript(dco_decode_list_blox_inline_element(raw[1]),);
+case 10: return BloxInlineElement_Reference(id: dco_decode_String(raw[1]),relation: dco_decode_opt_String(raw[2]),);
+case 11: return BloxInlineElement_Footnote(id: dco_decode_String(raw[1]),content: dco_decode_list_blox_inline_element(raw[2]),);
+case 12: return BloxInlineElement_Variable(dco_decode_String(raw[1]),);
+case 13: return BloxInlineElement_Custom(elementType: dco_decode_String(raw[1]),attributes: dco_decode_Map_String_String_None(raw[2]),content: dco_decode_String(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BloxListItem dco_decode_blox_list_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return BloxListItem(itemType: dco_decode_blox_list_item_type(arr[0]),
+content: dco_decode_String(arr[1]),
+children: dco_decode_list_blox_list_item(arr[2]),
+level: dco_decode_usize(arr[3]),
+number: dco_decode_opt_box_autoadd_usize(arr[4]),
+inlineElements: dco_decode_list_blox_inline_element(arr[5]),
+attributes: dco_decode_Map_String_String_None(arr[6]),
+span: dco_decode_blox_span(arr[7]),); }
+
+@protected BloxListItemType dco_decode_blox_list_item_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return BloxListItemType_Unchecked();
+case 1: return BloxListItemType_Checked();
+case 2: return BloxListItemType_Definition(term: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BloxMetadata dco_decode_blox_metadata(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return BloxMetadata(title: dco_decode_opt_String(arr[0]),
+authors: dco_decode_list_String(arr[1]),
+created: dco_decode_opt_String(arr[2]),
+modified: dco_decode_opt_String(arr[3]),
+tags: dco_decode_list_String(arr[4]),
+entries: dco_decode_list_blox_metadata_entry(arr[5]),); }
+
+@protected BloxMetadataEntry dco_decode_blox_metadata_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxMetadataEntry(block: dco_decode_String(arr[0]),
+key: dco_decode_String(arr[1]),
+value: dco_decode_String(arr[2]),); }
+
+@protected BloxOutputFormat dco_decode_blox_output_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BloxOutputFormat.values[raw as int]; }
+
+@protected BloxPackage dco_decode_blox_package(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxPackage(files: dco_decode_list_blox_package_file(arr[0]),
+archive: dco_decode_list_prim_u_8_strict(arr[1]),
+diagnostics: dco_decode_list_blox_diagnostic(arr[2]),); }
+
+@protected BloxPackageFile dco_decode_blox_package_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxPackageFile(path: dco_decode_String(arr[0]),
+content: dco_decode_String(arr[1]),); }
+
+@protected BloxParseResult dco_decode_blox_parse_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxParseResult(document: dco_decode_blox_document(arr[0]),
+diagnostics: dco_decode_list_blox_diagnostic(arr[1]),); }
+
+@protected BloxParserOptions dco_decode_blox_parser_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxParserOptions(strictNesting: dco_decode_bool(arr[0]),
+indent: dco_decode_blox_indent_policy(arr[1]),); }
+
+@protected BloxPosition dco_decode_blox_position(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxPosition(line: dco_decode_usize(arr[0]),
+column: dco_decode_usize(arr[1]),
+offset: dco_decode_usize(arr[2]),); }
+
+@protected BloxQuiz dco_decode_blox_quiz(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BloxQuiz(title: dco_decode_opt_String(arr[0]),
+description: dco_decode_String(arr[1]),
+questions: dco_decode_list_blox_quiz_question(arr[2]),); }
+
+@protected BloxQuizChoice dco_decode_blox_quiz_choice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxQuizChoice(content: dco_decode_String(arr[0]),
+correct: dco_decode_bool(arr[1]),); }
+
+@protected BloxQuizQuestion dco_decode_blox_quiz_question(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return BloxQuizQuestion(questionType: dco_decode_String(arr[0]),
+prompt: dco_decode_String(arr[1]),
+choices: dco_decode_list_blox_quiz_choice(arr[2]),
+answers: dco_decode_list_String(arr[3]),
+explanation: dco_decode_opt_String(arr[4]),
+points: dco_decode_usize(arr[5]),
+id: dco_decode_opt_String(arr[6]),
+span: dco_decode_blox_span(arr[7]),); }
+
+@protected BloxQuoteStyle dco_decode_blox_quote_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BloxQuoteStyle.values[raw as int]; }
+
+@protected BloxReferenceTarget dco_decode_blox_reference_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BloxReferenceTarget(path: dco_decode_list_prim_usize_strict(arr[0]),
+lineNumber: dco_decode_usize(arr[1]),
+headerSpan: dco_decode_blox_span(arr[2]),
+generated: dco_decode_bool(arr[3]),); }
+
+@protected BloxSeverity dco_decode_blox_severity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BloxSeverity.values[raw as int]; }
+
+@protected BloxSpan dco_decode_blox_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxSpan(start: dco_decode_blox_position(arr[0]),
+end: dco_decode_blox_position(arr[1]),); }
+
+@protected BloxTable dco_decode_blox_table(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BloxTable(caption: dco_decode_opt_String(arr[0]),
+columns: dco_decode_list_blox_column_spec(arr[1]),
+header: dco_decode_opt_box_autoadd_blox_table_row(arr[2]),
+rows: dco_decode_list_blox_table_row(arr[3]),); }
+
+@protected BloxTableCell dco_decode_blox_table_cell(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return BloxTableCell(content: dco_decode_String(arr[0]),
+colspan: dco_decode_usize(arr[1]),
+rowspan: dco_decode_usize(arr[2]),
+isHeader: dco_decode_bool(arr[3]),
+value: dco_decode_blox_cell_value(arr[4]),); }
+
+@protected BloxTableEditResult dco_decode_blox_table_edit_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxTableEditResult(block: dco_decode_blox_block(arr[0]),
+edits: dco_decode_list_blox_text_edit(arr[1]),); }
+
+@protected BloxTableOp dco_decode_blox_table_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return BloxTableOp_InsertRow(index: dco_decode_usize(raw[1]),cells: dco_decode_list_String(raw[2]),);
+case 1: return BloxTableOp_DeleteRow(index: dco_decode_usize(raw[1]),);
+case 2: return BloxTableOp_MoveRow(from: dco_decode_usize(raw[1]),to: dco_decode_usize(raw[2]),);
+case 3: return BloxTableOp_InsertColumn(index: dco_decode_usize(raw[1]),header: dco_decode_opt_String(raw[2]),);
+case 4: return BloxTableOp_DeleteColumn(index: dco_decode_usize(raw[1]),);
+case 5: return BloxTableOp_MoveColumn(from: dco_decode_usize(raw[1]),to: dco_decode_usize(raw[2]),);
+case 6: return BloxTableOp_Sort(column: dco_decode_usize(raw[1]),descending: dco_decode_bool(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BloxTableRow dco_decode_blox_table_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return BloxTableRow(cells: dco_decode_list_blox_table_cell(arr[0]),); }
+
+@protected BloxTask dco_decode_blox_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return BloxTask(file: dco_decode_String(arr[0]),
+text: dco_decode_String(arr[1]),
+checked: dco_decode_bool(arr[2]),
+span: dco_decode_blox_span(arr[3]),
+sectionPath: dco_decode_list_String(arr[4]),
+due: dco_decode_opt_String(arr[5]),
+assignee: dco_decode_opt_String(arr[6]),); }
+
+@protected BloxTextEdit dco_decode_blox_text_edit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BloxTextEdit(span: dco_decode_blox_span(arr[0]),
+replacement: dco_decode_String(arr[1]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected BloxDocument dco_decode_box_autoadd_blox_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_document(raw); }
+
+@protected BloxFix dco_decode_box_autoadd_blox_fix(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_fix(raw); }
+
+@protected BloxFormatStyle dco_decode_box_autoadd_blox_format_style(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_format_style(raw); }
+
+@protected BloxParserOptions dco_decode_box_autoadd_blox_parser_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_parser_options(raw); }
+
+@protected BloxQuiz dco_decode_box_autoadd_blox_quiz(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_quiz(raw); }
+
+@protected BloxReferenceTarget dco_decode_box_autoadd_blox_reference_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_reference_target(raw); }
+
+@protected BloxSpan dco_decode_box_autoadd_blox_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_span(raw); }
+
+@protected BloxTable dco_decode_box_autoadd_blox_table(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_table(raw); }
+
+@protected BloxTableOp dco_decode_box_autoadd_blox_table_op(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_table_op(raw); }
+
+@protected BloxTableRow dco_decode_box_autoadd_blox_table_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_blox_table_row(raw); }
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<BloxAttributeSpan> dco_decode_list_blox_attribute_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_attribute_span).toList(); }
+
+@protected List<BloxBlock> dco_decode_list_blox_block(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_block).toList(); }
+
+@protected List<BloxColumnSpec> dco_decode_list_blox_column_spec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_column_spec).toList(); }
+
+@protected List<BloxDiagnostic> dco_decode_list_blox_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_diagnostic).toList(); }
+
+@protected List<BloxFlashcard> dco_decode_list_blox_flashcard(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_flashcard).toList(); }
+
+@protected List<BloxInlineElement> dco_decode_list_blox_inline_element(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_inline_element).toList(); }
+
+@protected List<BloxListItem> dco_decode_list_blox_list_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_list_item).toList(); }
+
+@protected List<BloxMetadataEntry> dco_decode_list_blox_metadata_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_metadata_entry).toList(); }
+
+@protected List<BloxPackageFile> dco_decode_list_blox_package_file(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_package_file).toList(); }
+
+@protected List<BloxQuizChoice> dco_decode_list_blox_quiz_choice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_quiz_choice).toList(); }
+
+@protected List<BloxQuizQuestion> dco_decode_list_blox_quiz_question(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_quiz_question).toList(); }
+
+@protected List<BloxSpan> dco_decode_list_blox_span(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_span).toList(); }
+
+@protected List<BloxTableCell> dco_decode_list_blox_table_cell(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_table_cell).toList(); }
+
+@protected List<BloxTableRow> dco_decode_list_blox_table_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_table_row).toList(); }
+
+@protected List<BloxTask> dco_decode_list_blox_task(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_task).toList(); }
+
+@protected List<BloxTextEdit> dco_decode_list_blox_text_edit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blox_text_edit).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected Uint64List dco_decode_list_prim_usize_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint64List; }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected BloxFix? dco_decode_opt_box_autoadd_blox_fix(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_blox_fix(raw); }
+
+@protected BloxQuiz? dco_decode_opt_box_autoadd_blox_quiz(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_blox_quiz(raw); }
+
+@protected BloxReferenceTarget? dco_decode_opt_box_autoadd_blox_reference_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_blox_reference_target(raw); }
+
+@protected BloxTable? dco_decode_opt_box_autoadd_blox_table(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_blox_table(raw); }
+
+@protected BloxTableRow? dco_decode_opt_box_autoadd_blox_table_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_blox_table_row(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected BloxAttributeOrder sse_decode_blox_attribute_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BloxAttributeOrder.values[inner]; }
+
+@protected BloxAttributeSpan sse_decode_blox_attribute_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_keySpan = sse_decode_blox_span(deserializer);
+var var_valueSpan = sse_decode_blox_span(deserializer);
+return BloxAttributeSpan(key: var_key, keySpan: var_keySpan, valueSpan: var_valueSpan); }
+
+@protected BloxBlock sse_decode_blox_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_blockType = sse_decode_String(deserializer);
+var var_level = sse_decode_usize(deserializer);
+var var_attributes = sse_decode_Map_String_String_None(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_children = sse_decode_list_blox_block(deserializer);
+var var_lineNumber = sse_decode_usize(deserializer);
+var var_headerSpan = sse_decode_blox_span(deserializer);
+var var_contentSpan = sse_decode_blox_span(deserializer);
+var var_attributeSpans = sse_decode_list_blox_attribute_span(deserializer);
+var var_inlineElements = sse_decode_list_blox_inline_element(deserializer);
+var var_inlineSpans = sse_decode_list_blox_span(deserializer);
+var var_listItems = sse_decode_list_blox_list_item(deserializer);
+var var_table = sse_decode_opt_box_autoadd_blox_table(deserializer);
+var var_quiz = sse_decode_opt_box_autoadd_blox_quiz(deserializer);
+return BloxBlock(blockType: var_blockType, level: var_level, attributes: var_attributes, content: var_content, children: var_children, lineNumber: var_lineNumber, headerSpan: var_headerSpan, contentSpan: var_contentSpan, attributeSpans: var_attributeSpans, inlineElements: var_inlineElements, inlineSpans: var_inlineSpans, listItems: var_listItems, table: var_table, quiz: var_quiz); }
+
+@protected BloxCellValue sse_decode_blox_cell_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return BloxCellValue_Empty();case 1: var var_field0 = sse_decode_String(deserializer);
+return BloxCellValue_Text(var_field0);case 2: var var_field0 = sse_decode_f_64(deserializer);
+return BloxCellValue_Number(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return BloxCellValue_Date(var_field0);case 4: var var_amount = sse_decode_f_64(deserializer);
+var var_symbol = sse_decode_opt_String(deserializer);
+return BloxCellValue_Currency(amount: var_amount, symbol: var_symbol);case 5: var var_field0 = sse_decode_String(deserializer);
+return BloxCellValue_Error(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected BloxColumnSpec sse_decode_blox_column_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_align = sse_decode_String(deserializer);
+var var_width = sse_decode_opt_String(deserializer);
+var var_columnType = sse_decode_String(deserializer);
+var var_decimals = sse_decode_opt_box_autoadd_usize(deserializer);
+return BloxColumnSpec(align: var_align, width: var_width, columnType: var_columnType, decimals: var_decimals); }
+
+@protected BloxDiagnostic sse_decode_blox_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_severity = sse_decode_blox_severity(deserializer);
+var var_code = sse_decode_String(deserializer);
+var var_span = sse_decode_blox_span(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_fix = sse_decode_opt_box_autoadd_blox_fix(deserializer);
+return BloxDiagnostic(severity: var_severity, code: var_code, span: var_span, message: var_message, fix: var_fix); }
+
+@protected BloxDocument sse_decode_blox_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_blocks = sse_decode_list_blox_block(deserializer);
+var var_metadata = sse_decode_blox_metadata(deserializer);
+return BloxDocument(blocks: var_blocks, metadata: var_metadata); }
+
+@protected BloxFix sse_decode_blox_fix(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_span = sse_decode_blox_span(deserializer);
+var var_replacement = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return BloxFix(span: var_span, replacement: var_replacement, message: var_message); }
+
+@protected BloxFlashcard sse_decode_blox_flashcard(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_front = sse_decode_String(deserializer);
+var var_back = sse_decode_String(deserializer);
+var var_span = sse_decode_blox_span(deserializer);
+var var_due = sse_decode_opt_String(deserializer);
+var var_repetitions = sse_decode_u_32(deserializer);
+var var_intervalDays = sse_decode_u_32(deserializer);
+var var_ease = sse_decode_f_64(deserializer);
+return BloxFlashcard(id: var_id, kind: var_kind, front: var_front, back: var_back, span: var_span, due: var_due, repetitions: var_repetitions, intervalDays: var_intervalDays, ease: var_ease); }
+
+@protected BloxFormatStyle sse_decode_blox_format_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_useShorthand = sse_decode_bool(deserializer);
+var var_attributeOrder = sse_decode_blox_attribute_order(deserializer);
+var var_quoteStyle = sse_decode_blox_quote_style(deserializer);
+var var_blankLines = sse_decode_usize(deserializer);
+var var_alignTables = sse_decode_bool(deserializer);
+var var_normalizeLists = sse_decode_bool(deserializer);
+var var_wrapWidth = sse_decode_opt_box_autoadd_usize(deserializer);
+return BloxFormatStyle(useShorthand: var_useShorthand, attributeOrder: var_attributeOrder, quoteStyle: var_quoteStyle, blankLines: var_blankLines, alignTables: var_alignTables, normalizeLists: var_normalizeLists, wrapWidth: var_wrapWidth); }
+
+@protected BloxIndentPolicy sse_decode_blox_indent_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BloxIndentPolicy.values[inner]; }
+
+@protected BloxInlineElement sse_decode_blox_inline_element(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return BloxInlineElement_Text(var_field0);case 1: var var_content = sse_decode_list_blox_inline_element(deserializer);
+var var_url = sse_decode_String(deserializer);
+return BloxInlineElement_Link(content: var_content, url: var_url);case 2: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Bold(var_field0);case 3: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Italic(var_field0);case 4: var var_code = sse_decode_String(deserializer);
+var var_lang = sse_decode_opt_String(deserializer);
+return BloxInlineElement_Code(code: var_code, lang: var_lang);case 5: var var_field0 = sse_decode_String(deserializer);
+return BloxInlineElement_Math(var_field0);case 6: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Strikethrough(var_field0);case 7: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Highlight(var_field0);case 8: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Subscript(var_field0);case 9: var var_field0 = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Superscript(var_field0);case 10: var var_id = sse_decode_String(deserializer);
+var var_relation = sse_decode_opt_String(deserializer);
+return BloxInlineElement_Reference(id: var_id, relation: var_relation);case 11: var var_id = sse_decode_String(deserializer);
+var var_content = sse_decode_list_blox_inline_element(deserializer);
+return BloxInlineElement_Footnote(id: var_id, content: var_content);case 12: var var_field0 = sse_decode_String(deserializer);
+return BloxInlineElement_Variable(var_field0);case 13: var var_elementType = sse_decode_String(deserializer);
+var var_attributes = sse_decode_Map_String_String_None(deserializer);
+var var_content = sse_decode_String(deserializer);
+return BloxInlineElement_Custom(elementType: var_elementType, attributes: var_attributes, content: var_content); default: throw UnimplementedError(''); }
+             }
+
+@protected BloxListItem sse_decode_blox_list_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_itemType = sse_decode_blox_list_item_type(deserializer);
+var var_content = sse_decode_String(deserializer);
+var var_children = sse_decode_list_blox_list_item(deserializer);
+var var_level = sse_decode_usize(deserializer);
+var var_number = sse_decode_opt_box_autoadd_usize(deserializer);
+var var_inlineElements = sse_decode_list_blox_inline_element(deserializer);
+var var_attributes = sse_decode_Map_String_String_None(deserializer);
+var var_span = sse_decode_blox_span(deserializer);
+return BloxListItem(itemType: var_itemType, content: var_content, children: var_children, level: var_level, number: var_number, inlineElements: var_inlineElements, attributes: var_attributes, span: var_span); }
+
+@protected BloxListItemType sse_decode_blox_list_item_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return BloxListItemType_Unchecked();case 1: return BloxListItemType_Checked();case 2: var var_term = sse_decode_String(deserializer);
+return BloxListItemType_Definition(term: var_term); default: throw UnimplementedError(''); }
+             }
+
+@protected BloxMetadata sse_decode_blox_metadata(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_opt_String(deserializer);
+var var_authors = sse_decode_list_String(deserializer);
+var var_created = sse_decode_opt_String(deserializer);
+var var_modified = sse_decode_opt_String(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_entries = sse_decode_list_blox_metadata_entry(deserializer);
+return BloxMetadata(title: var_title, authors: var_authors, created: var_created, modified: var_modified, tags: var_tags, entries: var_entries); }
+
+@protected BloxMetadataEntry sse_decode_blox_metadata_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_block = sse_decode_String(deserializer);
+var var_key = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return BloxMetadataEntry(block: var_block, key: var_key, value: var_value); }
+
+@protected BloxOutputFormat sse_decode_blox_output_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BloxOutputFormat.values[inner]; }
+
+@protected BloxPackage sse_decode_blox_package(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_files = sse_decode_list_blox_package_file(deserializer);
+var var_archive = sse_decode_list_prim_u_8_strict(deserializer);
+var var_diagnostics = sse_decode_list_blox_diagnostic(deserializer);
+return BloxPackage(files: var_files, archive: var_archive, diagnostics: var_diagnostics); }
+
+@protected BloxPackageFile sse_decode_blox_package_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_content = sse_decode_String(deserializer);
+return BloxPackageFile(path: var_path, content: var_content); }
+
+@protected BloxParseResult sse_decode_blox_parse_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_document = sse_decode_blox_document(deserializer);
+var var_diagnostics = sse_decode_list_blox_diagnostic(deserializer);
+return BloxParseResult(document: var_document, diagnostics: var_diagnostics); }
+
+@protected BloxParserOptions sse_decode_blox_parser_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_strictNesting = sse_decode_bool(deserializer);
+var var_indent = sse_decode_blox_indent_policy(deserializer);
+return BloxParserOptions(strictNesting: var_strictNesting, indent: var_indent); }
+
+@protected BloxPosition sse_decode_blox_position(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_line = sse_decode_usize(deserializer);
+var var_column = sse_decode_usize(deserializer);
+var var_offset = sse_decode_usize(deserializer);
+return BloxPosition(line: var_line, column: var_column, offset: var_offset); }
+
+@protected BloxQuiz sse_decode_blox_quiz(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_title = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_questions = sse_decode_list_blox_quiz_question(deserializer);
+return BloxQuiz(title: var_title, description: var_description, questions: var_questions); }
+
+@protected BloxQuizChoice sse_decode_blox_quiz_choice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_content = sse_decode_String(deserializer);
+var var_correct = sse_decode_bool(deserializer);
+return BloxQuizChoice(content: var_content, correct: var_correct); }
+
+@protected BloxQuizQuestion sse_decode_blox_quiz_question(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_questionType = sse_decode_String(deserializer);
+var var_prompt = sse_decode_String(deserializer);
+var var_choices = sse_decode_list_blox_quiz_choice(deserializer);
+var var_answers = sse_decode_list_String(deserializer);
+var var_explanation = sse_decode_opt_String(deserializer);
+var var_points = sse_decode_usize(deserializer);
+var var_id = sse_decode_opt_String(deserializer);
+var var_span = sse_decode_blox_span(deserializer);
+return BloxQuizQuestion(questionType: var_questionType, prompt: var_prompt, choices: var_choices, answers: var_answers, explanation: var_explanation, points: var_points, id: var_id, span: var_span); }
+
+@protected BloxQuoteStyle sse_decode_blox_quote_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BloxQuoteStyle.values[inner]; }
+
+@protected BloxReferenceTarget sse_decode_blox_reference_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_list_prim_usize_strict(deserializer);
+var var_lineNumber = sse_decode_usize(deserializer);
+var var_headerSpan = sse_decode_blox_span(deserializer);
+var var_generated = sse_decode_bool(deserializer);
+return BloxReferenceTarget(path: var_path, lineNumber: var_lineNumber, headerSpan: var_headerSpan, generated: var_generated); }
+
+@protected BloxSeverity sse_decode_blox_severity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BloxSeverity.values[inner]; }
+
+@protected BloxSpan sse_decode_blox_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_blox_position(deserializer);
+var var_end = sse_decode_blox_position(deserializer);
+return BloxSpan(start: var_start, end: var_end); }
+
+@protected BloxTable sse_decode_blox_table(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_caption = sse_decode_opt_String(deserializer);
+var var_columns = sse_decode_list_blox_column_spec(deserializer);
+var var_header = sse_decode_opt_box_autoadd_blox_table_row(deserializer);
+var var_rows = sse_decode_list_blox_table_row(deserializer);
+return BloxTable(caption: var_caption, columns: var_columns, header: var_header, rows: var_rows); }
+
+@protected BloxTableCell sse_decode_blox_table_cell(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_content = sse_decode_String(deserializer);
+var var_colspan = sse_decode_usize(deserializer);
+var var_rowspan = sse_decode_usize(deserializer);
+var var_isHeader = sse_decode_bool(deserializer);
+var var_value = sse_decode_blox_cell_value(deserializer);
+return BloxTableCell(content: var_content, colspan: var_colspan, rowspan: var_rowspan, isHeader: var_isHeader, value: var_value); }
+
+@protected BloxTableEditResult sse_decode_blox_table_edit_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_block = sse_decode_blox_block(deserializer);
+var var_edits = sse_decode_list_blox_text_edit(deserializer);
+return BloxTableEditResult(block: var_block, edits: var_edits); }
+
+@protected BloxTableOp sse_decode_blox_table_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_index = sse_decode_usize(deserializer);
+var var_cells = sse_decode_list_String(deserializer);
+return BloxTableOp_InsertRow(index: var_index, cells: var_cells);case 1: var var_index = sse_decode_usize(deserializer);
+return BloxTableOp_DeleteRow(index: var_index);case 2: var var_from = sse_decode_usize(deserializer);
+var var_to = sse_decode_usize(deserializer);
+return BloxTableOp_MoveRow(from: var_from, to: var_to);case 3: var var_index = sse_decode_usize(deserializer);
+var var_header = sse_decode_opt_String(deserializer);
+return BloxTableOp_InsertColumn(index: var_index, header: var_header);case 4: var var_index = sse_decode_usize(deserializer);
+return BloxTableOp_DeleteColumn(index: var_index);case 5: var var_from = sse_decode_usize(deserializer);
+var var_to = sse_decode_usize(deserializer);
+return BloxTableOp_MoveColumn(from: var_from, to: var_to);case 6: var var_column = sse_decode_usize(deserializer);
+var var_descending = sse_decode_bool(deserializer);
+return BloxTableOp_Sort(column: var_column, descending: var_descending); default: throw UnimplementedError(''); }
+             }
+
+@protected BloxTableRow sse_decode_blox_table_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cells = sse_decode_list_blox_table_cell(deserializer);
+return BloxTableRow(cells: var_cells); }
+
+@protected BloxTask sse_decode_blox_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_file = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_checked = sse_decode_bool(deserializer);
+var var_span = sse_decode_blox_span(deserializer);
+var var_sectionPath = sse_decode_list_String(deserializer);
+var var_due = sse_decode_opt_String(deserializer);
+var var_assignee = sse_decode_opt_String(deserializer);
+return BloxTask(file: var_file, text: var_text, checked: var_checked, span: var_span, sectionPath: var_sectionPath, due: var_due, assignee: var_assignee); }
+
+@protected BloxTextEdit sse_decode_blox_text_edit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_span = sse_decode_blox_span(deserializer);
+var var_replacement = sse_decode_String(deserializer);
+return BloxTextEdit(span: var_span, replacement: var_replacement); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected BloxDocument sse_decode_box_autoadd_blox_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_document(deserializer)); }
+
+@protected BloxFix sse_decode_box_autoadd_blox_fix(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_fix(deserializer)); }
+
+@protected BloxFormatStyle sse_decode_box_autoadd_blox_format_style(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_format_style(deserializer)); }
+
+@protected BloxParserOptions sse_decode_box_autoadd_blox_parser_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_parser_options(deserializer)); }
+
+@protected BloxQuiz sse_decode_box_autoadd_blox_quiz(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_quiz(deserializer)); }
+
+@protected BloxReferenceTarget sse_decode_box_autoadd_blox_reference_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_reference_target(deserializer)); }
+
+@protected BloxSpan sse_decode_box_autoadd_blox_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_span(deserializer)); }
+
+@protected BloxTable sse_decode_box_autoadd_blox_table(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_table(deserializer)); }
+
+@protected BloxTableOp sse_decode_box_autoadd_blox_table_op(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_table_op(deserializer)); }
+
+@protected BloxTableRow sse_decode_box_autoadd_blox_table_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_blox_table_row(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxAttributeSpan> sse_decode_list_blox_attribute_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxAttributeSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_attribute_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxBlock> sse_decode_list_blox_block(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxBlock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_block(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxColumnSpec> sse_decode_list_blox_column_spec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxColumnSpec>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_column_spec(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxDiagnostic> sse_decode_list_blox_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxDiagnostic>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_diagnostic(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxFlashcard> sse_decode_list_blox_flashcard(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxFlashcard>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_flashcard(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxInlineElement> sse_decode_list_blox_inline_element(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxInlineElement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_inline_element(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxListItem> sse_decode_list_blox_list_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxListItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_list_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxMetadataEntry> sse_decode_list_blox_metadata_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxMetadataEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_metadata_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxPackageFile> sse_decode_list_blox_package_file(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxPackageFile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_package_file(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxQuizChoice> sse_decode_list_blox_quiz_choice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxQuizChoice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_quiz_choice(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxQuizQuestion> sse_decode_list_blox_quiz_question(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxQuizQuestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_quiz_question(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxSpan> sse_decode_list_blox_span(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxSpan>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_span(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxTableCell> sse_decode_list_blox_table_cell(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxTableCell>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_table_cell(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxTableRow> sse_decode_list_blox_table_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxTableRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_table_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxTask> sse_decode_list_blox_task(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxTask>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_task(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BloxTextEdit> sse_decode_list_blox_text_edit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BloxTextEdit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blox_text_edit(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint64List sse_decode_list_prim_usize_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BloxFix? sse_decode_opt_box_autoadd_blox_fix(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_blox_fix(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BloxQuiz? sse_decode_opt_box_autoadd_blox_quiz(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_blox_quiz(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BloxReferenceTarget? sse_decode_opt_box_autoadd_blox_reference_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_blox_reference_target(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BloxTable? sse_decode_opt_box_autoadd_blox_table(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_blox_table(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BloxTableRow? sse_decode_opt_box_autoadd_blox_table_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_blox_table_row(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_blox_attribute_order(BloxAttributeOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blox_attribute_span(BloxAttributeSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_blox_span(self.keySpan, serializer);
+sse_encode_blox_span(self.valueSpan, serializer);
+ }
+
+@protected void sse_encode_blox_block(BloxBlock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.blockType, serializer);
+sse_encode_usize(self.level, serializer);
+sse_encode_Map_String_String_None(self.attributes, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_list_blox_block(self.children, serializer);
+sse_encode_usize(self.lineNumber, serializer);
+sse_encode_blox_span(self.headerSpan, serializer);
+sse_encode_blox_span(self.contentSpan, serializer);
+sse_encode_list_blox_attribute_span(self.attributeSpans, serializer);
+sse_encode_list_blox_inline_element(self.inlineElements, serializer);
+sse_encode_list_blox_span(self.inlineSpans, serializer);
+sse_encode_list_blox_list_item(self.listItems, serializer);
+sse_encode_opt_box_autoadd_blox_table(self.table, serializer);
+sse_encode_opt_box_autoadd_blox_quiz(self.quiz, serializer);
+ }
+
+@protected void sse_encode_blox_cell_value(BloxCellValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case BloxCellValue_Empty(): sse_encode_i_32(0, serializer); case BloxCellValue_Text(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case BloxCellValue_Number(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_f_64(field0, serializer);
+case BloxCellValue_Date(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case BloxCellValue_Currency(amount: final amount,symbol: final symbol): sse_encode_i_32(4, serializer); sse_encode_f_64(amount, serializer);
+sse_encode_opt_String(symbol, serializer);
+case BloxCellValue_Error(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_blox_column_spec(BloxColumnSpec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.align, serializer);
+sse_encode_opt_String(self.width, serializer);
+sse_encode_String(self.columnType, serializer);
+sse_encode_opt_box_autoadd_usize(self.decimals, serializer);
+ }
+
+@protected void sse_encode_blox_diagnostic(BloxDiagnostic self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_severity(self.severity, serializer);
+sse_encode_String(self.code, serializer);
+sse_encode_blox_span(self.span, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_box_autoadd_blox_fix(self.fix, serializer);
+ }
+
+@protected void sse_encode_blox_document(BloxDocument self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_blox_block(self.blocks, serializer);
+sse_encode_blox_metadata(self.metadata, serializer);
+ }
+
+@protected void sse_encode_blox_fix(BloxFix self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_span(self.span, serializer);
+sse_encode_String(self.replacement, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_blox_flashcard(BloxFlashcard self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.front, serializer);
+sse_encode_String(self.back, serializer);
+sse_encode_blox_span(self.span, serializer);
+sse_encode_opt_String(self.due, serializer);
+sse_encode_u_32(self.repetitions, serializer);
+sse_encode_u_32(self.intervalDays, serializer);
+sse_encode_f_64(self.ease, serializer);
+ }
+
+@protected void sse_encode_blox_format_style(BloxFormatStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.useShorthand, serializer);
+sse_encode_blox_attribute_order(self.attributeOrder, serializer);
+sse_encode_blox_quote_style(self.quoteStyle, serializer);
+sse_encode_usize(self.blankLines, serializer);
+sse_encode_bool(self.alignTables, serializer);
+sse_encode_bool(self.normalizeLists, serializer);
+sse_encode_opt_box_autoadd_usize(self.wrapWidth, serializer);
+ }
+
+@protected void sse_encode_blox_indent_policy(BloxIndentPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blox_inline_element(BloxInlineElement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case BloxInlineElement_Text(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case BloxInlineElement_Link(content: final content,url: final url): sse_encode_i_32(1, serializer); sse_encode_list_blox_inline_element(content, serializer);
+sse_encode_String(url, serializer);
+case BloxInlineElement_Bold(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Italic(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Code(code: final code,lang: final lang): sse_encode_i_32(4, serializer); sse_encode_String(code, serializer);
+sse_encode_opt_String(lang, serializer);
+case BloxInlineElement_Math(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_String(field0, serializer);
+case BloxInlineElement_Strikethrough(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Highlight(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Subscript(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Superscript(field0: final field0): sse_encode_i_32(9, serializer); sse_encode_list_blox_inline_element(field0, serializer);
+case BloxInlineElement_Reference(id: final id,relation: final relation): sse_encode_i_32(10, serializer); sse_encode_String(id, serializer);
+sse_encode_opt_String(relation, serializer);
+case BloxInlineElement_Footnote(id: final id,content: final content): sse_encode_i_32(11, serializer); sse_encode_String(id, serializer);
+sse_encode_list_blox_inline_element(content, serializer);
+case BloxInlineElement_Variable(field0: final field0): sse_encode_i_32(12, serializer); sse_encode_String(field0, serializer);
+case BloxInlineElement_Custom(elementType: final elementType,attributes: final attributes,content: final content): sse_encode_i_32(13, serializer); sse_encode_String(elementType, serializer);
+sse_encode_Map_String_String_None(attributes, serializer);
+sse_encode_String(content, serializer);
+  } }
+
+@protected void sse_encode_blox_list_item(BloxListItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_list_item_type(self.itemType, serializer);
+sse_encode_String(self.content, serializer);
+sse_encode_list_blox_list_item(self.children, serializer);
+sse_encode_usize(self.level, serializer);
+sse_encode_opt_box_autoadd_usize(self.number, serializer);
+sse_encode_list_blox_inline_element(self.inlineElements, serializer);
+sse_encode_Map_String_String_None(self.attributes, serializer);
+sse_encode_blox_span(self.span, serializer);
+ }
+
+@protected void sse_encode_blox_list_item_type(BloxListItemType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case BloxListItemType_Unchecked(): sse_encode_i_32(0, serializer); case BloxListItemType_Checked(): sse_encode_i_32(1, serializer); case BloxListItemType_Definition(term: final term): sse_encode_i_32(2, serializer); sse_encode_String(term, serializer);
+  } }
+
+@protected void sse_encode_blox_metadata(BloxMetadata self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.title, serializer);
+sse_encode_list_String(self.authors, serializer);
+sse_encode_opt_String(self.created, serializer);
+sse_encode_opt_String(self.modified, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_list_blox_metadata_entry(self.entries, serializer);
+ }
+
+@protected void sse_encode_blox_metadata_entry(BloxMetadataEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.block, serializer);
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_blox_output_format(BloxOutputFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blox_package(BloxPackage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_blox_package_file(self.files, serializer);
+sse_encode_list_prim_u_8_strict(self.archive, serializer);
+sse_encode_list_blox_diagnostic(self.diagnostics, serializer);
+ }
+
+@protected void sse_encode_blox_package_file(BloxPackageFile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_String(self.content, serializer);
+ }
+
+@protected void sse_encode_blox_parse_result(BloxParseResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_document(self.document, serializer);
+sse_encode_list_blox_diagnostic(self.diagnostics, serializer);
+ }
+
+@protected void sse_encode_blox_parser_options(BloxParserOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.strictNesting, serializer);
+sse_encode_blox_indent_policy(self.indent, serializer);
+ }
+
+@protected void sse_encode_blox_position(BloxPosition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.line, serializer);
+sse_encode_usize(self.column, serializer);
+sse_encode_usize(self.offset, serializer);
+ }
+
+@protected void sse_encode_blox_quiz(BloxQuiz self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.title, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_list_blox_quiz_question(self.questions, serializer);
+ }
+
+@protected void sse_encode_blox_quiz_choice(BloxQuizChoice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.content, serializer);
+sse_encode_bool(self.correct, serializer);
+ }
+
+@protected void sse_encode_blox_quiz_question(BloxQuizQuestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.questionType, serializer);
+sse_encode_String(self.prompt, serializer);
+sse_encode_list_blox_quiz_choice(self.choices, serializer);
+sse_encode_list_String(self.answers, serializer);
+sse_encode_opt_String(self.explanation, serializer);
+sse_encode_usize(self.points, serializer);
+sse_encode_opt_String(self.id, serializer);
+sse_encode_blox_span(self.span, serializer);
+ }
+
+@protected void sse_encode_blox_quote_style(BloxQuoteStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blox_reference_target(BloxReferenceTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_usize_strict(self.path, serializer);
+sse_encode_usize(self.lineNumber, serializer);
+sse_encode_blox_span(self.headerSpan, serializer);
+sse_encode_bool(self.generated, serializer);
+ }
+
+@protected void sse_encode_blox_severity(BloxSeverity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blox_span(BloxSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_position(self.start, serializer);
+sse_encode_blox_position(self.end, serializer);
+ }
+
+@protected void sse_encode_blox_table(BloxTable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.caption, serializer);
+sse_encode_list_blox_column_spec(self.columns, serializer);
+sse_encode_opt_box_autoadd_blox_table_row(self.header, serializer);
+sse_encode_list_blox_table_row(self.rows, serializer);
+ }
+
+@protected void sse_encode_blox_table_cell(BloxTableCell self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.content, serializer);
+sse_encode_usize(self.colspan, serializer);
+sse_encode_usize(self.rowspan, serializer);
+sse_encode_bool(self.isHeader, serializer);
+sse_encode_blox_cell_value(self.value, serializer);
+ }
+
+@protected void sse_encode_blox_table_edit_result(BloxTableEditResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_block(self.block, serializer);
+sse_encode_list_blox_text_edit(self.edits, serializer);
+ }
+
+@protected void sse_encode_blox_table_op(BloxTableOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case BloxTableOp_InsertRow(index: final index,cells: final cells): sse_encode_i_32(0, serializer); sse_encode_usize(index, serializer);
+sse_encode_list_String(cells, serializer);
+case BloxTableOp_DeleteRow(index: final index): sse_encode_i_32(1, serializer); sse_encode_usize(index, serializer);
+case BloxTableOp_MoveRow(from: final from,to: final to): sse_encode_i_32(2, serializer); sse_encode_usize(from, serializer);
+sse_encode_usize(to, serializer);
+case BloxTableOp_InsertColumn(index: final index,header: final header): sse_encode_i_32(3, serializer); sse_encode_usize(index, serializer);
+sse_encode_opt_String(header, serializer);
+case BloxTableOp_DeleteColumn(index: final index): sse_encode_i_32(4, serializer); sse_encode_usize(index, serializer);
+case BloxTableOp_MoveColumn(from: final from,to: final to): sse_encode_i_32(5, serializer); sse_encode_usize(from, serializer);
+sse_encode_usize(to, serializer);
+case BloxTableOp_Sort(column: final column,descending: final descending): sse_encode_i_32(6, serializer); sse_encode_usize(column, serializer);
+sse_encode_bool(descending, serializer);
+  } }
+
+@protected void sse_encode_blox_table_row(BloxTableRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_blox_table_cell(self.cells, serializer);
+ }
+
+@protected void sse_encode_blox_task(BloxTask self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.file, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_bool(self.checked, serializer);
+sse_encode_blox_span(self.span, serializer);
+sse_encode_list_String(self.sectionPath, serializer);
+sse_encode_opt_String(self.due, serializer);
+sse_encode_opt_String(self.assignee, serializer);
+ }
+
+@protected void sse_encode_blox_text_edit(BloxTextEdit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_span(self.span, serializer);
+sse_encode_String(self.replacement, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_blox_document(BloxDocument self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_document(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_fix(BloxFix self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_fix(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_format_style(BloxFormatStyle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_format_style(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_parser_options(BloxParserOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_parser_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_quiz(BloxQuiz self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_quiz(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_reference_target(BloxReferenceTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_reference_target(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_span(BloxSpan self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_span(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_table(BloxTable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_table(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_table_op(BloxTableOp self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_table_op(self, serializer); }
+
+@protected void sse_encode_box_autoadd_blox_table_row(BloxTableRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_blox_table_row(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_blox_attribute_span(List<BloxAttributeSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_attribute_span(item, serializer); } }
+
+@protected void sse_encode_list_blox_block(List<BloxBlock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_block(item, serializer); } }
+
+@protected void sse_encode_list_blox_column_spec(List<BloxColumnSpec> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_column_spec(item, serializer); } }
+
+@protected void sse_encode_list_blox_diagnostic(List<BloxDiagnostic> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_diagnostic(item, serializer); } }
+
+@protected void sse_encode_list_blox_flashcard(List<BloxFlashcard> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_flashcard(item, serializer); } }
+
+@protected void sse_encode_list_blox_inline_element(List<BloxInlineElement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_inline_element(item, serializer); } }
+
+@protected void sse_encode_list_blox_list_item(List<BloxListItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_list_item(item, serializer); } }
+
+@protected void sse_encode_list_blox_metadata_entry(List<BloxMetadataEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_metadata_entry(item, serializer); } }
+
+@protected void sse_encode_list_blox_package_file(List<BloxPackageFile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_package_file(item, serializer); } }
+
+@protected void sse_encode_list_blox_quiz_choice(List<BloxQuizChoice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_quiz_choice(item, serializer); } }
+
+@protected void sse_encode_list_blox_quiz_question(List<BloxQuizQuestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_quiz_question(item, serializer); } }
+
+@protected void sse_encode_list_blox_span(List<BloxSpan> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_span(item, serializer); } }
+
+@protected void sse_encode_list_blox_table_cell(List<BloxTableCell> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_table_cell(item, serializer); } }
+
+@protected void sse_encode_list_blox_table_row(List<BloxTableRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_table_row(item, serializer); } }
+
+@protected void sse_encode_list_blox_task(List<BloxTask> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_task(item, serializer); } }
+
+@protected void sse_encode_list_blox_text_edit(List<BloxTextEdit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blox_text_edit(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_prim_usize_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_blox_fix(BloxFix? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_blox_fix(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_blox_quiz(BloxQuiz? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_blox_quiz(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_blox_reference_target(BloxReferenceTarget? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_blox_reference_target(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_blox_table(BloxTable? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_blox_table(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_blox_table_row(BloxTableRow? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_blox_table_row(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
@@ -1,6 +1,8 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.11.1.
 
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+
 import 'api/blox_api.dart';
 import 'api/simple.dart';
 import 'dart:async';
@@ -9,334 +11,542 @@ import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  BloxBlock dco_decode_blox_block(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected BloxAttributeOrder dco_decode_blox_attribute_order(dynamic raw);
+
+@protected BloxAttributeSpan dco_decode_blox_attribute_span(dynamic raw);
+
+@protected BloxBlock dco_decode_blox_block(dynamic raw);
+
+@protected BloxCellValue dco_decode_blox_cell_value(dynamic raw);
+
+@protected BloxColumnSpec dco_decode_blox_column_spec(dynamic raw);
+
+@protected BloxDiagnostic dco_decode_blox_diagnostic(dynamic raw);
+
+@protected BloxDocument dco_decode_blox_document(dynamic raw);
+
+@protected BloxFix dco_decode_blox_fix(dynamic raw);
+
+@protected BloxFlashcard dco_decode_blox_flashcard(dynamic raw);
+
+@protected BloxFormatStyle dco_decode_blox_format_style(dynamic raw);
+
+@protected BloxIndentPolicy dco_decode_blox_indent_policy(dynamic raw);
+
+@protected BloxInlineElement dco_decode_blox_inline_element(dynamic raw);
+
+@protected BloxListItem dco_decode_blox_list_item(dynamic raw);
+
+@protected BloxListItemType dco_decode_blox_list_item_type(dynamic raw);
+
+@protected BloxMetadata dco_decode_blox_metadata(dynamic raw);
+
+@protected BloxMetadataEntry dco_decode_blox_metadata_entry(dynamic raw);
+
+@protected BloxOutputFormat dco_decode_blox_output_format(dynamic raw);
+
+@protected BloxPackage dco_decode_blox_package(dynamic raw);
+
+@protected BloxPackageFile dco_decode_blox_package_file(dynamic raw);
+
+@protected BloxParseResult dco_decode_blox_parse_result(dynamic raw);
+
+@protected BloxParserOptions dco_decode_blox_parser_options(dynamic raw);
+
+@protected BloxPosition dco_decode_blox_position(dynamic raw);
+
+@protected BloxQuiz dco_decode_blox_quiz(dynamic raw);
+
+@protected BloxQuizChoice dco_decode_blox_quiz_choice(dynamic raw);
+
+@protected BloxQuizQuestion dco_decode_blox_quiz_question(dynamic raw);
+
+@protected BloxQuoteStyle dco_decode_blox_quote_style(dynamic raw);
+
+@protected BloxReferenceTarget dco_decode_blox_reference_target(dynamic raw);
+
+@protected BloxSeverity dco_decode_blox_severity(dynamic raw);
+
+@protected BloxSpan dco_decode_blox_span(dynamic raw);
+
+@protected BloxTable dco_decode_blox_table(dynamic raw);
+
+@protected BloxTableCell dco_decode_blox_table_cell(dynamic raw);
+
+@protected BloxTableEditResult dco_decode_blox_table_edit_result(dynamic raw);
+
+@protected BloxTableOp dco_decode_blox_table_op(dynamic raw);
+
+@protected BloxTableRow dco_decode_blox_table_row(dynamic raw);
+
+@protected BloxTask dco_decode_blox_task(dynamic raw);
+
+@protected BloxTextEdit dco_decode_blox_text_edit(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected BloxDocument dco_decode_box_autoadd_blox_document(dynamic raw);
+
+@protected BloxFix dco_decode_box_autoadd_blox_fix(dynamic raw);
+
+@protected BloxFormatStyle dco_decode_box_autoadd_blox_format_style(dynamic raw);
+
+@protected BloxParserOptions dco_decode_box_autoadd_blox_parser_options(dynamic raw);
+
+@protected BloxQuiz dco_decode_box_autoadd_blox_quiz(dynamic raw);
+
+@protected BloxReferenceTarget dco_decode_box_autoadd_blox_reference_target(dynamic raw);
+
+@protected BloxSpan dco_decode_box_autoadd_blox_span(dynamic raw);
+
+@protected BloxTable dco_decode_box_autoadd_blox_table(dynamic raw);
+
+@protected BloxTableOp dco_decode_box_autoadd_blox_table_op(dynamic raw);
+
+@protected BloxTableRow dco_decode_box_autoadd_blox_table_row(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BloxAttributeSpan> dco_decode_list_blox_attribute_span(dynamic raw);
+
+@protected List<BloxBlock> dco_decode_list_blox_block(dynamic raw);
+
+@protected List<BloxColumnSpec> dco_decode_list_blox_column_spec(dynamic raw);
+
+@protected List<BloxDiagnostic> dco_decode_list_blox_diagnostic(dynamic raw);
+
+@protected List<BloxFlashcard> dco_decode_list_blox_flashcard(dynamic raw);
+
+@protected List<BloxInlineElement> dco_decode_list_blox_inline_element(dynamic raw);
+
+@protected List<BloxListItem> dco_decode_list_blox_list_item(dynamic raw);
+
+@protected List<BloxMetadataEntry> dco_decode_list_blox_metadata_entry(dynamic raw);
+
+@protected List<BloxPackageFile> dco_decode_list_blox_package_file(dynamic raw);
+
+@protected List<BloxQuizChoice> dco_decode_list_blox_quiz_choice(dynamic raw);
+
+@protected List<BloxQuizQuestion> dco_decode_list_blox_quiz_question(dynamic raw);
+
+@protected List<BloxSpan> dco_decode_list_blox_span(dynamic raw);
+
+@protected List<BloxTableCell> dco_decode_list_blox_table_cell(dynamic raw);
+
+@protected List<BloxTableRow> dco_decode_list_blox_table_row(dynamic raw);
+
+@protected List<BloxTask> dco_decode_list_blox_task(dynamic raw);
+
+@protected List<BloxTextEdit> dco_decode_list_blox_text_edit(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_usize_strict(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected BloxFix? dco_decode_opt_box_autoadd_blox_fix(dynamic raw);
+
+@protected BloxQuiz? dco_decode_opt_box_autoadd_blox_quiz(dynamic raw);
+
+@protected BloxReferenceTarget? dco_decode_opt_box_autoadd_blox_reference_target(dynamic raw);
+
+@protected BloxTable? dco_decode_opt_box_autoadd_blox_table(dynamic raw);
+
+@protected BloxTableRow? dco_decode_opt_box_autoadd_blox_table_row(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
 
-  @protected
-  BloxDocument dco_decode_blox_document(dynamic raw);
+@protected int dco_decode_u_8(dynamic raw);
 
-  @protected
-  BloxInlineElement dco_decode_blox_inline_element(dynamic raw);
+@protected void dco_decode_unit(dynamic raw);
 
-  @protected
-  BloxListItem dco_decode_blox_list_item(dynamic raw);
+@protected BigInt dco_decode_usize(dynamic raw);
 
-  @protected
-  BloxListItemType dco_decode_blox_list_item_type(dynamic raw);
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer);
 
-  @protected
-  BloxOutputFormat dco_decode_blox_output_format(dynamic raw);
+@protected String sse_decode_String(SseDeserializer deserializer);
 
-  @protected
-  BloxTable dco_decode_blox_table(dynamic raw);
+@protected BloxAttributeOrder sse_decode_blox_attribute_order(SseDeserializer deserializer);
 
-  @protected
-  BloxTableCell dco_decode_blox_table_cell(dynamic raw);
+@protected BloxAttributeSpan sse_decode_blox_attribute_span(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow dco_decode_blox_table_row(dynamic raw);
+@protected BloxBlock sse_decode_blox_block(SseDeserializer deserializer);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected BloxCellValue sse_decode_blox_cell_value(SseDeserializer deserializer);
 
-  @protected
-  BloxDocument dco_decode_box_autoadd_blox_document(dynamic raw);
+@protected BloxColumnSpec sse_decode_blox_column_spec(SseDeserializer deserializer);
 
-  @protected
-  BloxTable dco_decode_box_autoadd_blox_table(dynamic raw);
+@protected BloxDiagnostic sse_decode_blox_diagnostic(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow dco_decode_box_autoadd_blox_table_row(dynamic raw);
+@protected BloxDocument sse_decode_blox_document(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected BloxFix sse_decode_blox_fix(SseDeserializer deserializer);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected BloxFlashcard sse_decode_blox_flashcard(SseDeserializer deserializer);
 
-  @protected
-  List<BloxBlock> dco_decode_list_blox_block(dynamic raw);
+@protected BloxFormatStyle sse_decode_blox_format_style(SseDeserializer deserializer);
 
-  @protected
-  List<BloxInlineElement> dco_decode_list_blox_inline_element(dynamic raw);
+@protected BloxIndentPolicy sse_decode_blox_indent_policy(SseDeserializer deserializer);
 
-  @protected
-  List<BloxListItem> dco_decode_list_blox_list_item(dynamic raw);
+@protected BloxInlineElement sse_decode_blox_inline_element(SseDeserializer deserializer);
 
-  @protected
-  List<BloxTableCell> dco_decode_list_blox_table_cell(dynamic raw);
+@protected BloxListItem sse_decode_blox_list_item(SseDeserializer deserializer);
 
-  @protected
-  List<BloxTableRow> dco_decode_list_blox_table_row(dynamic raw);
+@protected BloxListItemType sse_decode_blox_list_item_type(SseDeserializer deserializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected BloxMetadata sse_decode_blox_metadata(SseDeserializer deserializer);
 
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+@protected BloxMetadataEntry sse_decode_blox_metadata_entry(SseDeserializer deserializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected BloxOutputFormat sse_decode_blox_output_format(SseDeserializer deserializer);
 
-  @protected
-  BloxTable? dco_decode_opt_box_autoadd_blox_table(dynamic raw);
+@protected BloxPackage sse_decode_blox_package(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow? dco_decode_opt_box_autoadd_blox_table_row(dynamic raw);
+@protected BloxPackageFile sse_decode_blox_package_file(SseDeserializer deserializer);
 
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+@protected BloxParseResult sse_decode_blox_parse_result(SseDeserializer deserializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected BloxParserOptions sse_decode_blox_parser_options(SseDeserializer deserializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected BloxPosition sse_decode_blox_position(SseDeserializer deserializer);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected BloxQuiz sse_decode_blox_quiz(SseDeserializer deserializer);
 
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-      SseDeserializer deserializer);
+@protected BloxQuizChoice sse_decode_blox_quiz_choice(SseDeserializer deserializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected BloxQuizQuestion sse_decode_blox_quiz_question(SseDeserializer deserializer);
 
-  @protected
-  BloxBlock sse_decode_blox_block(SseDeserializer deserializer);
+@protected BloxQuoteStyle sse_decode_blox_quote_style(SseDeserializer deserializer);
 
-  @protected
-  BloxDocument sse_decode_blox_document(SseDeserializer deserializer);
+@protected BloxReferenceTarget sse_decode_blox_reference_target(SseDeserializer deserializer);
 
-  @protected
-  BloxInlineElement sse_decode_blox_inline_element(
-      SseDeserializer deserializer);
+@protected BloxSeverity sse_decode_blox_severity(SseDeserializer deserializer);
 
-  @protected
-  BloxListItem sse_decode_blox_list_item(SseDeserializer deserializer);
+@protected BloxSpan sse_decode_blox_span(SseDeserializer deserializer);
 
-  @protected
-  BloxListItemType sse_decode_blox_list_item_type(SseDeserializer deserializer);
+@protected BloxTable sse_decode_blox_table(SseDeserializer deserializer);
 
-  @protected
-  BloxOutputFormat sse_decode_blox_output_format(SseDeserializer deserializer);
+@protected BloxTableCell sse_decode_blox_table_cell(SseDeserializer deserializer);
 
-  @protected
-  BloxTable sse_decode_blox_table(SseDeserializer deserializer);
+@protected BloxTableEditResult sse_decode_blox_table_edit_result(SseDeserializer deserializer);
 
-  @protected
-  BloxTableCell sse_decode_blox_table_cell(SseDeserializer deserializer);
+@protected BloxTableOp sse_decode_blox_table_op(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow sse_decode_blox_table_row(SseDeserializer deserializer);
+@protected BloxTableRow sse_decode_blox_table_row(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected BloxTask sse_decode_blox_task(SseDeserializer deserializer);
 
-  @protected
-  BloxDocument sse_decode_box_autoadd_blox_document(
-      SseDeserializer deserializer);
+@protected BloxTextEdit sse_decode_blox_text_edit(SseDeserializer deserializer);
 
-  @protected
-  BloxTable sse_decode_box_autoadd_blox_table(SseDeserializer deserializer);
+@protected bool sse_decode_bool(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow sse_decode_box_autoadd_blox_table_row(
-      SseDeserializer deserializer);
+@protected BloxDocument sse_decode_box_autoadd_blox_document(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected BloxFix sse_decode_box_autoadd_blox_fix(SseDeserializer deserializer);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected BloxFormatStyle sse_decode_box_autoadd_blox_format_style(SseDeserializer deserializer);
 
-  @protected
-  List<BloxBlock> sse_decode_list_blox_block(SseDeserializer deserializer);
+@protected BloxParserOptions sse_decode_box_autoadd_blox_parser_options(SseDeserializer deserializer);
 
-  @protected
-  List<BloxInlineElement> sse_decode_list_blox_inline_element(
-      SseDeserializer deserializer);
+@protected BloxQuiz sse_decode_box_autoadd_blox_quiz(SseDeserializer deserializer);
 
-  @protected
-  List<BloxListItem> sse_decode_list_blox_list_item(
-      SseDeserializer deserializer);
+@protected BloxReferenceTarget sse_decode_box_autoadd_blox_reference_target(SseDeserializer deserializer);
 
-  @protected
-  List<BloxTableCell> sse_decode_list_blox_table_cell(
-      SseDeserializer deserializer);
+@protected BloxSpan sse_decode_box_autoadd_blox_span(SseDeserializer deserializer);
 
-  @protected
-  List<BloxTableRow> sse_decode_list_blox_table_row(
-      SseDeserializer deserializer);
+@protected BloxTable sse_decode_box_autoadd_blox_table(SseDeserializer deserializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected BloxTableOp sse_decode_box_autoadd_blox_table_op(SseDeserializer deserializer);
 
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-      SseDeserializer deserializer);
+@protected BloxTableRow sse_decode_box_autoadd_blox_table_row(SseDeserializer deserializer);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
 
-  @protected
-  BloxTable? sse_decode_opt_box_autoadd_blox_table(
-      SseDeserializer deserializer);
+@protected double sse_decode_f_64(SseDeserializer deserializer);
 
-  @protected
-  BloxTableRow? sse_decode_opt_box_autoadd_blox_table_row(
-      SseDeserializer deserializer);
+@protected int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  (String, String) sse_decode_record_string_string(
-      SseDeserializer deserializer);
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<BloxAttributeSpan> sse_decode_list_blox_attribute_span(SseDeserializer deserializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<BloxBlock> sse_decode_list_blox_block(SseDeserializer deserializer);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected List<BloxColumnSpec> sse_decode_list_blox_column_spec(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_Map_String_String_None(
-      Map<String, String> self, SseSerializer serializer);
+@protected List<BloxDiagnostic> sse_decode_list_blox_diagnostic(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected List<BloxFlashcard> sse_decode_list_blox_flashcard(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_block(BloxBlock self, SseSerializer serializer);
+@protected List<BloxInlineElement> sse_decode_list_blox_inline_element(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_document(BloxDocument self, SseSerializer serializer);
+@protected List<BloxListItem> sse_decode_list_blox_list_item(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_inline_element(
-      BloxInlineElement self, SseSerializer serializer);
+@protected List<BloxMetadataEntry> sse_decode_list_blox_metadata_entry(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_list_item(BloxListItem self, SseSerializer serializer);
+@protected List<BloxPackageFile> sse_decode_list_blox_package_file(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_list_item_type(
-      BloxListItemType self, SseSerializer serializer);
+@protected List<BloxQuizChoice> sse_decode_list_blox_quiz_choice(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_output_format(
-      BloxOutputFormat self, SseSerializer serializer);
+@protected List<BloxQuizQuestion> sse_decode_list_blox_quiz_question(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_table(BloxTable self, SseSerializer serializer);
+@protected List<BloxSpan> sse_decode_list_blox_span(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_table_cell(BloxTableCell self, SseSerializer serializer);
+@protected List<BloxTableCell> sse_decode_list_blox_table_cell(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_blox_table_row(BloxTableRow self, SseSerializer serializer);
+@protected List<BloxTableRow> sse_decode_list_blox_table_row(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected List<BloxTask> sse_decode_list_blox_task(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_blox_document(
-      BloxDocument self, SseSerializer serializer);
+@protected List<BloxTextEdit> sse_decode_list_blox_text_edit(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_blox_table(
-      BloxTable self, SseSerializer serializer);
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_box_autoadd_blox_table_row(
-      BloxTableRow self, SseSerializer serializer);
+@protected Uint64List sse_decode_list_prim_usize_strict(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_blox_block(
-      List<BloxBlock> self, SseSerializer serializer);
+@protected BloxFix? sse_decode_opt_box_autoadd_blox_fix(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_blox_inline_element(
-      List<BloxInlineElement> self, SseSerializer serializer);
+@protected BloxQuiz? sse_decode_opt_box_autoadd_blox_quiz(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_blox_list_item(
-      List<BloxListItem> self, SseSerializer serializer);
+@protected BloxReferenceTarget? sse_decode_opt_box_autoadd_blox_reference_target(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_blox_table_cell(
-      List<BloxTableCell> self, SseSerializer serializer);
+@protected BloxTable? sse_decode_opt_box_autoadd_blox_table(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_blox_table_row(
-      List<BloxTableRow> self, SseSerializer serializer);
+@protected BloxTableRow? sse_decode_opt_box_autoadd_blox_table_row(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_record_string_string(
-      List<(String, String)> self, SseSerializer serializer);
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected int sse_decode_u_32(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_blox_table(
-      BloxTable? self, SseSerializer serializer);
+@protected int sse_decode_u_8(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_blox_table_row(
-      BloxTableRow? self, SseSerializer serializer);
+@protected void sse_decode_unit(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_record_string_string(
-      (String, String) self, SseSerializer serializer);
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_blox_attribute_order(BloxAttributeOrder self, SseSerializer serializer);
+
+@protected void sse_encode_blox_attribute_span(BloxAttributeSpan self, SseSerializer serializer);
+
+@protected void sse_encode_blox_block(BloxBlock self, SseSerializer serializer);
+
+@protected void sse_encode_blox_cell_value(BloxCellValue self, SseSerializer serializer);
+
+@protected void sse_encode_blox_column_spec(BloxColumnSpec self, SseSerializer serializer);
+
+@protected void sse_encode_blox_diagnostic(BloxDiagnostic self, SseSerializer serializer);
+
+@protected void sse_encode_blox_document(BloxDocument self, SseSerializer serializer);
+
+@protected void sse_encode_blox_fix(BloxFix self, SseSerializer serializer);
+
+@protected void sse_encode_blox_flashcard(BloxFlashcard self, SseSerializer serializer);
+
+@protected void sse_encode_blox_format_style(BloxFormatStyle self, SseSerializer serializer);
+
+@protected void sse_encode_blox_indent_policy(BloxIndentPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_blox_inline_element(BloxInlineElement self, SseSerializer serializer);
+
+@protected void sse_encode_blox_list_item(BloxListItem self, SseSerializer serializer);
+
+@protected void sse_encode_blox_list_item_type(BloxListItemType self, SseSerializer serializer);
+
+@protected void sse_encode_blox_metadata(BloxMetadata self, SseSerializer serializer);
+
+@protected void sse_encode_blox_metadata_entry(BloxMetadataEntry self, SseSerializer serializer);
+
+@protected void sse_encode_blox_output_format(BloxOutputFormat self, SseSerializer serializer);
+
+@protected void sse_encode_blox_package(BloxPackage self, SseSerializer serializer);
+
+@protected void sse_encode_blox_package_file(BloxPackageFile self, SseSerializer serializer);
+
+@protected void sse_encode_blox_parse_result(BloxParseResult self, SseSerializer serializer);
+
+@protected void sse_encode_blox_parser_options(BloxParserOptions self, SseSerializer serializer);
+
+@protected void sse_encode_blox_position(BloxPosition self, SseSerializer serializer);
+
+@protected void sse_encode_blox_quiz(BloxQuiz self, SseSerializer serializer);
+
+@protected void sse_encode_blox_quiz_choice(BloxQuizChoice self, SseSerializer serializer);
+
+@protected void sse_encode_blox_quiz_question(BloxQuizQuestion self, SseSerializer serializer);
+
+@protected void sse_encode_blox_quote_style(BloxQuoteStyle self, SseSerializer serializer);
+
+@protected void sse_encode_blox_reference_target(BloxReferenceTarget self, SseSerializer serializer);
+
+@protected void sse_encode_blox_severity(BloxSeverity self, SseSerializer serializer);
+
+@protected void sse_encode_blox_span(BloxSpan self, SseSerializer serializer);
+
+@protected void sse_encode_blox_table(BloxTable self, SseSerializer serializer);
+
+@protected void sse_encode_blox_table_cell(BloxTableCell self, SseSerializer serializer);
+
+@protected void sse_encode_blox_table_edit_result(BloxTableEditResult self, SseSerializer serializer);
+
+@protected void sse_encode_blox_table_op(BloxTableOp self, SseSerializer serializer);
+
+@protected void sse_encode_blox_table_row(BloxTableRow self, SseSerializer serializer);
+
+@protected void sse_encode_blox_task(BloxTask self, SseSerializer serializer);
+
+@protected void sse_encode_blox_text_edit(BloxTextEdit self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_document(BloxDocument self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_fix(BloxFix self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_format_style(BloxFormatStyle self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_parser_options(BloxParserOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_quiz(BloxQuiz self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_reference_target(BloxReferenceTarget self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_span(BloxSpan self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_table(BloxTable self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_table_op(BloxTableOp self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_blox_table_row(BloxTableRow self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_attribute_span(List<BloxAttributeSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_block(List<BloxBlock> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_column_spec(List<BloxColumnSpec> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_diagnostic(List<BloxDiagnostic> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_flashcard(List<BloxFlashcard> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_inline_element(List<BloxInlineElement> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_list_item(List<BloxListItem> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_metadata_entry(List<BloxMetadataEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_package_file(List<BloxPackageFile> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_quiz_choice(List<BloxQuizChoice> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_quiz_question(List<BloxQuizQuestion> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_span(List<BloxSpan> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_table_cell(List<BloxTableCell> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_table_row(List<BloxTableRow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_task(List<BloxTask> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blox_text_edit(List<BloxTextEdit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_usize_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_blox_fix(BloxFix? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_blox_quiz(BloxQuiz? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_blox_reference_target(BloxReferenceTarget? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_blox_table(BloxTable? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_blox_table_row(BloxTableRow? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-      _lookup;
+        class RustLibWire implements BaseWire {
+
+            factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+              RustLibWire(lib.ffiDynamicLibrary);
+        
+            /// Holds the symbol lookup function.
+            final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+                _lookup;
+  
+            /// The symbols are looked up in [dynamicLibrary].
+            RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+                : _lookup = dynamicLibrary.lookup;
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-      : _lookup = dynamicLibrary.lookup;
-}
+            
+        }
+        
\ No newline at end of file
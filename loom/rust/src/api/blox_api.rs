@@ -1,12 +1,42 @@
 use flutter_rust_bridge::frb;
-use crate::blox::{BloxParser, BloxEncoder, BloxDecoder, Document, OutputFormat};
-use crate::blox::ast::{Block, ListItem, ListItemType, Table, TableRow, TableCell};
+use crate::blox::{BloxParser, BloxEncoder, BloxDecoder, Document, OutputFormat, Diagnostic, Severity, IndentPolicy, ParserOptions};
+use crate::blox::{AttributeOrder, FormatStyle, Formatter, QuoteStyle};
+use crate::blox::{Attribute, FormulaError, Interpolator, Metadata, MetadataBlock, MetadataDate, MetadataEntry, ReferenceTarget, Resolver};
+use crate::blox::{edit_table, filter_table, DirectoryFiles, TableEdit, TableLoader, TableOp, TextEdit};
+use crate::blox::{scan_files, toggle_task_in_file, FileTask, Package};
+use crate::blox::{extract_cards, review_log_path, Card, CardSchedule, ReviewLog};
+use crate::blox::ast::{Block, ListItem, ListItemType, QuestionType, Quiz, QuizChoice, QuizQuestion, Table, TableRow, TableCell, CellValue, ColumnAlign, ColumnSpec, ColumnType, Position, Span};
 
 #[derive(Debug, Clone)]
 #[frb]
 pub struct BloxDocument {
     pub blocks: Vec<BloxBlock>,
-    pub metadata: std::collections::HashMap<String, String>,
+    /// The attributes of the document's `#meta` and `#document` blocks
+    pub metadata: BloxMetadata,
+}
+
+/// Document metadata. The typed fields are read from `entries`, which is
+/// the only part used when a document is converted back.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxMetadata {
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    /// `YYYY-MM-DD`
+    pub created: Option<String>,
+    /// `YYYY-MM-DD`
+    pub modified: Option<String>,
+    pub tags: Vec<String>,
+    pub entries: Vec<BloxMetadataEntry>,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxMetadataEntry {
+    /// `meta` or `document`
+    pub block: String,
+    pub key: String,
+    pub value: String,
 }
 
 #[derive(Debug, Clone)]
@@ -18,27 +48,58 @@ pub struct BloxBlock {
     pub content: String,
     pub children: Vec<BloxBlock>,
     pub line_number: usize,
+    // Source locations
+    pub header_span: BloxSpan,
+    pub content_span: BloxSpan,
+    pub attribute_spans: Vec<BloxAttributeSpan>,
     // Enhanced fields
     pub inline_elements: Vec<BloxInlineElement>,
+    /// Spans of `inline_elements`, in the same order
+    pub inline_spans: Vec<BloxSpan>,
     pub list_items: Vec<BloxListItem>,
     pub table: Option<BloxTable>,
+    pub quiz: Option<BloxQuiz>,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxPosition {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxSpan {
+    pub start: BloxPosition,
+    pub end: BloxPosition,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxAttributeSpan {
+    pub key: String,
+    pub key_span: BloxSpan,
+    pub value_span: BloxSpan,
 }
 
 #[derive(Debug, Clone)]
 #[frb]
 pub enum BloxInlineElement {
     Text(String),
-    Link { text: String, url: String },
-    Bold(String),
-    Italic(String),
-    Code(String),
+    Link { content: Vec<BloxInlineElement>, url: String },
+    Bold(Vec<BloxInlineElement>),
+    Italic(Vec<BloxInlineElement>),
+    Code { code: String, lang: Option<String> },
     Math(String),
-    Strikethrough(String),
-    Highlight(String),
-    Subscript(String),
-    Superscript(String),
-    Reference(String),
-    Footnote { id: String, text: String },
+    Strikethrough(Vec<BloxInlineElement>),
+    Highlight(Vec<BloxInlineElement>),
+    Subscript(Vec<BloxInlineElement>),
+    Superscript(Vec<BloxInlineElement>),
+    Reference { id: String, relation: Option<String> },
+    Footnote { id: String, content: Vec<BloxInlineElement> },
+    Variable(String),
     Custom { element_type: String, attributes: std::collections::HashMap<String, String>, content: String },
 }
 
@@ -66,6 +127,12 @@ pub struct BloxListItem {
     pub content: String,
     pub children: Vec<BloxListItem>,
     pub level: usize,
+    /// The item's number in an ordered list
+    pub number: Option<usize>,
+    pub inline_elements: Vec<BloxInlineElement>,
+    pub attributes: std::collections::HashMap<String, String>,
+    /// Span of the item's first line, or of its `item` block header
+    pub span: BloxSpan,
 }
 
 #[derive(Debug, Clone)]
@@ -75,6 +142,32 @@ pub struct BloxTableCell {
     pub colspan: usize,
     pub rowspan: usize,
     pub is_header: bool,
+    pub value: BloxCellValue,
+}
+
+/// A cell read as its column's type, or a formula's result, for sorting
+#[derive(Debug, Clone)]
+#[frb]
+pub enum BloxCellValue {
+    Empty,
+    Text(String),
+    Number(f64),
+    /// `YYYY-MM-DD`
+    Date(String),
+    Currency { amount: f64, symbol: Option<String> },
+    /// A formula that could not be evaluated, as a code such as `#DIV/0!`
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxColumnSpec {
+    /// `left`, `center` or `right`
+    pub align: String,
+    pub width: Option<String>,
+    /// `text`, `number`, `date` or `currency`
+    pub column_type: String,
+    pub decimals: Option<usize>,
 }
 
 #[derive(Debug, Clone)]
@@ -87,10 +180,43 @@ pub struct BloxTableRow {
 #[frb]
 pub struct BloxTable {
     pub caption: Option<String>,
+    pub columns: Vec<BloxColumnSpec>,
     pub header: Option<BloxTableRow>,
     pub rows: Vec<BloxTableRow>,
 }
 
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxQuizChoice {
+    pub content: String,
+    pub correct: bool,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxQuizQuestion {
+    /// `single`, `multiple`, `truefalse` or `short`
+    pub question_type: String,
+    pub prompt: String,
+    pub choices: Vec<BloxQuizChoice>,
+    /// Accepted answers of short answer questions; `true` or `false` for true/false ones
+    pub answers: Vec<String>,
+    pub explanation: Option<String>,
+    pub points: usize,
+    pub id: Option<String>,
+    /// Span of the question's `?` line, or of its `question` block header
+    pub span: BloxSpan,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxQuiz {
+    pub title: Option<String>,
+    /// Text before the first question
+    pub description: String,
+    pub questions: Vec<BloxQuizQuestion>,
+}
+
 #[derive(Debug, Clone)]
 #[frb]
 pub enum BloxOutputFormat {
@@ -98,6 +224,177 @@ pub enum BloxOutputFormat {
     Markdown,
     Json,
     PlainText,
+    Gift,
+    Qti,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub enum BloxAttributeOrder {
+    Source,
+    Sorted,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub enum BloxQuoteStyle {
+    Minimal,
+    Double,
+    Single,
+}
+
+/// How `format_blox_string` lays out a document
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxFormatStyle {
+    pub use_shorthand: bool,
+    pub attribute_order: BloxAttributeOrder,
+    pub quote_style: BloxQuoteStyle,
+    pub blank_lines: usize,
+    pub align_tables: bool,
+    pub normalize_lists: bool,
+    pub wrap_width: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub enum BloxSeverity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxFix {
+    pub span: BloxSpan,
+    pub replacement: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxDiagnostic {
+    pub severity: BloxSeverity,
+    pub code: String,
+    pub span: BloxSpan,
+    pub message: String,
+    pub fix: Option<BloxFix>,
+}
+
+/// How the parser reads indentation in front of headers and content
+#[derive(Debug, Clone, Default)]
+#[frb]
+pub enum BloxIndentPolicy {
+    #[default]
+    Flat,
+    Header,
+    Common,
+}
+
+#[derive(Debug, Clone, Default)]
+#[frb]
+pub struct BloxParserOptions {
+    pub strict_nesting: bool,
+    pub indent: BloxIndentPolicy,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxParseResult {
+    pub document: BloxDocument,
+    pub diagnostics: Vec<BloxDiagnostic>,
+}
+
+/// The block an id refers to, for jumping to it in the editor
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxReferenceTarget {
+    /// Indexes from `BloxDocument.blocks` down through `children`
+    pub path: Vec<usize>,
+    pub line_number: usize,
+    pub header_span: BloxSpan,
+    /// Whether the id is a slug made for a heading rather than written in the source
+    pub generated: bool,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxTextEdit {
+    pub span: BloxSpan,
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxTableEditResult {
+    /// The table block, parsed from the edited source
+    pub block: BloxBlock,
+    /// Edits to apply to the source, in source order
+    pub edits: Vec<BloxTextEdit>,
+}
+
+/// A change to a table; rows count body rows and columns count grid columns
+#[derive(Debug, Clone)]
+#[frb]
+pub enum BloxTableOp {
+    InsertRow { index: usize, cells: Vec<String> },
+    DeleteRow { index: usize },
+    MoveRow { from: usize, to: usize },
+    InsertColumn { index: usize, header: Option<String> },
+    DeleteColumn { index: usize },
+    MoveColumn { from: usize, to: usize },
+    Sort { column: usize, descending: bool },
+}
+
+/// A check list item in one of the files scanned
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxTask {
+    pub file: String,
+    pub text: String,
+    pub checked: bool,
+    /// Span of the item's line, or of its `item` block header
+    pub span: BloxSpan,
+    /// Titles of the sections around the list, outermost first
+    pub section_path: Vec<String>,
+    pub due: Option<String>,
+    pub assignee: Option<String>,
+}
+
+/// A flashcard and its review schedule
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxFlashcard {
+    pub id: String,
+    /// `definition` or `question`
+    pub kind: String,
+    pub front: String,
+    pub back: String,
+    pub span: BloxSpan,
+    /// `YYYY-MM-DD`; `None` for a card never reviewed
+    pub due: Option<String>,
+    pub repetitions: u32,
+    pub interval_days: u32,
+    pub ease: f64,
+}
+
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxPackageFile {
+    pub path: String,
+    pub content: String,
+}
+
+/// The files of a decoded document, with problems found while writing them
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BloxPackage {
+    pub files: Vec<BloxPackageFile>,
+    /// The files as a zip archive, as LMS imports expect for QTI
+    pub archive: Vec<u8>,
+    pub diagnostics: Vec<BloxDiagnostic>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,7 +409,27 @@ impl From<Document> for BloxDocument {
     fn from(doc: Document) -> Self {
         Self {
             blocks: doc.blocks.into_iter().map(Into::into).collect(),
-            metadata: doc.metadata,
+            metadata: doc.metadata.into(),
+        }
+    }
+}
+
+impl From<Metadata> for BloxMetadata {
+    fn from(metadata: Metadata) -> Self {
+        Self {
+            title: metadata.title().map(str::to_string),
+            authors: metadata.authors(),
+            created: metadata.created().map(|date| date.to_string()),
+            modified: metadata.modified().map(|date| date.to_string()),
+            tags: metadata.tags(),
+            entries: metadata.entries
+                .into_iter()
+                .map(|entry| BloxMetadataEntry {
+                    block: entry.block.to_str().to_string(),
+                    key: entry.attribute.key,
+                    value: entry.attribute.value,
+                })
+                .collect(),
         }
     }
 }
@@ -120,6 +437,14 @@ impl From<Document> for BloxDocument {
 impl From<Block> for BloxBlock {
     fn from(block: Block) -> Self {
         let block_type = block.block_type.to_str().to_string();
+        let attribute_spans = block.attributes
+            .iter()
+            .map(|attr| BloxAttributeSpan {
+                key: attr.key.clone(),
+                key_span: attr.key_span.into(),
+                value_span: attr.value_span.into(),
+            })
+            .collect();
         let attributes = block.attributes
             .into_iter()
             .map(|attr| (attr.key, attr.value))
@@ -127,8 +452,10 @@ impl From<Block> for BloxBlock {
         
         let children = block.children.into_iter().map(Into::into).collect();
         let inline_elements = block.inline_elements.into_iter().map(Into::into).collect();
+        let inline_spans = block.inline_spans.into_iter().map(Into::into).collect();
         let list_items = block.list_items.into_iter().map(Into::into).collect();
         let table = block.table.map(Into::into);
+        let quiz = block.quiz.map(Into::into);
         
         Self {
             block_type,
@@ -137,9 +464,156 @@ impl From<Block> for BloxBlock {
             content: block.content,
             children,
             line_number: block.line_number,
+            header_span: block.header_span.into(),
+            content_span: block.content_span.into(),
+            attribute_spans,
             inline_elements,
+            inline_spans,
             list_items,
             table,
+            quiz,
+        }
+    }
+}
+
+impl From<ReferenceTarget> for BloxReferenceTarget {
+    fn from(target: ReferenceTarget) -> Self {
+        Self {
+            path: target.path,
+            line_number: target.line_number,
+            header_span: target.header_span.into(),
+            generated: target.generated,
+        }
+    }
+}
+
+impl From<TextEdit> for BloxTextEdit {
+    fn from(edit: TextEdit) -> Self {
+        Self {
+            span: edit.span.into(),
+            replacement: edit.replacement,
+        }
+    }
+}
+
+impl From<TableEdit> for BloxTableEditResult {
+    fn from(edit: TableEdit) -> Self {
+        Self {
+            block: edit.block.into(),
+            edits: edit.edits.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<BloxTableOp> for TableOp {
+    fn from(op: BloxTableOp) -> Self {
+        match op {
+            BloxTableOp::InsertRow { index, cells } => TableOp::InsertRow { index, cells },
+            BloxTableOp::DeleteRow { index } => TableOp::DeleteRow { index },
+            BloxTableOp::MoveRow { from, to } => TableOp::MoveRow { from, to },
+            BloxTableOp::InsertColumn { index, header } => TableOp::InsertColumn { index, header },
+            BloxTableOp::DeleteColumn { index } => TableOp::DeleteColumn { index },
+            BloxTableOp::MoveColumn { from, to } => TableOp::MoveColumn { from, to },
+            BloxTableOp::Sort { column, descending } => TableOp::Sort { column, descending },
+        }
+    }
+}
+
+impl From<FileTask> for BloxTask {
+    fn from(found: FileTask) -> Self {
+        let task = found.task;
+        Self {
+            file: found.file.to_string_lossy().into_owned(),
+            text: task.text,
+            checked: task.checked,
+            span: task.span.into(),
+            section_path: task.section_path,
+            due: task.due,
+            assignee: task.assignee,
+        }
+    }
+}
+
+impl BloxFlashcard {
+    fn new(card: Card, schedule: Option<&CardSchedule>) -> Self {
+        Self {
+            id: card.id,
+            kind: card.kind.to_str().to_string(),
+            front: card.front,
+            back: card.back,
+            span: card.span.into(),
+            due: schedule.map(|schedule| schedule.due.to_string()),
+            repetitions: schedule.map_or(0, |schedule| schedule.repetitions),
+            interval_days: schedule.map_or(0, |schedule| schedule.interval),
+            ease: schedule.map_or(2.5, |schedule| schedule.ease),
+        }
+    }
+}
+
+impl From<Package> for BloxPackage {
+    fn from(package: Package) -> Self {
+        let archive = package.to_zip();
+        Self {
+            files: package.files.into_iter().map(|file| BloxPackageFile { path: file.path, content: file.content }).collect(),
+            archive,
+            diagnostics: package.diagnostics.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<Position> for BloxPosition {
+    fn from(position: Position) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+            offset: position.offset,
+        }
+    }
+}
+
+impl From<Span> for BloxSpan {
+    fn from(span: Span) -> Self {
+        Self {
+            start: span.start.into(),
+            end: span.end.into(),
+        }
+    }
+}
+
+impl From<Diagnostic> for BloxDiagnostic {
+    fn from(diagnostic: Diagnostic) -> Self {
+        let severity = match diagnostic.severity {
+            Severity::Error => BloxSeverity::Error,
+            Severity::Warning => BloxSeverity::Warning,
+            Severity::Info => BloxSeverity::Info,
+            Severity::Hint => BloxSeverity::Hint,
+        };
+        
+        let fix = diagnostic.fix.map(|fix| BloxFix {
+            span: fix.span.into(),
+            replacement: fix.replacement,
+            message: fix.message,
+        });
+        
+        Self {
+            severity,
+            code: diagnostic.code,
+            span: diagnostic.span.into(),
+            message: diagnostic.message,
+            fix,
+        }
+    }
+}
+
+impl From<BloxParserOptions> for ParserOptions {
+    fn from(options: BloxParserOptions) -> Self {
+        ParserOptions {
+            strict_nesting: options.strict_nesting,
+            indent: match options.indent {
+                BloxIndentPolicy::Flat => IndentPolicy::Flat,
+                BloxIndentPolicy::Header => IndentPolicy::Header,
+                BloxIndentPolicy::Common => IndentPolicy::Common,
+            },
         }
     }
 }
@@ -148,17 +622,18 @@ impl From<crate::blox::InlineElement> for BloxInlineElement {
     fn from(element: crate::blox::InlineElement) -> Self {
         match element {
             crate::blox::InlineElement::Text(content) => BloxInlineElement::Text(content),
-            crate::blox::InlineElement::Link { text, url } => BloxInlineElement::Link { text, url },
-            crate::blox::InlineElement::Bold(content) => BloxInlineElement::Bold(content),
-            crate::blox::InlineElement::Italic(content) => BloxInlineElement::Italic(content),
-            crate::blox::InlineElement::Code(content) => BloxInlineElement::Code(content),
+            crate::blox::InlineElement::Link { content, url } => BloxInlineElement::Link { content: convert_inline(content), url },
+            crate::blox::InlineElement::Bold(children) => BloxInlineElement::Bold(convert_inline(children)),
+            crate::blox::InlineElement::Italic(children) => BloxInlineElement::Italic(convert_inline(children)),
+            crate::blox::InlineElement::Code { code, lang } => BloxInlineElement::Code { code, lang },
             crate::blox::InlineElement::Math(content) => BloxInlineElement::Math(content),
-            crate::blox::InlineElement::Strikethrough(content) => BloxInlineElement::Strikethrough(content),
-            crate::blox::InlineElement::Highlight(content) => BloxInlineElement::Highlight(content),
-            crate::blox::InlineElement::Subscript(content) => BloxInlineElement::Subscript(content),
-            crate::blox::InlineElement::Superscript(content) => BloxInlineElement::Superscript(content),
-            crate::blox::InlineElement::Reference(content) => BloxInlineElement::Reference(content),
-            crate::blox::InlineElement::Footnote { id, text } => BloxInlineElement::Footnote { id, text },
+            crate::blox::InlineElement::Strikethrough(children) => BloxInlineElement::Strikethrough(convert_inline(children)),
+            crate::blox::InlineElement::Highlight(children) => BloxInlineElement::Highlight(convert_inline(children)),
+            crate::blox::InlineElement::Subscript(children) => BloxInlineElement::Subscript(convert_inline(children)),
+            crate::blox::InlineElement::Superscript(children) => BloxInlineElement::Superscript(convert_inline(children)),
+            crate::blox::InlineElement::Reference { id, relation } => BloxInlineElement::Reference { id, relation },
+            crate::blox::InlineElement::Footnote { id, content } => BloxInlineElement::Footnote { id, content: convert_inline(content) },
+            crate::blox::InlineElement::Variable(name) => BloxInlineElement::Variable(name),
             crate::blox::InlineElement::Custom { element_type, attributes, content } => {
                 let attrs = attributes.into_iter()
                     .map(|attr| (attr.key, attr.value))
@@ -184,6 +659,10 @@ impl From<ListItem> for BloxListItem {
             content: item.content,
             children,
             level: item.level,
+            number: item.number,
+            inline_elements: convert_inline(item.inline_elements),
+            attributes: item.attributes.into_iter().map(|attr| (attr.key, attr.value)).collect(),
+            span: item.span.into(),
         }
     }
 }
@@ -195,12 +674,43 @@ impl From<Table> for BloxTable {
         
         Self {
             caption: table.caption,
+            columns: table.columns.into_iter().map(Into::into).collect(),
             header,
             rows,
         }
     }
 }
 
+impl From<Quiz> for BloxQuiz {
+    fn from(quiz: Quiz) -> Self {
+        Self {
+            title: quiz.title,
+            description: quiz.description,
+            questions: quiz.questions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<QuizQuestion> for BloxQuizQuestion {
+    fn from(question: QuizQuestion) -> Self {
+        let choices = question.choices
+            .into_iter()
+            .map(|choice| BloxQuizChoice { content: choice.content, correct: choice.correct })
+            .collect();
+        
+        Self {
+            question_type: question.question_type.to_str().to_string(),
+            prompt: question.prompt,
+            choices,
+            answers: question.answers,
+            explanation: question.explanation,
+            points: question.points,
+            id: question.id,
+            span: question.span.into(),
+        }
+    }
+}
+
 impl From<TableRow> for BloxTableRow {
     fn from(row: TableRow) -> Self {
         let cells = row.cells.into_iter().map(Into::into).collect();
@@ -215,6 +725,31 @@ impl From<TableCell> for BloxTableCell {
             colspan: cell.colspan,
             rowspan: cell.rowspan,
             is_header: cell.is_header,
+            value: cell.value.into(),
+        }
+    }
+}
+
+impl From<CellValue> for BloxCellValue {
+    fn from(value: CellValue) -> Self {
+        match value {
+            CellValue::Empty => BloxCellValue::Empty,
+            CellValue::Text(text) => BloxCellValue::Text(text),
+            CellValue::Number(number) => BloxCellValue::Number(number),
+            CellValue::Date(date) => BloxCellValue::Date(date.to_string()),
+            CellValue::Currency { amount, symbol } => BloxCellValue::Currency { amount, symbol },
+            CellValue::Error(error) => BloxCellValue::Error(error.code().to_string()),
+        }
+    }
+}
+
+impl From<ColumnSpec> for BloxColumnSpec {
+    fn from(spec: ColumnSpec) -> Self {
+        Self {
+            align: spec.align.to_str().to_string(),
+            width: spec.width,
+            column_type: spec.column_type.to_str().to_string(),
+            decimals: spec.decimals,
         }
     }
 }
@@ -223,20 +758,21 @@ impl From<BloxInlineElement> for crate::blox::InlineElement {
     fn from(element: BloxInlineElement) -> Self {
         match element {
             BloxInlineElement::Text(content) => crate::blox::InlineElement::Text(content),
-            BloxInlineElement::Link { text, url } => crate::blox::InlineElement::Link { text, url },
-            BloxInlineElement::Bold(content) => crate::blox::InlineElement::Bold(content),
-            BloxInlineElement::Italic(content) => crate::blox::InlineElement::Italic(content),
-            BloxInlineElement::Code(content) => crate::blox::InlineElement::Code(content),
+            BloxInlineElement::Link { content, url } => crate::blox::InlineElement::Link { content: convert_inline(content), url },
+            BloxInlineElement::Bold(children) => crate::blox::InlineElement::Bold(convert_inline(children)),
+            BloxInlineElement::Italic(children) => crate::blox::InlineElement::Italic(convert_inline(children)),
+            BloxInlineElement::Code { code, lang } => crate::blox::InlineElement::Code { code, lang },
             BloxInlineElement::Math(content) => crate::blox::InlineElement::Math(content),
-            BloxInlineElement::Strikethrough(content) => crate::blox::InlineElement::Strikethrough(content),
-            BloxInlineElement::Highlight(content) => crate::blox::InlineElement::Highlight(content),
-            BloxInlineElement::Subscript(content) => crate::blox::InlineElement::Subscript(content),
-            BloxInlineElement::Superscript(content) => crate::blox::InlineElement::Superscript(content),
-            BloxInlineElement::Reference(content) => crate::blox::InlineElement::Reference(content),
-            BloxInlineElement::Footnote { id, text } => crate::blox::InlineElement::Footnote { id, text },
+            BloxInlineElement::Strikethrough(children) => crate::blox::InlineElement::Strikethrough(convert_inline(children)),
+            BloxInlineElement::Highlight(children) => crate::blox::InlineElement::Highlight(convert_inline(children)),
+            BloxInlineElement::Subscript(children) => crate::blox::InlineElement::Subscript(convert_inline(children)),
+            BloxInlineElement::Superscript(children) => crate::blox::InlineElement::Superscript(convert_inline(children)),
+            BloxInlineElement::Reference { id, relation } => crate::blox::InlineElement::Reference { id, relation },
+            BloxInlineElement::Footnote { id, content } => crate::blox::InlineElement::Footnote { id, content: convert_inline(content) },
+            BloxInlineElement::Variable(name) => crate::blox::InlineElement::Variable(name),
             BloxInlineElement::Custom { element_type, attributes, content } => {
                 let attrs = attributes.into_iter()
-                    .map(|(k, v)| crate::blox::Attribute { key: k, value: v })
+                    .map(|(k, v)| crate::blox::Attribute::new(k, v))
                     .collect();
                 crate::blox::InlineElement::Custom { element_type, attributes: attrs, content }
             }
@@ -244,6 +780,42 @@ impl From<BloxInlineElement> for crate::blox::InlineElement {
     }
 }
 
+fn convert_inline<T: Into<U>, U>(elements: Vec<T>) -> Vec<U> {
+    elements.into_iter().map(Into::into).collect()
+}
+
+impl From<BloxMetadata> for Metadata {
+    fn from(metadata: BloxMetadata) -> Self {
+        let entries = metadata.entries
+            .into_iter()
+            .map(|entry| MetadataEntry {
+                block: if entry.block == "document" { MetadataBlock::Document } else { MetadataBlock::Meta },
+                attribute: Attribute::new(entry.key, entry.value),
+            })
+            .collect();
+        Self { entries }
+    }
+}
+
+impl From<BloxPosition> for Position {
+    fn from(position: BloxPosition) -> Self {
+        Self {
+            line: position.line,
+            column: position.column,
+            offset: position.offset,
+        }
+    }
+}
+
+impl From<BloxSpan> for Span {
+    fn from(span: BloxSpan) -> Self {
+        Self {
+            start: span.start.into(),
+            end: span.end.into(),
+        }
+    }
+}
+
 impl From<BloxListItem> for ListItem {
     fn from(item: BloxListItem) -> Self {
         let item_type = match item.item_type {
@@ -259,6 +831,10 @@ impl From<BloxListItem> for ListItem {
             content: item.content,
             children,
             level: item.level,
+            number: item.number,
+            inline_elements: convert_inline(item.inline_elements),
+            attributes: item.attributes.into_iter().map(|(key, value)| Attribute::new(key, value)).collect(),
+            span: item.span.into(),
         }
     }
 }
@@ -270,12 +846,43 @@ impl From<BloxTable> for Table {
         
         Self {
             caption: table.caption,
+            columns: table.columns.into_iter().map(Into::into).collect(),
             header,
             rows,
         }
     }
 }
 
+impl From<BloxQuiz> for Quiz {
+    fn from(quiz: BloxQuiz) -> Self {
+        Self {
+            title: quiz.title,
+            description: quiz.description,
+            questions: quiz.questions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<BloxQuizQuestion> for QuizQuestion {
+    fn from(question: BloxQuizQuestion) -> Self {
+        let choices = question.choices
+            .into_iter()
+            .map(|choice| QuizChoice { content: choice.content, correct: choice.correct })
+            .collect();
+        
+        Self {
+            question_type: QuestionType::from_str(&question.question_type).unwrap_or(QuestionType::ShortAnswer),
+            prompt: question.prompt,
+            choices,
+            answers: question.answers,
+            explanation: question.explanation,
+            points: question.points,
+            id: question.id,
+            span: question.span.into(),
+        }
+    }
+}
+
 impl From<BloxTableRow> for TableRow {
     fn from(row: BloxTableRow) -> Self {
         let cells = row.cells.into_iter().map(Into::into).collect();
@@ -290,6 +897,32 @@ impl From<BloxTableCell> for TableCell {
             colspan: cell.colspan,
             rowspan: cell.rowspan,
             is_header: cell.is_header,
+            value: cell.value.into(),
+        }
+    }
+}
+
+impl From<BloxCellValue> for CellValue {
+    fn from(value: BloxCellValue) -> Self {
+        match value {
+            BloxCellValue::Empty => CellValue::Empty,
+            BloxCellValue::Text(text) => CellValue::Text(text),
+            BloxCellValue::Number(number) => CellValue::Number(number),
+            BloxCellValue::Date(date) => MetadataDate::parse(&date).map_or(CellValue::Text(date), CellValue::Date),
+            BloxCellValue::Currency { amount, symbol } => CellValue::Currency { amount, symbol },
+            BloxCellValue::Error(code) => FormulaError::from_code(&code).map_or(CellValue::Text(code), CellValue::Error),
+        }
+    }
+}
+
+impl From<BloxColumnSpec> for ColumnSpec {
+    fn from(spec: BloxColumnSpec) -> Self {
+        let column_type = ColumnType::from_str(&spec.column_type).unwrap_or(ColumnType::Text);
+        Self {
+            align: ColumnAlign::from_str(&spec.align).unwrap_or_else(|| column_type.default_align()),
+            width: spec.width,
+            column_type,
+            decimals: spec.decimals,
         }
     }
 }
@@ -301,6 +934,29 @@ impl From<BloxOutputFormat> for OutputFormat {
             BloxOutputFormat::Markdown => OutputFormat::Markdown,
             BloxOutputFormat::Json => OutputFormat::Json,
             BloxOutputFormat::PlainText => OutputFormat::PlainText,
+            BloxOutputFormat::Gift => OutputFormat::Gift,
+            BloxOutputFormat::Qti => OutputFormat::Qti,
+        }
+    }
+}
+
+impl From<BloxFormatStyle> for FormatStyle {
+    fn from(style: BloxFormatStyle) -> Self {
+        Self {
+            use_shorthand: style.use_shorthand,
+            attribute_order: match style.attribute_order {
+                BloxAttributeOrder::Source => AttributeOrder::Source,
+                BloxAttributeOrder::Sorted => AttributeOrder::Sorted,
+            },
+            quote_style: match style.quote_style {
+                BloxQuoteStyle::Minimal => QuoteStyle::Minimal,
+                BloxQuoteStyle::Double => QuoteStyle::Double,
+                BloxQuoteStyle::Single => QuoteStyle::Single,
+            },
+            blank_lines: style.blank_lines,
+            align_tables: style.align_tables,
+            normalize_lists: style.normalize_lists,
+            wrap_width: style.wrap_width,
         }
     }
 }
@@ -316,6 +972,21 @@ pub fn parse_blox_string(content: String) -> Result<BloxDocument, String> {
     }
 }
 
+/// Parse a Blox document, reporting every problem instead of failing.
+/// The returned document is always usable for rendering; duplicate ids and
+/// unresolved references are reported along with parse problems.
+#[frb(sync)]
+pub fn parse_blox_string_with_diagnostics(content: String, options: BloxParserOptions) -> BloxParseResult {
+    let mut parser = BloxParser::with_options(options.into());
+    let mut output = parser.parse_with_diagnostics(&content);
+    output.diagnostics.extend(Resolver::new().resolve(&output.document).diagnostics);
+    
+    BloxParseResult {
+        document: output.document.into(),
+        diagnostics: output.diagnostics.into_iter().map(Into::into).collect(),
+    }
+}
+
 /// Parse a Blox document from file
 #[frb]
 pub async fn parse_blox_file(file_path: String) -> Result<BloxDocument, String> {
@@ -329,7 +1000,11 @@ pub async fn parse_blox_file(file_path: String) -> Result<BloxDocument, String>
     }).await.map_err(|e| e.to_string())?
 }
 
-/// Encode a Blox document back to string format
+/// Encode a Blox document back to string format. Metadata is written at
+/// the top; `encode_blox_document_preserving` keeps it where it was written.
+/// Fails for a document that cannot be read back as written, such as one
+/// whose content has `\r\n` line breaks or whose attribute values hold
+/// line breaks.
 #[frb(sync)]
 pub fn encode_blox_document(document: BloxDocument, use_shorthand: bool) -> Result<String, String> {
     // Convert back to internal format
@@ -343,7 +1018,84 @@ pub fn encode_blox_document(document: BloxDocument, use_shorthand: bool) -> Resu
     }
 }
 
-/// Decode a Blox document to specified output format
+/// Encode a document edited through its list items, tables or inline
+/// elements, writing the content of those blocks from the edited models
+#[frb(sync)]
+pub fn encode_blox_document_from_models(document: BloxDocument, use_shorthand: bool) -> Result<String, String> {
+    let internal_doc = convert_to_internal_document(document);
+    
+    BloxEncoder::with_options(0, use_shorthand)
+        .with_models(true)
+        .encode(&internal_doc)
+        .map_err(|error| error.to_string())
+}
+
+/// Encode an edited document as a change to `source`, the text it was
+/// parsed from, keeping the formatting of everything that was not edited.
+/// Blocks edited only through their structured models are written from them.
+#[frb(sync)]
+pub fn encode_blox_document_preserving(source: String, document: BloxDocument, use_shorthand: bool) -> Result<String, String> {
+    let tree = BloxParser::new().parse_tree(&source);
+    let mut internal_doc = convert_to_internal_document(document);
+    restore_attributes(&mut internal_doc.blocks, &tree.document().blocks);
+    
+    BloxEncoder::with_options(0, use_shorthand)
+        .encode_preserving(&tree, &internal_doc)
+        .map_err(|error| error.to_string())
+}
+
+/// Give blocks found in the source back the attributes the bridge's map
+/// cannot hold: repeated keys and positional values. Only keys whose value
+/// the map changes, adds or removes differ from the source.
+fn restore_attributes(blocks: &mut [Block], originals: &[Block]) {
+    for block in blocks {
+        if let Some(original) = find_block(originals, block.header_span) {
+            block.attributes = merge_attributes(&original.attributes, &block.attributes);
+        }
+        restore_attributes(&mut block.children, originals);
+    }
+}
+
+fn find_block(blocks: &[Block], header_span: Span) -> Option<&Block> {
+    blocks.iter().find_map(|block| {
+        if block.header_span == header_span {
+            Some(block)
+        } else {
+            find_block(&block.children, header_span)
+        }
+    })
+}
+
+/// `original` with the values of `edited`, which holds one attribute per
+/// key; a repeated key takes the edited value at its last occurrence
+fn merge_attributes(original: &[Attribute], edited: &[Attribute]) -> Vec<Attribute> {
+    let mut merged = Vec::new();
+    for (i, attribute) in original.iter().enumerate() {
+        let Some(edit) = edited.iter().find(|edit| edit.key == attribute.key) else {
+            continue;
+        };
+        let last = !original[i + 1..].iter().any(|later| later.key == attribute.key);
+        merged.push(if last && edit.value != attribute.value { edit.clone() } else { attribute.clone() });
+    }
+    merged.extend(edited.iter().filter(|edit| !original.iter().any(|attribute| attribute.key == edit.key)).cloned());
+    merged
+}
+
+/// Format Blox source in `style`, keeping its comments. Fails rather
+/// than return text that would parse as a different document.
+#[frb(sync)]
+pub fn format_blox_string(content: String, style: BloxFormatStyle) -> Result<String, String> {
+    Formatter::new(style.into()).format(&content).map_err(|error| error.to_string())
+}
+
+/// Whether Blox source is already formatted in `style`
+#[frb(sync)]
+pub fn check_blox_format(content: String, style: BloxFormatStyle) -> Result<bool, String> {
+    Formatter::new(style.into()).check(&content).map_err(|error| error.to_string())
+}
+
+/// Decode a Blox document to specified output format; GIFT and QTI are
+/// decoded with `decode_blox_package`
 #[frb(sync)]
 pub fn decode_blox_document(
     document: BloxDocument, 
@@ -360,6 +1112,28 @@ pub fn decode_blox_document(
     }
 }
 
+/// Decode a document to the files of `format`, such as the QTI package of its quizzes
+#[frb(sync)]
+pub fn decode_blox_package(document: BloxDocument, format: BloxOutputFormat) -> Result<BloxPackage, String> {
+    let internal_doc = convert_to_internal_document(document);
+    
+    BloxDecoder::new(format.into())
+        .decode_package(&internal_doc)
+        .map(Into::into)
+        .map_err(|error| error.to_string())
+}
+
+/// Render a document as HTML with quiz answers marked and explanations shown
+#[frb(sync)]
+pub fn decode_blox_answer_key(document: BloxDocument) -> Result<String, String> {
+    let internal_doc = convert_to_internal_document(document);
+    
+    BloxDecoder::new(OutputFormat::Html)
+        .with_answer_key(true)
+        .decode(&internal_doc)
+        .map_err(|error| error.to_string())
+}
+
 /// Parse large Blox file with progress updates
 #[frb]
 pub async fn parse_blox_file_with_progress(
@@ -372,14 +1146,13 @@ pub async fn parse_blox_file_with_progress(
             .map_err(|e| e.to_string())?;
         
         let total_lines = content.lines().count() as u64;
-        let mut lines_processed = 0u64;
         let mut current_section = "Starting...".to_string();
         
         let mut parser = BloxParser::new();
         
         // Parse line by line with progress updates
         for (line_num, line) in content.lines().enumerate() {
-            lines_processed = line_num as u64 + 1;
+            let lines_processed = line_num as u64 + 1;
             
             // Update current section based on line content
             if line.trim().starts_with('#') && line.contains("title=") {
@@ -392,7 +1165,7 @@ pub async fn parse_blox_file_with_progress(
             }
             
             // Send progress update every 100 lines or at end
-            if lines_processed % 100 == 0 || lines_processed == total_lines {
+            if lines_processed.is_multiple_of(100) || lines_processed == total_lines {
                 progress_callback(ParseProgress {
                     lines_processed,
                     total_lines,
@@ -409,54 +1182,147 @@ pub async fn parse_blox_file_with_progress(
     }).await.map_err(|e| e.to_string())?
 }
 
-/// Validate Blox syntax without full parsing
+/// Replace `{{!name}}` variables with their values.
+/// `variables` take precedence over `#var` definitions in the document;
+/// each variable without a value is reported as a diagnostic.
 #[frb(sync)]
-pub fn validate_blox_syntax(content: String) -> Result<Vec<String>, String> {
-    let mut warnings = Vec::new();
-    let mut current_level = 0;
+pub fn interpolate_blox_variables(document: BloxDocument, variables: std::collections::HashMap<String, String>) -> BloxParseResult {
+    let internal_doc = convert_to_internal_document(document);
+    let result = Interpolator::with_values(variables).interpolate(&internal_doc);
     
-    for (line_num, line) in content.lines().enumerate() {
-        let line_number = line_num + 1;
-        let trimmed = line.trim();
-        
-        // Skip empty lines and comments
-        if trimmed.is_empty() || trimmed.starts_with("//") {
-            continue;
-        }
-        
-        // Check block syntax
-        if trimmed.starts_with('#') {
-            let level = trimmed.chars().take_while(|&c| c == '#').count();
-            
-            if level > 6 {
-                warnings.push(format!("Line {}: Too many # symbols (max 6)", line_number));
-            }
-            
-            if level > current_level + 1 {
-                warnings.push(format!(
-                    "Line {}: Invalid nesting - jumped from level {} to {}", 
-                    line_number, current_level, level
-                ));
-            }
-            
-            current_level = level;
-            
-            // Check for block type after #
-            let after_hashes = &trimmed[level..].trim_start();
-            if after_hashes.is_empty() {
-                warnings.push(format!("Line {}: Missing block type after #", line_number));
-            } else if !after_hashes.chars().next().unwrap().is_alphabetic() {
-                warnings.push(format!("Line {}: Block type must start with letter", line_number));
-            }
-        }
+    BloxParseResult {
+        document: result.document.into(),
+        diagnostics: result.diagnostics.into_iter().map(Into::into).collect(),
     }
+}
+
+/// Load the rows of `#table src=...` blocks from files relative to `base_dir`,
+/// the directory the document was read from
+#[frb(sync)]
+pub fn load_blox_tables(document: BloxDocument, base_dir: String) -> BloxParseResult {
+    let internal_doc = convert_to_internal_document(document);
+    let files = DirectoryFiles::new(base_dir);
+    let result = TableLoader::new(&files).load(&internal_doc);
+    
+    BloxParseResult {
+        document: result.document.into(),
+        diagnostics: result.diagnostics.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// Find the block that `id` refers to, including slugs made for headings without an id
+#[frb(sync)]
+pub fn resolve_reference(document: BloxDocument, id: String) -> Option<BloxReferenceTarget> {
+    let internal_doc = convert_to_internal_document(document);
+    Resolver::new().resolve(&internal_doc).index.get(&id).cloned().map(Into::into)
+}
+
+/// Change the table block at `path` in `content`, returning the source edits that make the change
+#[frb(sync)]
+pub fn edit_blox_table(content: String, path: Vec<usize>, op: BloxTableOp) -> Result<BloxTableEditResult, String> {
+    edit_table(&content, &path, &op.into())
+        .map(Into::into)
+        .map_err(|error| error.to_string())
+}
+
+/// Keep the body rows of the table at `path` whose text contains `query`,
+/// ignoring case, in the cell at index `column` or in any cell
+#[frb(sync)]
+pub fn filter_blox_table_rows(content: String, path: Vec<usize>, column: Option<usize>, query: String) -> Result<BloxTableEditResult, String> {
+    let query = query.to_lowercase();
+    let matches = |cell: &TableCell| cell.content.to_lowercase().contains(&query);
+    
+    filter_table(&content, &path, |row| match column {
+        Some(column) => row.cells.get(column).is_some_and(matches),
+        None => row.cells.iter().any(matches),
+    })
+    .map(Into::into)
+    .map_err(|error| error.to_string())
+}
+
+/// Every task in the `.blox` files at `paths`, searching directories for them
+#[frb(sync)]
+pub fn scan_blox_tasks(paths: Vec<String>) -> Result<Vec<BloxTask>, String> {
+    scan_files(&paths)
+        .map(|tasks| tasks.into_iter().map(Into::into).collect())
+        .map_err(|error| error.to_string())
+}
+
+/// Check or uncheck the task at `span` in `file`, rewriting only its line on disk
+#[frb(sync)]
+pub fn toggle_blox_task(file: String, span: BloxSpan) -> Result<BloxTask, String> {
+    let path = std::path::PathBuf::from(file);
+    toggle_task_in_file(&path, span.into())
+        .map(|task| FileTask { file: path, task }.into())
+        .map_err(|error| error.to_string())
+}
+
+/// The flashcards of the file at `file_path`, with their review schedules
+#[frb(sync)]
+pub fn blox_flashcards(file_path: String) -> Result<Vec<BloxFlashcard>, String> {
+    let (cards, log) = load_flashcards(&file_path).map_err(|error| error.to_string())?;
+    Ok(cards.into_iter().map(|card| {
+        let schedule = log.schedule(&card.id);
+        BloxFlashcard::new(card, schedule)
+    }).collect())
+}
+
+/// The flashcards of the file at `file_path` to review on `today`
+/// (`YYYY-MM-DD`, or the current date), due cards before new ones
+#[frb(sync)]
+pub fn due_blox_flashcards(file_path: String, today: Option<String>) -> Result<Vec<BloxFlashcard>, String> {
+    let today = review_date(today)?;
+    let (cards, log) = load_flashcards(&file_path).map_err(|error| error.to_string())?;
+    Ok(log.due(&cards, today).into_iter().map(|card| BloxFlashcard::new(card.clone(), log.schedule(&card.id))).collect())
+}
+
+/// Record a review of the card `card_id`, graded from 0 (forgotten) to 5
+/// (perfect recall), in the review log next to the file
+#[frb(sync)]
+pub fn review_blox_flashcard(file_path: String, card_id: String, grade: u8, today: Option<String>) -> Result<BloxFlashcard, String> {
+    let today = review_date(today)?;
+    let (cards, mut log) = load_flashcards(&file_path).map_err(|error| error.to_string())?;
+    let card = cards
+        .into_iter()
+        .find(|card| card.id == card_id)
+        .ok_or_else(|| format!("No flashcard {} in {}", card_id, file_path))?;
+    
+    log.review(&card_id, grade, today);
+    log.save(&review_log_path(std::path::Path::new(&file_path))).map_err(|error| error.to_string())?;
+    Ok(BloxFlashcard::new(card, log.schedule(&card_id)))
+}
+
+fn load_flashcards(file_path: &str) -> crate::blox::ParseResult<(Vec<Card>, ReviewLog)> {
+    let document = BloxParser::new().parse_file(file_path)?;
+    let log = ReviewLog::load(&review_log_path(std::path::Path::new(file_path)))?;
+    Ok((extract_cards(&document), log))
+}
+
+fn review_date(date: Option<String>) -> Result<MetadataDate, String> {
+    match date {
+        Some(date) => MetadataDate::parse(&date).ok_or_else(|| format!("`{}` is not a YYYY-MM-DD date", date)),
+        None => Ok(MetadataDate::today()),
+    }
+}
+
+/// Validate Blox syntax without full parsing.
+/// Nesting is checked strictly; unknown block types are not reported.
+#[frb(sync)]
+pub fn validate_blox_syntax(content: String) -> Result<Vec<String>, String> {
+    let mut parser = BloxParser::with_options(ParserOptions { strict_nesting: true, ..ParserOptions::default() });
+    let output = parser.parse_with_diagnostics(&content);
+    
+    let warnings = output.diagnostics.iter()
+        .filter(|diagnostic| diagnostic.severity <= Severity::Warning)
+        .map(|diagnostic| format!("Line {}: {}", diagnostic.span.start.line, diagnostic.message))
+        .collect();
     
     Ok(warnings)
 }
 
 fn convert_to_internal_document(doc: BloxDocument) -> Document {
     let mut internal_doc = Document::new();
-    internal_doc.metadata = doc.metadata;
+    internal_doc.metadata = doc.metadata.into();
     internal_doc.blocks = doc.blocks.into_iter().map(convert_to_internal_block).collect();
     internal_doc
 }
@@ -466,9 +1332,20 @@ fn convert_to_internal_block(block: BloxBlock) -> crate::blox::Block {
     let mut internal_block = crate::blox::Block::new(block_type, block.level, block.line_number);
     
     internal_block.content = block.content;
+    internal_block.header_span = block.header_span.into();
+    internal_block.content_span = block.content_span.into();
+    
+    let attribute_spans = block.attribute_spans;
     internal_block.attributes = block.attributes
         .into_iter()
-        .map(|(k, v)| crate::blox::Attribute { key: k, value: v })
+        .map(|(k, v)| {
+            let mut attribute = crate::blox::Attribute::new(k, v);
+            if let Some(spans) = attribute_spans.iter().find(|spans| spans.key == attribute.key) {
+                attribute.key_span = spans.key_span.clone().into();
+                attribute.value_span = spans.value_span.clone().into();
+            }
+            attribute
+        })
         .collect();
     
     internal_block.children = block.children
@@ -482,12 +1359,18 @@ fn convert_to_internal_block(block: BloxBlock) -> crate::blox::Block {
         .map(Into::into)
         .collect();
     
+    internal_block.inline_spans = block.inline_spans
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    
     internal_block.list_items = block.list_items
         .into_iter()
         .map(Into::into)
         .collect();
     
     internal_block.table = block.table.map(Into::into);
+    internal_block.quiz = block.quiz.map(Into::into);
     
     internal_block
 }
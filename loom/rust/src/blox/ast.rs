@@ -1,10 +1,13 @@
-use std::collections::HashMap;
-use lazy_static::lazy_static;
+use crate::blox::attributes::AttributeScanner;
+use crate::blox::inline::parse_inline;
+use crate::blox::formula::FormulaError;
+use crate::blox::metadata::{Metadata, MetadataDate};
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Document {
     pub blocks: Vec<Block>,
-    pub metadata: HashMap<String, String>,
+    /// Metadata lifted from `#meta` and `#document` blocks
+    pub metadata: Metadata,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -15,10 +18,34 @@ pub struct Block {
     pub content: String,
     pub children: Vec<Block>,
     pub line_number: usize,
+    // Source locations
+    pub header_span: Span,
+    pub content_span: Span,
     // Enhanced content structures
     pub inline_elements: Vec<InlineElement>,
+    /// Spans of `inline_elements` and their children, in pre-order
+    pub inline_spans: Vec<Span>,
     pub list_items: Vec<ListItem>,
     pub table: Option<Table>,
+    pub quiz: Option<Quiz>,
+}
+
+/// A point in the source text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Position {
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+    /// 0-based byte offset from the start of the source
+    pub offset: usize,
+}
+
+/// A half-open range `[start, end)` in the source text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -33,6 +60,7 @@ pub enum BlockType {
     List,
     Math,
     Comment,
+    Quiz,
     
     // Shorthand aliases
     H1, H2, H3, H4, H5, H6,  // Headers
@@ -51,25 +79,75 @@ pub enum BlockType {
 pub struct Attribute {
     pub key: String,
     pub value: String,
+    /// Span of the key; empty and placed at the value for positional attributes
+    pub key_span: Span,
+    /// Span of the value, excluding any surrounding quotes
+    pub value_span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum InlineElement {
     Text(String),
-    Link { text: String, url: String },
-    Bold(String),
-    Italic(String),
-    Code(String),
+    Link { content: Vec<InlineElement>, url: String },
+    Bold(Vec<InlineElement>),
+    Italic(Vec<InlineElement>),
+    Code { code: String, lang: Option<String> },
     Math(String),
-    Strikethrough(String),
-    Highlight(String),
-    Subscript(String),
-    Superscript(String),
-    Reference(String),
-    Footnote { id: String, text: String },
+    Strikethrough(Vec<InlineElement>),
+    Highlight(Vec<InlineElement>),
+    Subscript(Vec<InlineElement>),
+    Superscript(Vec<InlineElement>),
+    Reference { id: String, relation: Option<String> },
+    Footnote { id: String, content: Vec<InlineElement> },
+    /// A `{{!name}}` variable, replaced with its value by `variables::interpolate`
+    Variable(String),
+    /// An inline type the parser does not know; content is kept as written
     Custom { element_type: String, attributes: Vec<Attribute>, content: String },
 }
 
+impl InlineElement {
+    /// Child elements, for the kinds that nest
+    pub fn children(&self) -> &[InlineElement] {
+        match self {
+            InlineElement::Link { content, .. } | InlineElement::Footnote { content, .. } => content,
+            InlineElement::Bold(children)
+            | InlineElement::Italic(children)
+            | InlineElement::Strikethrough(children)
+            | InlineElement::Highlight(children)
+            | InlineElement::Subscript(children)
+            | InlineElement::Superscript(children) => children,
+            _ => &[],
+        }
+    }
+    
+    pub fn children_mut(&mut self) -> &mut [InlineElement] {
+        match self {
+            InlineElement::Link { content, .. } | InlineElement::Footnote { content, .. } => content,
+            InlineElement::Bold(children)
+            | InlineElement::Italic(children)
+            | InlineElement::Strikethrough(children)
+            | InlineElement::Highlight(children)
+            | InlineElement::Subscript(children)
+            | InlineElement::Superscript(children) => children,
+            _ => &mut [],
+        }
+    }
+    
+    /// The text of this element and everything inside it, without markup
+    pub fn plain_text(&self) -> String {
+        match self {
+            InlineElement::Text(text) | InlineElement::Math(text) => text.clone(),
+            InlineElement::Code { code, .. } => code.clone(),
+            InlineElement::Reference { id, .. } => id.clone(),
+            InlineElement::Variable(name) => format!("{{{{!{}}}}}", name),
+            InlineElement::Custom { content, .. } => {
+                parse_inline(content).elements.iter().map(InlineElement::plain_text).collect()
+            }
+            _ => self.children().iter().map(InlineElement::plain_text).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ListType {
     Unordered,
@@ -90,7 +168,37 @@ pub struct ListItem {
     pub item_type: ListItemType,
     pub content: String,
     pub children: Vec<ListItem>,
+    /// Depth in the list, starting at 1
     pub level: usize,
+    /// The item's number in an ordered list
+    pub number: Option<usize>,
+    pub inline_elements: Vec<InlineElement>,
+    /// Attributes of its `{{key=value text}}` form, then of its `item` block
+    pub attributes: Vec<Attribute>,
+    /// Span of the item's first line, or of its `item` block header
+    pub span: Span,
+}
+
+impl ListItem {
+    /// The first value of `key`, as `Block::get_attribute` gives it, so a
+    /// `{{key=value text}}` form wins over its `item` block's header
+    pub fn get_attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.iter().find(|attr| attr.key == key).map(|attr| attr.value.as_str())
+    }
+    
+    /// Whether the item is checked or unchecked, as opposed to a definition
+    pub fn is_task(&self) -> bool {
+        matches!(self.item_type, ListItemType::Checked | ListItemType::Unchecked)
+    }
+    
+    /// Let `checked` and `term` attributes set the item type
+    fn apply_attributes(&mut self) {
+        if let Some(term) = self.get_attribute("term") {
+            self.item_type = ListItemType::Definition { term: term.to_string() };
+        } else if let Some(checked) = self.get_attribute("checked") {
+            self.item_type = if checked == "true" { ListItemType::Checked } else { ListItemType::Unchecked };
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -99,6 +207,47 @@ pub struct TableCell {
     pub colspan: usize,
     pub rowspan: usize,
     pub is_header: bool,
+    /// The content read as its column's type, or a formula's result
+    pub value: CellValue,
+}
+
+/// A cell's content as a typed value, for sorting and formatting
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum CellValue {
+    Empty,
+    Text(String),
+    Number(f64),
+    Date(MetadataDate),
+    Currency { amount: f64, symbol: Option<String> },
+    /// A formula that could not be evaluated
+    Error(FormulaError),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ColumnAlign {
+    Left,
+    Center,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ColumnType {
+    Text,
+    Number,
+    Date,
+    Currency,
+}
+
+/// How a column is read and shown, from the table's `align`, `width`,
+/// `types` and `decimals` attributes
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ColumnSpec {
+    pub align: ColumnAlign,
+    /// A CSS length such as `20%` or `120px`; a bare number is in characters
+    pub width: Option<String>,
+    pub column_type: ColumnType,
+    /// Decimal places numbers and amounts are shown with
+    pub decimals: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -109,15 +258,229 @@ pub struct TableRow {
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Table {
     pub caption: Option<String>,
+    pub columns: Vec<ColumnSpec>,
     pub header: Option<TableRow>,
     pub rows: Vec<TableRow>,
 }
 
+/// How a quiz question is answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum QuestionType {
+    SingleChoice,
+    MultipleChoice,
+    TrueFalse,
+    ShortAnswer,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QuizChoice {
+    pub content: String,
+    pub correct: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct QuizQuestion {
+    pub question_type: QuestionType,
+    pub prompt: String,
+    pub choices: Vec<QuizChoice>,
+    /// Accepted answers of short answer questions; `true` or `false` for true/false ones
+    pub answers: Vec<String>,
+    pub explanation: Option<String>,
+    pub points: usize,
+    pub id: Option<String>,
+    /// Span of the question's `?` line, or of its `question` block header
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Quiz {
+    pub title: Option<String>,
+    /// Text before the first question
+    pub description: String,
+    pub questions: Vec<QuizQuestion>,
+}
+
 impl Document {
     pub fn new() -> Self {
         Self {
             blocks: Vec::new(),
-            metadata: HashMap::new(),
+            metadata: Metadata::new(),
+        }
+    }
+    
+    /// The block at `path`, a list of indexes from `blocks` down through `children`
+    pub fn block_at(&self, path: &[usize]) -> Option<&Block> {
+        let (first, rest) = path.split_first()?;
+        rest.iter().try_fold(self.blocks.get(*first)?, |block, &i| block.children.get(i))
+    }
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Position {
+    pub fn new(line: usize, column: usize, offset: usize) -> Self {
+        Self { line, column, offset }
+    }
+    
+    /// Locate a byte offset within `text`, counting lines and columns from 1
+    pub fn at(text: &str, offset: usize) -> Self {
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            offset,
+        }
+    }
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+    
+    /// An empty span at `position`
+    pub fn point(position: Position) -> Self {
+        Self { start: position, end: position }
+    }
+    
+    /// Span of the byte range `start..end` within `text`
+    pub fn of_range(text: &str, start: usize, end: usize) -> Self {
+        Self {
+            start: Position::at(text, start),
+            end: Position::at(text, end),
+        }
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.start.offset == self.end.offset
+    }
+    
+    pub fn contains(&self, offset: usize) -> bool {
+        self.start.offset <= offset && offset < self.end.offset
+    }
+}
+
+impl Attribute {
+    pub fn new(key: String, value: String) -> Self {
+        Self {
+            key,
+            value,
+            key_span: Span::default(),
+            value_span: Span::default(),
+        }
+    }
+}
+
+impl Table {
+    /// The header row, if any, then the body rows
+    pub fn all_rows(&self) -> impl Iterator<Item = &TableRow> {
+        self.header.iter().chain(&self.rows)
+    }
+}
+
+impl ColumnAlign {
+    /// Read `l`, `c`, `r` or the full names
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "l" | "left" => Some(Self::Left),
+            "c" | "center" | "centre" => Some(Self::Center),
+            "r" | "right" => Some(Self::Right),
+            _ => None,
+        }
+    }
+    
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Center => "center",
+            Self::Right => "right",
+        }
+    }
+}
+
+impl ColumnType {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Some(Self::Text),
+            "number" => Some(Self::Number),
+            "date" => Some(Self::Date),
+            "currency" => Some(Self::Currency),
+            _ => None,
+        }
+    }
+    
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Date => "date",
+            Self::Currency => "currency",
+        }
+    }
+    
+    /// Numbers and amounts line up on the right unless `align` says otherwise
+    pub fn default_align(&self) -> ColumnAlign {
+        match self {
+            Self::Number | Self::Currency => ColumnAlign::Right,
+            Self::Text | Self::Date => ColumnAlign::Left,
+        }
+    }
+}
+
+impl QuestionType {
+    /// Read `single`, `multiple`, `truefalse` or `short`
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "single" => Some(Self::SingleChoice),
+            "multiple" => Some(Self::MultipleChoice),
+            "truefalse" | "true-false" => Some(Self::TrueFalse),
+            "short" => Some(Self::ShortAnswer),
+            _ => None,
+        }
+    }
+    
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::SingleChoice => "single",
+            Self::MultipleChoice => "multiple",
+            Self::TrueFalse => "truefalse",
+            Self::ShortAnswer => "short",
+        }
+    }
+    
+    /// The type's name in messages
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::SingleChoice => "single choice",
+            Self::MultipleChoice => "multiple choice",
+            Self::TrueFalse => "true/false",
+            Self::ShortAnswer => "short answer",
+        }
+    }
+}
+
+impl Quiz {
+    pub fn total_points(&self) -> usize {
+        self.questions.iter().map(|question| question.points).sum()
+    }
+}
+
+impl Default for ColumnSpec {
+    fn default() -> Self {
+        Self {
+            align: ColumnAlign::Left,
+            width: None,
+            column_type: ColumnType::Text,
+            decimals: None,
         }
     }
 }
@@ -131,14 +494,18 @@ impl Block {
             content: String::new(),
             children: Vec::new(),
             line_number,
+            header_span: Span::default(),
+            content_span: Span::default(),
             inline_elements: Vec::new(),
+            inline_spans: Vec::new(),
             list_items: Vec::new(),
             table: None,
+            quiz: None,
         }
     }
     
     pub fn add_attribute(&mut self, key: String, value: String) {
-        self.attributes.push(Attribute { key, value });
+        self.attributes.push(Attribute::new(key, value));
     }
     
     pub fn get_attribute(&self, key: &str) -> Option<&str> {
@@ -148,283 +515,238 @@ impl Block {
             .map(|attr| attr.value.as_str())
     }
     
-    /// Parse inline elements from content
+    /// Whether the content is taken as written rather than read as Blox.
+    ///
+    /// Code and math blocks are verbatim, and so is any block with `raw=true`.
+    pub fn is_verbatim(&self) -> bool {
+        matches!(self.block_type.canonical(), BlockType::Code | BlockType::Math) || self.is_raw()
+    }
+    
+    /// Whether the block has `raw=true`, so only headers of known types end its content
+    pub fn is_raw(&self) -> bool {
+        self.get_attribute("raw") == Some("true")
+    }
+    
+    /// Parse inline elements from content.
+    ///
+    /// Spans are recorded relative to `content`, in pre-order (each element
+    /// before its children); `BloxParser` maps them onto the source document.
     pub fn parse_inline_elements(&mut self) -> Result<(), String> {
-        use regex::Regex;
-        use lazy_static::lazy_static;
-        
-        lazy_static! {
-            static ref BOLD: Regex = Regex::new(r"\*\*([^*]+)\*\*").unwrap();
-            static ref ITALIC: Regex = Regex::new(r"\*([^*]+)\*").unwrap();
-            static ref CODE_INLINE: Regex = Regex::new(r"`([^`]+)`").unwrap();
-            static ref LINK: Regex = Regex::new(r"\[([^\]]+)\]\(([^)]+)\)").unwrap();
-            static ref STRIKETHROUGH: Regex = Regex::new(r"~~([^~]+)~~").unwrap();
-            static ref HIGHLIGHT: Regex = Regex::new(r"==([^=]+)==").unwrap();
-            static ref SUPERSCRIPT: Regex = Regex::new(r"\^([^^]+)\^").unwrap();
-            static ref SUBSCRIPT: Regex = Regex::new(r"_([^_]+)_").unwrap();
-            static ref MATH_INLINE: Regex = Regex::new(r"\$([^$]+)\$").unwrap();
-        }
-        
-        if self.content.is_empty() {
-            self.inline_elements = vec![InlineElement::Text(self.content.clone())];
-            return Ok(());
-        }
-        
-        let mut elements = Vec::new();
-        let _remaining = self.content.clone();
-        let _last_end = 0;
-        
-        // Collect all matches with their positions
-        let mut matches = Vec::new();
-        
-        // Bold matches
-        for cap in BOLD.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Bold(cap[1].to_string())));
-            }
-        }
-        
-        // Italic matches
-        for cap in ITALIC.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Italic(cap[1].to_string())));
-            }
-        }
-        
-        // Code matches
-        for cap in CODE_INLINE.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Code(cap[1].to_string())));
-            }
-        }
+        let parsed = parse_inline(&self.content);
         
-        // Link matches
-        for cap in LINK.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Link { 
-                    text: cap[1].to_string(), 
-                    url: cap[2].to_string() 
-                }));
-            }
-        }
-        
-        // Strikethrough matches
-        for cap in STRIKETHROUGH.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Strikethrough(cap[1].to_string())));
-            }
-        }
-        
-        // Highlight matches
-        for cap in HIGHLIGHT.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Highlight(cap[1].to_string())));
-            }
-        }
-        
-        // Superscript matches
-        for cap in SUPERSCRIPT.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Superscript(cap[1].to_string())));
-            }
-        }
-        
-        // Subscript matches
-        for cap in SUBSCRIPT.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Subscript(cap[1].to_string())));
-            }
-        }
-        
-        // Math matches
-        for cap in MATH_INLINE.captures_iter(&self.content) {
-            if let Some(m) = cap.get(0) {
-                matches.push((m.start(), m.end(), InlineElement::Math(cap[1].to_string())));
-            }
-        }
-        
-        // Sort matches by start position
-        matches.sort_by_key(|(start, _, _)| *start);
-        
-        // Build elements from matches
-        let mut pos = 0;
-        for (start, end, element) in matches {
-            if start > pos {
-                // Add text before this match
-                let text = self.content[pos..start].to_string();
-                if !text.is_empty() {
-                    elements.push(InlineElement::Text(text));
-                }
-            }
-            elements.push(element);
-            pos = end;
-        }
-        
-        // Add remaining text
-        if pos < self.content.len() {
-            let text = self.content[pos..].to_string();
-            if !text.is_empty() {
-                elements.push(InlineElement::Text(text));
-            }
-        }
-        
-        // If no elements were found, treat the whole content as text
-        if elements.is_empty() {
-            elements.push(InlineElement::Text(self.content.clone()));
-        }
+        self.inline_spans = parsed.ranges
+            .iter()
+            .map(|range| Span::of_range(&self.content, range.start, range.end))
+            .collect();
+        self.inline_elements = parsed.elements;
         
-        self.inline_elements = elements;
         Ok(())
     }
     
-    /// Parse list items from content
-    pub fn parse_list_items(&mut self) -> Result<(), String> {
-        if self.content.is_empty() {
-            return Ok(());
-        }
-        
-        let list_type = match self.get_attribute("type").unwrap_or("unordered") {
+    /// Whether this is an `item` block, read as an item of its parent list
+    pub fn is_list_item(&self) -> bool {
+        matches!(&self.block_type, BlockType::Custom(name) if name.eq_ignore_ascii_case("item"))
+    }
+    
+    /// The kind of list given by `type`, unordered by default
+    pub fn list_type(&self) -> ListType {
+        match self.get_attribute("type").unwrap_or("unordered") {
             "ordered" => ListType::Ordered,
             "check" => ListType::Check,
             "definition" => ListType::Definition,
             _ => ListType::Unordered,
-        };
-        
-        let mut items = Vec::new();
-        let lines: Vec<&str> = self.content.lines().collect();
-        let mut current_item: Option<ListItem> = None;
-        let mut current_level = 0;
-        
-        for line in lines {
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
-            
-            // Count leading dashes to determine level
-            let level = line.chars().take_while(|&c| c == '-').count();
-            
-            if level > 0 {
-                // This is a list item
-                let content = line[level..].trim();
-                
-                let item_type = if content.starts_with("[x] ") || content.starts_with("[X] ") {
-                    ListItemType::Checked
-                } else if content.starts_with("[ ] ") {
-                    ListItemType::Unchecked
-                } else if list_type == ListType::Definition && content.contains(": ") {
-                    let parts: Vec<&str> = content.splitn(2, ": ").collect();
-                    if parts.len() == 2 {
-                        ListItemType::Definition { term: parts[0].to_string() }
-                    } else {
-                        ListItemType::Unchecked
-                    }
-                } else {
-                    ListItemType::Unchecked
-                };
-                
-                let clean_content = match &item_type {
-                    ListItemType::Checked => content[4..].to_string(),
-                    ListItemType::Unchecked if content.starts_with("[ ] ") => content[4..].to_string(),
-                    ListItemType::Definition { .. } => {
-                        let parts: Vec<&str> = content.splitn(2, ": ").collect();
-                        if parts.len() == 2 { parts[1].to_string() } else { content.to_string() }
-                    }
-                    _ => content.to_string(),
-                };
-                
-                let new_item = ListItem {
-                    item_type,
-                    content: clean_content,
-                    children: Vec::new(),
-                    level,
-                };
-                
-                if level > current_level {
-                    // This is a child of the current item
-                    if let Some(ref mut parent) = current_item {
-                        parent.children.push(new_item);
-                    }
-                } else {
-                    // This is a sibling or parent level item
-                    if let Some(item) = current_item.take() {
-                        items.push(item);
-                    }
-                    current_item = Some(new_item);
-                }
-                
-                current_level = level;
-            } else if let Some(ref mut item) = current_item {
-                // Continuation of current item
-                if !item.content.is_empty() {
-                    item.content.push('\n');
-                }
-                item.content.push_str(line);
-            }
         }
+    }
+    
+    /// Parse list items from content lines starting with dashes, where the
+    /// number of dashes gives the depth, followed by any `item` child blocks.
+    ///
+    /// Spans of items read from the content are relative to it, as with
+    /// `inline_spans`; `BloxParser` places them in the source.
+    pub fn parse_list_items(&mut self) -> Result<(), String> {
+        self.parse_list_items_with(|span| span)
+    }
+    
+    /// Parse list items, placing the spans of items read from the content
+    /// with `place`; items from `item` children take the child's header span
+    pub fn parse_list_items_with(&mut self, place: impl Fn(Span) -> Span) -> Result<(), String> {
+        let list_type = self.list_type();
+        let (_, mut items) = dash_items(&self.content, &list_type, 0, &place);
+        items.extend(self.children.iter().filter(|child| child.is_list_item()).map(|child| block_item(child, &list_type, 1)));
         
-        if let Some(item) = current_item {
-            items.push(item);
-        }
+        // Items are kept under a bad `start=`, numbered from 1
+        let (start, result) = match self.get_attribute("start").map(|start| (start, start.trim().parse())) {
+            Some((_, Ok(start))) => (start, Ok(())),
+            Some((start, Err(_))) => (1, Err(format!("`start={}` is not a number", start))),
+            None => (1, Ok(())),
+        };
+        finish_items(&mut items, (list_type == ListType::Ordered).then_some(start));
         
         self.list_items = items;
-        Ok(())
+        result
     }
     
-    /// Parse table from content
+    /// Parse table from content; see `blox::table` for the formats read
     pub fn parse_table(&mut self) -> Result<(), String> {
-        if self.content.is_empty() {
+        if self.content.trim().is_empty() {
             return Ok(());
         }
         
-        let caption = self.get_attribute("caption").map(|s| s.to_string());
-        let has_header = self.get_attribute("header").unwrap_or("false") == "true";
-        
-        let lines: Vec<&str> = self.content.lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect();
-        
-        if lines.is_empty() {
-            return Ok(());
+        self.table = Some(crate::blox::table::read_table(self).table);
+        Ok(())
+    }
+    
+    /// Whether this is a `question` block, read as a question of its parent quiz
+    pub fn is_quiz_question(&self) -> bool {
+        matches!(&self.block_type, BlockType::Custom(name) if name.eq_ignore_ascii_case("question"))
+    }
+    
+    /// Parse quiz questions from content and `question` children; see
+    /// `blox::quiz` for the grammar. Spans of questions read from the
+    /// content are relative to it, as with `inline_spans`.
+    pub fn parse_quiz(&mut self) -> Result<(), String> {
+        self.quiz = Some(crate::blox::quiz::read_quiz(self, &|span| span).quiz);
+        Ok(())
+    }
+}
+
+/// Read dash items from list content, returning the lines before the first
+/// item and the items, nested by their number of dashes. Each item's span is
+/// its first line, placed in the source by `place`.
+fn dash_items(content: &str, list_type: &ListType, base_level: usize, place: &impl Fn(Span) -> Span) -> (String, Vec<ListItem>) {
+    let mut leading: Vec<&str> = Vec::new();
+    let mut items = Vec::new();
+    // The open item at each depth, deepest last
+    let mut open: Vec<ListItem> = Vec::new();
+    let mut line_start = 0;
+    
+    for line in content.split('\n') {
+        let range = line_start..line_start + line.len();
+        line_start = range.end + 1;
+        if line.trim().is_empty() {
+            continue;
         }
         
-        let mut rows = Vec::new();
-        let mut header_row = None;
-        
-        for (i, line) in lines.iter().enumerate() {
-            let cells: Vec<&str> = line.split('|').map(|cell| cell.trim()).collect();
-            let is_header = has_header && i == 0;
-            
-            let table_cells: Vec<TableCell> = cells.iter().map(|cell| {
-                TableCell {
-                    content: cell.to_string(),
-                    colspan: 1,
-                    rowspan: 1,
-                    is_header,
+        let dashes = line.chars().take_while(|&c| c == '-').count();
+        if dashes == 0 {
+            // Continuation of the deepest open item
+            match open.last_mut() {
+                Some(item) => {
+                    if !item.content.is_empty() {
+                        item.content.push('\n');
+                    }
+                    item.content.push_str(line);
                 }
-            }).collect();
-            
-            let row = TableRow { cells: table_cells };
-            
-            if is_header {
-                header_row = Some(row);
-            } else {
-                rows.push(row);
+                None => leading.push(line),
             }
+            continue;
         }
         
-        self.table = Some(Table {
-            caption,
-            header: header_row,
-            rows,
-        });
-        
-        Ok(())
+        let level = base_level + dashes;
+        while open.last().is_some_and(|item| item.level >= level) {
+            let item = open.pop().unwrap();
+            match open.last_mut() {
+                Some(parent) => parent.children.push(item),
+                None => items.push(item),
+            }
+        }
+        let mut item = marked_item(line[dashes..].trim(), level, list_type);
+        item.span = place(Span::of_range(content, range.start, range.end));
+        open.push(item);
+    }
+    
+    while let Some(item) = open.pop() {
+        match open.last_mut() {
+            Some(parent) => parent.children.push(item),
+            None => items.push(item),
+        }
+    }
+    
+    (leading.join("\n"), items)
+}
+
+/// Build an item from its text, reading a leading `[x]` or `[ ]`, a
+/// `term: ` in definition lists, or a whole `{{key=value text}}` giving the
+/// item attributes such as `checked=true`
+fn marked_item(text: &str, level: usize, list_type: &ListType) -> ListItem {
+    let (mut item_type, text) = if let Some(rest) = text.strip_prefix("[x] ").or_else(|| text.strip_prefix("[X] ")) {
+        (Some(ListItemType::Checked), rest)
+    } else if let Some(rest) = text.strip_prefix("[ ] ") {
+        (Some(ListItemType::Unchecked), rest)
+    } else {
+        (None, text)
+    };
+    
+    let mut attributes = Vec::new();
+    let mut content = text;
+    if let Some(inner) = text.strip_prefix("{{").and_then(|rest| rest.strip_suffix("}}")) {
+        let (tokens, content_start) = AttributeScanner::keyed_prefix(inner);
+        if !tokens.is_empty() {
+            attributes = tokens.into_iter().filter_map(|token| Some(Attribute::new(token.key?.0, token.value))).collect();
+            content = inner[content_start..].trim();
+        }
+    }
+    
+    let mut item = ListItem {
+        item_type: ListItemType::Unchecked,
+        content: content.to_string(),
+        children: Vec::new(),
+        level,
+        number: None,
+        inline_elements: Vec::new(),
+        attributes,
+        span: Span::default(),
+    };
+    if item_type.is_none() && *list_type == ListType::Definition {
+        if let Some((term, rest)) = content.split_once(": ") {
+            item_type = Some(ListItemType::Definition { term: term.to_string() });
+            item.content = rest.to_string();
+        }
+    }
+    item.item_type = item_type.unwrap_or(ListItemType::Unchecked);
+    item.apply_attributes();
+    item
+}
+
+/// Build an item from an `item` block: its content up to the first dash
+/// line, with later dash lines and `item` children as its children
+fn block_item(block: &Block, list_type: &ListType, level: usize) -> ListItem {
+    let (text, children) = dash_items(&block.content, list_type, level, &|span| span);
+    let mut item = marked_item(text.trim(), level, list_type);
+    item.span = block.header_span;
+    item.attributes.extend(block.attributes.iter().cloned());
+    item.apply_attributes();
+    
+    // The parser reads the items of `item` blocks as they close, with their spans
+    if block.list_items.is_empty() {
+        item.children = children;
+        item.children.extend(block.children.iter().filter(|child| child.is_list_item()).map(|child| block_item(child, list_type, level + 1)));
+    } else {
+        item.children = block.list_items.clone();
+        set_levels(&mut item.children, level + 1);
+    }
+    item
+}
+
+fn set_levels(items: &mut [ListItem], level: usize) {
+    for item in items {
+        item.level = level;
+        set_levels(&mut item.children, level + 1);
+    }
+}
+
+/// Parse each item's inline elements and, in ordered lists, number the
+/// items at each depth, the top level from `start`
+fn finish_items(items: &mut [ListItem], start: Option<usize>) {
+    for (i, item) in items.iter_mut().enumerate() {
+        item.number = start.map(|start| start.saturating_add(i));
+        item.inline_elements = parse_inline(&item.content).elements;
+        finish_items(&mut item.children, start.map(|_| 1));
     }
 }
 
 impl BlockType {
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "section" => BlockType::Section,
@@ -436,6 +758,7 @@ impl BlockType {
             "list" => BlockType::List,
             "math" => BlockType::Math,
             "comment" => BlockType::Comment,
+            "quiz" => BlockType::Quiz,
             
             // Shorthand
             "h1" => BlockType::H1,
@@ -466,6 +789,7 @@ impl BlockType {
             BlockType::List => "list",
             BlockType::Math => "math",
             BlockType::Comment => "comment",
+            BlockType::Quiz => "quiz",
             
             BlockType::H1 => "h1",
             BlockType::H2 => "h2",
@@ -0,0 +1,254 @@
+use std::ops::Range;
+
+/// One attribute read from a block header or an inline element.
+///
+/// All ranges are byte ranges into the scanned text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeToken {
+    /// Key and its range, or `None` for a positional value
+    pub key: Option<(String, Range<usize>)>,
+    /// The value with quotes removed and escapes resolved
+    pub value: String,
+    /// Range of the value, inside the quotes when quoted
+    pub value_range: Range<usize>,
+    /// Range of the whole token, including key and quotes
+    pub range: Range<usize>,
+    /// The quote character that opened the value but was never closed
+    pub unclosed_quote: Option<char>,
+}
+
+impl AttributeToken {
+    pub fn is_keyed(&self) -> bool {
+        self.key.is_some()
+    }
+}
+
+/// Tokenizer for the attribute grammar shared by block headers and inline elements.
+///
+/// Attributes are `key=value`, `key="quoted value"` or a bare positional
+/// value. Inside quotes, `\"`, `\'` and `\\` stand for the literal character
+/// (spec 4.2). Yields `Err` with the range of text that is not an attribute.
+#[derive(Debug, Clone)]
+pub struct AttributeScanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> AttributeScanner<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+    
+    /// Byte offset of the first character not yet consumed
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+    
+    /// Consume `key=value` attributes and stop before the first positional value.
+    ///
+    /// Inline elements use this to split `{{type attrs content}}`; the
+    /// returned offset is where the content starts.
+    pub fn keyed_prefix(text: &'a str) -> (Vec<AttributeToken>, usize) {
+        let mut scanner = Self::new(text);
+        let mut tokens = Vec::new();
+        
+        loop {
+            let mut lookahead = scanner.clone();
+            match lookahead.next() {
+                Some(Ok(token)) if token.is_keyed() && token.unclosed_quote.is_none() => {
+                    tokens.push(token);
+                    scanner = lookahead;
+                }
+                _ => break,
+            }
+        }
+        
+        scanner.skip_whitespace();
+        (tokens, scanner.pos)
+    }
+    
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+    
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+    
+    /// Read up to the next whitespace, returning the range read
+    fn read_word(&mut self) -> Range<usize> {
+        let start = self.pos;
+        let rest = self.rest();
+        self.pos += rest.find(char::is_whitespace).unwrap_or(rest.len());
+        start..self.pos
+    }
+    
+    /// Read a value at the current position: quoted, or up to the next whitespace
+    fn read_value(&mut self) -> (String, Range<usize>, Option<char>) {
+        let quote = match self.rest().chars().next() {
+            Some(c @ ('"' | '\'')) => c,
+            _ => {
+                let range = self.read_word();
+                return (self.text[range.clone()].to_string(), range, None);
+            }
+        };
+        
+        let start = self.pos + 1;
+        let mut value = String::new();
+        let mut chars = self.text[start..].char_indices();
+        
+        while let Some((i, c)) = chars.next() {
+            if c == quote {
+                self.pos = start + i + 1;
+                return (value, start..start + i, None);
+            }
+            if c == '\\' {
+                if let Some((_, next)) = chars.clone().next().filter(|(_, next)| *next == quote || *next == '\\') {
+                    value.push(next);
+                    chars.next();
+                    continue;
+                }
+            }
+            value.push(c);
+        }
+        
+        // No closing quote: take the word as written so nothing is lost
+        let range = self.read_word();
+        (self.text[range.clone()].to_string(), range, Some(quote))
+    }
+}
+
+impl Iterator for AttributeScanner<'_> {
+    type Item = Result<AttributeToken, Range<usize>>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let rest = self.rest();
+        if rest.is_empty() {
+            return None;
+        }
+        
+        let key_len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        
+        if key_len > 0 && rest[key_len..].starts_with('=') {
+            let key = rest[..key_len].to_string();
+            self.pos += key_len + 1;
+            
+            if self.rest().is_empty() || self.rest().starts_with(char::is_whitespace) {
+                return Some(Err(start..self.pos));
+            }
+            
+            let (value, value_range, unclosed_quote) = self.read_value();
+            return Some(Ok(AttributeToken {
+                key: Some((key, start..start + key_len)),
+                value,
+                value_range,
+                range: start..self.pos,
+                unclosed_quote,
+            }));
+        }
+        
+        let (value, value_range, unclosed_quote) = self.read_value();
+        if unclosed_quote.is_none() && value_range.start == start && value.contains('=') {
+            return Some(Err(start..self.pos));
+        }
+        
+        Some(Ok(AttributeToken {
+            key: None,
+            value,
+            value_range,
+            range: start..self.pos,
+            unclosed_quote,
+        }))
+    }
+}
+
+/// Read `text` as a single quoted value, if it is exactly one
+pub fn unquote(text: &str) -> Option<String> {
+    let mut scanner = AttributeScanner::new(text);
+    match (scanner.next(), scanner.next()) {
+        (Some(Ok(token)), None) if !token.is_keyed() && token.unclosed_quote.is_none() && token.value_range.start > token.range.start => {
+            Some(token.value)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `value` reads back unchanged when written without quotes
+pub fn is_bare(value: &str) -> bool {
+    !value.is_empty()
+        && !value.contains(char::is_whitespace)
+        && !value.contains('"')
+        && !value.starts_with('\'')
+}
+
+/// Write `value` so the scanner reads it back: bare when it can be, in double quotes otherwise
+pub fn quote(value: &str) -> String {
+    if is_bare(value) {
+        value.to_string()
+    } else {
+        quote_with(value, '"')
+    }
+}
+
+/// `value` in `quote` characters, with the quote character and backslashes escaped
+pub fn quote_with(value: &str, quote: char) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push(quote);
+    for c in value.chars() {
+        if c == quote || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push(quote);
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_scan_attributes() {
+        let text = r#""My \"Title\"" id=intro note='it\'s' bare width="#;
+        let tokens: Vec<_> = AttributeScanner::new(text).collect();
+        
+        let title = tokens[0].as_ref().unwrap();
+        assert!(title.key.is_none());
+        assert_eq!(title.value, "My \"Title\"");
+        assert_eq!(&text[title.value_range.clone()], r#"My \"Title\""#);
+        
+        let id = tokens[1].as_ref().unwrap();
+        assert_eq!(id.key.as_ref().unwrap().0, "id");
+        assert_eq!(id.value, "intro");
+        
+        assert_eq!(tokens[2].as_ref().unwrap().value, "it's");
+        assert_eq!(tokens[3].as_ref().unwrap().value, "bare");
+        assert_eq!(tokens[4], Err(text.len() - 6..text.len()));
+    }
+    
+    #[test]
+    fn test_unclosed_quote() {
+        let tokens: Vec<_> = AttributeScanner::new(r#"title="Intro more"#).collect();
+        let title = tokens[0].as_ref().unwrap();
+        assert_eq!(title.value, "\"Intro");
+        assert_eq!(title.unclosed_quote, Some('"'));
+        assert_eq!(tokens[1].as_ref().unwrap().value, "more");
+    }
+    
+    #[test]
+    fn test_keyed_prefix() {
+        let text = r#"href="https://example.com" lang=en Example link=here"#;
+        let (tokens, content_start) = AttributeScanner::keyed_prefix(text);
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(&text[content_start..], "Example link=here");
+        
+        assert_eq!(unquote(r#""print('Hello')""#), Some("print('Hello')".to_string()));
+        assert_eq!(unquote("plain words"), None);
+    }
+}
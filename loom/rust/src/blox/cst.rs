@@ -0,0 +1,225 @@
+//! The source as the parser read it, line by line.
+//!
+//! A `SyntaxTree` keeps every byte of the source next to the document
+//! parsed from it, so the comments, blank lines, attribute spelling and
+//! line endings the AST has no place for can be written back unchanged.
+//! It is a side index rather than a syntax tree the AST is read from: each
+//! node records only the range of lines from its block's header up to the
+//! next header, and is tied to its block in the AST by the header span.
+
+use std::ops::Range;
+
+use crate::blox::ast::{Block, Document, Span};
+use crate::blox::metadata::MetadataBlock;
+
+/// What the parser read a line as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Header,
+    /// Block content, including malformed headers kept as text
+    Content,
+    /// The opening or closing fence of verbatim content
+    Fence,
+    Comment,
+    Blank,
+}
+
+impl LineKind {
+    /// Whether the line is a comment or blank line, which the AST keeps
+    /// nothing of unless it falls inside verbatim content
+    pub fn is_trivia(&self) -> bool {
+        matches!(self, Self::Comment | Self::Blank)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxLine {
+    pub kind: LineKind,
+    /// Span of the line's text, excluding its line ending
+    pub span: Span,
+    /// Byte offset just past the line ending
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxNode {
+    /// Header span of the block the node was read as
+    pub header_span: Span,
+    /// Indices of the lines the node owns: its header, its content and the
+    /// comments and blank lines after it, up to the next header
+    pub lines: Range<usize>,
+    /// Whether the block was lifted into `Document.metadata`
+    pub metadata: bool,
+    pub children: Vec<SyntaxNode>,
+}
+
+/// A parsed document together with the source it was parsed from
+#[derive(Debug, Clone)]
+pub struct SyntaxTree {
+    pub source: String,
+    pub lines: Vec<SyntaxLine>,
+    /// Lines before the first block
+    pub prologue: Range<usize>,
+    pub nodes: Vec<SyntaxNode>,
+    document: Document,
+}
+
+impl SyntaxTree {
+    /// Build the tree from the lines the parser read, the blocks it closed
+    /// before metadata was lifted, and the finished document
+    pub(crate) fn new(source: &str, lines: Vec<SyntaxLine>, blocks: &[Block], document: Document) -> Self {
+        let mut starts = Vec::new();
+        collect_starts(blocks, &mut starts);
+        
+        let mut next = 0;
+        let mut nodes = build_nodes(blocks, &starts, &mut next, lines.len());
+        for (node, block) in nodes.iter_mut().zip(blocks) {
+            node.metadata = MetadataBlock::of(&block.block_type).is_some()
+                && !document.blocks.iter().any(|kept| kept.header_span == block.header_span);
+        }
+        
+        Self {
+            source: source.to_string(),
+            prologue: 0..starts.first().copied().unwrap_or(lines.len()),
+            lines,
+            nodes,
+            document,
+        }
+    }
+    
+    /// The AST read from the source
+    pub fn document(&self) -> &Document {
+        &self.document
+    }
+    
+    pub fn into_document(self) -> Document {
+        self.document
+    }
+    
+    /// Source text of `lines`, line endings included
+    pub fn text(&self, lines: Range<usize>) -> &str {
+        if lines.is_empty() {
+            return "";
+        }
+        &self.source[self.lines[lines.start].span.start.offset..self.lines[lines.end - 1].end]
+    }
+    
+    /// The line ending the source uses, judged by its first line
+    pub fn line_ending(&self) -> &'static str {
+        match self.lines.first() {
+            Some(line) if self.source[line.span.end.offset..line.end].starts_with('\r') => "\r\n",
+            _ => "\n",
+        }
+    }
+    
+    /// The node read from the block whose header is at `header_span`
+    pub fn node(&self, header_span: Span) -> Option<&SyntaxNode> {
+        if header_span.start.line == 0 {
+            // Blocks that were never parsed have no place in the source
+            return None;
+        }
+        find_node(&self.nodes, header_span)
+    }
+    
+    /// The block in `document()` whose header is at `header_span`
+    pub fn block(&self, header_span: Span) -> Option<&Block> {
+        find_block(&self.document.blocks, header_span)
+    }
+}
+
+impl SyntaxNode {
+    /// Index of the header line, which an implicit paragraph does not have
+    pub fn header_line(&self, tree: &SyntaxTree) -> Option<usize> {
+        (tree.lines[self.lines.start].kind == LineKind::Header).then_some(self.lines.start)
+    }
+    
+    /// The lines after the header, split into the block's content and the
+    /// comments and blank lines that trail it
+    pub fn body(&self, tree: &SyntaxTree) -> (Range<usize>, Range<usize>) {
+        let start = self.lines.start + usize::from(self.header_line(tree).is_some());
+        let mut end = self.lines.end;
+        while end > start && tree.lines[end - 1].kind.is_trivia() {
+            end -= 1;
+        }
+        (start..end, end..self.lines.end)
+    }
+}
+
+/// Index of each block's first line, in source order
+fn collect_starts(blocks: &[Block], starts: &mut Vec<usize>) {
+    for block in blocks {
+        starts.push(block.header_span.start.line - 1);
+        collect_starts(&block.children, starts);
+    }
+}
+
+fn build_nodes(blocks: &[Block], starts: &[usize], next: &mut usize, line_count: usize) -> Vec<SyntaxNode> {
+    blocks
+        .iter()
+        .map(|block| {
+            let start = starts[*next];
+            *next += 1;
+            // The next block in source order is this one's first child or next sibling
+            let end = starts.get(*next).copied().unwrap_or(line_count);
+            SyntaxNode {
+                header_span: block.header_span,
+                lines: start..end,
+                metadata: false,
+                children: build_nodes(&block.children, starts, next, line_count),
+            }
+        })
+        .collect()
+}
+
+fn find_node(nodes: &[SyntaxNode], header_span: Span) -> Option<&SyntaxNode> {
+    nodes.iter().find_map(|node| {
+        if node.header_span == header_span {
+            Some(node)
+        } else {
+            find_node(&node.children, header_span)
+        }
+    })
+}
+
+fn find_block(blocks: &[Block], header_span: Span) -> Option<&Block> {
+    blocks.iter().find_map(|block| {
+        if block.header_span == header_span {
+            Some(block)
+        } else {
+            find_block(&block.children, header_span)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::parser::BloxParser;
+    
+    #[test]
+    fn test_syntax_tree() {
+        let source = "// notes\r\n#meta author=Ada\r\n\r\n#section \"Intro\"\r\nText\r\n// aside\r\n##code\r\n```\r\n#x\r\n```\r\n\r\nloose";
+        let tree = BloxParser::new().parse_tree(source);
+        let kinds: Vec<LineKind> = tree.lines.iter().map(|line| line.kind).collect();
+        assert_eq!(kinds, vec![
+            LineKind::Comment, LineKind::Header, LineKind::Blank, LineKind::Header, LineKind::Content, LineKind::Comment,
+            LineKind::Header, LineKind::Fence, LineKind::Content, LineKind::Fence, LineKind::Blank, LineKind::Content,
+        ]);
+        assert_eq!(tree.line_ending(), "\r\n");
+        assert_eq!(tree.text(tree.prologue.clone()), "// notes\r\n");
+        
+        assert_eq!(tree.nodes.len(), 2);
+        assert!(tree.nodes[0].metadata);
+        assert_eq!(tree.text(tree.nodes[0].lines.clone()), "#meta author=Ada\r\n\r\n");
+        
+        let code = &tree.nodes[1].children[0];
+        let (content, trivia) = code.body(&tree);
+        assert_eq!(tree.text(content), "```\r\n#x\r\n```\r\n\r\nloose");
+        assert!(trivia.is_empty());
+        assert_eq!(tree.block(code.header_span).unwrap().content, "#x\nloose");
+        
+        let section = &tree.nodes[1];
+        assert_eq!(tree.text(section.body(&tree).1), "// aside\r\n");
+        assert_eq!(tree.document().metadata.get("author"), Some("Ada"));
+    }
+}
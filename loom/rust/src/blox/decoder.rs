@@ -1,8 +1,17 @@
 use crate::blox::{Document, Block, BloxError, ParseResult};
-use std::collections::HashMap;
+use crate::blox::ast::{ColumnAlign, ColumnSpec, InlineElement, ListItem, ListItemType, ListType, QuestionType, Quiz, QuizQuestion, Table, TableCell, TableRow};
+use crate::blox::table;
+use crate::blox::quiz;
+use crate::blox::quiz_export::{self, Package, PackageFile};
+use crate::blox::escape::unescape_inline;
+use crate::blox::inline::parse_inline;
+use crate::blox::verbatim::Fence;
+use crate::blox::variables::is_definition;
 
 pub struct BloxDecoder {
     output_format: OutputFormat,
+    /// Show quiz answers and explanations
+    answer_key: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -11,15 +20,27 @@ pub enum OutputFormat {
     Markdown,
     Json,
     PlainText,
+    /// Moodle GIFT text holding the document's quizzes, decoded with
+    /// `decode_package` so questions it cannot hold are reported
+    Gift,
+    /// An IMS QTI 2.1 package of the document's quizzes, decoded with `decode_package`
+    Qti,
 }
 
 impl BloxDecoder {
     pub fn new(format: OutputFormat) -> Self {
         Self {
             output_format: format,
+            answer_key: false,
         }
     }
     
+    /// Render quizzes with their correct answers marked and explanations shown
+    pub fn with_answer_key(mut self, answer_key: bool) -> Self {
+        self.answer_key = answer_key;
+        self
+    }
+    
     /// Decode a Blox document to the specified output format
     pub fn decode(&self, document: &Document) -> ParseResult<String> {
         match self.output_format {
@@ -27,31 +48,64 @@ impl BloxDecoder {
             OutputFormat::Markdown => self.to_markdown(document),
             OutputFormat::Json => self.to_json(document),
             OutputFormat::PlainText => self.to_plain_text(document),
+            OutputFormat::Gift => Err(BloxError::PackageOutput { format: "GIFT".to_string() }),
+            OutputFormat::Qti => Err(BloxError::PackageOutput { format: "QTI".to_string() }),
         }
     }
     
+    /// Decode a document to the files of the output format, with problems
+    /// found while writing them; formats other than QTI give a single file
+    pub fn decode_package(&self, document: &Document) -> ParseResult<Package> {
+        let (name, extension) = match self.output_format {
+            OutputFormat::Gift => return Ok(quiz_export::to_gift(document)),
+            OutputFormat::Qti => return Ok(quiz_export::to_qti(document)),
+            OutputFormat::Html => ("document", "html"),
+            OutputFormat::Markdown => ("document", "md"),
+            OutputFormat::Json => ("document", "json"),
+            OutputFormat::PlainText => ("document", "txt"),
+        };
+        Ok(Package {
+            files: vec![PackageFile { path: format!("{}.{}", name, extension), content: self.decode(document)? }],
+            diagnostics: Vec::new(),
+        })
+    }
+    
     fn to_html(&self, document: &Document) -> ParseResult<String> {
         let mut output = String::new();
         
         output.push_str("<!DOCTYPE html>\n<html>\n<head>\n");
         output.push_str("<meta charset=\"UTF-8\">\n");
         
-        if let Some(title) = document.metadata.get("title") {
+        if let Some(title) = document.metadata.title() {
             output.push_str(&format!("<title>{}</title>\n", html_escape(title)));
         }
         
+        let authors = document.metadata.authors();
+        if !authors.is_empty() {
+            output.push_str(&format!("<meta name=\"author\" content=\"{}\">\n", html_escape(&authors.join(", "))));
+        }
+        
         output.push_str("</head>\n<body>\n");
         
+        let mut quizzes = 0;
         for block in &document.blocks {
-            self.block_to_html(&mut output, block, 0)?;
+            self.block_to_html(&mut output, block, 0, &mut quizzes)?;
         }
         
         output.push_str("</body>\n</html>");
         Ok(output)
     }
     
-    fn block_to_html(&self, output: &mut String, block: &Block, depth: usize) -> ParseResult<()> {
+    /// Write a block and its children as HTML; `quizzes` counts the quizzes
+    /// written so far, to keep their input names apart
+    fn block_to_html(&self, output: &mut String, block: &Block, depth: usize, quizzes: &mut usize) -> ParseResult<()> {
+        // Variable definitions are not part of the output
+        if is_definition(block) {
+            return Ok(());
+        }
+        
         let indent = "  ".repeat(depth);
+        let content = block_text(block);
         
         match &block.block_type {
             crate::blox::BlockType::Section | 
@@ -72,15 +126,15 @@ impl BloxDecoder {
                 output.push_str(&format!("{}<h{}>{}</h{}>\n", 
                     indent, level, html_escape(title), level));
                 
-                if !block.content.is_empty() {
+                if !content.is_empty() {
                     output.push_str(&format!("{}<p>{}</p>\n", 
-                        indent, html_escape(&block.content)));
+                        indent, html_inline(&block.content)));
                 }
             }
             
             crate::blox::BlockType::Paragraph | crate::blox::BlockType::P => {
                 output.push_str(&format!("{}<p>{}</p>\n", 
-                    indent, html_escape(&block.content)));
+                    indent, html_inline(&block.content)));
             }
             
             crate::blox::BlockType::Code | crate::blox::BlockType::C => {
@@ -92,13 +146,13 @@ impl BloxDecoder {
                 };
                 
                 output.push_str(&format!("{}<pre><code{}>{}</code></pre>\n",
-                    indent, class_attr, html_escape(&block.content)));
+                    indent, class_attr, html_escape(&content)));
             }
             
             crate::blox::BlockType::Quote | crate::blox::BlockType::Q => {
                 output.push_str(&format!("{}<blockquote>\n", indent));
                 output.push_str(&format!("{}  <p>{}</p>\n", 
-                    indent, html_escape(&block.content)));
+                    indent, html_inline(&block.content)));
                 
                 if let Some(author) = block.get_attribute("author") {
                     output.push_str(&format!("{}  <cite>{}</cite>\n", 
@@ -128,14 +182,7 @@ impl BloxDecoder {
             }
             
             crate::blox::BlockType::List => {
-                output.push_str(&format!("{}<ul>\n", indent));
-                for line in block.content.lines() {
-                    if !line.trim().is_empty() {
-                        output.push_str(&format!("{}  <li>{}</li>\n", 
-                            indent, html_escape(line.trim())));
-                    }
-                }
-                output.push_str(&format!("{}</ul>\n", indent));
+                html_list(output, &block_list_items(block), &block.list_type(), &indent);
             }
             
             crate::blox::BlockType::Table | crate::blox::BlockType::Tbl => {
@@ -146,39 +193,44 @@ impl BloxDecoder {
                         indent, html_escape(caption)));
                 }
                 
-                let lines: Vec<&str> = block.content.lines().collect();
-                if !lines.is_empty() {
-                    // First line as header
-                    output.push_str(&format!("{}  <thead>\n{}    <tr>\n", indent, indent));
-                    for cell in lines[0].split('|') {
-                        output.push_str(&format!("{}      <th>{}</th>\n", 
-                            indent, html_escape(cell.trim())));
-                    }
-                    output.push_str(&format!("{}    </tr>\n{}  </thead>\n", indent, indent));
-                    
-                    // Remaining lines as body
-                    if lines.len() > 1 {
-                        output.push_str(&format!("{}  <tbody>\n", indent));
-                        for line in &lines[1..] {
-                            if !line.trim().is_empty() {
-                                output.push_str(&format!("{}    <tr>\n", indent));
-                                for cell in line.split('|') {
-                                    output.push_str(&format!("{}      <td>{}</td>\n", 
-                                        indent, html_escape(cell.trim())));
-                                }
-                                output.push_str(&format!("{}    </tr>\n", indent));
-                            }
+                let table = block_table(block);
+                if table.columns.iter().any(|column| column.width.is_some()) {
+                    output.push_str(&format!("{}  <colgroup>\n", indent));
+                    for column in &table.columns {
+                        match &column.width {
+                            Some(width) => output.push_str(&format!("{}    <col style=\"width: {}\" />\n", indent, css_length(width))),
+                            None => output.push_str(&format!("{}    <col />\n", indent)),
                         }
-                        output.push_str(&format!("{}  </tbody>\n", indent));
                     }
+                    output.push_str(&format!("{}  </colgroup>\n", indent));
+                }
+                
+                let layout = table::layout(table.all_rows());
+                let mut starts = layout.columns.iter();
+                if let Some(header) = &table.header {
+                    output.push_str(&format!("{}  <thead>\n", indent));
+                    html_table_row(output, header, starts.next().unwrap(), &table.columns, &indent);
+                    output.push_str(&format!("{}  </thead>\n", indent));
+                }
+                if !table.rows.is_empty() {
+                    output.push_str(&format!("{}  <tbody>\n", indent));
+                    for (row, columns) in table.rows.iter().zip(starts) {
+                        html_table_row(output, row, columns, &table.columns, &indent);
+                    }
+                    output.push_str(&format!("{}  </tbody>\n", indent));
                 }
                 
                 output.push_str(&format!("{}</table>\n", indent));
             }
             
+            crate::blox::BlockType::Quiz => {
+                *quizzes += 1;
+                html_quiz(output, &block_quiz(block), *quizzes, self.answer_key, &indent);
+            }
+            
             crate::blox::BlockType::Math | crate::blox::BlockType::M => {
                 output.push_str(&format!("{}<div class=\"math\">\n", indent));
-                output.push_str(&format!("{}  $${}$$\n", indent, block.content));
+                output.push_str(&format!("{}  $${}$$\n", indent, content));
                 output.push_str(&format!("{}</div>\n", indent));
             }
             
@@ -186,16 +238,18 @@ impl BloxDecoder {
                 // Custom or unknown block types
                 output.push_str(&format!("{}<div class=\"{}\">\n", 
                     indent, block.block_type.to_str()));
-                if !block.content.is_empty() {
-                    output.push_str(&format!("{}  {}\n", indent, html_escape(&block.content)));
+                if block.is_verbatim() {
+                    output.push_str(&format!("{}  <pre>{}</pre>\n", indent, html_escape(&content)));
+                } else if !content.is_empty() {
+                    output.push_str(&format!("{}  {}\n", indent, html_inline(&block.content)));
                 }
                 output.push_str(&format!("{}</div>\n", indent));
             }
         }
         
         // Process children
-        for child in &block.children {
-            self.block_to_html(output, child, depth + 1)?;
+        for child in rendered_children(block) {
+            self.block_to_html(output, child, depth + 1, quizzes)?;
         }
         
         Ok(())
@@ -204,7 +258,7 @@ impl BloxDecoder {
     fn to_markdown(&self, document: &Document) -> ParseResult<String> {
         let mut output = String::new();
         
-        for block in &document.blocks {
+        for block in document.blocks.iter().filter(|block| !is_definition(block)) {
             self.block_to_markdown(&mut output, block)?;
             output.push('\n');
         }
@@ -213,6 +267,12 @@ impl BloxDecoder {
     }
     
     fn block_to_markdown(&self, output: &mut String, block: &Block) -> ParseResult<()> {
+        if is_definition(block) {
+            return Ok(());
+        }
+        
+        let content = block_text(block);
+        
         match &block.block_type {
             crate::blox::BlockType::Section | 
             crate::blox::BlockType::H1 | crate::blox::BlockType::H2 | 
@@ -231,25 +291,26 @@ impl BloxDecoder {
                 let title = block.get_attribute("title").unwrap_or("");
                 output.push_str(&format!("{} {}\n", "#".repeat(level), title));
                 
-                if !block.content.is_empty() {
+                if !content.is_empty() {
                     output.push('\n');
-                    output.push_str(&block.content);
+                    output.push_str(&markdown_inline(&block.content));
                     output.push('\n');
                 }
             }
             
             crate::blox::BlockType::Paragraph | crate::blox::BlockType::P => {
-                output.push_str(&block.content);
+                output.push_str(&markdown_inline(&block.content));
                 output.push('\n');
             }
             
             crate::blox::BlockType::Code | crate::blox::BlockType::C => {
                 let lang = block.get_attribute("lang").unwrap_or("");
-                output.push_str(&format!("```{}\n{}\n```\n", lang, block.content));
+                let fence = Fence::for_content(&content);
+                output.push_str(&format!("{}{}\n{}\n{}\n", fence, lang, content, fence));
             }
             
             crate::blox::BlockType::Quote | crate::blox::BlockType::Q => {
-                for line in block.content.lines() {
+                for line in markdown_inline(&block.content).lines() {
                     output.push_str(&format!("> {}\n", line));
                 }
                 
@@ -264,15 +325,42 @@ impl BloxDecoder {
                 output.push_str(&format!("![{}]({})\n", alt, src));
             }
             
+            crate::blox::BlockType::Table | crate::blox::BlockType::Tbl => {
+                output.push_str(&markdown_table(&block_table(block)));
+            }
+            
+            crate::blox::BlockType::List => {
+                for line in text_list(&block_list_items(block), &block.list_type(), true) {
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+            }
+            
+            crate::blox::BlockType::Quiz => {
+                let quiz = block_quiz(block);
+                if let Some(title) = &quiz.title {
+                    output.push_str(&format!("**{}**\n\n", markdown_text(title)));
+                }
+                for line in text_quiz(&quiz, true, self.answer_key) {
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+            }
+            
+            _ if block.is_verbatim() => {
+                let fence = Fence::for_content(&content);
+                output.push_str(&format!("{}\n{}\n{}\n", fence, content, fence));
+            }
+            
             _ => {
                 // For other block types, just output content
-                output.push_str(&block.content);
+                output.push_str(&markdown_inline(&block.content));
                 output.push('\n');
             }
         }
         
         // Process children
-        for child in &block.children {
+        for child in rendered_children(block) {
             self.block_to_markdown(output, child)?;
         }
         
@@ -290,7 +378,7 @@ impl BloxDecoder {
     fn to_plain_text(&self, document: &Document) -> ParseResult<String> {
         let mut output = String::new();
         
-        for block in &document.blocks {
+        for block in document.blocks.iter().filter(|block| !is_definition(block)) {
             self.block_to_plain_text(&mut output, block, 0);
             output.push('\n');
         }
@@ -299,6 +387,10 @@ impl BloxDecoder {
     }
     
     fn block_to_plain_text(&self, output: &mut String, block: &Block, depth: usize) {
+        if is_definition(block) {
+            return;
+        }
+        
         let indent = "  ".repeat(depth);
         
         // Add title if it exists
@@ -307,20 +399,494 @@ impl BloxDecoder {
         }
         
         // Add content
-        if !block.content.is_empty() {
-            for line in block.content.lines() {
+        if block.block_type.canonical() == crate::blox::BlockType::Table {
+            if let Some(caption) = block.get_attribute("caption") {
+                output.push_str(&format!("{}{}\n", indent, caption));
+            }
+            for line in plain_table(&block_table(block)) {
+                output.push_str(&format!("{}{}\n", indent, line));
+            }
+        } else if block.block_type.canonical() == crate::blox::BlockType::List {
+            for line in text_list(&block_list_items(block), &block.list_type(), false) {
+                output.push_str(&format!("{}{}\n", indent, line));
+            }
+        } else if block.block_type == crate::blox::BlockType::Quiz {
+            for line in text_quiz(&block_quiz(block), false, self.answer_key) {
+                if line.is_empty() {
+                    output.push('\n');
+                } else {
+                    output.push_str(&format!("{}{}\n", indent, line));
+                }
+            }
+        } else if !block.content.is_empty() {
+            let text = if block.is_verbatim() { block.content.clone() } else { text_inline(&block.content) };
+            for line in text.lines() {
                 output.push_str(&format!("{}{}\n", indent, line));
             }
         }
         
         // Process children
-        for child in &block.children {
+        for child in rendered_children(block) {
             self.block_to_plain_text(output, child, depth + 1);
         }
     }
 }
 
-fn html_escape(text: &str) -> String {
+/// The content as text: verbatim content as written, otherwise with inline escapes resolved
+fn block_text(block: &Block) -> String {
+    if block.is_verbatim() {
+        block.content.clone()
+    } else {
+        unescape_inline(&block.content)
+    }
+}
+
+/// Render Blox inline markup as HTML
+pub(crate) fn html_inline(text: &str) -> String {
+    parse_inline(text).elements.iter().map(inline_to_html).collect()
+}
+
+/// The block's table, read from its content if it was built without one
+fn block_table(block: &Block) -> Table {
+    block.table.clone().unwrap_or_else(|| table::read_table(block).table)
+}
+
+/// A list's items, read from its content and children when not parsed yet
+fn block_list_items(block: &Block) -> Vec<ListItem> {
+    if !block.list_items.is_empty() {
+        return block.list_items.clone();
+    }
+    let mut block = block.clone();
+    // A bad `start=` still leaves the items, numbered from 1
+    let _ = block.parse_list_items();
+    block.list_items
+}
+
+/// A quiz's questions, read from its content and children when not parsed yet
+pub(crate) fn block_quiz(block: &Block) -> Quiz {
+    block.quiz.clone().unwrap_or_else(|| quiz::read_quiz(block, &|span| span).quiz)
+}
+
+/// The children rendered after a block; a list's `item` children are
+/// rendered as its items, and a quiz's `question` children as its questions
+fn rendered_children(block: &Block) -> impl Iterator<Item = &Block> {
+    let is_list = block.block_type.canonical() == crate::blox::BlockType::List;
+    let is_quiz = block.block_type == crate::blox::BlockType::Quiz;
+    block.children.iter().filter(move |child| !(is_list && child.is_list_item() || is_quiz && child.is_quiz_question()))
+}
+
+fn html_list(output: &mut String, items: &[ListItem], list_type: &ListType, indent: &str) {
+    let start = items.first().and_then(|item| item.number).filter(|&number| number != 1);
+    let (open, close) = match list_type {
+        ListType::Ordered => (start.map_or("<ol>".to_string(), |start| format!("<ol start=\"{}\">", start)), "</ol>"),
+        ListType::Check => ("<ul class=\"check\">".to_string(), "</ul>"),
+        ListType::Definition => ("<dl>".to_string(), "</dl>"),
+        ListType::Unordered => ("<ul>".to_string(), "</ul>"),
+    };
+    
+    output.push_str(&format!("{}{}\n", indent, open));
+    for item in items {
+        let content = html_inline(&item.content);
+        let (open, close) = match (&item.item_type, list_type) {
+            (ListItemType::Definition { term }, _) => (format!("<dt>{}</dt><dd>", html_inline(term)), "</dd>"),
+            (_, ListType::Definition) => ("<dd>".to_string(), "</dd>"),
+            (ListItemType::Checked, ListType::Check) => ("<li><input type=\"checkbox\" checked disabled /> ".to_string(), "</li>"),
+            (ListItemType::Unchecked, ListType::Check) => ("<li><input type=\"checkbox\" disabled /> ".to_string(), "</li>"),
+            _ => ("<li>".to_string(), "</li>"),
+        };
+        
+        if item.children.is_empty() {
+            output.push_str(&format!("{}  {}{}{}\n", indent, open, content, close));
+        } else {
+            output.push_str(&format!("{}  {}{}\n", indent, open, content));
+            html_list(output, &item.children, list_type, &format!("{}    ", indent));
+            output.push_str(&format!("{}  {}\n", indent, close));
+        }
+    }
+    output.push_str(&format!("{}{}\n", indent, close));
+}
+
+/// A list as Markdown or plain text lines, children indented under their item's text
+fn text_list(items: &[ListItem], list_type: &ListType, markdown: bool) -> Vec<String> {
+    let inline = |text: &str| if markdown { markdown_inline(text) } else { text_inline(text) };
+    let mut lines = Vec::new();
+    
+    for item in items {
+        let mut marker = match item.number {
+            Some(number) => format!("{}. ", number),
+            None if markdown => "- ".to_string(),
+            None => String::new(),
+        };
+        match (&item.item_type, list_type) {
+            (ListItemType::Checked, ListType::Check) => marker.push_str("[x] "),
+            (ListItemType::Unchecked, ListType::Check) => marker.push_str("[ ] "),
+            (ListItemType::Definition { term }, _) if markdown => marker.push_str(&format!("**{}**: ", inline(term))),
+            (ListItemType::Definition { term }, _) => marker.push_str(&format!("{}: ", inline(term))),
+            _ if !markdown && item.number.is_none() => marker.push_str("- "),
+            _ => {}
+        }
+        
+        let pad = " ".repeat(marker.chars().count().min(4));
+        for (i, line) in inline(&item.content).lines().enumerate() {
+            lines.push(if i == 0 { format!("{}{}", marker, line) } else { format!("{}{}", pad, line) });
+        }
+        if item.content.is_empty() {
+            lines.push(marker.trim_end().to_string());
+        }
+        lines.extend(text_list(&item.children, list_type, markdown).into_iter().map(|line| format!("{}{}", pad, line)));
+    }
+    
+    lines
+}
+
+/// A question's choices as written and whether each is correct; true/false
+/// questions offer `True` and `False`
+pub(crate) fn question_choices(question: &QuizQuestion) -> Vec<(String, bool)> {
+    match question.question_type {
+        QuestionType::TrueFalse => {
+            let answer = question.answers.first().map(String::as_str);
+            vec![("True".to_string(), answer == Some("true")), ("False".to_string(), answer == Some("false"))]
+        }
+        _ => question.choices.iter().map(|choice| (choice.content.clone(), choice.correct)).collect(),
+    }
+}
+
+/// A quiz as a form; questions without an `id` are named after the quiz's
+/// `number` in the document and their own, so two quizzes never share inputs
+fn html_quiz(output: &mut String, quiz: &Quiz, number: usize, answer_key: bool, indent: &str) {
+    output.push_str(&format!("{}<fieldset class=\"quiz\">\n", indent));
+    if let Some(title) = &quiz.title {
+        output.push_str(&format!("{}  <legend>{}</legend>\n", indent, html_escape(title)));
+    }
+    if !quiz.description.is_empty() {
+        output.push_str(&format!("{}  <p>{}</p>\n", indent, html_inline(&quiz.description)));
+    }
+    
+    let inner = format!("{}      ", indent);
+    let disabled = if answer_key { " disabled" } else { "" };
+    output.push_str(&format!("{}  <ol class=\"questions\">\n", indent));
+    for (i, question) in quiz.questions.iter().enumerate() {
+        let name = html_escape(&question.id.clone().unwrap_or_else(|| format!("quiz{}-q{}", number, i + 1)));
+        let id = question.id.as_ref().map_or(String::new(), |_| format!(" id=\"{}\"", name));
+        output.push_str(&format!(
+            "{}    <li class=\"question\"{} data-type=\"{}\" data-points=\"{}\">\n",
+            indent, id, question.question_type.to_str(), question.points
+        ));
+        output.push_str(&format!("{}<p>{}</p>\n", inner, html_inline(&question.prompt)));
+        
+        if question.question_type == QuestionType::ShortAnswer {
+            output.push_str(&format!("{}<input type=\"text\" name=\"{}\"{} />\n", inner, name, disabled));
+            if answer_key && !question.answers.is_empty() {
+                let answers: Vec<String> = question.answers.iter().map(|answer| html_escape(answer)).collect();
+                output.push_str(&format!("{}<p class=\"answer\">{}</p>\n", inner, answers.join(" / ")));
+            }
+        } else {
+            let input = if question.question_type == QuestionType::MultipleChoice { "checkbox" } else { "radio" };
+            output.push_str(&format!("{}<ul class=\"choices\">\n", inner));
+            for (j, (content, correct)) in question_choices(question).into_iter().enumerate() {
+                let (open, checked) = if answer_key && correct { ("<li class=\"correct\">", " checked") } else { ("<li>", "") };
+                output.push_str(&format!(
+                    "{}  {}<label><input type=\"{}\" name=\"{}\" value=\"{}\"{}{} /> {}</label></li>\n",
+                    inner, open, input, name, j + 1, checked, disabled, html_inline(&content)
+                ));
+            }
+            output.push_str(&format!("{}</ul>\n", inner));
+        }
+        
+        if let Some(explanation) = question.explanation.as_ref().filter(|_| answer_key) {
+            output.push_str(&format!("{}<p class=\"explanation\">{}</p>\n", inner, html_inline(explanation)));
+        }
+        output.push_str(&format!("{}    </li>\n", indent));
+    }
+    output.push_str(&format!("{}  </ol>\n", indent));
+    output.push_str(&format!("{}</fieldset>\n", indent));
+}
+
+/// A quiz as Markdown or plain text lines: its description, then its
+/// questions numbered with their choices under them
+fn text_quiz(quiz: &Quiz, markdown: bool, answer_key: bool) -> Vec<String> {
+    let inline = |text: &str| if markdown { markdown_inline(text) } else { text_inline(text) };
+    let mut lines = Vec::new();
+    if !quiz.description.is_empty() {
+        lines.extend(inline(&quiz.description).lines().map(str::to_string));
+        lines.push(String::new());
+    }
+    
+    for (i, question) in quiz.questions.iter().enumerate() {
+        let marker = format!("{}. ", i + 1);
+        let pad = " ".repeat(marker.len());
+        let prompt = inline(&question.prompt);
+        let mut prompt_lines = prompt.lines();
+        lines.push(format!("{}{}", marker, prompt_lines.next().unwrap_or("")).trim_end().to_string());
+        lines.extend(prompt_lines.map(|line| format!("{}{}", pad, line)));
+        if question.points != 1 {
+            lines.last_mut().unwrap().push_str(&format!(" ({} points)", question.points));
+        }
+        
+        for (content, correct) in question_choices(question) {
+            let mark = if answer_key && correct { "[x]" } else { "[ ]" };
+            lines.push(format!("{}- {} {}", pad, mark, inline(&content)));
+        }
+        if answer_key {
+            if question.question_type == QuestionType::ShortAnswer && !question.answers.is_empty() {
+                lines.push(format!("{}Answer: {}", pad, question.answers.join(" / ")));
+            }
+            for line in question.explanation.iter().flat_map(|explanation| inline(explanation).lines().map(str::to_string).collect::<Vec<_>>()) {
+                lines.push(if markdown { format!("{}> {}", pad, line) } else { format!("{}{}", pad, line) });
+            }
+        }
+    }
+    
+    lines
+}
+
+/// A table cell as text: its formatted value, or its content without markup
+fn cell_text(cell: &TableCell, column: Option<&ColumnSpec>) -> String {
+    table::display_value(cell, column).unwrap_or_else(|| text_inline(&cell.content))
+}
+
+/// A column width as CSS, where a bare number counts characters
+fn css_length(width: &str) -> String {
+    if width.ends_with(|c: char| c.is_ascii_digit()) { format!("{}ch", width) } else { width.to_string() }
+}
+
+/// Render a table row, with `colspan`/`rowspan` on merged cells; `starts`
+/// gives the column each cell starts in
+fn html_table_row(output: &mut String, row: &TableRow, starts: &[usize], columns: &[ColumnSpec], indent: &str) {
+    output.push_str(&format!("{}    <tr>\n", indent));
+    for (cell, &start) in row.cells.iter().zip(starts) {
+        let column = columns.get(start);
+        let tag = if cell.is_header { "th" } else { "td" };
+        let mut attrs = String::new();
+        if cell.colspan > 1 {
+            attrs.push_str(&format!(" colspan=\"{}\"", cell.colspan));
+        }
+        if cell.rowspan > 1 {
+            attrs.push_str(&format!(" rowspan=\"{}\"", cell.rowspan));
+        }
+        if let Some(align) = column.map(|column| column.align).filter(|&align| align != ColumnAlign::Left) {
+            attrs.push_str(&format!(" style=\"text-align: {}\"", align.to_str()));
+        }
+        
+        let content = match table::display_value(cell, column) {
+            Some(text) => html_escape(&text),
+            None => html_inline(&cell.content),
+        };
+        output.push_str(&format!("{}      <{}{}>{}</{}>\n", indent, tag, attrs, content, tag));
+    }
+    output.push_str(&format!("{}    </tr>\n", indent));
+}
+
+/// Lay a table out on a grid of columns, giving each cell's text to the
+/// column it starts in and leaving the columns it spans empty
+fn table_grid(table: &Table, text: impl Fn(&TableCell, Option<&ColumnSpec>) -> String) -> Vec<Vec<(String, usize)>> {
+    let layout = table::layout(table.all_rows());
+    let count = layout.column_count().max(1);
+    
+    table.all_rows()
+        .zip(&layout.columns)
+        .map(|(row, starts)| {
+            let mut cells = vec![(String::new(), 1); count];
+            for (cell, &start) in row.cells.iter().zip(starts) {
+                if start < count {
+                    cells[start] = (text(cell, table.columns.get(start)), cell.colspan.min(count - start));
+                }
+            }
+            cells
+        })
+        .collect()
+}
+
+/// Render a table as a Markdown pipe table; merged cells become empty cells
+fn markdown_table(table: &Table) -> String {
+    let grid = table_grid(table, |cell, column| {
+        let text = table::display_value(cell, column).unwrap_or_else(|| markdown_inline(&cell.content));
+        text.replace('|', "\\|").replace('\n', "<br>")
+    });
+    let count = grid.first().map_or(0, Vec::len);
+    let row = |cells: &[(String, usize)]| format!("| {} |\n", cells.iter().map(|(text, _)| text.as_str()).collect::<Vec<_>>().join(" | "));
+    
+    let mut output = String::new();
+    if let Some(caption) = &table.caption {
+        output.push_str(&format!("*{}*\n\n", markdown_text(caption)));
+    }
+    
+    // Markdown tables need a header row, so use an empty one if there is none
+    let mut rows = grid.iter();
+    match table.header {
+        Some(_) => output.push_str(&row(rows.next().unwrap())),
+        None => output.push_str(&row(&vec![(String::new(), 1); count])),
+    }
+    
+    let rule: Vec<&str> = (0..count)
+        .map(|i| match table.columns.get(i).map(|column| column.align) {
+            Some(ColumnAlign::Center) => ":---:",
+            Some(ColumnAlign::Right) => "---:",
+            _ => "---",
+        })
+        .collect();
+    output.push_str(&format!("| {} |\n", rule.join(" | ")));
+    
+    for cells in rows {
+        output.push_str(&row(cells));
+    }
+    output
+}
+
+/// Render a table as text in padded columns, each at least as wide as a
+/// character `width` gives it
+fn plain_table(table: &Table) -> Vec<String> {
+    let grid = table_grid(table, cell_text);
+    let count = grid.first().map_or(0, Vec::len);
+    
+    let mut widths: Vec<usize> = (0..count)
+        .map(|i| {
+            table.columns.get(i)
+                .and_then(|column| column.width.as_deref())
+                .and_then(|width| width.parse().ok())
+                .unwrap_or(0)
+        })
+        .collect();
+    for cells in &grid {
+        for (i, (text, span)) in cells.iter().enumerate() {
+            let len = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+            if *span == 1 {
+                widths[i] = widths[i].max(len);
+            }
+        }
+    }
+    // Widen the last column of a merged cell that does not fit its columns
+    for cells in &grid {
+        for (i, (text, span)) in cells.iter().enumerate().filter(|(_, (_, span))| *span > 1) {
+            let available = widths[i..i + span].iter().sum::<usize>() + 2 * (span - 1);
+            let len = text.chars().count();
+            if len > available {
+                widths[i + span - 1] += len - available;
+            }
+        }
+    }
+    
+    let mut lines = Vec::new();
+    for (r, cells) in grid.iter().enumerate() {
+        let mut line = String::new();
+        let mut i = 0;
+        while i < cells.len() {
+            let (text, span) = &cells[i];
+            let width = widths[i..i + span].iter().sum::<usize>() + 2 * (span - 1);
+            let text = text.replace('\n', " ");
+            let padding = width.saturating_sub(text.chars().count());
+            let (left, right) = match table.columns.get(i).map(|column| column.align) {
+                Some(ColumnAlign::Right) => (padding, 0),
+                Some(ColumnAlign::Center) => (padding / 2, padding - padding / 2),
+                _ => (0, padding),
+            };
+            
+            if i > 0 {
+                line.push_str("  ");
+            }
+            line.push_str(&format!("{}{}{}", " ".repeat(left), text, " ".repeat(right)));
+            i += span;
+        }
+        lines.push(line.trim_end().to_string());
+        
+        if r == 0 && table.header.is_some() {
+            let rule: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+            lines.push(rule.join("  "));
+        }
+    }
+    lines
+}
+
+fn inline_to_html(element: &InlineElement) -> String {
+    let children = |elements: &[InlineElement]| elements.iter().map(inline_to_html).collect::<String>();
+    
+    match element {
+        InlineElement::Text(text) => html_escape(text),
+        InlineElement::Bold(content) => format!("<strong>{}</strong>", children(content)),
+        InlineElement::Italic(content) => format!("<em>{}</em>", children(content)),
+        InlineElement::Strikethrough(content) => format!("<del>{}</del>", children(content)),
+        InlineElement::Highlight(content) => format!("<mark>{}</mark>", children(content)),
+        InlineElement::Subscript(content) => format!("<sub>{}</sub>", children(content)),
+        InlineElement::Superscript(content) => format!("<sup>{}</sup>", children(content)),
+        InlineElement::Code { code, lang: Some(lang) } => {
+            format!("<code class=\"language-{}\">{}</code>", html_escape(lang), html_escape(code))
+        }
+        InlineElement::Code { code, lang: None } => format!("<code>{}</code>", html_escape(code)),
+        InlineElement::Math(math) => format!("<span class=\"math\">${}$</span>", html_escape(math)),
+        InlineElement::Link { content, url } => {
+            let text = if content.is_empty() { html_escape(url) } else { children(content) };
+            format!("<a href=\"{}\">{}</a>", html_escape(url), text)
+        }
+        InlineElement::Reference { id, relation } => {
+            let relation = relation.as_ref()
+                .map_or(String::new(), |relation| format!(" data-relation=\"{}\"", html_escape(relation)));
+            format!("<a class=\"ref\" href=\"#{}\"{}>{}</a>", html_escape(id), relation, html_escape(id))
+        }
+        InlineElement::Footnote { id, content } => {
+            format!("<span class=\"footnote\" data-id=\"{}\">{}</span>", html_escape(id), children(content))
+        }
+        InlineElement::Variable(name) => {
+            format!("<span class=\"variable\" data-name=\"{}\">{}</span>", html_escape(name), html_escape(&element.plain_text()))
+        }
+        InlineElement::Custom { element_type, content, .. } => {
+            format!("<span class=\"{}\">{}</span>", html_escape(element_type), html_inline(content))
+        }
+    }
+}
+
+/// Render Blox inline markup as Markdown
+pub(crate) fn markdown_inline(text: &str) -> String {
+    let rendered: String = parse_inline(text).elements.iter().map(inline_to_markdown).collect();
+    markdown_text(&rendered)
+}
+
+fn inline_to_markdown(element: &InlineElement) -> String {
+    let children = |elements: &[InlineElement]| elements.iter().map(inline_to_markdown).collect::<String>();
+    
+    match element {
+        InlineElement::Text(text) => text.clone(),
+        InlineElement::Bold(content) => format!("**{}**", children(content)),
+        InlineElement::Italic(content) => format!("*{}*", children(content)),
+        InlineElement::Strikethrough(content) => format!("~~{}~~", children(content)),
+        InlineElement::Highlight(content) => format!("<mark>{}</mark>", children(content)),
+        InlineElement::Subscript(content) => format!("<sub>{}</sub>", children(content)),
+        InlineElement::Superscript(content) => format!("<sup>{}</sup>", children(content)),
+        InlineElement::Code { code, .. } if code.contains('`') => format!("`` {} ``", code),
+        InlineElement::Code { code, .. } => format!("`{}`", code),
+        InlineElement::Math(math) => format!("${}$", math),
+        InlineElement::Link { content, url } => {
+            let text = if content.is_empty() { url.clone() } else { children(content) };
+            format!("[{}]({})", text, url)
+        }
+        InlineElement::Reference { id, .. } => format!("[{}](#{})", id, id),
+        InlineElement::Footnote { content, .. } => format!("^[{}]", children(content)),
+        InlineElement::Variable(_) => element.plain_text(),
+        InlineElement::Custom { content, .. } => {
+            parse_inline(content).elements.iter().map(inline_to_markdown).collect()
+        }
+    }
+}
+
+/// Render Blox inline markup as plain text
+fn text_inline(text: &str) -> String {
+    parse_inline(text).elements.iter().map(InlineElement::plain_text).collect()
+}
+
+/// Escape text so Markdown does not read a leading `#` as a heading
+fn markdown_text(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            if line.trim_start().starts_with('#') {
+                line.replacen('#', "\\#", 1)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub(crate) fn html_escape(text: &str) -> String {
     text.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
@@ -331,7 +897,7 @@ fn html_escape(text: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::blox::{BlockType, Block, Attribute};
+    use crate::blox::{BlockType, Block};
     
     #[test]
     fn test_html_output() {
@@ -359,4 +925,209 @@ mod tests {
         let result = decoder.decode(&doc).unwrap();
         assert!(result.contains("# Hello World"));
     }
+    
+    #[test]
+    fn test_inline_rendering() {
+        let mut doc = Document::new();
+        let mut block = Block::new(BlockType::Paragraph, 1, 1);
+        block.content = "A [**bold** link](https://example.com) and {{code lang=rust \"a < b\"}} see {{@intro}}".to_string();
+        doc.blocks.push(block);
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains(
+            "<p>A <a href=\"https://example.com\"><strong>bold</strong> link</a> and \
+             <code class=\"language-rust\">a &lt; b</code> see <a class=\"ref\" href=\"#intro\">intro</a></p>"
+        ));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.starts_with("A [**bold** link](https://example.com) and `a < b` see [intro](#intro)"));
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).decode(&doc).unwrap();
+        assert!(text.starts_with("A bold link and a < b see intro"));
+    }
+    
+    #[test]
+    fn test_variables() {
+        let doc = crate::blox::BloxParser::new().parse_string("#var name=Loom\n#p\nHello {{!name}}\n").unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<p>Hello <span class=\"variable\" data-name=\"name\">{{!name}}</span></p>"));
+        assert!(!html.contains("class=\"var\""));
+        
+        let resolved = crate::blox::Interpolator::new().interpolate(&doc).document;
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&resolved).unwrap();
+        assert!(html.contains("<p>Hello Loom</p>"));
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).decode(&doc).unwrap();
+        assert_eq!(text, "Hello {{!name}}\n\n");
+    }
+    
+    #[test]
+    fn test_table_spans() {
+        let content = "#table header=true\nItem, Q1, Q2\n{{cell rowspan=2 Fruit}}, 1, 2\n3, 4\n{{cell colspan=3 **Total**}}\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<thead>\n    <tr>\n      <th>Item</th>"));
+        assert!(html.contains("<td rowspan=\"2\">Fruit</td>"));
+        assert!(html.contains("<td colspan=\"3\"><strong>Total</strong></td>"));
+        assert_eq!(html.matches("<tr>").count(), 4);
+    }
+    
+    #[test]
+    fn test_table_columns() {
+        let content = "#table \"Sales\" header=true types=text,currency,number align=,,c width=,10,\nItem, Total, Qty\nTea | hot, 1200, 3\n{{cell colspan=2 **All**}}, 4\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<col />\n    <col style=\"width: 10ch\" />"));
+        assert!(html.contains("<th style=\"text-align: right\">Total</th>"));
+        assert!(html.contains("<td style=\"text-align: right\">1,200.00</td>"));
+        assert!(html.contains("<td colspan=\"2\"><strong>All</strong></td>"));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert_eq!(markdown, "*Sales*\n\n| Item | Total | Qty |\n| --- | ---: | :---: |\n| Tea \\| hot | 1,200.00 | 3 |\n| **All** |  | 4 |\n\n");
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).decode(&doc).unwrap();
+        assert_eq!(text, "Sales\nItem            Total  Qty\n---------  ----------  ---\nTea | hot    1,200.00   3\nAll                     4\n\n");
+    }
+    
+    #[test]
+    fn test_table_formulas() {
+        let content = "#table header=true types=text,number\nItem, Cost\nA, 1.5\nB, 2\nTotal, =SUM(B2:B3)\nBad, =B2/0\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<td style=\"text-align: right\">3.5</td>"));
+        assert!(html.contains("<td style=\"text-align: right\">#DIV/0!</td>"));
+        assert!(!html.contains("SUM"));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.contains("| Total | 3.5 |"));
+        
+        // The encoder keeps the formulas
+        let encoded = crate::blox::BloxEncoder::new().encode(&doc).unwrap();
+        assert!(encoded.contains("Total, =SUM(B2:B3)\nBad, =B2/0"));
+    }
+    
+    #[test]
+    fn test_lists() {
+        let content = "#list type=ordered start=2\n- First *one*\n-- Nested\n##item\nSecond\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<ol start=\"2\">\n  <li>First <em>one</em>\n    <ol>\n      <li>Nested</li>\n    </ol>\n  </li>\n  <li>Second</li>\n</ol>\n"));
+        assert!(!html.contains("class=\"item\""));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.starts_with("2. First *one*\n   1. Nested\n3. Second\n"));
+        
+        let content = "#list type=check\n- [x] Done\n- [ ] Open\n-- Step\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<li><input type=\"checkbox\" checked disabled /> Done</li>"));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.starts_with("- [x] Done\n- [ ] Open\n    - [ ] Step\n"));
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).decode(&doc).unwrap();
+        assert!(text.starts_with("[x] Done\n[ ] Open\n    [ ] Step\n"));
+        
+        // A bad `start=` numbers the items from 1 rather than losing them
+        let mut block = Block::new(BlockType::List, 1, 1);
+        block.add_attribute("type".to_string(), "ordered".to_string());
+        block.add_attribute("start".to_string(), "x".to_string());
+        block.content = "- One\n- Two".to_string();
+        let mut doc = Document::new();
+        doc.blocks.push(block);
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<ol>\n  <li>One</li>\n  <li>Two</li>\n</ol>\n"), "{}", html);
+    }
+    
+    #[test]
+    fn test_quizzes() {
+        let content = "#quiz \"Basics\"\n? Capital of *France*?\n- [ ] Lyon\n- [x] Paris\n> Since 987.\n##question type=short points=2 id=gas\nName a noble gas.\n= Neon\n= Argon\n";
+        let doc = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<fieldset class=\"quiz\">\n  <legend>Basics</legend>\n  <ol class=\"questions\">\n    <li class=\"question\" data-type=\"single\" data-points=\"1\">\n      <p>Capital of <em>France</em>?</p>"));
+        assert!(html.contains("<li><label><input type=\"radio\" name=\"quiz1-q1\" value=\"2\" /> Paris</label></li>"));
+        assert!(html.contains("<li class=\"question\" id=\"gas\" data-type=\"short\" data-points=\"2\">\n      <p>Name a noble gas.</p>\n      <input type=\"text\" name=\"gas\" />"));
+        assert!(!html.contains("Since 987") && !html.contains("Neon") && !html.contains("class=\"question\">"));
+        
+        let key = BloxDecoder::new(OutputFormat::Html).with_answer_key(true).decode(&doc).unwrap();
+        assert!(key.contains("<li class=\"correct\"><label><input type=\"radio\" name=\"quiz1-q1\" value=\"2\" checked disabled /> Paris</label></li>"));
+        assert!(key.contains("<p class=\"explanation\">Since 987.</p>"));
+        assert!(key.contains("<p class=\"answer\">Neon / Argon</p>"));
+        
+        // Unnamed questions of different quizzes do not share inputs
+        let two = crate::blox::BloxParser::new().parse_string(&format!("{}#quiz\n? Sky is blue.\n- [x] True\n- [ ] False\n", content)).unwrap();
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&two).unwrap();
+        assert!(html.contains("name=\"quiz1-q1\"") && html.contains("name=\"quiz2-q1\""), "{}", html);
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert_eq!(markdown, "**Basics**\n\n1. Capital of *France*?\n   - [ ] Lyon\n   - [ ] Paris\n2. Name a noble gas. (2 points)\n\n");
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).with_answer_key(true).decode(&doc).unwrap();
+        assert_eq!(text, "Basics\n1. Capital of France?\n   - [ ] Lyon\n   - [x] Paris\n   Since 987.\n2. Name a noble gas. (2 points)\n   Answer: Neon / Argon\n\n");
+    }
+    
+    #[test]
+    fn test_quiz_packages() {
+        let doc = crate::blox::BloxParser::new().parse_string("#quiz\n? Sky is blue.\n= true\n").unwrap();
+        
+        let gift = BloxDecoder::new(OutputFormat::Gift).decode_package(&doc).unwrap();
+        assert_eq!(gift.files[0].content, "::q1::[markdown]Sky is blue. {\nTRUE\n}\n");
+        assert!(gift.diagnostics.is_empty());
+        
+        // GIFT text alone would drop the problems found writing it
+        assert!(matches!(BloxDecoder::new(OutputFormat::Gift).decode(&doc), Err(BloxError::PackageOutput { .. })));
+        let unscored = crate::blox::BloxParser::new().parse_string("#quiz\n? Sky is blue.\n").unwrap();
+        let gift = BloxDecoder::new(OutputFormat::Gift).decode_package(&unscored).unwrap();
+        assert_eq!(gift.diagnostics[0].code, "unsupported-question");
+        
+        assert!(matches!(BloxDecoder::new(OutputFormat::Qti).decode(&doc), Err(BloxError::PackageOutput { .. })));
+        let package = BloxDecoder::new(OutputFormat::Qti).decode_package(&doc).unwrap();
+        assert_eq!(package.files.len(), 3);
+        
+        let package = BloxDecoder::new(OutputFormat::Markdown).decode_package(&doc).unwrap();
+        assert_eq!(package.files[0].path, "document.md");
+    }
+    
+    #[test]
+    fn test_escaped_content() {
+        let mut doc = Document::new();
+        let mut block = Block::new(BlockType::Paragraph, 1, 1);
+        block.content = "#hashtag and \\{\\{literal\\}\\}".to_string();
+        doc.blocks.push(block);
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<p>#hashtag and {{literal}}</p>"));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.starts_with("\\#hashtag and {{literal}}"));
+        
+        let text = BloxDecoder::new(OutputFormat::PlainText).decode(&doc).unwrap();
+        assert!(text.starts_with("#hashtag and {{literal}}"));
+    }
+    
+    #[test]
+    fn test_verbatim_content() {
+        let mut doc = Document::new();
+        let mut code = Block::new(BlockType::Code, 1, 1);
+        code.content = "x = \"\\{\\{\" # **not bold**\n```".to_string();
+        let mut raw = Block::new(BlockType::Custom("shell".to_string()), 1, 1);
+        raw.add_attribute("raw".to_string(), "true".to_string());
+        raw.content = "echo **hi**".to_string();
+        doc.blocks.extend([code, raw]);
+        
+        let html = BloxDecoder::new(OutputFormat::Html).decode(&doc).unwrap();
+        assert!(html.contains("<code>x = &quot;\\{\\{&quot; # **not bold**\n```</code>"));
+        assert!(html.contains("<pre>echo **hi**</pre>"));
+        
+        let markdown = BloxDecoder::new(OutputFormat::Markdown).decode(&doc).unwrap();
+        assert!(markdown.starts_with("````\nx = \"\\{\\{\" # **not bold**\n```\n````\n"));
+        assert!(markdown.contains("```\necho **hi**\n```"));
+    }
 }
@@ -0,0 +1,67 @@
+use crate::blox::ast::Span;
+use crate::blox::error::BloxError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+/// A suggested edit that resolves a diagnostic
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Fix {
+    pub span: Span,
+    pub replacement: String,
+    pub message: String,
+}
+
+/// A problem found in a document, located by its source span
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable, kebab-case identifier such as `invalid-nesting`
+    pub code: String,
+    pub span: Span,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, span: Span, message: String) -> Self {
+        Self {
+            severity,
+            code: code.to_string(),
+            span,
+            message,
+            fix: None,
+        }
+    }
+
+    pub fn error(code: &str, span: Span, message: String) -> Self {
+        Self::new(Severity::Error, code, span, message)
+    }
+
+    pub fn warning(code: &str, span: Span, message: String) -> Self {
+        Self::new(Severity::Warning, code, span, message)
+    }
+
+    pub fn info(code: &str, span: Span, message: String) -> Self {
+        Self::new(Severity::Info, code, span, message)
+    }
+
+    /// Build a diagnostic from a parse error, using the error's code and message
+    pub fn from_error(error: &BloxError, severity: Severity, span: Span) -> Self {
+        Self::new(severity, error.code(), span, error.detail())
+    }
+
+    pub fn with_fix(mut self, span: Span, replacement: String, message: String) -> Self {
+        self.fix = Some(Fix { span, replacement, message });
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
@@ -1,88 +1,302 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt::Write;
+use std::ops::Range;
 use crate::blox::ast::{Document, Block, BlockType, Attribute};
-use crate::blox::error::ParseResult;
+use crate::blox::cst::SyntaxTree;
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::metadata::MetadataBlock;
+use crate::blox::attributes;
+use crate::blox::escape::escape_line;
+use crate::blox::format::{self, AttributeOrder, FormatStyle, QuoteStyle};
+use crate::blox::structured;
+use crate::blox::verbatim::{self, Fence};
 
 pub struct BloxEncoder {
     indent_size: usize,
-    use_shorthand: bool,
+    style: FormatStyle,
+    /// Whether content is written from the blocks' structured models
+    models: bool,
 }
 
 impl BloxEncoder {
     pub fn new() -> Self {
         Self {
             indent_size: 0,
-            use_shorthand: true,
+            style: FormatStyle::new(),
+            models: false,
         }
     }
     
+    /// Indent nested headers and their content by `indent_size` spaces per
+    /// level, a layout the parser reads back under `IndentPolicy::Header`
     pub fn with_options(indent_size: usize, use_shorthand: bool) -> Self {
         Self {
             indent_size,
-            use_shorthand,
+            style: FormatStyle { use_shorthand, ..FormatStyle::new() },
+            models: false,
         }
     }
     
-    /// Encode a Document back to Blox format
+    pub fn with_style(style: FormatStyle) -> Self {
+        Self { indent_size: 0, style, models: false }
+    }
+    
+    /// Write list, table and inline content from `list_items`, `table` and
+    /// `inline_elements` where they no longer read the same as `content`,
+    /// so edits made to the models are kept; see `structured::synced`
+    pub fn with_models(mut self, models: bool) -> Self {
+        self.models = models;
+        self
+    }
+    
+    pub fn style(&self) -> &FormatStyle {
+        &self.style
+    }
+    
+    /// Encode a Document back to Blox format.
+    ///
+    /// Parsing the result gives back the document: lines that would read as
+    /// headers or comments are escaped, verbatim content is fenced when it
+    /// has to be, and attribute values are quoted as the parser reads them.
+    /// Blank lines in content that is not verbatim, which the parser skips,
+    /// are written as a lone `\`. A document with no Blox form is
+    /// `Unencodable`: one whose attribute values hold line breaks, or whose
+    /// content has `\r\n` line breaks, which the parser reads as `\n`.
+    ///
+    /// Document metadata keeps no place among the blocks, so its `#meta` and
+    /// `#document` headers are written first, ahead of the first block, even
+    /// where the source had them further down. The document read back is
+    /// the same; use `encode_preserving` to keep them where they were written.
     pub fn encode(&self, document: &Document) -> ParseResult<String> {
+        self.check_encodable(document)?;
         let mut output = String::new();
+        self.encode_metadata(&mut output, document);
+        
+        // Encode all blocks
+        for (index, block) in document.blocks.iter().enumerate() {
+            self.encode_block(&mut output, block, 0, document.blocks.get(index + 1))?;
+        }
+        
+        Ok(output)
+    }
+    
+    /// Encode `document` as an edit of the source `tree` was parsed from.
+    ///
+    /// Blocks are matched to the source by their header spans. A header or
+    /// content that is unchanged is copied from the source together with
+    /// the comments and blank lines around it, so an unedited document gives
+    /// back its source byte for byte. Edited headers and content are written
+    /// as `encode` writes them, in the source's line ending, and blocks with
+    /// no place in the source are encoded in full. Attributes are compared
+    /// regardless of order, so reordering them alone keeps the written header.
+    ///
+    /// A block whose content is unchanged but whose structured model was
+    /// edited is written from the model, as `with_models` would write it.
+    pub fn encode_preserving(&self, tree: &SyntaxTree, document: &Document) -> ParseResult<String> {
+        self.check_encodable(document)?;
+        let mut writer = PreservingWriter {
+            encoder: self,
+            tree,
+            output: String::new(),
+            used: HashSet::new(),
+        };
+        writer.copy(tree.prologue.clone());
+        
+        let original = &tree.document().metadata;
+        let metadata_kept = original.entries.len() == document.metadata.entries.len()
+            && original.entries.iter().zip(&document.metadata.entries).all(|(a, b)| {
+                a.block == b.block && same_attribute(&a.attribute, &b.attribute)
+            });
+        if !metadata_kept {
+            writer.generate(|output| self.encode_metadata(output, document));
+        }
+        
+        // Metadata blocks stay where they were written, ahead of the first
+        // block that followed them in the source
+        let mut metadata_nodes = tree.nodes.iter().filter(|node| node.metadata && metadata_kept).peekable();
+        for (index, block) in document.blocks.iter().enumerate() {
+            let next_start = document.blocks[index..].iter().find_map(|block| tree.node(block.header_span)).map(|node| node.lines.start);
+            while let Some(node) = metadata_nodes.next_if(|node| next_start.is_none_or(|start| node.lines.start < start)) {
+                writer.copy(node.lines.clone());
+            }
+            writer.block(block, 0, document.blocks.get(index + 1))?;
+        }
+        for node in metadata_nodes {
+            writer.copy(node.lines.clone());
+        }
         
-        // Encode document metadata as comments
-        if !document.metadata.is_empty() {
-            writeln!(output, "// Document metadata").unwrap();
-            for (key, value) in &document.metadata {
-                writeln!(output, "// {}: {}", key, value).unwrap();
+        Ok(writer.output)
+    }
+    
+    /// Check that every header in `document` can be written so that it
+    /// reads back as the same block in the same place
+    fn check_encodable(&self, document: &Document) -> ParseResult<()> {
+        for entry in &document.metadata.entries {
+            check_attribute(&entry.attribute)?;
+        }
+        self.check_blocks(&document.blocks, 0)
+    }
+    
+    fn check_blocks(&self, blocks: &[Block], parent_level: usize) -> ParseResult<()> {
+        let mut previous_level = None;
+        for block in blocks {
+            let name = self.get_block_type_string(&block.block_type);
+            let valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            if !valid_name || BlockType::from_str(&name).canonical() != block.block_type.canonical() {
+                return Err(unencodable(format!("`{}` cannot be written as a block type", name)));
+            }
+            
+            // A block deeper than its previous sibling would be read as that sibling's child
+            let deepest = previous_level.unwrap_or(6);
+            if block.level <= parent_level || block.level > deepest {
+                return Err(unencodable(format!(
+                    "#{} at level {} would not be read back under its parent at level {}",
+                    name, block.level, parent_level,
+                )));
+            }
+            if parent_level == 0 && MetadataBlock::of(&block.block_type).is_some() && block.content.is_empty() && block.children.is_empty() {
+                return Err(unencodable(format!("#{} with no content would be read back as document metadata", name)));
             }
+            
+            for attribute in &block.attributes {
+                check_attribute(attribute)?;
+            }
+            check_content(block, &name)?;
+            self.check_blocks(&block.children, block.level)?;
+            previous_level = Some(block.level);
+        }
+        Ok(())
+    }
+    
+    /// Write document metadata as the blocks it is read from, one header
+    /// per run of entries from the same block. Headers take the level of the
+    /// first block, so that block follows them rather than nesting under them.
+    fn encode_metadata(&self, output: &mut String, document: &Document) {
+        let metadata = &document.metadata;
+        let hashes = "#".repeat(document.blocks.first().map_or(1, |block| block.level));
+        let mut entries = metadata.entries.iter().peekable();
+        while let Some(first) = entries.next() {
+            let mut attributes = vec![first.attribute.clone()];
+            while let Some(entry) = entries.next_if(|entry| entry.block == first.block) {
+                attributes.push(entry.attribute.clone());
+            }
+            
+            let block_type = BlockType::Custom(first.block.to_str().to_string());
+            writeln!(output, "{}{} {}", hashes, first.block.to_str(), self.encode_attributes(&attributes, &block_type)).unwrap();
+        }
+        if !metadata.is_empty() {
+            self.blank_lines(output);
+        }
+    }
+    
+    fn blank_lines(&self, output: &mut String) {
+        for _ in 0..self.style.blank_lines {
             writeln!(output).unwrap();
         }
+    }
+    
+    /// Write `block` and its children; `next` is the block written after them
+    fn encode_block(&self, output: &mut String, block: &Block, parent_level: usize, next: Option<&Block>) -> ParseResult<()> {
+        let synced = if self.models { structured::synced(block)? } else { None };
+        let block = synced.as_ref().unwrap_or(block);
+        let indent = self.indent(parent_level);
+        self.encode_header(output, block, &indent);
+        self.encode_content(output, block, &indent, block.children.first().or(next));
         
-        // Encode all blocks
-        for block in &document.blocks {
-            self.encode_block(&mut output, block, 0)?;
+        // Write children
+        for (index, child) in block.children.iter().enumerate() {
+            self.encode_block(output, child, parent_level + 1, block.children.get(index + 1).or(next))?;
         }
         
-        Ok(output)
+        // Add blank lines after block
+        self.blank_lines(output);
+        
+        Ok(())
     }
     
-    fn encode_block(&self, output: &mut String, block: &Block, parent_level: usize) -> ParseResult<()> {
-        // Calculate indentation
-        let indent = if self.indent_size > 0 {
+    fn indent(&self, parent_level: usize) -> String {
+        if self.indent_size > 0 {
             " ".repeat(parent_level * self.indent_size)
         } else {
             String::new()
-        };
-        
-        // Generate block header
+        }
+    }
+    
+    fn encode_header(&self, output: &mut String, block: &Block, indent: &str) {
+        writeln!(output, "{}{}", indent, self.header_line(block)).unwrap();
+    }
+    
+    /// A block's header line, without indentation or line ending
+    pub(crate) fn header_line(&self, block: &Block) -> String {
         let level_markers = "#".repeat(block.level);
         let block_type = self.get_block_type_string(&block.block_type);
         let attributes = self.encode_attributes(&block.attributes, &block.block_type);
         
-        // Write block start line
         if attributes.is_empty() {
-            writeln!(output, "{}{}{}", indent, level_markers, block_type).unwrap();
+            format!("{}{}", level_markers, block_type)
         } else {
-            writeln!(output, "{}{}{} {}", indent, level_markers, block_type, attributes).unwrap();
+            format!("{}{} {}", level_markers, block_type, attributes)
+        }
+    }
+    
+    /// Write block content, escaping lines that would read back as headers or
+    /// comments. `next` is the block whose header follows the content.
+    pub(crate) fn encode_content(&self, output: &mut String, block: &Block, indent: &str, next: Option<&Block>) {
+        if block.is_verbatim() {
+            self.encode_verbatim_content(output, block, indent, next);
+        } else if !block.content.is_empty() {
+            for line in self.styled_content(block).split('\n') {
+                writeln!(output, "{}{}", indent, escape_line(line)).unwrap();
+            }
+        }
+    }
+    
+    /// Content laid out as the style asks for its block type
+    fn styled_content<'a>(&self, block: &'a Block) -> Cow<'a, str> {
+        match block.block_type.canonical() {
+            BlockType::List if self.style.normalize_lists => Cow::Owned(format::normalize_list(&block.content)),
+            BlockType::Table if self.style.align_tables => format::align_table(block).map_or(Cow::Borrowed(&block.content), Cow::Owned),
+            BlockType::Paragraph => match self.style.wrap_width {
+                Some(width) => Cow::Owned(format::wrap(&block.content, width)),
+                None => Cow::Borrowed(&block.content),
+            },
+            _ => Cow::Borrowed(&block.content),
         }
+    }
+    
+    /// Write content that is taken as written, fencing it when a line would
+    /// otherwise end the block or read back differently
+    fn encode_verbatim_content(&self, output: &mut String, block: &Block, indent: &str, next: Option<&Block>) {
+        // Children only follow a verbatim block once a fence has closed it
+        let fenced = !block.children.is_empty()
+            || !self.ends_verbatim(block, next)
+            || (!block.content.is_empty() && verbatim::needs_fence(&block.content, block.level, verbatim::ending_types(block)));
         
-        // Write block content
+        let fence = fenced.then(|| Fence::for_content(&block.content));
+        
+        if let Some(fence) = fence {
+            writeln!(output, "{}{}", indent, fence).unwrap();
+        }
         if !block.content.is_empty() {
-            for line in block.content.lines() {
+            for line in block.content.split('\n') {
                 writeln!(output, "{}{}", indent, line).unwrap();
             }
         }
-        
-        // Write children
-        for child in &block.children {
-            self.encode_block(output, child, parent_level + 1)?;
+        if let Some(fence) = fence {
+            writeln!(output, "{}{}", indent, fence).unwrap();
         }
-        
-        // Add blank line after block (except for last block)
-        writeln!(output).unwrap();
-        
-        Ok(())
+    }
+    
+    /// Whether the header of `next` ends the verbatim content of `block`
+    /// unfenced, as it does unless `block` is raw and `next` of a custom type
+    fn ends_verbatim(&self, block: &Block, next: Option<&Block>) -> bool {
+        next.is_none_or(|next| verbatim::ends_block(&self.header_line(next), block.level, verbatim::ending_types(block)))
     }
     
     fn get_block_type_string(&self, block_type: &BlockType) -> String {
-        if self.use_shorthand {
+        if self.style.use_shorthand {
             // Use shorthand when available
             match block_type {
                 BlockType::Section => "section".to_string(),
@@ -95,8 +309,12 @@ impl BloxEncoder {
                 _ => block_type.to_str().to_string(),
             }
         } else {
-            // Use canonical form
-            block_type.canonical().to_str().to_string()
+            // Use canonical form, except for headings, whose level would be lost
+            match block_type {
+                BlockType::H1 | BlockType::H2 | BlockType::H3 |
+                BlockType::H4 | BlockType::H5 | BlockType::H6 => block_type.to_str().to_string(),
+                _ => block_type.canonical().to_str().to_string(),
+            }
         }
     }
     
@@ -108,20 +326,18 @@ impl BloxEncoder {
         let mut parts = Vec::new();
         
         // Handle positional attributes first
-        if let Some(positional) = self.get_positional_attribute(attributes, block_type) {
-            parts.push(self.quote_positional_value(&positional.value));
-        }
-        
-        // Handle key=value attributes
-        for attr in attributes {
-            if !self.is_positional_attribute(&attr.key, block_type) {
-                let value = if attr.value.contains(' ') || attr.value.contains('"') {
-                    format!("\"{}\"", attr.value.replace('"', "\\\""))
-                } else {
-                    attr.value.clone()
-                };
-                parts.push(format!("{}={}", attr.key, value));
-            }
+        let positional = self.get_positional_attribute(attributes, block_type);
+        if let Some(positional) = positional {
+            parts.push(self.quote_value(&positional.value, true));
+        }
+        
+        // Handle key=value attributes, including others with the positional key
+        let mut keyed: Vec<&Attribute> = attributes.iter().filter(|attr| !positional.is_some_and(|positional| std::ptr::eq(*attr, positional))).collect();
+        if self.style.attribute_order == AttributeOrder::Sorted {
+            keyed.sort_by(|a, b| a.key.cmp(&b.key));
+        }
+        for attr in keyed {
+            parts.push(format!("{}={}", attr.key, self.quote_value(&attr.value, false)));
         }
         
         parts.join(" ")
@@ -138,28 +354,146 @@ impl BloxEncoder {
             _ => return None,
         };
         
-        attributes.iter().find(|attr| attr.key == positional_key)
+        // The parser adds positional values after keyed ones, so of several
+        // attributes with the key the last is the one written positionally
+        attributes.iter().rfind(|attr| attr.key == positional_key)
     }
     
-    fn is_positional_attribute(&self, key: &str, block_type: &BlockType) -> bool {
-        match block_type {
-            BlockType::Section | BlockType::H1 | BlockType::H2 | 
-            BlockType::H3 | BlockType::H4 | BlockType::H5 | BlockType::H6 => key == "title",
-            BlockType::Image | BlockType::Img => key == "src",
-            BlockType::Code | BlockType::C => key == "lang",
-            BlockType::Quote | BlockType::Q => key == "author",
-            BlockType::Table | BlockType::Tbl => key == "caption",
-            _ => false,
+    /// An attribute value as the style quotes it
+    fn quote_value(&self, value: &str, positional: bool) -> String {
+        match self.style.quote_style {
+            QuoteStyle::Minimal if !positional && attributes::is_bare(value) => value.to_string(),
+            QuoteStyle::Minimal | QuoteStyle::Double => attributes::quote_with(value, '"'),
+            QuoteStyle::Single => attributes::quote_with(value, '\''),
         }
     }
+}
+
+/// Output of `encode_preserving` as it is written
+struct PreservingWriter<'a> {
+    encoder: &'a BloxEncoder,
+    tree: &'a SyntaxTree,
+    output: String,
+    /// First lines of the nodes already written, so a block copied in the
+    /// edit takes its source only once
+    used: HashSet<usize>,
+}
+
+impl PreservingWriter<'_> {
+    fn block(&mut self, block: &Block, parent_level: usize, next: Option<&Block>) -> ParseResult<()> {
+        let tree = self.tree;
+        // Content left as it was read gives way to a model that was edited
+        let model_edited = self.encoder.models || tree.block(block.header_span).is_some_and(|original| {
+            original.content == block.content && structured::model_edited(block, original)
+        });
+        let synced = if model_edited { structured::synced(block)? } else { None };
+        let block = synced.as_ref().unwrap_or(block);
+        
+        let matched = tree
+            .node(block.header_span)
+            .filter(|node| !node.metadata && self.used.insert(node.lines.start))
+            .zip(tree.block(block.header_span));
+        let Some((node, original)) = matched else {
+            let mut encoded = String::new();
+            self.encoder.encode_block(&mut encoded, block, parent_level, next)?;
+            self.write(&encoded);
+            return Ok(());
+        };
+        
+        let indent = self.encoder.indent(parent_level);
+        let same_header = block.block_type == original.block_type
+            && block.level == original.level
+            && same_attributes(&block.attributes, &original.attributes);
+        match node.header_line(tree) {
+            Some(line) if same_header => self.copy(line..line + 1),
+            _ if same_header => {}
+            _ => self.generate(|output| self.encoder.encode_header(output, block, &indent)),
+        }
+        
+        // Comments among edited content go with it; those after it are kept.
+        // A verbatim block gaining or losing children, or followed by a
+        // header that no longer ends it, changes its fencing.
+        let (content, trivia) = node.body(tree);
+        let following = block.children.first().or(next);
+        let same_content = block.content == original.content
+            && block.is_verbatim() == original.is_verbatim()
+            && (!block.is_verbatim() || block.children.is_empty() == original.children.is_empty())
+            && (!block.is_verbatim() || self.encoder.ends_verbatim(block, following));
+        if same_content {
+            self.copy(content);
+        } else {
+            self.generate(|output| self.encoder.encode_content(output, block, &indent, following));
+        }
+        self.copy(trivia);
+        
+        for (index, child) in block.children.iter().enumerate() {
+            self.block(child, parent_level + 1, block.children.get(index + 1).or(next))?;
+        }
+        Ok(())
+    }
     
-    fn quote_positional_value(&self, value: &str) -> String {
-        if value.is_empty() {
-            format!("\"{}\"", value)
+    /// Copy source lines as written
+    fn copy(&mut self, lines: Range<usize>) {
+        let text = self.tree.text(lines);
+        self.write(text);
+    }
+    
+    /// Write text from the encoder in the source's line ending
+    fn generate(&mut self, encode: impl FnOnce(&mut String)) {
+        let mut text = String::new();
+        encode(&mut text);
+        let ending = self.tree.line_ending();
+        if ending == "\n" {
+            self.write(&text);
         } else {
-            format!("\"{}\"", value.replace('"', "\\\""))
+            self.write(&text.replace('\n', ending));
+        }
+    }
+    
+    fn write(&mut self, text: &str) {
+        // Source whose last line had no line ending may no longer be last
+        if !text.is_empty() && !self.output.is_empty() && !self.output.ends_with('\n') {
+            self.output.push_str(self.tree.line_ending());
         }
+        self.output.push_str(text);
+    }
+}
+
+/// Check that an attribute can be written on a header line
+fn check_attribute(attribute: &Attribute) -> ParseResult<()> {
+    if attribute.key.is_empty() || !attribute.key.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return Err(unencodable(format!("`{}` is not an attribute key", attribute.key)));
+    }
+    if attribute.value.contains(['\n', '\r']) {
+        return Err(unencodable(format!("the value of `{}` has a line break", attribute.key)));
     }
+    Ok(())
+}
+
+/// Content lines are read back without a trailing `\r`
+fn check_content(block: &Block, name: &str) -> ParseResult<()> {
+    if block.content.split('\n').any(|line| line.ends_with('\r')) {
+        return Err(unencodable(format!("#{} content has a line ending in `\\r`, which would be dropped when read back", name)));
+    }
+    Ok(())
+}
+
+fn unencodable(message: String) -> BloxError {
+    BloxError::Unencodable { message }
+}
+
+fn same_attribute(a: &Attribute, b: &Attribute) -> bool {
+    a.key == b.key && a.value == b.value
+}
+
+/// Whether two attribute lists hold the same keys and values in any order
+pub(crate) fn same_attributes(a: &[Attribute], b: &[Attribute]) -> bool {
+    let pairs = |attributes: &[Attribute]| {
+        let mut pairs: Vec<(String, String)> = attributes.iter().map(|attr| (attr.key.clone(), attr.value.clone())).collect();
+        pairs.sort();
+        pairs
+    };
+    pairs(a) == pairs(b)
 }
 
 impl Default for BloxEncoder {
@@ -171,7 +505,7 @@ impl Default for BloxEncoder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::blox::{BlockType, Block};
+    use crate::blox::{BlockType, Block, InlineElement};
     
     #[test]
     fn test_simple_encoding() {
@@ -205,5 +539,142 @@ mod tests {
         assert!(result.contains("#img \"logo.png\""));
         assert!(result.contains("alt=\"Company Logo\""));
         assert!(result.contains("width=100"));
+        
+        // A positional value after a keyed one with the same key keeps both, in order
+        let document = crate::blox::BloxParser::new().parse_string("#img src=a.png \"b.png\" alt='it\\'s'\n").unwrap();
+        let result = encoder.encode(&document).unwrap();
+        assert_eq!(result, "#img \"b.png\" src=a.png alt=it's\n\n");
+        let parsed = crate::blox::BloxParser::new().parse_string(&result).unwrap();
+        let pairs = |block: &Block| block.attributes.iter().map(|attr| (attr.key.clone(), attr.value.clone())).collect::<Vec<_>>();
+        assert_eq!(pairs(&parsed.blocks[0]), pairs(&document.blocks[0]));
+    }
+    
+    #[test]
+    fn test_escaped_content_round_trip() {
+        let encoder = BloxEncoder::new();
+        let mut doc = Document::new();
+        
+        let mut block = Block::new(BlockType::Paragraph, 1, 1);
+        block.content = "#hashtag\n// slashes\n\\#already escaped\nplain".to_string();
+        doc.blocks.push(block);
+        
+        let result = encoder.encode(&doc).unwrap();
+        assert!(result.contains("\n\\#hashtag\n\\// slashes\n\\\\#already escaped\nplain\n"));
+        
+        let parsed = crate::blox::BloxParser::new().parse_string(&result).unwrap();
+        assert_eq!(parsed.blocks.len(), 1);
+        assert_eq!(parsed.blocks[0].content, doc.blocks[0].content);
+    }
+    
+    #[test]
+    fn test_metadata_round_trip() {
+        let content = "#meta author=Ada version=\"0.1 beta\"\n#document title=Notes\n#meta tags=x\n\n#p\nBody\n";
+        let document = crate::blox::BloxParser::new().parse_string(content).unwrap();
+        
+        let result = BloxEncoder::new().encode(&document).unwrap();
+        assert!(result.starts_with("#meta author=Ada version=\"0.1 beta\"\n#document title=Notes\n#meta tags=x\n\n#p\n"));
+        
+        let parsed = crate::blox::BloxParser::new().parse_string(&result).unwrap();
+        assert!(parsed.metadata.iter().eq(document.metadata.iter()));
+        assert!(parsed.metadata.entries.iter().zip(&document.metadata.entries).all(|(a, b)| a.block == b.block));
+    }
+    
+    #[test]
+    fn test_verbatim_round_trip() {
+        let encoder = BloxEncoder::new();
+        let mut doc = Document::new();
+        
+        let mut plain = Block::new(BlockType::Code, 1, 1);
+        plain.content = "# comment\n// comment".to_string();
+        let mut fenced = Block::new(BlockType::Code, 1, 1);
+        fenced.content = "#define X 1\n```\n\\#escaped\n".to_string();
+        let mut parent = Block::new(BlockType::Math, 1, 1);
+        parent.content = "x^2".to_string();
+        parent.children.push(Block::new(BlockType::Paragraph, 2, 1));
+        parent.children[0].content = "child".to_string();
+        doc.blocks.extend([plain, fenced, parent]);
+        
+        let result = encoder.encode(&doc).unwrap();
+        assert!(result.contains("#c\n# comment\n// comment\n"));
+        assert!(result.contains("#c\n````\n#define X 1\n"));
+        
+        let parsed = crate::blox::BloxParser::new().parse_string(&result).unwrap();
+        assert_eq!(parsed.blocks.len(), 3);
+        for (parsed, original) in parsed.blocks.iter().zip(&doc.blocks) {
+            assert_eq!(parsed.content, original.content);
+            assert_eq!(parsed.children.len(), original.children.len());
+        }
+    }
+    
+    #[test]
+    fn test_preserving_round_trip() {
+        let sources = [
+            "// Draft\n\n#meta author=Ada   version='0.1'\n#h1   \"Notes\"  id=top\n\n\nIntro line\n// aside\n\n##c python\n```\n#!/bin/sh\n```\n\n\n#p\nLast line",
+            "#section title=A\r\nText\r\n\r\n##list\r\n- one\r\n- two\r\n",
+            "loose text\n#quiz \"Q\"\n? Why?\n= Because\n",
+            "",
+        ];
+        let encoder = BloxEncoder::new();
+        for source in sources {
+            let tree = crate::blox::BloxParser::new().parse_tree(source);
+            assert_eq!(encoder.encode_preserving(&tree, tree.document()).unwrap(), source);
+        }
+    }
+    
+    #[test]
+    fn test_preserving_edits() {
+        let source = "// Draft\n#meta author=Ada\n\n#h1 \"Notes\" id=top\nIntro\n// keep me\n\n##p\nOld text\n\n#section   title=End\nBye\n";
+        let tree = crate::blox::BloxParser::new().parse_tree(source);
+        let encoder = BloxEncoder::new();
+        
+        // Content edits leave the header and surrounding trivia alone
+        let mut document = tree.document().clone();
+        document.blocks[0].children[0].content = "New text\n#not a header".to_string();
+        assert_eq!(
+            encoder.encode_preserving(&tree, &document).unwrap(),
+            source.replace("Old text\n", "New text\n\\#not a header\n"),
+        );
+        
+        // Header edits rewrite only the header line
+        let mut document = tree.document().clone();
+        document.blocks[1].attributes[0].value = "The End".to_string();
+        document.blocks[0].attributes.reverse();
+        assert_eq!(
+            encoder.encode_preserving(&tree, &document).unwrap(),
+            source.replace("#section   title=End\n", "#section \"The End\"\n"),
+        );
+        
+        // New blocks are encoded in full, removed ones take their trivia with them
+        let mut document = tree.document().clone();
+        let mut added = Block::new(BlockType::Quote, 1, 1);
+        added.content = "Added".to_string();
+        document.blocks[0].children.clear();
+        document.blocks.insert(1, added);
+        document.metadata.insert("author".to_string(), "Grace".to_string());
+        assert_eq!(
+            encoder.encode_preserving(&tree, &document).unwrap(),
+            "// Draft\n#meta author=Grace\n\n#h1 \"Notes\" id=top\nIntro\n// keep me\n\n#q\nAdded\n\n#section   title=End\nBye\n",
+        );
+    }
+    
+    #[test]
+    fn test_model_edits() {
+        let source = "#p\nSee **this**\n\n// todo\n#list\n- one\n- two\n";
+        let tree = crate::blox::BloxParser::new().parse_tree(source);
+        let mut document = tree.document().clone();
+        document.blocks[0].inline_elements[1] = InlineElement::Italic(vec![InlineElement::Text("that".to_string())]);
+        document.blocks[1].list_items.remove(0);
+        
+        // Edited models are dropped unless asked for, or edited in place of content
+        assert_eq!(BloxEncoder::new().encode(&document).unwrap(), "#p\nSee **this**\n\n#list\n- one\n- two\n\n");
+        assert_eq!(BloxEncoder::new().with_models(true).encode(&document).unwrap(), "#p\nSee {{italic that}}\n\n#list\n- two\n\n");
+        assert_eq!(
+            BloxEncoder::new().encode_preserving(&tree, &document).unwrap(),
+            "#p\nSee {{italic that}}\n\n// todo\n#list\n- two\n",
+        );
+        
+        // Edited content wins over a model left as it was read
+        document.blocks[1].content = "- three".to_string();
+        assert!(BloxEncoder::new().encode_preserving(&tree, &document).unwrap().ends_with("#list\n- three\n"));
     }
 }
@@ -14,6 +14,21 @@ pub enum BloxError {
     #[error("Invalid attribute syntax at line {line}: {attribute}")]
     InvalidAttribute { line: usize, attribute: String },
     
+    #[error("Cannot edit table at line {line}: {message}")]
+    TableEdit { line: usize, message: String },
+    
+    #[error("No task at line {line}")]
+    TaskNotFound { line: usize },
+    
+    #[error("{format} output comes with files or problems `decode` cannot return; decode it with `decode_package`")]
+    PackageOutput { format: String },
+    
+    #[error("Formatting would change the document at line {line}")]
+    FormatChanged { line: usize },
+    
+    #[error("Cannot encode document: {message}")]
+    Unencodable { message: String },
+    
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
     
@@ -22,3 +37,37 @@ pub enum BloxError {
 }
 
 pub type ParseResult<T> = Result<T, BloxError>;
+
+impl BloxError {
+    /// Stable identifier used as the code of diagnostics built from this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            BloxError::ParseError { .. } => "parse-error",
+            BloxError::InvalidNesting { .. } => "invalid-nesting",
+            BloxError::UnknownBlockType { .. } => "unknown-block-type",
+            BloxError::InvalidAttribute { .. } => "invalid-attribute",
+            BloxError::TableEdit { .. } => "table-edit",
+            BloxError::TaskNotFound { .. } => "task-not-found",
+            BloxError::PackageOutput { .. } => "package-output",
+            BloxError::FormatChanged { .. } => "format-changed",
+            BloxError::Unencodable { .. } => "unencodable",
+            BloxError::IoError(_) => "io-error",
+            BloxError::Utf8Error(_) => "utf8-error",
+        }
+    }
+    
+    /// The error message without its location, for diagnostics that carry a span
+    pub fn detail(&self) -> String {
+        match self {
+            BloxError::ParseError { message, .. } => message.clone(),
+            BloxError::InvalidNesting { expected, actual, .. } => {
+                format!("Invalid block nesting: expected level {}, got {}", expected, actual)
+            }
+            BloxError::InvalidAttribute { attribute, .. } => {
+                format!("Invalid attribute syntax: {}", attribute)
+            }
+            BloxError::TableEdit { message, .. } => message.clone(),
+            _ => self.to_string(),
+        }
+    }
+}
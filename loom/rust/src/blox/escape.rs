@@ -0,0 +1,184 @@
+//! Backslash escapes (spec section 3.5).
+//!
+//! Escapes work at two levels. A backslash in front of `#` or `//` stops a
+//! line being read as a block header or comment; the parser drops it when
+//! the line is added to `Block.content`, wherever it is in the line, so
+//! `\#` reads as `#` at the start of a line and in the middle alike. Verbatim
+//! content drops it only at the start of a line. Outside verbatim content,
+//! a line of backslashes alone loses one too, so that a line holding only
+//! `\` is a blank line, which would otherwise be skipped. Inside text, `\{\{`, `\}\}`,
+//! `\!` and `\@` stand for the literal characters. `Block.content` keeps
+//! these because it is still inline markup; they are resolved in inline
+//! `Text` elements and by the decoders.
+
+/// Characters that a backslash makes literal in inline markup
+pub const ESCAPABLE: &[char] = &['{', '}', '!', '@'];
+
+/// Remove the backslash that keeps a verbatim line from being read as a
+/// header or comment.
+///
+/// Returns the unescaped line and the byte index where the backslash was,
+/// or `None` if the line has no such escape. Only one backslash is dropped,
+/// so `\\#` is kept as the text `\#`.
+pub fn unescape_line_start(line: &str) -> Option<(String, usize)> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+    let marker = rest.trim_start_matches('\\');
+    
+    if marker.len() == rest.len() || !(marker.starts_with('#') || marker.starts_with("//")) {
+        return None;
+    }
+    
+    let mut unescaped = String::with_capacity(line.len() - 1);
+    unescaped.push_str(&line[..indent]);
+    unescaped.push_str(&rest[1..]);
+    Some((unescaped, indent))
+}
+
+/// Remove the backslashes that make `#` and `//` literal, wherever they are
+/// in the line.
+///
+/// One backslash is dropped from each run in front of `#` or `//`, so `\\#`
+/// is kept as the text `\#`, and from a line that is only backslashes, so
+/// `\` is a blank line. Returns the unescaped line and the byte indices in
+/// it where backslashes were dropped.
+pub fn unescape_line(line: &str) -> (String, Vec<usize>) {
+    let indent = line.len() - line.trim_start().len();
+    if only_backslashes(line) {
+        return (format!("{}{}", &line[..indent], &line[indent + 1..]), vec![indent]);
+    }
+    
+    let mut unescaped = String::with_capacity(line.len());
+    let mut dropped = Vec::new();
+    let mut rest = line;
+    
+    while let Some(start) = rest.find('\\') {
+        unescaped.push_str(&rest[..start]);
+        let after = rest[start..].trim_start_matches('\\');
+        let end = rest.len() - after.len();
+        if after.starts_with('#') || after.starts_with("//") {
+            dropped.push(unescaped.len());
+            unescaped.push_str(&rest[start + 1..end]);
+        } else {
+            unescaped.push_str(&rest[start..end]);
+        }
+        rest = after;
+    }
+    
+    unescaped.push_str(rest);
+    (unescaped, dropped)
+}
+
+/// Escape a content line so it reads back unchanged: the inverse of
+/// `unescape_line`.
+///
+/// A line that would be read as a header or comment gets a backslash, and
+/// every run of backslashes in front of `#` or `//` gets one more. So does a
+/// blank line, which would be skipped, or one that is only backslashes.
+pub fn escape_line(line: &str) -> String {
+    let indent = line.len() - line.trim_start().len();
+    if line.trim().is_empty() || only_backslashes(line) {
+        return format!("{}\\{}", &line[..indent], &line[indent..]);
+    }
+    
+    let mut escaped = String::with_capacity(line.len() + 1);
+    escaped.push_str(&line[..indent]);
+    let mut rest = &line[indent..];
+    if rest.starts_with('#') || rest.starts_with("//") {
+        escaped.push('\\');
+    }
+    
+    while let Some(start) = rest.find('\\') {
+        let after = rest[start..].trim_start_matches('\\');
+        escaped.push_str(&rest[..rest.len() - after.len()]);
+        if after.starts_with('#') || after.starts_with("//") {
+            escaped.push('\\');
+        }
+        rest = after;
+    }
+    
+    escaped.push_str(rest);
+    escaped
+}
+
+/// Whether the line, apart from surrounding whitespace, is one or more backslashes
+fn only_backslashes(line: &str) -> bool {
+    let text = line.trim();
+    !text.is_empty() && text.chars().all(|c| c == '\\')
+}
+
+/// Resolve inline escapes into the characters they stand for
+pub fn unescape_inline(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(&next) = chars.peek() {
+                if ESCAPABLE.contains(&next) {
+                    output.push(next);
+                    chars.next();
+                    continue;
+                }
+            }
+        }
+        output.push(c);
+    }
+    
+    output
+}
+
+/// Escape the characters that inline markup would read, the inverse of `unescape_inline`
+pub fn escape_inline(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if ESCAPABLE.contains(&c) {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_line_start_escapes() {
+        assert_eq!(unescape_line_start("\\#hashtag"), Some(("#hashtag".to_string(), 0)));
+        assert_eq!(unescape_line_start("  \\// not a comment"), Some(("  // not a comment".to_string(), 2)));
+        assert_eq!(unescape_line_start("\\\\#kept"), Some(("\\#kept".to_string(), 0)));
+        assert_eq!(unescape_line_start("\\{\\{ inline"), None);
+        assert_eq!(unescape_line_start("plain"), None);
+    }
+    
+    #[test]
+    fn test_line_escapes() {
+        assert_eq!(unescape_line("\\#hashtag and \\#tag"), ("#hashtag and #tag".to_string(), vec![0, 13]));
+        assert_eq!(unescape_line("a \\// b \\\\#kept"), ("a // b \\#kept".to_string(), vec![2, 7]));
+        assert_eq!(unescape_line("C:\\Users \\{\\{"), ("C:\\Users \\{\\{".to_string(), vec![]));
+        
+        assert_eq!(unescape_line("\\"), (String::new(), vec![0]));
+        assert_eq!(unescape_line("  \\\\ "), ("  \\ ".to_string(), vec![2]));
+        
+        for line in ["#hashtag", "// slashes", "\\#escaped", "  #indented", "plain text", "\\{\\{", "mid \\# and \\\\// x", "a #b // c", "trail\\", "", "  ", "\\", " \\\\ "] {
+            let escaped = escape_line(line);
+            assert_eq!(unescape_line(&escaped).0, line, "{}", escaped);
+        }
+    }
+    
+    #[test]
+    fn test_inline_escapes() {
+        assert_eq!(
+            unescape_inline("a literal \\{\\{ braces \\}\\}, \\! \\@ and \\#"),
+            "a literal {{ braces }}, ! @ and \\#"
+        );
+        assert_eq!(unescape_inline("C:\\Users\\me"), "C:\\Users\\me");
+        assert_eq!(unescape_inline("trailing \\"), "trailing \\");
+        
+        for text in ["{{!name}} #1 // @you", "C:\\Users\\me", "\\# kept"] {
+            assert_eq!(unescape_inline(&escape_inline(text)), text);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Flashcards: definition list items and quiz questions, reviewed on an
+//! SM-2 schedule.
+//!
+//! Each card's id is a hash of its kind and front, so it survives edits
+//! elsewhere in the document and changes to its answer. Cards with the same
+//! front, such as two definitions of one term, add their occurrence to the
+//! id (`-2`, `-3`, ...), so each keeps its own schedule. Reviews are kept
+//! in a JSON sidecar file next to the document, `notes.reviews.json` for
+//! `notes.blox`, holding each card's schedule and its review history.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::blox::ast::{Block, BlockType, Document, ListItem, ListItemType, QuestionType, QuizQuestion, Span};
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::metadata::MetadataDate;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardKind {
+    Definition,
+    Question,
+}
+
+impl CardKind {
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Definition => "definition",
+            Self::Question => "question",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub id: String,
+    pub kind: CardKind,
+    /// The term, or the question's prompt followed by its choices
+    pub front: String,
+    /// The definition, or the question's answers followed by its explanation
+    pub back: String,
+    /// Span of the item or question the card was made from
+    pub span: Span,
+}
+
+/// Every card in `document`, in document order
+pub fn extract_cards(document: &Document) -> Vec<Card> {
+    let mut cards = Vec::new();
+    collect_blocks(&document.blocks, &mut cards);
+    
+    // Cards with the same front are told apart by their order
+    let mut seen: BTreeMap<String, usize> = BTreeMap::new();
+    for card in &mut cards {
+        let count = seen.entry(card.id.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            card.id = format!("{}-{}", card.id, count);
+        }
+    }
+    cards
+}
+
+fn collect_blocks(blocks: &[Block], cards: &mut Vec<Card>) {
+    for block in blocks {
+        match block.block_type.canonical() {
+            BlockType::List => collect_items(&block.list_items, cards),
+            BlockType::Quiz => {
+                let questions = block.quiz.iter().flat_map(|quiz| &quiz.questions);
+                cards.extend(questions.filter_map(question_card));
+            }
+            _ => {}
+        }
+        collect_blocks(&block.children, cards);
+    }
+}
+
+fn collect_items(items: &[ListItem], cards: &mut Vec<Card>) {
+    for item in items {
+        if let ListItemType::Definition { term } = &item.item_type {
+            cards.push(card(CardKind::Definition, term.clone(), item.content.clone(), item.span));
+        }
+        collect_items(&item.children, cards);
+    }
+}
+
+/// A card asking a question, unless it has no answer to show
+fn question_card(question: &QuizQuestion) -> Option<Card> {
+    let mut front = question.prompt.clone();
+    let answers: Vec<String> = match question.question_type {
+        QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+            for choice in &question.choices {
+                front.push_str(&format!("\n- {}", choice.content));
+            }
+            question.choices.iter().filter(|choice| choice.correct).map(|choice| choice.content.clone()).collect()
+        }
+        QuestionType::TrueFalse => question.answers.iter().map(|answer| if answer == "true" { "True" } else { "False" }.to_string()).collect(),
+        QuestionType::ShortAnswer => question.answers.clone(),
+    };
+    if answers.is_empty() {
+        return None;
+    }
+    
+    let mut back = answers.join("\n");
+    if let Some(explanation) = &question.explanation {
+        back.push_str("\n\n");
+        back.push_str(explanation);
+    }
+    Some(card(CardKind::Question, front, back, question.span))
+}
+
+/// A card whose id is the hash of its kind and front alone; `extract_cards`
+/// numbers the ids of cards that share one
+fn card(kind: CardKind, front: String, back: String, span: Span) -> Card {
+    let key = format!("{}\n{}", kind.to_str(), front.split_whitespace().collect::<Vec<_>>().join(" "));
+    Card { id: format!("{:016x}", fnv1a(key.as_bytes())), kind, front, back, span }
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is the same in every build
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// One review of a card, graded from 0 (forgotten) to 5 (perfect recall)
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CardReview {
+    pub date: MetadataDate,
+    pub grade: u8,
+}
+
+/// When a card is next due and the state SM-2 schedules it from
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CardSchedule {
+    /// Reviews in a row graded 3 or better
+    pub repetitions: u32,
+    /// Days until the next review
+    pub interval: u32,
+    pub ease: f64,
+    pub due: MetadataDate,
+    pub history: Vec<CardReview>,
+}
+
+impl CardSchedule {
+    fn new(today: MetadataDate) -> Self {
+        Self {
+            repetitions: 0,
+            interval: 0,
+            ease: 2.5,
+            due: today,
+            history: Vec::new(),
+        }
+    }
+    
+    /// Apply a review graded `grade` on `date`
+    fn review(&mut self, grade: u8, date: MetadataDate) {
+        let grade = grade.min(5);
+        if grade >= 3 {
+            self.interval = match self.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => (self.interval as f64 * self.ease).round() as u32,
+            };
+            self.repetitions += 1;
+        } else {
+            self.repetitions = 0;
+            self.interval = 1;
+        }
+        
+        let miss = f64::from(5 - grade);
+        self.ease = (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(1.3);
+        self.due = date.add_days(self.interval.into());
+        self.history.push(CardReview { date, grade });
+    }
+}
+
+/// The schedules of the cards of one document, by card id
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ReviewLog {
+    pub cards: BTreeMap<String, CardSchedule>,
+}
+
+impl ReviewLog {
+    pub fn new() -> Self {
+        Self { cards: BTreeMap::new() }
+    }
+    
+    /// Read the log at `path`, or start an empty one if there is none yet
+    pub fn load(path: &Path) -> ParseResult<Self> {
+        if !path.exists() {
+            return Ok(Self::new());
+        }
+        let text = std::fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| BloxError::ParseError {
+            line: e.line(),
+            message: format!("Invalid review log: {}", e),
+        })
+    }
+    
+    pub fn save(&self, path: &Path) -> ParseResult<()> {
+        let text = serde_json::to_string_pretty(self).map_err(|e| BloxError::ParseError {
+            line: 0,
+            message: format!("JSON serialization error: {}", e),
+        })?;
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+    
+    pub fn schedule(&self, id: &str) -> Option<&CardSchedule> {
+        self.cards.get(id)
+    }
+    
+    /// Record a review of the card `id` on `date`, returning its new schedule
+    pub fn review(&mut self, id: &str, grade: u8, date: MetadataDate) -> &CardSchedule {
+        let schedule = self.cards.entry(id.to_string()).or_insert_with(|| CardSchedule::new(date));
+        schedule.review(grade, date);
+        schedule
+    }
+    
+    /// The cards to review on `today`: those due by then, most overdue
+    /// first, followed by cards never reviewed, in document order
+    pub fn due<'a>(&self, cards: &'a [Card], today: MetadataDate) -> Vec<&'a Card> {
+        let mut due: Vec<(&Card, MetadataDate)> = cards
+            .iter()
+            .filter_map(|card| self.cards.get(&card.id).map(|schedule| (card, schedule.due)))
+            .filter(|(_, date)| *date <= today)
+            .collect();
+        due.sort_by_key(|(_, date)| *date);
+        
+        let new = cards.iter().filter(|card| !self.cards.contains_key(&card.id));
+        due.into_iter().map(|(card, _)| card).chain(new).collect()
+    }
+}
+
+impl Default for ReviewLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where the review log of the document at `path` is kept
+pub fn review_log_path(path: &Path) -> PathBuf {
+    path.with_extension("reviews.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::parser::BloxParser;
+    
+    const SOURCE: &str = "#list type=definition\n- Mitosis: Cell division into two identical cells\n- Meiosis: Division into four gametes\n#quiz\n? Powerhouse of the cell?\n- [ ] Nucleus\n- [x] Mitochondria\n> It makes ATP.\n? No answer\n";
+    
+    fn cards(source: &str) -> Vec<Card> {
+        extract_cards(&BloxParser::new().parse_string(source).unwrap())
+    }
+    
+    #[test]
+    fn test_extract_cards() {
+        let cards = cards(SOURCE);
+        let faces: Vec<(&str, &str)> = cards.iter().map(|card| (card.front.as_str(), card.back.as_str())).collect();
+        assert_eq!(faces, vec![
+            ("Mitosis", "Cell division into two identical cells"),
+            ("Meiosis", "Division into four gametes"),
+            ("Powerhouse of the cell?\n- Nucleus\n- Mitochondria", "Mitochondria\n\nIt makes ATP."),
+        ]);
+        assert_eq!(cards[2].kind, CardKind::Question);
+        assert_eq!(cards[0].span.start.line, 2);
+        
+        // Ids follow the front, not the answer or the card's place
+        let edited = self::cards(&SOURCE.replace("two identical cells", "two cells").replace("#list", "#p\nIntro\n#list"));
+        assert_eq!(edited[0].id, cards[0].id);
+        assert_ne!(cards[0].id, cards[1].id);
+        
+        // Two definitions of one term are scheduled apart
+        let same = self::cards("#list type=definition\n- A: 1\n- A: 2\n- A: 3\n");
+        assert_eq!(same[1].id, format!("{}-2", same[0].id));
+        assert_eq!(same[2].id, format!("{}-3", same[0].id));
+        let mut log = ReviewLog::new();
+        log.review(&same[1].id, 5, MetadataDate::parse("2024-01-01").unwrap());
+        assert_eq!(log.due(&same, MetadataDate::parse("2024-01-01").unwrap()).len(), 2);
+    }
+    
+    #[test]
+    fn test_schedule() {
+        let day = |text: &str| MetadataDate::parse(text).unwrap();
+        let mut log = ReviewLog::new();
+        
+        let schedule = log.review("card", 5, day("2024-01-01"));
+        assert_eq!((schedule.interval, schedule.due), (1, day("2024-01-02")));
+        let schedule = log.review("card", 4, day("2024-01-02"));
+        assert_eq!((schedule.interval, schedule.due), (6, day("2024-01-08")));
+        let schedule = log.review("card", 3, day("2024-01-08"));
+        assert_eq!(schedule.interval, 16);
+        assert!((schedule.ease - 2.46).abs() < 1e-9);
+        
+        let schedule = log.review("card", 1, day("2024-01-23"));
+        assert_eq!((schedule.repetitions, schedule.interval), (0, 1));
+        assert_eq!(schedule.history.len(), 4);
+        
+        let cards = cards(SOURCE);
+        let mut log = ReviewLog::new();
+        log.review(&cards[0].id, 5, day("2024-01-01"));
+        log.review(&cards[1].id, 0, day("2024-01-01"));
+        let due: Vec<&str> = log.due(&cards, day("2024-01-02")).iter().map(|card| card.front.as_str()).collect();
+        assert_eq!(due, vec!["Mitosis", "Meiosis", "Powerhouse of the cell?\n- Nucleus\n- Mitochondria"]);
+        assert_eq!(log.due(&cards, day("2024-01-01")).len(), 1);
+        
+        let path = std::env::temp_dir().join(format!("loom-reviews-{}.json", std::process::id()));
+        log.save(&path).unwrap();
+        assert_eq!(ReviewLog::load(&path).unwrap(), log);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(review_log_path(Path::new("notes/biology.blox")), Path::new("notes/biology.reviews.json"));
+    }
+}
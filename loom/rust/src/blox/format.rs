@@ -0,0 +1,551 @@
+//! Canonical formatting (`blox fmt`).
+//!
+//! `Formatter` rewrites a document in the layout a `FormatStyle` describes:
+//! block type names, attribute order and quoting, blank lines between
+//! blocks, aligned table columns, normalised list markers and optionally
+//! paragraphs wrapped at a width. Comments are kept. Headers and content
+//! are written by `BloxEncoder`, and the result is parsed again to make
+//! sure the document it reads as has not changed.
+
+use std::collections::HashSet;
+
+use crate::blox::ast::{Attribute, Block, BlockType, Document, ListItem};
+use crate::blox::cst::{LineKind, SyntaxNode, SyntaxTree};
+use crate::blox::encoder::BloxEncoder;
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::parser::BloxParser;
+use crate::blox::table::{self, TableFormat};
+
+/// The order `key=value` attributes are written in, after any positional value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOrder {
+    /// As they were written
+    Source,
+    /// Sorted by key
+    Sorted,
+}
+
+/// When attribute values are quoted, and with which quote
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Positional values, and other values only when they need it, with `"`
+    Minimal,
+    /// Every value, with `"`
+    Double,
+    /// Every value, with `'`
+    Single,
+}
+
+/// How `BloxEncoder` lays out what it writes. The defaults match what the
+/// encoder has always written, leaving content as it is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatStyle {
+    /// Write `#p`, `#c`, `#tbl` and the like rather than `#paragraph`, `#code`, `#table`
+    pub use_shorthand: bool,
+    pub attribute_order: AttributeOrder,
+    pub quote_style: QuoteStyle,
+    /// Blank lines written before each block after the first
+    pub blank_lines: usize,
+    /// Pad CSV and pipe table cells so their columns line up
+    pub align_tables: bool,
+    /// Write list items as `- text`, `-- text`, `- [x] text`
+    pub normalize_lists: bool,
+    /// Wrap paragraph text at this many characters
+    pub wrap_width: Option<usize>,
+}
+
+impl FormatStyle {
+    pub fn new() -> Self {
+        Self {
+            use_shorthand: true,
+            attribute_order: AttributeOrder::Source,
+            quote_style: QuoteStyle::Minimal,
+            blank_lines: 1,
+            align_tables: false,
+            normalize_lists: false,
+            wrap_width: None,
+        }
+    }
+}
+
+impl Default for FormatStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A line of output before blank lines are laid out
+enum Piece {
+    Header(String),
+    /// Content, written exactly as given
+    Text(String),
+    Comment(String),
+    Blank,
+}
+
+impl Piece {
+    fn is_trivia(&self) -> bool {
+        matches!(self, Self::Comment(_) | Self::Blank)
+    }
+}
+
+pub struct Formatter {
+    encoder: BloxEncoder,
+}
+
+impl Formatter {
+    pub fn new(style: FormatStyle) -> Self {
+        Self { encoder: BloxEncoder::with_style(style) }
+    }
+    
+    /// Format `source`.
+    ///
+    /// Headers with attribute text that could not be read are kept as
+    /// written, as is verbatim content. Content with comments among its
+    /// lines keeps its lines, so those comments stay where they are.
+    /// Fails with `FormatChanged` rather than return text that reads as a
+    /// different document.
+    pub fn format(&self, source: &str) -> ParseResult<String> {
+        let mut parser = BloxParser::new();
+        let tree = parser.parse_tree(source);
+        let damaged: HashSet<usize> = parser
+            .diagnostics()
+            .iter()
+            .filter(|diagnostic| diagnostic.code == "invalid-attribute")
+            .map(|diagnostic| diagnostic.span.start.line)
+            .collect();
+        
+        let mut pieces = Vec::new();
+        trivia(&tree, tree.prologue.clone(), &mut pieces);
+        for node in &tree.nodes {
+            self.node(&tree, node, &damaged, &mut pieces);
+        }
+        let output = self.layout(pieces, tree.line_ending());
+        
+        let formatted = BloxParser::new().parse_with_diagnostics(&output).document;
+        if let Some(line) = first_difference(tree.document(), &formatted) {
+            return Err(BloxError::FormatChanged { line });
+        }
+        Ok(output)
+    }
+    
+    /// Whether `source` is already formatted
+    pub fn check(&self, source: &str) -> ParseResult<bool> {
+        Ok(self.format(source)? == source)
+    }
+    
+    fn node(&self, tree: &SyntaxTree, node: &SyntaxNode, damaged: &HashSet<usize>, pieces: &mut Vec<Piece>) {
+        let header = node.header_line(tree);
+        let written = |line: usize| tree.text(line..line + 1).trim_end_matches(['\n', '\r']).to_string();
+        let kept = header.filter(|line| damaged.contains(&(line + 1)));
+        
+        if node.metadata {
+            // Metadata blocks are header-only; their attributes are in `Document.metadata`
+            let line = header.unwrap_or(node.lines.start);
+            pieces.push(Piece::Header(match kept {
+                Some(line) => written(line),
+                None => self.metadata_header(tree, line),
+            }));
+            trivia(tree, line + 1..node.lines.end, pieces);
+            return;
+        }
+        
+        let Some(block) = tree.block(node.header_span) else {
+            pieces.extend(node.lines.clone().map(|line| Piece::Text(written(line))));
+            return;
+        };
+        if header.is_some() {
+            pieces.push(Piece::Header(match kept {
+                Some(line) => written(line),
+                None => self.encoder.header_line(block),
+            }));
+        }
+        
+        let (content, after) = node.body(tree);
+        let commented = content.clone().any(|line| tree.lines[line].kind == LineKind::Comment);
+        if block.is_verbatim() {
+            pieces.extend(content.map(|line| Piece::Text(written(line))));
+        } else if commented {
+            for line in content {
+                pieces.push(match tree.lines[line].kind {
+                    LineKind::Comment => Piece::Comment(written(line)),
+                    LineKind::Blank => Piece::Blank,
+                    _ => Piece::Text(written(line)),
+                });
+            }
+        } else {
+            let mut text = String::new();
+            self.encoder.encode_content(&mut text, block, "", None);
+            pieces.extend(text.lines().map(|line| Piece::Text(line.to_string())));
+        }
+        trivia(tree, after, pieces);
+        
+        for child in &node.children {
+            self.node(tree, child, damaged, pieces);
+        }
+    }
+    
+    /// The header of a lifted metadata block, from the entries read from it
+    fn metadata_header(&self, tree: &SyntaxTree, line: usize) -> String {
+        let span = tree.lines[line].span;
+        let text = tree.source[span.start.offset..span.end.offset].trim_start();
+        let level = text.chars().take_while(|&c| c == '#').count();
+        let name = text[level..].split_whitespace().next().unwrap_or_default();
+        
+        let attributes: Vec<Attribute> = tree
+            .document()
+            .metadata
+            .entries
+            .iter()
+            .map(|entry| entry.attribute.clone())
+            .filter(|attribute| (span.start.offset..=span.end.offset).contains(&attribute.value_span.start.offset))
+            .collect();
+        let block_type = BlockType::Custom(name.to_lowercase());
+        let mut block = Block::new(block_type, level, line + 1);
+        block.attributes = attributes;
+        self.encoder.header_line(&block)
+    }
+    
+    /// Join pieces into lines, setting the blank lines between them: the
+    /// style's number before each header, at most one inside content, and
+    /// none at the start or end of the document
+    fn layout(&self, pieces: Vec<Piece>, ending: &str) -> String {
+        let between = self.encoder.style().blank_lines;
+        let mut lines: Vec<&str> = Vec::new();
+        let mut i = 0;
+        
+        while i < pieces.len() {
+            match &pieces[i] {
+                Piece::Header(header) => {
+                    if i > 0 && !pieces[i - 1].is_trivia() && !lines.is_empty() {
+                        lines.extend(std::iter::repeat_n("", between));
+                    }
+                    lines.push(header);
+                    i += 1;
+                }
+                Piece::Text(text) => {
+                    lines.push(text);
+                    i += 1;
+                }
+                Piece::Comment(_) | Piece::Blank => {
+                    let start = i;
+                    while pieces.get(i).is_some_and(Piece::is_trivia) {
+                        i += 1;
+                    }
+                    let (next, previous) = (pieces.get(i), start.checked_sub(1).map(|index| &pieces[index]));
+                    let gap = match next {
+                        Some(Piece::Text(_)) => 1,
+                        _ => between,
+                    };
+                    
+                    let comments = pieces[start..i].iter().filter(|piece| matches!(piece, Piece::Comment(_))).count();
+                    if comments == 0 {
+                        let blanks = match next {
+                            Some(Piece::Header(_)) => between,
+                            Some(Piece::Text(_)) if !matches!(previous, Some(Piece::Header(_))) => 1,
+                            _ => 0,
+                        };
+                        if !lines.is_empty() {
+                            lines.extend(std::iter::repeat_n("", blanks));
+                        }
+                        continue;
+                    }
+                    
+                    // Runs of blank lines around comments keep their place but not their length
+                    let mut blanks = 0;
+                    for piece in &pieces[start..i] {
+                        match piece {
+                            Piece::Comment(comment) => {
+                                if blanks > 0 && !lines.is_empty() {
+                                    lines.extend(std::iter::repeat_n("", gap));
+                                }
+                                lines.push(comment);
+                                blanks = 0;
+                            }
+                            _ => blanks += 1,
+                        }
+                    }
+                    if blanks > 0 && next.is_some() {
+                        lines.extend(std::iter::repeat_n("", gap));
+                    }
+                }
+            }
+        }
+        
+        let mut output = lines.join(ending);
+        if !output.is_empty() {
+            output.push_str(ending);
+        }
+        output
+    }
+}
+
+/// Comment and blank lines as pieces
+fn trivia(tree: &SyntaxTree, lines: std::ops::Range<usize>, pieces: &mut Vec<Piece>) {
+    for line in lines {
+        pieces.push(match tree.lines[line].kind {
+            LineKind::Comment => Piece::Comment(tree.text(line..line + 1).trim_end_matches(['\n', '\r']).to_string()),
+            _ => Piece::Blank,
+        });
+    }
+}
+
+/// List content with each item's dashes followed by one space, and `[X]` as `[x]`
+pub(crate) fn normalize_list(content: &str) -> String {
+    let lines: Vec<String> = content
+        .split('\n')
+        .map(|line| {
+            let dashes = line.chars().take_while(|&c| c == '-').count();
+            if dashes == 0 {
+                return line.to_string();
+            }
+            
+            let text = line[dashes..].trim();
+            let text = match text.strip_prefix("[X] ") {
+                Some(rest) => format!("[x] {}", rest),
+                None => text.to_string(),
+            };
+            if text.is_empty() {
+                "-".repeat(dashes)
+            } else {
+                format!("{} {}", "-".repeat(dashes), text)
+            }
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Table content with its cells padded into columns, or `None` when the
+/// table is TSV, has a row over several lines or could not be read cleanly
+pub(crate) fn align_table(block: &Block) -> Option<String> {
+    let format = match block.get_attribute("format") {
+        Some(name) => TableFormat::from_str(name)?,
+        None => TableFormat::detect(&block.content),
+    };
+    if format == TableFormat::Tsv {
+        return None;
+    }
+    
+    let content = &block.content;
+    let mut issues = Vec::new();
+    let records = table::split_records(content, format, &mut issues);
+    if !issues.is_empty() || records.is_empty() || records.iter().any(|record| content[record.range.clone()].contains('\n')) {
+        return None;
+    }
+    
+    // Fields as written, quotes and all
+    let rows: Vec<Vec<&str>> = records
+        .iter()
+        .map(|record| record.fields.iter().map(|field| content[field.range.clone()].trim_end_matches([' ', '\t'])).collect())
+        .collect();
+    // A Markdown separator row under the first row is kept
+    let separated = format == TableFormat::Pipe
+        && content.lines().map(str::trim).any(|line| line.contains('-') && line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ')));
+    
+    let mut widths: Vec<usize> = Vec::new();
+    for row in &rows {
+        for (index, field) in row.iter().enumerate() {
+            if index == widths.len() {
+                widths.push(0);
+            }
+            widths[index] = widths[index].max(field.chars().count()).max(if separated { 3 } else { 0 });
+        }
+    }
+    let pad = |field: &str, index: usize| " ".repeat(widths[index] - field.chars().count());
+    
+    let mut lines: Vec<String> = Vec::new();
+    for row in &rows {
+        let line = match format {
+            TableFormat::Pipe => {
+                let cells: Vec<String> = row.iter().enumerate().map(|(index, field)| format!("{}{}", field, pad(field, index))).collect();
+                format!("| {} |", cells.join(" | "))
+            }
+            _ => {
+                let mut line = String::new();
+                for (index, field) in row.iter().enumerate() {
+                    line.push_str(field);
+                    if index + 1 < row.len() {
+                        line.push_str(&format!(",{} ", pad(field, index)));
+                    }
+                }
+                line.trim_end().to_string()
+            }
+        };
+        lines.push(line);
+    }
+    
+    if separated {
+        let dashes: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        lines.insert(1.min(lines.len()), format!("| {} |", dashes.join(" | ")));
+    }
+    Some(lines.join("\n"))
+}
+
+/// Paragraph text reflowed into lines of at most `width` characters where
+/// its words allow. Inline elements are never split.
+pub(crate) fn wrap(content: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    
+    for word in words(content) {
+        let word_width = word.chars().count();
+        if !line.is_empty() && line_width + 1 + word_width > width {
+            lines.push(std::mem::take(&mut line));
+            line_width = 0;
+        }
+        if !line.is_empty() {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Whitespace-separated words, keeping each `{{ }}` element whole
+fn words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+    let mut chars = text.char_indices().peekable();
+    
+    while let Some((i, c)) = chars.next() {
+        if c.is_whitespace() && depth == 0 {
+            if let Some(begin) = start.take() {
+                words.push(&text[begin..i]);
+            }
+            continue;
+        }
+        start.get_or_insert(i);
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' if text[i..].starts_with("{{") => {
+                depth += 1;
+                chars.next();
+            }
+            '}' if depth > 0 && text[i..].starts_with("}}") => {
+                depth -= 1;
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push(&text[begin..]);
+    }
+    words
+}
+
+/// Line of the first block in `original` that `formatted` does not read the
+/// same way, comparing what formatting may not change
+fn first_difference(original: &Document, formatted: &Document) -> Option<usize> {
+    if metadata_pairs(original) != metadata_pairs(formatted) {
+        return Some(1);
+    }
+    different_blocks(&original.blocks, &formatted.blocks, 1)
+}
+
+fn metadata_pairs(document: &Document) -> Vec<(&str, &str)> {
+    let mut pairs: Vec<(&str, &str)> = document.metadata.iter().collect();
+    pairs.sort_by_key(|(key, _)| *key);
+    pairs
+}
+
+fn different_blocks(original: &[Block], formatted: &[Block], line: usize) -> Option<usize> {
+    for (index, block) in original.iter().enumerate() {
+        let Some(other) = formatted.get(index) else {
+            return Some(block.line_number);
+        };
+        if !same_block(block, other) {
+            return Some(block.line_number);
+        }
+        if let Some(line) = different_blocks(&block.children, &other.children, block.line_number) {
+            return Some(line);
+        }
+    }
+    (formatted.len() > original.len()).then_some(line)
+}
+
+fn same_block(a: &Block, b: &Block) -> bool {
+    let same_content = a.content == b.content
+        || match a.block_type.canonical() {
+            BlockType::Paragraph => !a.is_verbatim() && a.content.split_whitespace().eq(b.content.split_whitespace()),
+            BlockType::Table => a.table == b.table,
+            BlockType::List => same_items(&a.list_items, &b.list_items),
+            _ => false,
+        };
+    a.block_type.canonical() == b.block_type.canonical()
+        && a.level == b.level
+        && attribute_pairs(&a.attributes) == attribute_pairs(&b.attributes)
+        && same_content
+}
+
+fn same_items(a: &[ListItem], b: &[ListItem]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|(a, b)| {
+            a.item_type == b.item_type
+                && a.content == b.content
+                && a.level == b.level
+                && a.number == b.number
+                && attribute_pairs(&a.attributes) == attribute_pairs(&b.attributes)
+                && same_items(&a.children, &b.children)
+        })
+}
+
+fn attribute_pairs(attributes: &[Attribute]) -> Vec<(&str, &str)> {
+    let mut pairs: Vec<(&str, &str)> = attributes.iter().map(|attr| (attr.key.as_str(), attr.value.as_str())).collect();
+    // Only the order of attributes with the same key matters
+    pairs.sort_by_key(|(key, _)| *key);
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn style() -> FormatStyle {
+        FormatStyle {
+            use_shorthand: false,
+            attribute_order: AttributeOrder::Sorted,
+            quote_style: QuoteStyle::Double,
+            align_tables: true,
+            normalize_lists: true,
+            wrap_width: Some(24),
+            ..FormatStyle::new()
+        }
+    }
+    
+    #[test]
+    fn test_format() {
+        let source = "\n// Notes\n#meta   version=1 author=Ada\n#h1 'Title'  id=top\n#p\nA paragraph long enough that it has to be wrapped {{link href=x some link}} here.\n\n\n\n#tbl header=true\nName,Price\nApple,1.20\n#list type=ordered\n-first\n--  [X] nested\n// about code\n##c python\n  x  =  1\n\n";
+        let formatter = Formatter::new(style());
+        let formatted = formatter.format(source).unwrap();
+        assert_eq!(formatted, "// Notes\n#meta author=\"Ada\" version=\"1\"\n\n#h1 \"Title\" id=\"top\"\n\n#paragraph\nA paragraph long enough\nthat it has to be\nwrapped\n{{link href=x some link}}\nhere.\n\n#table header=\"true\"\nName,  Price\nApple, 1.20\n\n#list type=\"ordered\"\n- first\n-- [x] nested\n// about code\n##code \"python\"\n  x  =  1\n");
+        
+        assert!(formatter.check(&formatted).unwrap());
+        assert!(!formatter.check(source).unwrap());
+        assert!(Formatter::new(FormatStyle::new()).check("#p\nText\n\n#c\ncode\n").unwrap());
+    }
+    
+    #[test]
+    fn test_format_keeps_what_it_cannot_read() {
+        let formatter = Formatter::new(style());
+        let source = "#p\r\nBefore\r\n// inside\r\n\r\n\r\nAfter\r\n#section title=\"unclosed\r\n| a | bb |\r\n";
+        assert_eq!(
+            formatter.format(source).unwrap(),
+            "#paragraph\r\nBefore\r\n// inside\r\n\r\nAfter\r\n\r\n#section title=\"unclosed\r\n| a | bb |\r\n",
+        );
+        
+        let pipes = "#table\n|a|bb|\n|-|-|\n| ccc | d |\n";
+        assert_eq!(formatter.format(pipes).unwrap(), "#table\n| a   | bb  |\n| --- | --- |\n| ccc | d   |\n");
+        assert_eq!(normalize_list("- a\n-\n- [X]\ncontinued"), "- a\n-\n- [X]\ncontinued");
+        assert_eq!(words("a {{b c}}  d\\ e"), vec!["a", "{{b c}}", "d\\ e"]);
+    }
+}
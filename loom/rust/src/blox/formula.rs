@@ -0,0 +1,717 @@
+//! Spreadsheet-style formulas in table cells.
+//!
+//! A cell whose content starts with `=` is evaluated over the table. `A1`
+//! is the first column of the first row as written, header row included,
+//! and `A1:B3` is a range. Formulas have arithmetic, comparisons and the
+//! functions `SUM`, `AVG`, `MIN`, `MAX`, `COUNT` and `IF`; arguments are
+//! separated by `,` or `;`, so a CSV cell can avoid quoting. Each formula
+//! cell's `value` becomes its result, or an error value such as `#DIV/0!`.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::blox::ast::{CellValue, ColumnSpec, ColumnType, TableRow};
+use crate::blox::table::Layout;
+
+/// Why a formula has no value, shown in its cell as in a spreadsheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FormulaError {
+    /// The formula could not be read
+    Syntax,
+    /// An unknown function
+    Name,
+    /// A cell outside the table
+    Ref,
+    /// A value of the wrong kind, such as text in arithmetic
+    Value,
+    DivideByZero,
+    /// The formula depends on its own result
+    Cycle,
+}
+
+impl FormulaError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Syntax => "#ERROR!",
+            Self::Name => "#NAME?",
+            Self::Ref => "#REF!",
+            Self::Value => "#VALUE!",
+            Self::DivideByZero => "#DIV/0!",
+            Self::Cycle => "#CYCLE!",
+        }
+    }
+    
+    pub fn from_code(code: &str) -> Option<Self> {
+        [Self::Syntax, Self::Name, Self::Ref, Self::Value, Self::DivideByZero, Self::Cycle]
+            .into_iter()
+            .find(|error| error.code() == code)
+    }
+}
+
+impl fmt::Display for FormulaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Whether cell content is a formula
+pub fn is_formula(content: &str) -> bool {
+    content.starts_with('=')
+}
+
+/// A cell address, counted from zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellRef {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl CellRef {
+    /// Read an address such as `B2`
+    pub fn parse(name: &str) -> Option<Self> {
+        let letters = name.chars().take_while(char::is_ascii_alphabetic).count();
+        let (column, row) = name.split_at(letters);
+        if letters == 0 || letters > 3 || row.is_empty() || !row.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        
+        let column = column.to_ascii_uppercase().bytes().fold(0, |n, b| n * 26 + usize::from(b - b'A' + 1));
+        let row: usize = row.parse().ok()?;
+        (row > 0).then(|| Self { row: row - 1, column: column - 1 })
+    }
+}
+
+impl fmt::Display for CellRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut letters = Vec::new();
+        let mut n = self.column + 1;
+        while n > 0 {
+            letters.push(b'A' + ((n - 1) % 26) as u8);
+            n = (n - 1) / 26;
+        }
+        letters.reverse();
+        write!(f, "{}{}", String::from_utf8_lossy(&letters), self.row + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Text(String),
+    Ref(CellRef),
+    Range(CellRef, CellRef),
+    Negate(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Name(String),
+    Op(Op),
+    Colon,
+    Separator,
+    Open,
+    Close,
+}
+
+/// Deepest nesting of parentheses, signs and calls, and longest chain of
+/// operators, so evaluating a formula cannot run out of stack
+const MAX_NESTING: usize = 64;
+
+/// Parse a formula, with or without its leading `=`
+pub fn parse(formula: &str) -> Result<Expr, FormulaError> {
+    let tokens = tokenize(formula.strip_prefix('=').unwrap_or(formula))?;
+    let mut parser = ExprParser { tokens, pos: 0, depth: 0 };
+    let expr = parser.comparison()?;
+    if parser.pos == parser.tokens.len() { Ok(expr) } else { Err(FormulaError::Syntax) }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, FormulaError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '0'..='9' | '.' => {
+                let mut end = i + 1;
+                while let Some(&(j, next)) = chars.peek() {
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    end = j + 1;
+                    chars.next();
+                }
+                Token::Number(text[i..end].parse().map_err(|_| FormulaError::Syntax)?)
+            }
+            'A'..='Z' | 'a'..='z' | '_' => {
+                let mut end = i + 1;
+                while let Some(&(j, next)) = chars.peek() {
+                    if !(next.is_ascii_alphanumeric() || next == '_') {
+                        break;
+                    }
+                    end = j + 1;
+                    chars.next();
+                }
+                Token::Name(text[i..end].to_string())
+            }
+            '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) if chars.peek().is_some_and(|&(_, next)| next == '"') => {
+                            value.push('"');
+                            chars.next();
+                        }
+                        Some((_, '"')) => break,
+                        Some((_, c)) => value.push(c),
+                        None => return Err(FormulaError::Syntax),
+                    }
+                }
+                Token::Text(value)
+            }
+            '+' => Token::Op(Op::Add),
+            '-' => Token::Op(Op::Subtract),
+            '*' => Token::Op(Op::Multiply),
+            '/' => Token::Op(Op::Divide),
+            '=' => Token::Op(Op::Equal),
+            '<' | '>' => {
+                let next = chars.peek().map(|&(_, next)| next);
+                let op = match (c, next) {
+                    ('<', Some('=')) => Op::LessEqual,
+                    ('<', Some('>')) => Op::NotEqual,
+                    ('>', Some('=')) => Op::GreaterEqual,
+                    ('<', _) => Op::Less,
+                    _ => Op::Greater,
+                };
+                if matches!(op, Op::LessEqual | Op::NotEqual | Op::GreaterEqual) {
+                    chars.next();
+                }
+                Token::Op(op)
+            }
+            ':' => Token::Colon,
+            ',' | ';' => Token::Separator,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => return Err(FormulaError::Syntax),
+        };
+        tokens.push(token);
+    }
+    
+    Ok(tokens)
+}
+
+struct ExprParser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Operands being read, innermost last
+    depth: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+    
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+    
+    fn expect(&mut self, token: Token) -> Result<(), FormulaError> {
+        if self.next() == Some(token) { Ok(()) } else { Err(FormulaError::Syntax) }
+    }
+    
+    fn binary(&mut self, ops: &[Op], operand: fn(&mut Self) -> Result<Expr, FormulaError>) -> Result<Expr, FormulaError> {
+        let mut left = operand(self)?;
+        let mut chained = 0;
+        while let Some(&Token::Op(op)) = self.peek() {
+            if !ops.contains(&op) {
+                break;
+            }
+            chained += 1;
+            if chained > MAX_NESTING {
+                return Err(FormulaError::Syntax);
+            }
+            self.pos += 1;
+            left = Expr::Binary(op, Box::new(left), Box::new(operand(self)?));
+        }
+        Ok(left)
+    }
+    
+    fn comparison(&mut self) -> Result<Expr, FormulaError> {
+        let ops = [Op::Equal, Op::NotEqual, Op::Less, Op::LessEqual, Op::Greater, Op::GreaterEqual];
+        self.binary(&ops, Self::sum)
+    }
+    
+    fn sum(&mut self) -> Result<Expr, FormulaError> {
+        self.binary(&[Op::Add, Op::Subtract], Self::product)
+    }
+    
+    fn product(&mut self) -> Result<Expr, FormulaError> {
+        self.binary(&[Op::Multiply, Op::Divide], Self::unary)
+    }
+    
+    /// Every nested operand is read through here, which bounds the depth
+    fn unary(&mut self) -> Result<Expr, FormulaError> {
+        if self.depth >= MAX_NESTING {
+            return Err(FormulaError::Syntax);
+        }
+        self.depth += 1;
+        let expr = match self.peek() {
+            Some(Token::Op(Op::Subtract)) => {
+                self.pos += 1;
+                self.unary().map(|inner| Expr::Negate(Box::new(inner)))
+            }
+            Some(Token::Op(Op::Add)) => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.primary(),
+        };
+        self.depth -= 1;
+        expr
+    }
+    
+    fn primary(&mut self) -> Result<Expr, FormulaError> {
+        match self.next().ok_or(FormulaError::Syntax)? {
+            Token::Number(number) => Ok(Expr::Number(number)),
+            Token::Text(text) => Ok(Expr::Text(text)),
+            Token::Open => {
+                let expr = self.comparison()?;
+                self.expect(Token::Close)?;
+                Ok(expr)
+            }
+            Token::Name(name) if self.peek() == Some(&Token::Open) => {
+                self.pos += 1;
+                let mut args = Vec::new();
+                if self.peek() == Some(&Token::Close) {
+                    self.pos += 1;
+                    return Ok(Expr::Call(name.to_uppercase(), args));
+                }
+                loop {
+                    args.push(self.comparison()?);
+                    match self.next() {
+                        Some(Token::Separator) => continue,
+                        Some(Token::Close) => break,
+                        _ => return Err(FormulaError::Syntax),
+                    }
+                }
+                Ok(Expr::Call(name.to_uppercase(), args))
+            }
+            Token::Name(name) => {
+                let start = CellRef::parse(&name).ok_or(FormulaError::Name)?;
+                if self.peek() != Some(&Token::Colon) {
+                    return Ok(Expr::Ref(start));
+                }
+                self.pos += 1;
+                match self.next() {
+                    Some(Token::Name(end)) => Ok(Expr::Range(start, CellRef::parse(&end).ok_or(FormulaError::Syntax)?)),
+                    _ => Err(FormulaError::Syntax),
+                }
+            }
+            _ => Err(FormulaError::Syntax),
+        }
+    }
+}
+
+/// A value during evaluation
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    Empty,
+}
+
+impl Value {
+    fn to_number(&self) -> Result<f64, FormulaError> {
+        match self {
+            Value::Number(number) => Ok(*number),
+            Value::Bool(value) => Ok(if *value { 1.0 } else { 0.0 }),
+            Value::Empty => Ok(0.0),
+            Value::Text(text) => text.trim().parse().map_err(|_| FormulaError::Value),
+        }
+    }
+    
+    fn is_true(&self) -> Result<bool, FormulaError> {
+        match self {
+            Value::Bool(value) => Ok(*value),
+            Value::Number(number) => Ok(*number != 0.0),
+            Value::Empty => Ok(false),
+            Value::Text(_) => Err(FormulaError::Value),
+        }
+    }
+}
+
+/// A formula cell whose result could not be worked out at its own formula,
+/// rather than at a cell it refers to
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormulaProblem {
+    /// Index of the row, header included, and of the cell in the row
+    pub row: usize,
+    pub cell: usize,
+    pub error: FormulaError,
+}
+
+/// Evaluate every formula in `rows` (header row first, if any), replacing
+/// each formula cell's value with its result
+pub fn evaluate(rows: &mut [TableRow], layout: &Layout, columns: &[ColumnSpec]) -> Vec<FormulaProblem> {
+    let (results, cyclic, inherited) = {
+        let mut sheet = Sheet::new(rows, layout);
+        let mut results = Vec::new();
+        for (r, row) in rows.iter().enumerate() {
+            for (i, cell) in row.cells.iter().enumerate() {
+                if is_formula(&cell.content) {
+                    results.push((r, i, sheet.cell_value(r, i)));
+                }
+            }
+        }
+        (results, sheet.cyclic, sheet.inherited)
+    };
+    
+    let mut problems = Vec::new();
+    let mut values = Vec::new();
+    for (r, i, result) in results {
+        let column = layout.columns[r][i];
+        let value = match result {
+            Ok(value) => typed_value(value, columns.get(column), rows, layout, column),
+            Err(error) => {
+                let own = match error {
+                    FormulaError::Syntax | FormulaError::Name => true,
+                    FormulaError::Cycle => cyclic.contains(&(r, i)),
+                    _ => !inherited.contains(&(r, i)),
+                };
+                if own {
+                    problems.push(FormulaProblem { row: r, cell: i, error });
+                }
+                CellValue::Error(error)
+            }
+        };
+        values.push((r, i, value));
+    }
+    
+    for (r, i, value) in values {
+        rows[r].cells[i].value = value;
+    }
+    problems
+}
+
+/// Give a result the type of its column, taking a currency symbol from the
+/// column's other amounts, or else from any amount in the table
+fn typed_value(value: Value, column: Option<&ColumnSpec>, rows: &[TableRow], layout: &Layout, index: usize) -> CellValue {
+    match value {
+        Value::Number(amount) if column.is_some_and(|spec| spec.column_type == ColumnType::Currency) => {
+            let symbols: Vec<(usize, &String)> = rows.iter()
+                .zip(&layout.columns)
+                .flat_map(|(row, starts)| row.cells.iter().zip(starts))
+                .filter_map(|(cell, &start)| match &cell.value {
+                    CellValue::Currency { symbol: Some(symbol), .. } => Some((start, symbol)),
+                    _ => None,
+                })
+                .collect();
+            let symbol = symbols.iter().find(|(start, _)| *start == index).or(symbols.first()).map(|(_, symbol)| symbol.to_string());
+            CellValue::Currency { amount, symbol }
+        }
+        Value::Number(number) => CellValue::Number(number),
+        Value::Text(text) => CellValue::Text(text),
+        Value::Bool(value) => CellValue::Text(if value { "TRUE" } else { "FALSE" }.to_string()),
+        Value::Empty => CellValue::Number(0.0),
+    }
+}
+
+struct Sheet<'a> {
+    rows: &'a [TableRow],
+    /// Row and cell index of the cell starting at each address
+    starts: HashMap<CellRef, (usize, usize)>,
+    height: usize,
+    width: usize,
+    results: HashMap<(usize, usize), Result<Value, FormulaError>>,
+    /// Formula cells being evaluated, innermost last
+    stack: Vec<(usize, usize)>,
+    cyclic: HashSet<(usize, usize)>,
+    /// Formula cells whose error came from a cell they refer to
+    inherited: HashSet<(usize, usize)>,
+}
+
+impl<'a> Sheet<'a> {
+    fn new(rows: &'a [TableRow], layout: &Layout) -> Self {
+        let mut starts = HashMap::new();
+        for (r, columns) in layout.columns.iter().enumerate() {
+            for (i, &column) in columns.iter().enumerate() {
+                starts.insert(CellRef { row: r, column }, (r, i));
+            }
+        }
+        
+        Self {
+            rows,
+            starts,
+            height: rows.len(),
+            width: layout.column_count(),
+            results: HashMap::new(),
+            stack: Vec::new(),
+            cyclic: HashSet::new(),
+            inherited: HashSet::new(),
+        }
+    }
+    
+    fn cell_value(&mut self, r: usize, i: usize) -> Result<Value, FormulaError> {
+        if let Some(result) = self.results.get(&(r, i)) {
+            return result.clone();
+        }
+        
+        let cell = &self.rows[r].cells[i];
+        if !is_formula(&cell.content) {
+            return Ok(match &cell.value {
+                CellValue::Empty => Value::Empty,
+                CellValue::Number(number) => Value::Number(*number),
+                CellValue::Currency { amount, .. } => Value::Number(*amount),
+                CellValue::Date(date) => Value::Text(date.to_string()),
+                CellValue::Text(text) => Value::Text(text.clone()),
+                CellValue::Error(error) => return Err(*error),
+            });
+        }
+        
+        if let Some(at) = self.stack.iter().position(|&entry| entry == (r, i)) {
+            self.cyclic.extend(self.stack[at..].iter().copied());
+            return Err(FormulaError::Cycle);
+        }
+        
+        self.stack.push((r, i));
+        let result = parse(&cell.content).and_then(|expr| self.eval(&expr));
+        self.stack.pop();
+        
+        let result = if self.cyclic.contains(&(r, i)) { Err(FormulaError::Cycle) } else { result };
+        self.results.insert((r, i), result.clone());
+        result
+    }
+    
+    fn at(&mut self, address: CellRef) -> Result<Value, FormulaError> {
+        if address.row >= self.height || address.column >= self.width {
+            return Err(FormulaError::Ref);
+        }
+        match self.starts.get(&address) {
+            Some(&(r, i)) => {
+                let result = self.cell_value(r, i);
+                if result.is_err() {
+                    if let Some(&current) = self.stack.last() {
+                        self.inherited.insert(current);
+                    }
+                }
+                result
+            }
+            // Covered by a merged cell
+            None => Ok(Value::Empty),
+        }
+    }
+    
+    fn eval(&mut self, expr: &Expr) -> Result<Value, FormulaError> {
+        match expr {
+            Expr::Number(number) => Ok(Value::Number(*number)),
+            Expr::Text(text) => Ok(Value::Text(text.clone())),
+            Expr::Ref(address) => self.at(*address),
+            Expr::Range(..) => Err(FormulaError::Value),
+            Expr::Negate(inner) => Ok(Value::Number(-self.eval(inner)?.to_number()?)),
+            Expr::Binary(op, left, right) => {
+                let (left, right) = (self.eval(left)?, self.eval(right)?);
+                binary(*op, &left, &right)
+            }
+            Expr::Call(name, args) if name == "IF" => {
+                if !(2..=3).contains(&args.len()) {
+                    return Err(FormulaError::Value);
+                }
+                if self.eval(&args[0])?.is_true()? {
+                    self.eval(&args[1])
+                } else {
+                    args.get(2).map_or(Ok(Value::Bool(false)), |arg| self.eval(arg))
+                }
+            }
+            Expr::Call(name, args) => {
+                let numbers = self.numbers(args)?;
+                let total: f64 = numbers.iter().sum();
+                match name.as_str() {
+                    "SUM" => Ok(Value::Number(total)),
+                    "AVG" | "AVERAGE" if numbers.is_empty() => Err(FormulaError::DivideByZero),
+                    "AVG" | "AVERAGE" => Ok(Value::Number(total / numbers.len() as f64)),
+                    "MIN" => Ok(Value::Number(numbers.iter().copied().reduce(f64::min).unwrap_or(0.0))),
+                    "MAX" => Ok(Value::Number(numbers.iter().copied().reduce(f64::max).unwrap_or(0.0))),
+                    "COUNT" => Ok(Value::Number(numbers.len() as f64)),
+                    _ => Err(FormulaError::Name),
+                }
+            }
+        }
+    }
+    
+    /// The numbers among function arguments: ranges give only their
+    /// numeric cells, while other arguments must be numbers
+    fn numbers(&mut self, args: &[Expr]) -> Result<Vec<f64>, FormulaError> {
+        let mut numbers = Vec::new();
+        for arg in args {
+            match arg {
+                Expr::Range(start, end) => {
+                    for row in start.row.min(end.row)..=start.row.max(end.row) {
+                        for column in start.column.min(end.column)..=start.column.max(end.column) {
+                            if let Value::Number(number) = self.at(CellRef { row, column })? {
+                                numbers.push(number);
+                            }
+                        }
+                    }
+                }
+                Expr::Ref(address) => {
+                    if let Value::Number(number) = self.at(*address)? {
+                        numbers.push(number);
+                    }
+                }
+                _ => numbers.push(self.eval(arg)?.to_number()?),
+            }
+        }
+        Ok(numbers)
+    }
+}
+
+fn binary(op: Op, left: &Value, right: &Value) -> Result<Value, FormulaError> {
+    let compare = || match (left, right) {
+        (Value::Text(a), Value::Text(b)) => Ok(a.to_lowercase().cmp(&b.to_lowercase())),
+        // Numbers sort before text
+        (Value::Text(_), _) => Ok(std::cmp::Ordering::Greater),
+        (_, Value::Text(_)) => Ok(std::cmp::Ordering::Less),
+        _ => left.to_number()?.partial_cmp(&right.to_number()?).ok_or(FormulaError::Value),
+    };
+    
+    Ok(match op {
+        Op::Add => Value::Number(left.to_number()? + right.to_number()?),
+        Op::Subtract => Value::Number(left.to_number()? - right.to_number()?),
+        Op::Multiply => Value::Number(left.to_number()? * right.to_number()?),
+        Op::Divide => {
+            let divisor = right.to_number()?;
+            if divisor == 0.0 {
+                return Err(FormulaError::DivideByZero);
+            }
+            Value::Number(left.to_number()? / divisor)
+        }
+        Op::Equal => Value::Bool(compare()?.is_eq()),
+        Op::NotEqual => Value::Bool(compare()?.is_ne()),
+        Op::Less => Value::Bool(compare()?.is_lt()),
+        Op::LessEqual => Value::Bool(compare()?.is_le()),
+        Op::Greater => Value::Bool(compare()?.is_gt()),
+        Op::GreaterEqual => Value::Bool(compare()?.is_ge()),
+    })
+}
+
+/// Show a number without trailing zeros, to at most ten decimal places
+pub fn format_general(number: f64) -> String {
+    let fixed = format!("{:.10}", number);
+    let trimmed = fixed.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" { "0".to_string() } else { trimmed.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::ast::{Block, BlockType};
+    use crate::blox::table::read_table;
+    
+    fn table(content: &str, attributes: &[(&str, &str)]) -> crate::blox::ParsedTable {
+        let mut block = Block::new(BlockType::Table, 1, 1);
+        block.content = content.to_string();
+        for (key, value) in attributes {
+            block.add_attribute(key.to_string(), value.to_string());
+        }
+        read_table(&block)
+    }
+    
+    #[test]
+    fn test_addresses_and_parsing() {
+        assert_eq!(CellRef::parse("B2"), Some(CellRef { row: 1, column: 1 }));
+        assert_eq!(CellRef::parse("aa10").unwrap().to_string(), "AA10");
+        assert!(CellRef::parse("A0").is_none());
+        assert!(CellRef::parse("SUM").is_none());
+        
+        assert_eq!(parse("=1 + 2 * -3").unwrap(), Expr::Binary(
+            Op::Add,
+            Box::new(Expr::Number(1.0)),
+            Box::new(Expr::Binary(Op::Multiply, Box::new(Expr::Number(2.0)), Box::new(Expr::Negate(Box::new(Expr::Number(3.0)))))),
+        ));
+        assert_eq!(parse("=sum(A1:B2; 3)").unwrap(), Expr::Call("SUM".to_string(), vec![
+            Expr::Range(CellRef { row: 0, column: 0 }, CellRef { row: 1, column: 1 }),
+            Expr::Number(3.0),
+        ]));
+        assert_eq!(parse("=(1"), Err(FormulaError::Syntax));
+        assert_eq!(parse("=total"), Err(FormulaError::Name));
+    }
+    
+    #[test]
+    fn test_budget_table() {
+        let content = "Item, Cost, Qty, Total\nTea, \"$1,200\", 2, =B2*C2\nCake, $30.50, 4, =B3*C3\nSum, =SUM(B2:B3), =AVG(C2:C3), =SUM(D2:D3)\nBig, =IF(D4>1000; \"yes\"; \"no\"), =COUNT(A2:C3), =MAX(D2:D3)/MIN(B3)";
+        let parsed = table(content, &[("header", "true"), ("types", "text,currency,number,currency")]);
+        assert!(parsed.issues.is_empty(), "{:?}", parsed.issues);
+        
+        let values: Vec<Vec<&CellValue>> = parsed.table.rows.iter().map(|row| row.cells.iter().map(|cell| &cell.value).collect()).collect();
+        assert_eq!(values[0][3], &CellValue::Currency { amount: 2400.0, symbol: Some("$".to_string()) });
+        assert_eq!(values[2][1], &CellValue::Currency { amount: 1230.5, symbol: Some("$".to_string()) });
+        assert_eq!(values[2][2], &CellValue::Number(3.0));
+        assert_eq!(values[2][3], &CellValue::Currency { amount: 2522.0, symbol: Some("$".to_string()) });
+        assert_eq!(values[3][1], &CellValue::Text("yes".to_string()));
+        assert_eq!(values[3][2], &CellValue::Number(4.0));
+        assert_eq!(parsed.table.rows[0].cells[3].content, "=B2*C2");
+    }
+    
+    #[test]
+    fn test_errors() {
+        let content = "=B1, =A1, =1/0, =A1+1\n=Z9, =NOPE(1), =\"a\"*2, =C1+1\n=(, 5, , =C3+1";
+        let parsed = table(content, &[]);
+        
+        let values: Vec<Vec<&CellValue>> = parsed.table.rows.iter().map(|row| row.cells.iter().map(|cell| &cell.value).collect()).collect();
+        assert_eq!(values[0], vec![
+            &CellValue::Error(FormulaError::Cycle),
+            &CellValue::Error(FormulaError::Cycle),
+            &CellValue::Error(FormulaError::DivideByZero),
+            &CellValue::Error(FormulaError::Cycle),
+        ]);
+        assert_eq!(values[1], vec![
+            &CellValue::Error(FormulaError::Ref),
+            &CellValue::Error(FormulaError::Name),
+            &CellValue::Error(FormulaError::Value),
+            &CellValue::Error(FormulaError::DivideByZero),
+        ]);
+        assert_eq!(values[2][0], &CellValue::Error(FormulaError::Syntax));
+        assert_eq!(values[2][3], &CellValue::Number(1.0));
+        
+        // Only errors that start at a cell are reported
+        let reported: Vec<_> = parsed.issues.iter().map(|issue| &content[issue.range.clone()]).collect();
+        assert_eq!(reported, vec!["=B1", "=A1", "=1/0", "=Z9", "=NOPE(1)", "=\"a\"*2", "=("]);
+        
+        // Nesting too deep to evaluate is a syntax error, not a crash
+        let deep = format!("={}1{}", "(".repeat(5000), ")".repeat(5000));
+        assert_eq!(parse(&deep), Err(FormulaError::Syntax));
+        assert_eq!(parse(&format!("={}1", "-".repeat(5000))), Err(FormulaError::Syntax));
+        assert_eq!(parse(&format!("=1{}", "+1".repeat(5000))), Err(FormulaError::Syntax));
+        assert!(parse(&format!("={}1{}", "(".repeat(20), ")".repeat(20))).is_ok());
+    }
+    
+    #[test]
+    fn test_general_format() {
+        assert_eq!(format_general(3.0), "3");
+        assert_eq!(format_general(0.1 + 0.2), "0.3");
+        assert_eq!(format_general(-2.5), "-2.5");
+        assert_eq!(format_general(-0.0), "0");
+    }
+}
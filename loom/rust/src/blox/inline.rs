@@ -0,0 +1,621 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::blox::ast::{Attribute, InlineElement};
+use crate::blox::attributes::{self, AttributeScanner};
+use crate::blox::escape::{unescape_inline, ESCAPABLE};
+
+/// Deepest nesting of inline elements; openers beyond it are plain text
+const MAX_NESTING: usize = 32;
+
+/// Inline elements parsed from a piece of text
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParsedInline {
+    pub elements: Vec<InlineElement>,
+    /// Byte range of every element in pre-order: each element comes
+    /// before its children, and siblings in document order
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Parse inline markup into a tree of elements.
+///
+/// Handles `{{type attrs content}}` (spec 3.3) and the shorthand forms
+/// `**bold**`, `*italic*`, `` `code` ``, `$math$`, `~~strike~~`,
+/// `==highlight==`, `^sup^`, `_sub_` and `[text](url)`. Code and math
+/// spans bind tightest and are never parsed further; the other forms
+/// nest. An opener without a matching closer is plain text, and `_` only
+/// counts at word boundaries so `snake_case_names` stay text.
+pub fn parse_inline(text: &str) -> ParsedInline {
+    let mut parser = InlineParser::new(text, 0..text.len(), 0);
+    let nodes = parser.parse_sequence(None).unwrap_or_default();
+    
+    let mut parsed = ParsedInline::default();
+    for node in nodes {
+        parsed.ranges.push(node.range);
+        parsed.ranges.extend(node.descendants);
+        parsed.elements.push(node.element);
+    }
+    parsed
+}
+
+/// An element with the pre-order ranges of everything inside it
+struct Node {
+    element: InlineElement,
+    range: Range<usize>,
+    descendants: Vec<Range<usize>>,
+}
+
+impl Node {
+    fn leaf(element: InlineElement, range: Range<usize>) -> Self {
+        Self { element, range, descendants: Vec::new() }
+    }
+}
+
+/// Split child nodes into their elements and pre-order ranges
+fn into_children(nodes: Vec<Node>) -> (Vec<InlineElement>, Vec<Range<usize>>) {
+    let mut elements = Vec::new();
+    let mut ranges = Vec::new();
+    for node in nodes {
+        ranges.push(node.range);
+        ranges.extend(node.descendants);
+        elements.push(node.element);
+    }
+    (elements, ranges)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Delimiter {
+    Strong,
+    Emphasis,
+    Strikethrough,
+    Highlight,
+    Superscript,
+    Subscript,
+    LinkText,
+}
+
+impl Delimiter {
+    fn marker(self) -> &'static str {
+        match self {
+            Delimiter::Strong => "**",
+            Delimiter::Emphasis => "*",
+            Delimiter::Strikethrough => "~~",
+            Delimiter::Highlight => "==",
+            Delimiter::Superscript => "^",
+            Delimiter::Subscript => "_",
+            Delimiter::LinkText => "]",
+        }
+    }
+    
+    fn wrap(self, children: Vec<InlineElement>) -> InlineElement {
+        match self {
+            Delimiter::Strong => InlineElement::Bold(children),
+            Delimiter::Emphasis => InlineElement::Italic(children),
+            Delimiter::Strikethrough => InlineElement::Strikethrough(children),
+            Delimiter::Highlight => InlineElement::Highlight(children),
+            Delimiter::Superscript => InlineElement::Superscript(children),
+            Delimiter::Subscript => InlineElement::Subscript(children),
+            Delimiter::LinkText => unreachable!("link text is wrapped by parse_link"),
+        }
+    }
+}
+
+struct InlineParser<'a> {
+    text: &'a str,
+    pos: usize,
+    /// End of the region being parsed; nested markup content stops short of the text end
+    limit: usize,
+    /// For each delimiter, the earliest opener known to have no closer
+    /// after it; later openers of that kind fail without rescanning
+    exhausted: HashMap<Delimiter, usize>,
+    /// Number of elements the parser is currently inside
+    depth: usize,
+}
+
+impl<'a> InlineParser<'a> {
+    fn new(text: &'a str, region: Range<usize>, depth: usize) -> Self {
+        Self { text, pos: region.start, limit: region.end, exhausted: HashMap::new(), depth }
+    }
+    
+    fn rest(&self) -> &str {
+        &self.text[self.pos..self.limit]
+    }
+    
+    fn char_before(&self, index: usize) -> Option<char> {
+        self.text[..index].chars().next_back()
+    }
+    
+    fn char_after(&self, index: usize) -> Option<char> {
+        self.text[index..self.limit].chars().next()
+    }
+    
+    /// Parse elements until `closer` (consumed) or the end of the region.
+    ///
+    /// Returns `None` if a closer was expected but never found.
+    fn parse_sequence(&mut self, closer: Option<Delimiter>) -> Option<Vec<Node>> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+        let mut text_start = self.pos;
+        
+        while self.pos < self.limit {
+            if let Some(closer) = closer {
+                if self.is_closer(closer) {
+                    flush_text(&mut nodes, &mut text, text_start..self.pos);
+                    self.pos += closer.marker().len();
+                    return Some(nodes);
+                }
+            }
+            
+            let start = self.pos;
+            if let Some(node) = self.parse_element() {
+                flush_text(&mut nodes, &mut text, text_start..start);
+                nodes.push(node);
+                text_start = self.pos;
+                continue;
+            }
+            
+            self.pos = start;
+            self.push_literal(&mut text);
+        }
+        
+        if closer.is_some() {
+            return None;
+        }
+        
+        flush_text(&mut nodes, &mut text, text_start..self.pos);
+        Some(nodes)
+    }
+    
+    /// Take the next character (or escape, or unmatched opener) as text
+    fn push_literal(&mut self, text: &mut String) {
+        let rest = self.rest();
+        let mut chars = rest.chars();
+        let c = chars.next().unwrap();
+        
+        if c == '\\' {
+            if let Some(next) = chars.next().filter(|next| ESCAPABLE.contains(next)) {
+                text.push(next);
+                self.pos += 1 + next.len_utf8();
+                return;
+            }
+        }
+        
+        // A two-character opener that did not match is text as a whole,
+        // so its second character cannot open something else
+        for pair in ["{{", "**", "~~", "=="] {
+            if rest.starts_with(pair) {
+                text.push_str(pair);
+                self.pos += pair.len();
+                return;
+            }
+        }
+        
+        text.push(c);
+        self.pos += c.len_utf8();
+    }
+    
+    fn is_closer(&self, delimiter: Delimiter) -> bool {
+        let marker = delimiter.marker();
+        if !self.rest().starts_with(marker) {
+            return false;
+        }
+        if delimiter == Delimiter::LinkText {
+            return true;
+        }
+        
+        // Closers follow text, never whitespace
+        if self.char_before(self.pos).is_none_or(char::is_whitespace) {
+            return false;
+        }
+        
+        let after = self.char_after(self.pos + marker.len());
+        match delimiter {
+            // `**` inside italic opens bold rather than closing the italic,
+            // unless the run of stars can only close (`***` at a word end)
+            Delimiter::Emphasis => {
+                let run = self.rest().len() - self.rest().trim_start_matches('*').len();
+                after != Some('*') || self.char_after(self.pos + run).is_none_or(|c| c.is_whitespace() || c.is_ascii_punctuation())
+            }
+            Delimiter::Subscript => !after.is_some_and(char::is_alphanumeric),
+            _ => true,
+        }
+    }
+    
+    fn parse_element(&mut self) -> Option<Node> {
+        let rest = self.rest();
+        if self.depth >= MAX_NESTING {
+            return None;
+        }
+        
+        if rest.starts_with("{{") {
+            self.parse_markup()
+        } else if rest.starts_with('`') {
+            self.parse_leaf('`', |code| InlineElement::Code { code, lang: None })
+        } else if rest.starts_with('$') {
+            self.parse_leaf('$', InlineElement::Math)
+        } else if rest.starts_with("**") {
+            self.parse_delimited(Delimiter::Strong)
+        } else if rest.starts_with("~~") {
+            self.parse_delimited(Delimiter::Strikethrough)
+        } else if rest.starts_with("==") {
+            self.parse_delimited(Delimiter::Highlight)
+        } else if rest.starts_with('*') {
+            self.parse_delimited(Delimiter::Emphasis)
+        } else if rest.starts_with('^') {
+            self.parse_delimited(Delimiter::Superscript)
+        } else if rest.starts_with('_') {
+            // Only at the start of a word, so `snake_case` stays text
+            if self.char_before(self.pos).is_some_and(char::is_alphanumeric) {
+                return None;
+            }
+            self.parse_delimited(Delimiter::Subscript)
+        } else if rest.starts_with('[') {
+            self.parse_link()
+        } else {
+            None
+        }
+    }
+    
+    fn is_exhausted(&self, start: usize, delimiter: Delimiter) -> bool {
+        self.exhausted.get(&delimiter).is_some_and(|bound| start >= *bound)
+    }
+    
+    fn exhaust(&mut self, start: usize, delimiter: Delimiter) {
+        let bound = self.exhausted.entry(delimiter).or_insert(start);
+        *bound = (*bound).min(start);
+    }
+    
+    /// Code and math: everything up to the closing marker, taken literally
+    fn parse_leaf(&mut self, marker: char, build: impl Fn(String) -> InlineElement) -> Option<Node> {
+        let start = self.pos;
+        let content_start = start + marker.len_utf8();
+        let length = self.text[content_start..self.limit].find(marker)?;
+        if length == 0 {
+            return None;
+        }
+        
+        self.pos = content_start + length + marker.len_utf8();
+        let content = self.text[content_start..content_start + length].to_string();
+        Some(Node::leaf(build(content), start..self.pos))
+    }
+    
+    fn parse_delimited(&mut self, delimiter: Delimiter) -> Option<Node> {
+        let start = self.pos;
+        self.pos += delimiter.marker().len();
+        
+        // Openers come before text, never whitespace
+        if self.char_after(self.pos).is_none_or(char::is_whitespace) || self.is_exhausted(start, delimiter) {
+            return None;
+        }
+        
+        self.depth += 1;
+        let children = self.parse_sequence(Some(delimiter));
+        self.depth -= 1;
+        let Some(children) = children else {
+            self.exhaust(start, delimiter);
+            return None;
+        };
+        let (elements, descendants) = into_children(children);
+        Some(Node {
+            element: delimiter.wrap(elements),
+            range: start..self.pos,
+            descendants,
+        })
+    }
+    
+    fn parse_link(&mut self) -> Option<Node> {
+        let start = self.pos;
+        self.pos += 1;
+        if self.is_exhausted(start, Delimiter::LinkText) {
+            return None;
+        }
+        
+        self.depth += 1;
+        let children = self.parse_sequence(Some(Delimiter::LinkText));
+        self.depth -= 1;
+        let url_start = self.pos + 1;
+        let url_length = self.text.get(url_start..self.limit).and_then(|rest| rest.find(')'));
+        let opens_url = self.rest().starts_with('(');
+        let (Some(children), Some(url_length), true) = (children, url_length, opens_url) else {
+            // Without a `)` ahead, no later link can close either
+            if url_length.is_none() {
+                self.exhaust(start, Delimiter::LinkText);
+            }
+            return None;
+        };
+        self.pos = url_start + url_length + 1;
+        
+        let (content, descendants) = into_children(children);
+        Some(Node {
+            element: InlineElement::Link {
+                content,
+                url: self.text[url_start..url_start + url_length].trim().to_string(),
+            },
+            range: start..self.pos,
+            descendants,
+        })
+    }
+    
+    fn parse_markup(&mut self) -> Option<Node> {
+        let start = self.pos;
+        let end = find_close(&self.text[..self.limit], start + 2)?;
+        let head = Markup::parse(self.text, start + 2..end - 2)?;
+        self.pos = end;
+        let range = start..end;
+        
+        let Markup { element_type, attributes, content } = match head {
+            MarkupHead::Reference(id) => {
+                return Some(Node::leaf(InlineElement::Reference { id, relation: None }, range));
+            }
+            MarkupHead::Variable(name) => {
+                return Some(Node::leaf(InlineElement::Variable(name), range));
+            }
+            MarkupHead::Element(markup) => markup,
+        };
+        
+        let raw_content = &self.text[content.clone()];
+        let get = |key: &str| attributes.iter().find(|attr| attr.key == key).map(|attr| attr.value.clone());
+        let fits = |allowed: &[&str]| attributes.iter().all(|attr| allowed.contains(&attr.key.as_str()));
+        let literal = || attributes::unquote(raw_content).unwrap_or_else(|| unescape_inline(raw_content));
+        
+        // Known types are only used when every attribute has a place in the
+        // typed element; anything else is kept whole as `Custom`
+        let (element, descendants) = match element_type.to_lowercase().as_str() {
+            "bold" | "b" | "strong" if fits(&[]) => self.parse_nested(content, InlineElement::Bold),
+            "italic" | "i" | "em" if fits(&[]) => self.parse_nested(content, InlineElement::Italic),
+            "strike" | "strikethrough" | "del" if fits(&[]) => self.parse_nested(content, InlineElement::Strikethrough),
+            "highlight" | "mark" if fits(&[]) => self.parse_nested(content, InlineElement::Highlight),
+            "sub" if fits(&[]) => self.parse_nested(content, InlineElement::Subscript),
+            "sup" if fits(&[]) => self.parse_nested(content, InlineElement::Superscript),
+            "link" if fits(&["href"]) && get("href").is_some() => {
+                let url = get("href").unwrap();
+                self.parse_nested(content, |content| InlineElement::Link { content, url })
+            }
+            "footnote" | "fn" if fits(&["id"]) => {
+                let id = get("id").unwrap_or_default();
+                self.parse_nested(content, |content| InlineElement::Footnote { id, content })
+            }
+            "code" if fits(&["lang"]) => (InlineElement::Code { code: literal(), lang: get("lang") }, Vec::new()),
+            "math" if fits(&[]) => (InlineElement::Math(literal()), Vec::new()),
+            "ref" if fits(&["id", "relation"]) && raw_content.is_empty() && get("id").is_some() => {
+                (InlineElement::Reference { id: get("id").unwrap(), relation: get("relation") }, Vec::new())
+            }
+            // Unknown types keep their content as written (spec 4.4)
+            _ => (InlineElement::Custom {
+                element_type: element_type.clone(),
+                attributes: attributes.clone(),
+                content: raw_content.to_string(),
+            }, Vec::new()),
+        };
+        
+        Some(Node { element, range, descendants })
+    }
+    
+    /// Parse markup content as children of the element built by `wrap`
+    fn parse_nested(
+        &self,
+        content: Range<usize>,
+        wrap: impl FnOnce(Vec<InlineElement>) -> InlineElement,
+    ) -> (InlineElement, Vec<Range<usize>>) {
+        let (children, descendants) = into_children(self.parse_content(content));
+        (wrap(children), descendants)
+    }
+    
+    /// Parse the content of a markup element, which may be a single quoted string
+    fn parse_content(&self, content: Range<usize>) -> Vec<Node> {
+        if let Some(text) = attributes::unquote(&self.text[content.clone()]) {
+            return vec![Node::leaf(InlineElement::Text(text), content)];
+        }
+        
+        let mut parser = InlineParser::new(self.text, content, self.depth + 1);
+        parser.parse_sequence(None).unwrap_or_default()
+    }
+}
+
+fn flush_text(nodes: &mut Vec<Node>, text: &mut String, range: Range<usize>) {
+    if !text.is_empty() {
+        nodes.push(Node::leaf(InlineElement::Text(std::mem::take(text)), range));
+    }
+}
+
+enum MarkupHead {
+    Reference(String),
+    Variable(String),
+    Element(Markup),
+}
+
+/// The parts of `{{type attrs content}}`
+struct Markup {
+    element_type: String,
+    attributes: Vec<Attribute>,
+    /// Byte range of the content within the full text
+    content: Range<usize>,
+}
+
+impl Markup {
+    /// Parse the text between `{{` and `}}`, given as a range of `text`
+    fn parse(text: &str, inner: Range<usize>) -> Option<MarkupHead> {
+        let slice = &text[inner.clone()];
+        let trimmed = slice.trim();
+        
+        // `{{@id}}` is shorthand for `{{ref id=id}}`
+        if let Some(id) = trimmed.strip_prefix('@') {
+            if id.is_empty() || id.contains(char::is_whitespace) {
+                return None;
+            }
+            return Some(MarkupHead::Reference(id.to_string()));
+        }
+        
+        // `{{!name}}` is a variable
+        if let Some(name) = trimmed.strip_prefix('!') {
+            if !is_variable_name(name) {
+                return None;
+            }
+            return Some(MarkupHead::Variable(name.to_string()));
+        }
+        
+        let head_start = inner.start + (slice.len() - slice.trim_start().len());
+        let type_len = trimmed
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(trimmed.len());
+        let element_type = &trimmed[..type_len];
+        let rest = &trimmed[type_len..];
+        if !element_type.starts_with(|c: char| c.is_ascii_alphabetic()) || !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+            return None;
+        }
+        
+        let (tokens, content_start) = AttributeScanner::keyed_prefix(rest);
+        let attributes = tokens
+            .into_iter()
+            .filter_map(|token| token.key.map(|(key, _)| Attribute::new(key, token.value)))
+            .collect();
+        
+        let rest_start = head_start + type_len;
+        
+        Some(MarkupHead::Element(Markup {
+            element_type: element_type.to_string(),
+            attributes,
+            content: rest_start + content_start..rest_start + rest.len(),
+        }))
+    }
+}
+
+/// Whether `name` can name a variable: the characters of an attribute key
+pub fn is_variable_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Byte offset just past the `}}` that closes an element opened before `from`
+fn find_close(text: &str, from: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 1;
+    let mut i = from;
+    while i + 1 < bytes.len() {
+        match (bytes[i], bytes[i + 1]) {
+            (b'\\', _) => i += 2,
+            (b'{', b'{') => {
+                depth += 1;
+                i += 2;
+            }
+            (b'}', b'}') => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn text(value: &str) -> InlineElement {
+        InlineElement::Text(value.to_string())
+    }
+    
+    fn parse(value: &str) -> Vec<InlineElement> {
+        parse_inline(value).elements
+    }
+    
+    #[test]
+    fn test_spec_examples() {
+        assert_eq!(parse("{{bold 重要文本}}"), vec![InlineElement::Bold(vec![text("重要文本")])]);
+        assert_eq!(
+            parse(r#"{{code lang=python "print('Hello')"}}"#),
+            vec![InlineElement::Code { code: "print('Hello')".to_string(), lang: Some("python".to_string()) }]
+        );
+        assert_eq!(
+            parse(r#"{{link href="https://example.com" 示例链接}}"#),
+            vec![InlineElement::Link { content: vec![text("示例链接")], url: "https://example.com".to_string() }]
+        );
+        assert_eq!(
+            parse("{{@kay-quote}}"),
+            vec![InlineElement::Reference { id: "kay-quote".to_string(), relation: None }]
+        );
+        assert_eq!(
+            parse(r#"{{ref id=kay-quote relation="supports"}}"#),
+            vec![InlineElement::Reference { id: "kay-quote".to_string(), relation: Some("supports".to_string()) }]
+        );
+    }
+    
+    #[test]
+    fn test_custom_and_unparsed_markup() {
+        let elements = parse("{{cell colspan=2 全标题}}");
+        let [InlineElement::Custom { element_type, attributes, content }] = elements.as_slice() else {
+            panic!("expected a custom element");
+        };
+        assert_eq!(element_type, "cell");
+        assert_eq!(attributes, &vec![Attribute::new("colspan".to_string(), "2".to_string())]);
+        assert_eq!(content, "全标题");
+        
+        // Known types with attributes they cannot hold stay lossless
+        assert!(matches!(parse("{{bold class=x text}}").as_slice(), [InlineElement::Custom { .. }]));
+        
+        assert_eq!(
+            parse("欢迎使用 {{!project_name}}！"),
+            vec![text("欢迎使用 "), InlineElement::Variable("project_name".to_string()), text("！")]
+        );
+        assert_eq!(parse("{{!not a name}}"), vec![text("{{!not a name}}")]);
+        assert_eq!(parse("{{}} {{1st thing}}"), vec![text("{{}} {{1st thing}}")]);
+        assert_eq!(parse(r"\{\{not markup\}\} {{unclosed"), vec![text("{{not markup}} {{unclosed")]);
+    }
+    
+    #[test]
+    fn test_no_overlapping_matches() {
+        assert_eq!(parse("**bold**"), vec![InlineElement::Bold(vec![text("bold")])]);
+        assert_eq!(parse("snake_case_names and a_b"), vec![text("snake_case_names and a_b")]);
+        assert_eq!(
+            parse("H _2_ O and x^2^"),
+            vec![
+                text("H "),
+                InlineElement::Subscript(vec![text("2")]),
+                text(" O and x"),
+                InlineElement::Superscript(vec![text("2")]),
+            ]
+        );
+        assert_eq!(parse("`a **b** c`"), vec![InlineElement::Code { code: "a **b** c".to_string(), lang: None }]);
+        assert_eq!(parse("2 * 3 * 4 and **open"), vec![text("2 * 3 * 4 and **open")]);
+    }
+    
+    #[test]
+    fn test_nesting() {
+        assert_eq!(
+            parse("[see **this** page](https://example.com) *a **b** c*"),
+            vec![
+                InlineElement::Link {
+                    content: vec![text("see "), InlineElement::Bold(vec![text("this")]), text(" page")],
+                    url: "https://example.com".to_string(),
+                },
+                text(" "),
+                InlineElement::Italic(vec![text("a "), InlineElement::Bold(vec![text("b")]), text(" c")]),
+            ]
+        );
+        assert_eq!(
+            parse("{{link href=x {{bold nested}}}}"),
+            vec![InlineElement::Link { content: vec![InlineElement::Bold(vec![text("nested")])], url: "x".to_string() }]
+        );
+    }
+    
+    #[test]
+    fn test_unbalanced_input_is_not_rescanned() {
+        let source = "*a _b ^c [d ~~e ==f ".repeat(500);
+        let parsed = parse_inline(&source);
+        assert_eq!(parsed.elements, vec![text(&source)]);
+        
+        let nested = "{{b ".repeat(100) + &"}}".repeat(100);
+        assert_eq!(parse_inline(&nested).ranges.len(), MAX_NESTING + 1);
+    }
+    
+    #[test]
+    fn test_preorder_ranges() {
+        let source = "a **b *c*** d";
+        let parsed = parse_inline(source);
+        let slices: Vec<&str> = parsed.ranges.iter().map(|range| &source[range.clone()]).collect();
+        assert_eq!(slices, vec!["a ", "**b *c***", "b ", "*c*", "c", " d"]);
+    }
+}
@@ -0,0 +1,267 @@
+//! Document metadata read from `#meta` and `#document` blocks.
+
+use std::fmt;
+
+use crate::blox::ast::{Attribute, Block, BlockType};
+
+/// Keys whose values are read as dates
+pub const DATE_KEYS: &[&str] = &["created", "date", "modified", "updated"];
+
+/// Keys that belong in `#document` when metadata is added by key alone
+const DOCUMENT_KEYS: &[&str] = &["title", "created", "date", "modified", "updated"];
+
+/// The block a metadata entry is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum MetadataBlock {
+    Meta,
+    Document,
+}
+
+impl MetadataBlock {
+    /// The metadata block a block type names, if any
+    pub fn of(block_type: &BlockType) -> Option<Self> {
+        match block_type {
+            BlockType::Custom(name) if name.eq_ignore_ascii_case("meta") => Some(Self::Meta),
+            BlockType::Custom(name) if name.eq_ignore_ascii_case("document") => Some(Self::Document),
+            _ => None,
+        }
+    }
+    
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Meta => "meta",
+            Self::Document => "document",
+        }
+    }
+}
+
+/// One `key=value` from a metadata block
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MetadataEntry {
+    pub block: MetadataBlock,
+    pub attribute: Attribute,
+}
+
+/// A calendar date from a metadata value such as `2024-05-01`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct MetadataDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl MetadataDate {
+    /// Read an ISO 8601 date, ignoring any time that follows it
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (date, rest) = if value.is_char_boundary(10) { value.split_at(10) } else { (value, "") };
+        if !(rest.is_empty() || rest.starts_with(['T', ' '])) {
+            return None;
+        }
+        
+        let mut parts = date.split('-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 || !date.chars().all(|c| c.is_ascii_digit() || c == '-') {
+            return None;
+        }
+        
+        let date = Self {
+            year: year.parse().ok()?,
+            month: month.parse().ok()?,
+            day: day.parse().ok()?,
+        };
+        let valid = (1..=12).contains(&date.month) && (1..=date.days_in_month()).contains(&date.day);
+        valid.then_some(date)
+    }
+    
+    /// The date `days` days after 1970-01-01
+    pub fn from_days(days: i64) -> Self {
+        // Civil calendar from a day count, in 400-year eras starting in March
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u8;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+    
+    /// Days from 1970-01-01 to this date
+    pub fn days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let month = i64::from(self.month);
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+    
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+    
+    /// The current date in UTC
+    pub fn today() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Self::from_days((seconds / 86_400) as i64)
+    }
+    
+    fn days_in_month(&self) -> u8 {
+        match self.month {
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+}
+
+impl fmt::Display for MetadataDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Document-level metadata, in the order it was written
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Metadata {
+    pub entries: Vec<MetadataEntry>,
+}
+
+impl Metadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    
+    /// Value of the first entry with `key`
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.attribute.key == key)
+            .map(|entry| entry.attribute.value.as_str())
+    }
+    
+    /// Set the first entry with `key`, or add one to the block the key usually belongs in
+    pub fn insert(&mut self, key: String, value: String) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.attribute.key == key) {
+            entry.attribute.value = value;
+            return;
+        }
+        
+        let block = if DOCUMENT_KEYS.contains(&key.as_str()) { MetadataBlock::Document } else { MetadataBlock::Meta };
+        self.entries.push(MetadataEntry { block, attribute: Attribute::new(key, value) });
+    }
+    
+    /// Add the attributes of a `#meta` or `#document` block, returning
+    /// `false` if `block` is neither
+    pub fn add_block(&mut self, block: &Block) -> bool {
+        let Some(source) = MetadataBlock::of(&block.block_type) else {
+            return false;
+        };
+        
+        self.entries.extend(block.attributes.iter().map(|attribute| MetadataEntry {
+            block: source,
+            attribute: attribute.clone(),
+        }));
+        true
+    }
+    
+    /// Every key and value, in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|entry| (entry.attribute.key.as_str(), entry.attribute.value.as_str()))
+    }
+    
+    pub fn title(&self) -> Option<&str> {
+        self.get("title")
+    }
+    
+    /// Authors from every `author` and `authors` entry, split on commas
+    pub fn authors(&self) -> Vec<String> {
+        self.list(&["author", "authors"])
+    }
+    
+    /// Tags from every `tag` and `tags` entry, split on commas
+    pub fn tags(&self) -> Vec<String> {
+        self.list(&["tag", "tags"])
+    }
+    
+    pub fn created(&self) -> Option<MetadataDate> {
+        self.date(&["created", "date"])
+    }
+    
+    pub fn modified(&self) -> Option<MetadataDate> {
+        self.date(&["modified", "updated"])
+    }
+    
+    fn list(&self, keys: &[&str]) -> Vec<String> {
+        self.iter()
+            .filter(|(key, _)| keys.contains(key))
+            .flat_map(|(_, value)| value.split(','))
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    }
+    
+    fn date(&self, keys: &[&str]) -> Option<MetadataDate> {
+        self.iter()
+            .find(|(key, _)| keys.contains(key))
+            .and_then(|(_, value)| MetadataDate::parse(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_typed_metadata() {
+        let mut metadata = Metadata::new();
+        metadata.insert("author".to_string(), "Ada, Grace".to_string());
+        metadata.insert("title".to_string(), "Notes".to_string());
+        metadata.insert("tags".to_string(), "rust, parsing,".to_string());
+        metadata.insert("created".to_string(), "2024-02-29T10:00".to_string());
+        metadata.insert("modified".to_string(), "2023-02-29".to_string());
+        metadata.insert("title".to_string(), "Renamed".to_string());
+        
+        assert_eq!(metadata.title(), Some("Renamed"));
+        assert_eq!(metadata.authors(), vec!["Ada", "Grace"]);
+        assert_eq!(metadata.tags(), vec!["rust", "parsing"]);
+        assert_eq!(metadata.created(), Some(MetadataDate { year: 2024, month: 2, day: 29 }));
+        assert_eq!(metadata.modified(), None);
+        
+        let keys: Vec<_> = metadata.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["author", "title", "tags", "created", "modified"]);
+        assert_eq!(metadata.entries[0].block, MetadataBlock::Meta);
+        assert_eq!(metadata.entries[1].block, MetadataBlock::Document);
+    }
+    
+    #[test]
+    fn test_date_parsing() {
+        assert_eq!(MetadataDate::parse("2024-05-01").unwrap().to_string(), "2024-05-01");
+        assert!(MetadataDate::parse("2024-13-01").is_none());
+        assert!(MetadataDate::parse("2024-5-1").is_none());
+        assert!(MetadataDate::parse("yesterday").is_none());
+        assert!(MetadataDate::parse("2024-05-01x").is_none());
+    }
+    
+    #[test]
+    fn test_date_arithmetic() {
+        let date = |text: &str| MetadataDate::parse(text).unwrap();
+        assert_eq!(date("1970-01-01").days(), 0);
+        assert_eq!(date("2000-03-01").days(), 11_017);
+        assert_eq!(MetadataDate::from_days(-1), date("1969-12-31"));
+        assert_eq!(date("2024-02-28").add_days(1), date("2024-02-29"));
+        assert_eq!(date("2023-12-25").add_days(10), date("2024-01-04"));
+        assert_eq!(date("2024-03-01").add_days(-1).add_days(365), date("2025-02-28"));
+    }
+}
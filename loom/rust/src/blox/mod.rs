@@ -1,8 +1,27 @@
 pub mod parser;
+pub mod cst;
 pub mod encoder;
+pub mod format;
+pub mod structured;
 pub mod decoder;
 pub mod ast;
 pub mod error;
+pub mod diagnostic;
+pub mod escape;
+pub mod attributes;
+pub mod inline;
+pub mod verbatim;
+pub mod metadata;
+pub mod variables;
+pub mod resolver;
+pub mod table;
+pub mod formula;
+pub mod table_edit;
+pub mod table_source;
+pub mod tasks;
+pub mod quiz;
+pub mod quiz_export;
+pub mod flashcards;
 
 #[cfg(test)]
 mod simple_test;
@@ -10,8 +29,25 @@ mod simple_test;
 #[cfg(test)]
 mod comprehensive_test;
 
-pub use parser::BloxParser;
+#[cfg(test)]
+mod round_trip_test;
+
+pub use parser::{BloxParser, IndentPolicy, ParseOutput, ParserOptions};
+pub use cst::{LineKind, SyntaxLine, SyntaxNode, SyntaxTree};
 pub use encoder::BloxEncoder;
+pub use format::{AttributeOrder, FormatStyle, Formatter, QuoteStyle};
 pub use decoder::{BloxDecoder, OutputFormat};
 pub use ast::{Document, Block, BlockType, Attribute, InlineElement};
 pub use error::{BloxError, ParseResult};
+pub use diagnostic::{Diagnostic, Severity, Fix};
+pub use metadata::{Metadata, MetadataBlock, MetadataDate, MetadataEntry};
+pub use variables::{Interpolated, Interpolator};
+pub use resolver::{IdIndex, ReferenceTarget, Resolution, Resolver};
+pub use table::{read_table, ParsedTable, TableFormat, TableIssue};
+pub use formula::FormulaError;
+pub use table_edit::{apply_edits, edit_table, filter_table, TableEdit, TableOp, TextEdit};
+pub use table_source::{DirectoryFiles, LoadedTables, MemoryFiles, TableFiles, TableLoader};
+pub use tasks::{extract_tasks, scan_files, toggle_task, toggle_task_in_file, FileTask, Task};
+pub use quiz::{read_quiz, ParsedQuiz, QuizIssue};
+pub use quiz_export::{to_gift, to_qti, Package, PackageFile};
+pub use flashcards::{extract_cards, review_log_path, Card, CardKind, CardReview, CardSchedule, ReviewLog};
@@ -1,10 +1,18 @@
 use regex::Regex;
 use lazy_static::lazy_static;
-use std::io::{BufRead, BufReader};
-use std::fs::File;
 
-use crate::blox::ast::{Document, Block, BlockType, Attribute};
-use crate::blox::error::ParseResult;
+use std::ops::Range;
+
+use crate::blox::ast::{Document, Block, BlockType, Attribute, Position, Span};
+use crate::blox::cst::{LineKind, SyntaxLine, SyntaxTree};
+use crate::blox::attributes::AttributeScanner;
+use crate::blox::diagnostic::{Diagnostic, Severity};
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::escape;
+use crate::blox::metadata::{MetadataBlock, MetadataEntry, MetadataDate, DATE_KEYS};
+use crate::blox::verbatim::{self, Fence};
+use crate::blox::table;
+use crate::blox::quiz;
 
 lazy_static! {
     /// Matches block start lines: #{1,6} block_type attributes
@@ -12,139 +20,338 @@ lazy_static! {
         r"^(#{1,6})\s*([a-zA-Z][a-zA-Z0-9_-]*)\s*(.*?)$"
     ).unwrap();
     
-    /// Matches key=value attributes with quoted or unquoted values
-    static ref ATTRIBUTE: Regex = Regex::new(
-        r#"(\w+)=(?:"([^"]*)"|'([^']*)'|([^\s]+))"#
-    ).unwrap();
-    
-    /// Matches positional attributes (quoted strings without key=)
-    static ref POSITIONAL_ATTR: Regex = Regex::new(
-        r#"(?:^|\s+)(?:"([^"]*)"|'([^']*)'|([^\s"'=]+))"#
-    ).unwrap();
-    
     /// Matches comment lines
     static ref COMMENT: Regex = Regex::new(
         r"^\s*//"
     ).unwrap();
-    
-    /// Matches inline elements {{type:content}}
-    static ref INLINE: Regex = Regex::new(
-        r"\{\{([^:}]+):([^}]*)\}\}"
-    ).unwrap();
-    
-    /// Matches bold text: **text**
-    static ref BOLD: Regex = Regex::new(
-        r"\*\*([^*]+)\*\*"
-    ).unwrap();
-    
-    /// Matches italic text: *text*
-    static ref ITALIC: Regex = Regex::new(
-        r"(?<!\*)\*([^*]+)\*(?!\*)"
-    ).unwrap();
-    
-    /// Matches code text: `code`
-    static ref CODE_INLINE: Regex = Regex::new(
-        r"`([^`]+)`"
-    ).unwrap();
-    
-    /// Matches links: [text](url)
-    static ref LINK: Regex = Regex::new(
-        r"\[([^\]]+)\]\(([^)]+)\)"
-    ).unwrap();
-    
-    /// Matches strikethrough: ~~text~~
-    static ref STRIKETHROUGH: Regex = Regex::new(
-        r"~~([^~]+)~~"
-    ).unwrap();
-    
-    /// Matches highlight: ==text==
-    static ref HIGHLIGHT: Regex = Regex::new(
-        r"==([^=]+)=="
-    ).unwrap();
-    
-    /// Matches superscript: ^text^
-    static ref SUPERSCRIPT: Regex = Regex::new(
-        r"\^([^^]+)\^"
-    ).unwrap();
-    
-    /// Matches subscript: _text_
-    static ref SUBSCRIPT: Regex = Regex::new(
-        r"(?<!\w)_([^_]+)_(?!\w)"
-    ).unwrap();
-    
-    /// Matches math: $formula$
-    static ref MATH_INLINE: Regex = Regex::new(
-        r"\$([^$]+)\$"
-    ).unwrap();
+}
+
+/// Block types from the spec that have no dedicated `BlockType` variant
+const SPEC_CUSTOM_BLOCK_TYPES: &[&str] = &["meta", "document", "var", "item", "question"];
+
+/// Every block type name the parser knows, used to suggest fixes for typos
+pub(crate) const KNOWN_BLOCK_TYPES: &[&str] = &[
+    "section", "paragraph", "code", "quote", "image", "table", "list", "math", "comment",
+    "h1", "h2", "h3", "h4", "h5", "h6", "p", "c", "q", "img", "tbl", "m",
+    "meta", "document", "quiz", "var", "item", "question",
+];
+
+/// Settings that change how strictly the parser applies the spec
+#[derive(Debug, Clone, Default)]
+pub struct ParserOptions {
+    /// Reject a child whose level is not exactly one more than its parent's (spec 4.5)
+    pub strict_nesting: bool,
+    /// How whitespace in front of headers and content lines is read
+    pub indent: IndentPolicy,
+}
+
+/// How indentation is read, so documents written with an indented layout
+/// (`BloxEncoder::with_options`) parse back unchanged.
+///
+/// Indentation is the spaces and tabs in front of a line, a tab counting as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndentPolicy {
+    /// Headers start in the first column; an indented `#` line is content,
+    /// kept with its indentation
+    #[default]
+    Flat,
+    /// Headers may be indented, and each content line loses as much of its
+    /// block header's indentation as it starts with
+    Header,
+    /// Headers may be indented, and each block's content loses the
+    /// indentation its lines have in common. Content whose every line is
+    /// indented does not read back as written.
+    Common,
 }
 
 pub struct BloxParser {
+    options: ParserOptions,
     document: Document,
-    block_stack: Vec<Block>,
+    block_stack: Vec<OpenBlock>,
     current_line: usize,
+    /// Byte offset of the start of the current line
+    line_offset: usize,
+    diagnostics: Vec<Diagnostic>,
+    /// Whether errors are being turned into diagnostics rather than returned
+    recovering: bool,
+    /// Every line read so far, as the parser read it, while building a `SyntaxTree`
+    syntax_lines: Option<Vec<SyntaxLine>>,
+}
+
+/// A parsed document together with every problem found while parsing it
+#[derive(Debug, Clone)]
+pub struct ParseOutput {
+    pub document: Document,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A block that is still receiving content lines
+struct OpenBlock {
+    block: Block,
+    /// Source of each line appended to `block.content`
+    content_lines: Vec<ContentLine>,
+    /// Set while lines are taken as written rather than read as Blox
+    verbatim: Option<Verbatim>,
+    /// Blank lines in verbatim content, kept only if more content follows
+    pending_blank_lines: Vec<(String, Span)>,
+    /// Width of the header's indentation
+    indent: usize,
+}
+
+impl OpenBlock {
+    fn new(block: Block, indent: usize) -> Self {
+        let verbatim = block.is_verbatim().then_some(Verbatim::Open);
+        Self {
+            block,
+            content_lines: Vec::new(),
+            verbatim,
+            pending_blank_lines: Vec::new(),
+            indent,
+        }
+    }
+    
+    fn push_line(&mut self, content: &str, line: ContentLine) {
+        if !self.content_lines.is_empty() {
+            for (blank, span) in std::mem::take(&mut self.pending_blank_lines) {
+                self.block.content.push('\n');
+                self.block.content.push_str(&blank);
+                self.content_lines.push(ContentLine { span, unescaped: Vec::new() });
+            }
+            self.block.content.push('\n');
+        }
+        self.block.content.push_str(content);
+        self.content_lines.push(line);
+    }
+}
+
+/// How a verbatim block is reading its lines
+enum Verbatim {
+    /// Until a header at the same or higher level
+    Open,
+    /// Until the closing fence, after which lines are read as Blox again
+    Fenced(Fence),
+}
+
+struct ContentLine {
+    span: Span,
+    /// Byte indices in the content line where escaping backslashes were dropped
+    unescaped: Vec<usize>,
 }
 
 #[derive(Debug)]
 enum LineType {
     BlockStart {
+        /// Width of the indentation in front of the `#`s
+        indent: usize,
         level: usize,
         block_type: String,
-        attributes_str: String,
+        /// Byte range of the block type within the line
+        type_range: Range<usize>,
+        /// Byte offset of the attribute text within the line
+        attributes_start: usize,
     },
-    Content(String),
+    /// A line starting with `#` that is not a valid block header
+    MalformedBlockStart { reason: &'static str },
+    Content,
     Comment,
     Empty,
 }
 
 impl BloxParser {
     pub fn new() -> Self {
+        Self::with_options(ParserOptions::default())
+    }
+    
+    pub fn with_options(options: ParserOptions) -> Self {
         Self {
+            options,
             document: Document::new(),
             block_stack: Vec::new(),
             current_line: 0,
+            line_offset: 0,
+            diagnostics: Vec::new(),
+            recovering: false,
+            syntax_lines: None,
         }
     }
     
     /// Parse a Blox document from a string
     pub fn parse_string(&mut self, content: &str) -> ParseResult<Document> {
-        for (line_num, line) in content.lines().enumerate() {
-            self.current_line = line_num + 1;
-            self.parse_line(line)?;
+        self.parse_lines(content, false)?;
+        self.finalize_document()
+    }
+    
+    /// Parse a Blox document from a string, recovering from every error.
+    ///
+    /// Always produces a document; problems that would make `parse_string`
+    /// fail are reported as error diagnostics instead.
+    pub fn parse_with_diagnostics(&mut self, content: &str) -> ParseOutput {
+        // Errors are recovered line by line, so neither call can fail here
+        let _ = self.parse_lines(content, true);
+        let document = self.finalize_document().unwrap_or_default();
+        
+        ParseOutput {
+            document,
+            diagnostics: std::mem::take(&mut self.diagnostics),
         }
+    }
+    
+    /// Parse a Blox document into a syntax tree that keeps the source it
+    /// was read from, recovering from every error as `parse_with_diagnostics` does.
+    ///
+    /// Problems found are left in `diagnostics()`.
+    pub fn parse_tree(&mut self, content: &str) -> SyntaxTree {
+        self.syntax_lines = Some(Vec::new());
+        let _ = self.parse_lines(content, true);
+        self.close_blocks_at_level(0);
         
-        self.finalize_document()
+        // Nodes are built before metadata blocks leave the document
+        let blocks = self.document.blocks.clone();
+        self.lift_metadata();
+        
+        SyntaxTree::new(content, self.syntax_lines.take().unwrap_or_default(), &blocks, self.document.clone())
     }
     
-    /// Parse a Blox document from a file
-    pub fn parse_file(&mut self, file_path: &str) -> ParseResult<Document> {
-        let file = File::open(file_path)?;
-        let reader = BufReader::new(file);
+    /// Problems found so far, excluding those already returned by `parse_with_diagnostics`
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+    
+    fn parse_lines(&mut self, content: &str, recover: bool) -> ParseResult<()> {
+        let mut offset = 0;
+        self.recovering = recover;
         
-        for (line_num, line_result) in reader.lines().enumerate() {
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
             self.current_line = line_num + 1;
-            let line = line_result?;
-            self.parse_line(&line)?;
+            self.line_offset = offset;
+            offset += raw_line.len();
+            
+            let line = raw_line.strip_suffix('\n').unwrap_or(raw_line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            
+            let span = self.span_in_line(line, 0, line.len());
+            let kind = match self.parse_line(line) {
+                Ok(kind) => kind,
+                Err(error) => {
+                    if !recover {
+                        return Err(error);
+                    }
+                    self.diagnostics.push(Diagnostic::from_error(&error, Severity::Error, span));
+                    LineKind::Header
+                }
+            };
+            if let Some(lines) = &mut self.syntax_lines {
+                lines.push(SyntaxLine { kind, span, end: offset });
+            }
         }
         
-        self.finalize_document()
+        Ok(())
+    }
+    
+    /// Parse a Blox document from a file
+    pub fn parse_file(&mut self, file_path: &str) -> ParseResult<Document> {
+        let content = std::fs::read_to_string(file_path)?;
+        self.parse_string(&content)
     }
     
-    fn parse_line(&mut self, line: &str) -> ParseResult<()> {
+    /// Read one line, returning what it was read as
+    fn parse_line(&mut self, line: &str) -> ParseResult<LineKind> {
+        if let Some(kind) = self.parse_verbatim_line(line) {
+            return Ok(kind);
+        }
+        
         let line_type = self.classify_line(line);
         
         match line_type {
-            LineType::BlockStart { level, block_type, attributes_str } => {
-                self.handle_block_start(level, block_type, attributes_str)?;
+            LineType::BlockStart { indent, level, block_type, type_range, attributes_start } => {
+                self.handle_block_start(line, indent, level, block_type, type_range, attributes_start)?;
+                return Ok(LineKind::Header);
             }
-            LineType::Content(content) => {
-                self.handle_content_line(content);
+            LineType::MalformedBlockStart { reason } => {
+                let error = BloxError::ParseError {
+                    line: self.current_line,
+                    message: reason.to_string(),
+                };
+                let span = self.span_in_line(line, 0, line.len());
+                self.diagnostics.push(Diagnostic::from_error(&error, Severity::Warning, span));
+                
+                // Keep the text rather than dropping it
+                self.handle_content_line(line);
             }
-            LineType::Comment | LineType::Empty => {
-                // Ignore comments and empty lines
+            LineType::Content => {
+                self.handle_content_line(line);
             }
+            LineType::Comment => return Ok(LineKind::Comment),
+            LineType::Empty => return Ok(LineKind::Blank),
         }
         
-        Ok(())
+        Ok(LineKind::Content)
+    }
+    
+    /// Take `line` as written if the open block is verbatim, returning what
+    /// it was read as.
+    ///
+    /// Returns `None` when the line should be read as Blox instead, either
+    /// because no verbatim block is open or because the line ends it.
+    fn parse_verbatim_line(&mut self, line: &str) -> Option<LineKind> {
+        let start = self.content_start(line);
+        let span = self.span_in_line(line, start, line.len());
+        let policy = self.options.indent;
+        let current = self.block_stack.last_mut()?;
+        
+        match current.verbatim {
+            None => None,
+            Some(Verbatim::Fenced(fence)) => {
+                if fence.closes(line) {
+                    current.verbatim = None;
+                    return Some(LineKind::Fence);
+                }
+                current.push_line(&line[start..], ContentLine { span, unescaped: Vec::new() });
+                Some(LineKind::Content)
+            }
+            Some(Verbatim::Open) => {
+                if line.trim().is_empty() {
+                    // Blank lines before the content are dropped, as elsewhere
+                    if !current.content_lines.is_empty() {
+                        current.pending_blank_lines.push((line[start..].to_string(), span));
+                    }
+                    return Some(LineKind::Blank);
+                }
+                
+                if current.content_lines.is_empty() {
+                    if let Some(fence) = Fence::open(line) {
+                        current.verbatim = Some(Verbatim::Fenced(fence));
+                        return Some(LineKind::Fence);
+                    }
+                }
+                
+                // Only a header indented no further than the block's own ends it
+                let level = current.block.level;
+                let indent = indent_width(line);
+                let ends = match policy {
+                    IndentPolicy::Flat => verbatim::ends_block(line, level, verbatim::ending_types(&current.block)),
+                    _ => {
+                        indent <= current.indent
+                            && verbatim::ends_block(&line[indent..], level, verbatim::ending_types(&current.block))
+                    }
+                };
+                if ends {
+                    return None;
+                }
+                
+                self.handle_content_line(line);
+                Some(LineKind::Content)
+            }
+        }
+    }
+    
+    /// Byte offset in `line` where the open block's content starts, past
+    /// the indentation the policy strips from each line
+    fn content_start(&self, line: &str) -> usize {
+        match (self.options.indent, self.block_stack.last()) {
+            (IndentPolicy::Header, Some(open)) => indent_width(line).min(open.indent),
+            _ => 0,
+        }
     }
     
     fn classify_line(&self, line: &str) -> LineType {
@@ -160,153 +367,306 @@ impl BloxParser {
             return LineType::Comment;
         }
         
+        // Headers start in the first column unless the policy allows indentation
+        let indent = match self.options.indent {
+            IndentPolicy::Flat => 0,
+            _ => indent_width(line),
+        };
+        let header = &line[indent..];
+        
         // Block start line
-        if let Some(captures) = BLOCK_START.captures(line) {
+        if let Some(captures) = BLOCK_START.captures(header) {
             let level = captures[1].len();
             let block_type = captures[2].to_string();
-            let attributes_str = captures.get(3).map_or("", |m| m.as_str()).to_string();
+            let type_range = captures.get(2).unwrap().range();
+            let attributes_start = captures.get(3).map_or(header.len(), |m| m.start());
             
             return LineType::BlockStart {
+                indent,
                 level,
                 block_type,
-                attributes_str,
+                type_range: indent + type_range.start..indent + type_range.end,
+                attributes_start: indent + attributes_start,
             };
         }
         
+        // Looks like a block start but isn't one
+        if header.starts_with('#') {
+            let hashes = header.chars().take_while(|&c| c == '#').count();
+            let reason = if hashes > 6 {
+                "Too many # symbols (max 6)"
+            } else if header[hashes..].trim().is_empty() {
+                "Missing block type after #"
+            } else {
+                "Block type must start with letter"
+            };
+            
+            return LineType::MalformedBlockStart { reason };
+        }
+        
         // Content line
-        LineType::Content(line.to_string())
+        LineType::Content
     }
     
     fn handle_block_start(
         &mut self,
+        line: &str,
+        indent: usize,
         level: usize,
         block_type: String,
-        attributes_str: String,
+        type_range: Range<usize>,
+        attributes_start: usize,
     ) -> ParseResult<()> {
         // Close blocks at same or higher level
         self.close_blocks_at_level(level);
         
+        // A child must be exactly one level deeper than its parent. The
+        // block is kept where it was written either way; nesting is never fixed.
+        let parent_level = self.block_stack.last().map_or(0, |open| open.block.level);
+        if level > parent_level + 1 {
+            let error = BloxError::InvalidNesting {
+                line: self.current_line,
+                expected: parent_level + 1,
+                actual: level,
+            };
+            
+            if self.options.strict_nesting && !self.recovering {
+                return Err(error);
+            }
+            
+            let severity = if self.options.strict_nesting { Severity::Error } else { Severity::Warning };
+            let span = self.span_in_line(line, indent, indent + level);
+            self.diagnostics.push(Diagnostic::from_error(&error, severity, span));
+        }
+        
         // Create new block
         let block_type_enum = BlockType::from_str(&block_type);
+        if let BlockType::Custom(name) = &block_type_enum {
+            self.report_unknown_block_type(name, self.span_in_line(line, type_range.start, type_range.end));
+        }
+        
         let mut block = Block::new(block_type_enum, level, self.current_line);
+        block.header_span = self.span_in_line(line, 0, line.len());
         
         // Parse attributes
-        let attributes = self.parse_attributes(&attributes_str, &block_type)?;
+        let attributes = self.parse_attributes(line, attributes_start, &block_type)?;
         block.attributes = attributes;
         
         // Add block to stack
-        self.block_stack.push(block);
+        self.block_stack.push(OpenBlock::new(block, indent));
         
         Ok(())
     }
     
-    fn handle_content_line(&mut self, content: String) {
-        if let Some(current_block) = self.block_stack.last_mut() {
-            if !current_block.content.is_empty() {
-                current_block.content.push('\n');
+    fn handle_content_line(&mut self, line: &str) {
+        let start = self.content_start(line);
+        let span = self.span_in_line(line, start, line.len());
+        let content = &line[start..];
+        
+        // `\#` and `\//` keep a line from being read as a header or comment;
+        // verbatim content keeps them anywhere but at the start of a line
+        let verbatim = self.block_stack.last().is_some_and(|open| open.verbatim.is_some());
+        let (content, unescaped) = if verbatim {
+            match escape::unescape_line_start(content) {
+                Some((unescaped, at)) => (unescaped, vec![at]),
+                None => (content.to_string(), Vec::new()),
             }
-            current_block.content.push_str(&content);
+        } else {
+            escape::unescape_line(content)
+        };
+        let line = ContentLine { span, unescaped };
+        
+        if let Some(current) = self.block_stack.last_mut() {
+            current.push_line(&content, line);
         } else {
             // Content without a block - create implicit paragraph
             let mut paragraph = Block::new(BlockType::Paragraph, 1, self.current_line);
-            paragraph.content = content;
-            self.block_stack.push(paragraph);
+            paragraph.header_span = Span::point(span.start);
+            let mut open = OpenBlock::new(paragraph, 0);
+            open.push_line(&content, line);
+            self.block_stack.push(open);
         }
     }
     
-    fn parse_attributes(&self, attrs_str: &str, block_type: &str) -> ParseResult<Vec<Attribute>> {
-        let mut attributes = Vec::new();
-        let attrs_str = attrs_str.trim();
-        
-        if attrs_str.is_empty() {
-            return Ok(attributes);
+    fn report_unknown_block_type(&mut self, name: &str, span: Span) {
+        if SPEC_CUSTOM_BLOCK_TYPES.contains(&name.to_lowercase().as_str()) {
+            return;
         }
         
-        // First, try to parse key=value attributes
-        for captures in ATTRIBUTE.captures_iter(attrs_str) {
-            let key = captures[1].to_string();
-            let value = captures.get(2)
-                .or(captures.get(3))
-                .or(captures.get(4))
-                .map(|m| m.as_str())
-                .unwrap_or("")
-                .to_string();
-            
-            attributes.push(Attribute { key, value });
+        let error = BloxError::UnknownBlockType { block_type: name.to_string() };
+        let mut diagnostic = Diagnostic::from_error(&error, Severity::Info, span);
+        
+        let suggestion = KNOWN_BLOCK_TYPES
+            .iter()
+            .map(|known| (edit_distance(&name.to_lowercase(), known), known))
+            .filter(|(distance, known)| *distance <= 2 && known.len() > 3)
+            .min_by_key(|(distance, _)| *distance);
+        if let Some((_, known)) = suggestion {
+            diagnostic = diagnostic.with_fix(span, known.to_string(), format!("Did you mean `{}`?", known));
         }
         
-        // Then handle positional attributes (shorthand)
-        let remaining = ATTRIBUTE.replace_all(attrs_str, "");
-        let positional_attrs: Vec<&str> = POSITIONAL_ATTR
-            .captures_iter(&remaining)
-            .filter_map(|caps| {
-                caps.get(1).or(caps.get(2)).or(caps.get(3))
-                    .map(|m| m.as_str())
-            })
-            .collect();
+        self.diagnostics.push(diagnostic);
+    }
+    
+    fn invalid_attribute_diagnostic(&self, line: &str, range: Range<usize>) -> Diagnostic {
+        let error = BloxError::InvalidAttribute {
+            line: self.current_line,
+            attribute: line[range.clone()].to_string(),
+        };
+        Diagnostic::from_error(&error, Severity::Warning, self.span_in_line(line, range.start, range.end))
+    }
+    
+    /// Parse the attribute text starting at byte `attrs_start` of `line`
+    fn parse_attributes(&mut self, line: &str, attrs_start: usize, block_type: &str) -> ParseResult<Vec<Attribute>> {
+        let mut attributes = Vec::new();
+        let mut positional = Vec::new();
+        let attrs_str = &line[attrs_start..];
         
-        // Apply positional attributes based on block type
-        for (i, value) in positional_attrs.iter().enumerate() {
-            let key = self.get_positional_key(block_type, i);
-            attributes.push(Attribute {
-                key: key.to_string(),
-                value: value.to_string(),
-            });
+        for token in AttributeScanner::new(attrs_str) {
+            let token = match token {
+                Ok(token) => token,
+                Err(range) => {
+                    // Text that none of the attribute forms matched
+                    let range = attrs_start + range.start..attrs_start + range.end;
+                    let diagnostic = self.invalid_attribute_diagnostic(line, range);
+                    self.diagnostics.push(diagnostic);
+                    continue;
+                }
+            };
+            
+            if let Some(quote) = token.unclosed_quote {
+                let end = attrs_start + token.range.end;
+                let diagnostic = self.invalid_attribute_diagnostic(line, attrs_start + token.range.start..end);
+                let fix_span = self.span_in_line(line, end, end);
+                self.diagnostics.push(diagnostic.with_fix(fix_span, quote.to_string(), "Close the quoted value".to_string()));
+            }
+            
+            let value_start = attrs_start + token.value_range.start;
+            let value_span = self.span_in_line(line, value_start, attrs_start + token.value_range.end);
+            
+            match token.key {
+                Some((key, key_range)) => attributes.push(Attribute {
+                    key,
+                    value: token.value,
+                    key_span: self.span_in_line(line, attrs_start + key_range.start, attrs_start + key_range.end),
+                    value_span,
+                }),
+                None => {
+                    // Apply positional attributes (shorthand) based on block type
+                    let key = self.get_positional_key(block_type, positional.len());
+                    positional.push(Attribute {
+                        key: key.to_string(),
+                        value: token.value,
+                        key_span: self.span_in_line(line, value_start, value_start),
+                        value_span,
+                    });
+                }
+            }
         }
         
+        attributes.append(&mut positional);
         Ok(attributes)
     }
     
+    /// Position of byte `index` within `line`, the line currently being parsed
+    fn position_in_line(&self, line: &str, index: usize) -> Position {
+        Position::new(
+            self.current_line,
+            line[..index].chars().count() + 1,
+            self.line_offset + index,
+        )
+    }
+    
+    fn span_in_line(&self, line: &str, start: usize, end: usize) -> Span {
+        Span::new(self.position_in_line(line, start), self.position_in_line(line, end))
+    }
+    
     fn get_positional_key(&self, block_type: &str, index: usize) -> &'static str {
         match (block_type, index) {
             ("section" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6", 0) => "title",
             ("image" | "img", 0) => "src",
             ("image" | "img", 1) => "alt",
             ("code" | "c", 0) => "lang",
+            ("document", 0) => "title",
             ("quote" | "q", 0) => "author",
             ("table" | "tbl", 0) => "caption",
+            ("quiz", 0) => "title",
             _ => "value",
         }
     }
     
     fn close_blocks_at_level(&mut self, level: usize) {
-        while let Some(last_block) = self.block_stack.last() {
-            if last_block.level < level {
+        while let Some(last) = self.block_stack.last() {
+            if last.block.level < level {
                 break;
             }
             
-            let mut completed_block = self.block_stack.pop().unwrap();
+            let OpenBlock { block: mut completed_block, mut content_lines, .. } = self.block_stack.pop().unwrap();
+            if self.options.indent == IndentPolicy::Common {
+                dedent(&mut completed_block.content, &mut content_lines);
+            }
+            
+            completed_block.content_span = match (content_lines.first(), content_lines.last()) {
+                (Some(first), Some(last)) => Span::new(first.span.start, last.span.end),
+                _ => Span::point(completed_block.header_span.end),
+            };
             
             // Parse enhanced features based on block type
-            self.parse_enhanced_features(&mut completed_block);
+            self.parse_enhanced_features(&mut completed_block, &content_lines);
+            
+            // Inline spans are relative to the content; move them into the source
+            completed_block.inline_spans = completed_block.inline_spans
+                .iter()
+                .map(|span| map_content_span(&completed_block, &content_lines, *span))
+                .collect();
             
             if let Some(parent) = self.block_stack.last_mut() {
-                parent.children.push(completed_block);
+                parent.block.children.push(completed_block);
             } else {
                 self.document.blocks.push(completed_block);
             }
         }
     }
     
-    fn parse_enhanced_features(&self, block: &mut Block) {
-        // Parse inline elements for all blocks
+    fn parse_enhanced_features(&mut self, block: &mut Block, content_lines: &[ContentLine]) {
+        // Parse inline elements for all blocks except those taken as written
+        if block.is_verbatim() {
+            return;
+        }
         if let Err(e) = block.parse_inline_elements() {
-            eprintln!("Warning: Failed to parse inline elements: {}", e);
+            self.report_feature_error(block, format!("Failed to parse inline elements: {}", e));
         }
         
         // Parse specific features based on block type
-        match block.block_type {
-            BlockType::List => {
-                if let Err(e) = block.parse_list_items() {
-                    eprintln!("Warning: Failed to parse list items: {}", e);
-                }
+        match block.block_type.canonical() {
+            BlockType::List => self.parse_list_items(block, content_lines),
+            BlockType::Custom(_) if block.is_list_item() => {
+                // Items nested in an `item` block are placed while its content lines are known
+                self.parse_list_items(block, content_lines);
             }
             BlockType::Table => {
-                if let Err(e) = block.parse_table() {
-                    eprintln!("Warning: Failed to parse table: {}", e);
+                if block.content.trim().is_empty() {
+                    return;
+                }
+                let parsed = table::read_table(block);
+                for issue in parsed.issues {
+                    let attribute = issue.attribute.and_then(|key| block.attributes.iter().find(|attr| attr.key == key));
+                    let span = match attribute {
+                        Some(attribute) => attribute.value_span,
+                        None => map_content_span(block, content_lines, Span::of_range(&block.content, issue.range.start, issue.range.end)),
+                    };
+                    self.diagnostics.push(Diagnostic::warning(issue.code, span, issue.message));
                 }
+                block.table = Some(parsed.table);
             }
-            BlockType::Paragraph | BlockType::Section | BlockType::H1 | BlockType::H2 | BlockType::H3 | BlockType::H4 | BlockType::H5 | BlockType::H6 => {
+            BlockType::Quiz => {
+                let parsed = quiz::read_quiz(block, &|span| map_content_span(block, content_lines, span));
+                for issue in parsed.issues {
+                    self.diagnostics.push(Diagnostic::warning(issue.code, issue.span, issue.message));
+                }
+                block.quiz = Some(parsed.quiz);
+            }
+            BlockType::Paragraph | BlockType::Section => {
                 // For paragraphs and headings, always try to parse inline elements
                 // (already done above)
             }
@@ -316,12 +676,62 @@ impl BloxParser {
         }
     }
     
+    fn parse_list_items(&mut self, block: &mut Block, content_lines: &[ContentLine]) {
+        let mut frame = Block::new(block.block_type.clone(), block.level, block.line_number);
+        frame.content = block.content.clone();
+        frame.content_span = block.content_span;
+        
+        if let Err(e) = block.parse_list_items_with(|span| map_content_span(&frame, content_lines, span)) {
+            self.report_feature_error(block, format!("Failed to parse list items: {}", e));
+        }
+    }
+    
+    fn report_feature_error(&mut self, block: &Block, message: String) {
+        let error = BloxError::ParseError { line: block.line_number, message };
+        self.diagnostics.push(Diagnostic::from_error(&error, Severity::Warning, block.content_span));
+    }
+    
     fn finalize_document(&mut self) -> ParseResult<Document> {
         // Close all remaining blocks
         self.close_blocks_at_level(0);
+        self.lift_metadata();
         
         Ok(self.document.clone())
     }
+    
+    /// Move top-level `#meta` and `#document` blocks into `Document.metadata`.
+    ///
+    /// Only header-only blocks are lifted; one with content or children is
+    /// left in place so nothing is lost.
+    fn lift_metadata(&mut self) {
+        for block in std::mem::take(&mut self.document.blocks) {
+            let Some(source) = MetadataBlock::of(&block.block_type) else {
+                self.document.blocks.push(block);
+                continue;
+            };
+            
+            if !block.content.is_empty() || !block.children.is_empty() {
+                self.diagnostics.push(Diagnostic::warning(
+                    "metadata-content",
+                    block.header_span,
+                    format!("#{} has content, so it is kept as a block instead of document metadata", source.to_str()),
+                ));
+                self.document.blocks.push(block);
+                continue;
+            }
+            
+            for attribute in block.attributes {
+                if DATE_KEYS.contains(&attribute.key.as_str()) && MetadataDate::parse(&attribute.value).is_none() {
+                    self.diagnostics.push(Diagnostic::warning(
+                        "invalid-metadata-date",
+                        attribute.value_span,
+                        format!("`{}` is not a date in YYYY-MM-DD form", attribute.value),
+                    ));
+                }
+                self.document.metadata.entries.push(MetadataEntry { block: source, attribute });
+            }
+        }
+    }
 }
 
 impl Default for BloxParser {
@@ -330,9 +740,94 @@ impl Default for BloxParser {
     }
 }
 
+/// Number of spaces and tabs at the start of `line`
+fn indent_width(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+/// Remove the indentation that the lines of `content` have in common,
+/// moving the source span of each line past what was removed
+fn dedent(content: &mut String, content_lines: &mut [ContentLine]) {
+    let common = content
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(indent_width)
+        .min()
+        .unwrap_or(0);
+    if common == 0 {
+        return;
+    }
+    
+    let lines: Vec<&str> = content.split('\n').collect();
+    let mut dedented = Vec::with_capacity(lines.len());
+    for (text, line) in lines.iter().zip(content_lines.iter_mut()) {
+        // Blank lines may be shorter than the common indentation
+        let removed = common.min(indent_width(text));
+        dedented.push(&text[removed..]);
+        line.span.start = Position::new(line.span.start.line, line.span.start.column + removed, line.span.start.offset + removed);
+        for at in &mut line.unescaped {
+            *at -= removed;
+        }
+    }
+    *content = dedented.join("\n");
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    
+    previous[b.len()]
+}
+
+/// Map a span relative to `block.content` onto the source, given the source
+/// span of each content line
+fn map_content_span(block: &Block, content_lines: &[ContentLine], span: Span) -> Span {
+    Span::new(
+        map_content_position(block, content_lines, span.start),
+        map_content_position(block, content_lines, span.end),
+    )
+}
+
+fn map_content_position(block: &Block, content_lines: &[ContentLine], position: Position) -> Position {
+    let Some(line) = content_lines.get(position.line.saturating_sub(1)) else {
+        return block.content_span.start;
+    };
+    
+    // Byte offset within the content where this content line starts
+    let line_start = if position.line <= 1 {
+        0
+    } else {
+        block.content
+            .match_indices('\n')
+            .nth(position.line - 2)
+            .map_or(0, |(i, _)| i + 1)
+    };
+    
+    // Positions after a dropped escape are one character further on in the
+    // source for each; a position at the escape itself keeps the backslash in the span
+    let shift = line.unescaped.iter().filter(|&&at| position.offset - line_start > at).count();
+    
+    Position::new(
+        line.span.start.line,
+        line.span.start.column + position.column - 1 + shift,
+        line.span.start.offset + position.offset - line_start + shift,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::blox::ast::{InlineElement, ListItemType};
     
     #[test]
     fn test_simple_parsing() {
@@ -366,4 +861,358 @@ fn hello() {
         assert_eq!(block.get_attribute("alt"), Some("Logo"));
         assert_eq!(block.get_attribute("width"), Some("100"));
     }
+    
+    #[test]
+    fn test_source_spans() {
+        let mut parser = BloxParser::new();
+        let content = "#h1 \"Title\" id=intro\r\nSome **bold** text\nmore\n##p\nchild\n";
+        
+        let doc = parser.parse_string(content).unwrap();
+        let block = &doc.blocks[0];
+        
+        assert_eq!(block.header_span.start, Position::new(1, 1, 0));
+        assert_eq!(block.header_span.end, Position::new(1, 21, 20));
+        assert_eq!(block.content_span.start, Position::new(2, 1, 22));
+        assert_eq!(block.content_span.end, Position::new(3, 5, 45));
+        
+        let title = &block.attributes[1];
+        assert_eq!(title.key, "title");
+        assert_eq!(&content[title.value_span.start.offset..title.value_span.end.offset], "Title");
+        assert!(title.key_span.is_empty());
+        
+        let id = &block.attributes[0];
+        assert_eq!(&content[id.key_span.start.offset..id.key_span.end.offset], "id");
+        assert_eq!(id.value_span.start, Position::new(1, 16, 15));
+        
+        fn count(elements: &[InlineElement]) -> usize {
+            elements.iter().map(|e| 1 + count(e.children())).sum()
+        }
+        assert_eq!(block.inline_spans.len(), count(&block.inline_elements));
+        let bold = block.inline_elements.iter()
+            .position(|e| matches!(e, InlineElement::Bold(_)))
+            .unwrap();
+        let span = block.inline_spans[bold];
+        assert_eq!(&content[span.start.offset..span.end.offset], "**bold**");
+        assert_eq!(span.start, Position::new(2, 6, 27));
+        
+        let last = block.inline_spans.last().unwrap();
+        assert_eq!(last.end, block.content_span.end);
+        
+        let child = &block.children[0];
+        assert_eq!(child.header_span.start, Position::new(4, 1, 46));
+        assert_eq!(child.content_span.start.line, 5);
+    }
+    
+    #[test]
+    fn test_diagnostics_are_collected() {
+        let mut parser = BloxParser::new();
+        let content = r#"#sectoin title="Intro
+Body text
+###p
+Deep paragraph
+####### too deep
+#meta author=me
+"#;
+        
+        let output = parser.parse_with_diagnostics(content);
+        let codes: Vec<&str> = output.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        
+        assert_eq!(codes, vec!["unknown-block-type", "invalid-attribute", "invalid-nesting", "parse-error"]);
+        assert_eq!(output.document.blocks.len(), 1);
+        assert_eq!(output.document.metadata.get("author"), Some("me"));
+        
+        let unknown = &output.diagnostics[0];
+        assert_eq!(unknown.severity, Severity::Info);
+        assert_eq!(unknown.fix.as_ref().unwrap().replacement, "section");
+        assert_eq!(&content[unknown.span.start.offset..unknown.span.end.offset], "sectoin");
+        
+        let attribute = &output.diagnostics[1];
+        assert_eq!(attribute.fix.as_ref().unwrap().replacement, "\"");
+        assert_eq!(attribute.fix.as_ref().unwrap().span.start.offset, 21);
+        
+        let nesting = &output.diagnostics[2];
+        assert_eq!(nesting.span.start.line, 3);
+        assert!(nesting.message.contains("expected level 2, got 3"));
+        
+        let malformed = &output.diagnostics[3];
+        assert_eq!(malformed.span.start.line, 5);
+        assert!(malformed.message.contains("Too many # symbols"));
+        
+        // The malformed header is kept as text
+        assert!(output.document.blocks[0].children[0].content.contains("####### too deep"));
+    }
+    
+    #[test]
+    fn test_escapes() {
+        let mut parser = BloxParser::new();
+        let content = "#p\n\\#hashtag **hot**\n\\// not a comment\nliteral \\{\\{braces\\}\\}\n";
+        
+        let doc = parser.parse_string(content).unwrap();
+        assert_eq!(doc.blocks.len(), 1);
+        
+        let block = &doc.blocks[0];
+        assert_eq!(block.content, "#hashtag **hot**\n// not a comment\nliteral \\{\\{braces\\}\\}");
+        assert_eq!(block.inline_elements[0], InlineElement::Text("#hashtag ".to_string()));
+        assert!(matches!(block.inline_elements.last(), Some(InlineElement::Text(text)) if text.ends_with("literal {{braces}}")));
+        
+        // Spans still point at the source, backslash included
+        let bold = block.inline_spans[1];
+        assert_eq!(&content[bold.start.offset..bold.end.offset], "**hot**");
+        assert_eq!(bold.start, Position::new(2, 11, 13));
+        assert_eq!(block.inline_spans[0].start, Position::new(2, 1, 3));
+        
+        // The same escapes mean the same in the middle of a line, in the
+        // content and in spans; verbatim content keeps them there
+        let content = "#p\nIssue \\#1 and \\\\#2, see \\// **here**\n#code\n\\#x \\#y\n";
+        let doc = BloxParser::new().parse_string(content).unwrap();
+        assert_eq!(doc.blocks[0].content, "Issue #1 and \\#2, see // **here**");
+        let bold = doc.blocks[0].inline_spans[1];
+        assert_eq!(&content[bold.start.offset..bold.end.offset], "**here**");
+        assert_eq!(doc.blocks[1].content, "#x \\#y");
+    }
+    
+    #[test]
+    fn test_inline_markup() {
+        let mut parser = BloxParser::new();
+        let content = "#p\nSee {{@kay-quote}} and {{code lang=python \"print('Hello')\"}} or {{bold **not** nested}}.\n";
+        
+        let doc = parser.parse_string(content).unwrap();
+        let block = &doc.blocks[0];
+        
+        assert_eq!(block.inline_elements, vec![
+            InlineElement::Text("See ".to_string()),
+            InlineElement::Reference { id: "kay-quote".to_string(), relation: None },
+            InlineElement::Text(" and ".to_string()),
+            InlineElement::Code { code: "print('Hello')".to_string(), lang: Some("python".to_string()) },
+            InlineElement::Text(" or ".to_string()),
+            InlineElement::Bold(vec![
+                InlineElement::Bold(vec![InlineElement::Text("not".to_string())]),
+                InlineElement::Text(" nested".to_string()),
+            ]),
+            InlineElement::Text(".".to_string()),
+        ]);
+        
+        let reference = block.inline_spans[1];
+        assert_eq!(&content[reference.start.offset..reference.end.offset], "{{@kay-quote}}");
+        
+        // Children follow their parent in the spans
+        let spans: Vec<&str> = block.inline_spans[5..9].iter()
+            .map(|span| &content[span.start.offset..span.end.offset])
+            .collect();
+        assert_eq!(spans, vec!["{{bold **not** nested}}", "**not**", "not", " nested"]);
+    }
+    
+    #[test]
+    fn test_quoted_attribute_escapes() {
+        let mut parser = BloxParser::new();
+        let content = "#section \"Say \\\"hi\\\"\" id=greeting\n";
+        
+        let doc = parser.parse_string(content).unwrap();
+        assert_eq!(doc.blocks[0].get_attribute("title"), Some("Say \"hi\""));
+        assert_eq!(doc.blocks[0].get_attribute("id"), Some("greeting"));
+        assert!(parser.diagnostics().is_empty());
+    }
+    
+    #[test]
+    fn test_strict_nesting() {
+        let content = "#section \"A\"\n###p\nskipped a level\n##p\nfine\n";
+        
+        let mut parser = BloxParser::with_options(ParserOptions { strict_nesting: true, ..ParserOptions::default() });
+        let error = parser.parse_string(content).unwrap_err();
+        assert!(matches!(error, BloxError::InvalidNesting { line: 2, expected: 2, actual: 3 }));
+        
+        let mut parser = BloxParser::with_options(ParserOptions { strict_nesting: true, ..ParserOptions::default() });
+        let output = parser.parse_with_diagnostics(content);
+        assert_eq!(output.diagnostics.len(), 1);
+        assert_eq!(output.diagnostics[0].severity, Severity::Error);
+        
+        // The misnested block is reported, not moved
+        let section = &output.document.blocks[0];
+        assert_eq!(section.children.len(), 2);
+        assert_eq!(section.children[0].level, 3);
+        
+        let mut parser = BloxParser::new();
+        assert!(parser.parse_string(content).is_ok());
+        assert_eq!(parser.diagnostics()[0].severity, Severity::Warning);
+    }
+    
+    #[test]
+    fn test_verbatim_blocks() {
+        let content = r#"#section "Code"
+##code lang=python
+# compute total
+total = a + b  // not a comment
+
+###note kept as content
+print(total)
+
+##p
+after
+#c sh
+```
+#!/bin/sh
+#section inside the fence
+
+```
+##p
+child
+#code c raw=true
+#include <stdio.h>
+#section "End"
+"#;
+        
+        let mut parser = BloxParser::new();
+        let output = parser.parse_with_diagnostics(content);
+        assert!(output.diagnostics.is_empty(), "{:?}", output.diagnostics);
+        
+        let section = &output.document.blocks[0];
+        assert_eq!(section.children.len(), 2);
+        assert_eq!(
+            section.children[0].content,
+            "# compute total\ntotal = a + b  // not a comment\n\n###note kept as content\nprint(total)"
+        );
+        assert!(section.children[0].inline_elements.is_empty());
+        assert_eq!(section.children[1].content, "after");
+        
+        // Lines after the closing fence are read as Blox again
+        let fenced = &output.document.blocks[1];
+        assert_eq!(fenced.content, "#!/bin/sh\n#section inside the fence\n");
+        assert_eq!(fenced.children[0].content, "child");
+        
+        let raw = &output.document.blocks[2];
+        assert_eq!(raw.content, "#include <stdio.h>");
+        assert_eq!(output.document.blocks[3].get_attribute("title"), Some("End"));
+    }
+    
+    #[test]
+    fn test_indented_blocks() {
+        let content = "#section Outer\nIntro\n  ##p\n  text\n    deeper\n  ##code python\n  def f():\n    #todo\n      return 1\n  #q\n  after\n";
+        
+        let mut parser = BloxParser::with_options(ParserOptions { indent: IndentPolicy::Header, ..ParserOptions::default() });
+        let output = parser.parse_with_diagnostics(content);
+        assert!(output.diagnostics.is_empty(), "{:?}", output.diagnostics);
+        
+        let section = &output.document.blocks[0];
+        assert_eq!(section.content, "Intro");
+        assert_eq!(section.children[0].content, "text\n  deeper");
+        assert_eq!(section.children[0].content_span.start.column, 3);
+        
+        // Only a header indented no further than the code block's own ends it
+        assert_eq!(section.children[1].content, "def f():\n  #todo\n    return 1");
+        assert_eq!(output.document.blocks[1].content, "after");
+        
+        // The default reads indented headers as content, as written
+        let doc = BloxParser::new().parse_string(content).unwrap();
+        assert_eq!(doc.blocks.len(), 1);
+        assert!(doc.blocks[0].content.starts_with("Intro\n  ##p\n  text"));
+        
+        let doc = BloxParser::new().parse_string("#p\nTagged\n  #hashtag here\n#list\n- item\n  #word\n").unwrap();
+        assert_eq!(doc.blocks.len(), 2);
+        assert_eq!(doc.blocks[0].content, "Tagged\n  #hashtag here");
+        assert_eq!(doc.blocks[1].list_items[0].content, "item\n  #word");
+        
+        // Common strips what the content lines share, whatever the header's indentation
+        let content = "#section\n    ##p\n        text\n          more\n";
+        let mut parser = BloxParser::with_options(ParserOptions { indent: IndentPolicy::Header, ..ParserOptions::default() });
+        let doc = parser.parse_string(content).unwrap();
+        assert_eq!(doc.blocks[0].children[0].content, "    text\n      more");
+        
+        let mut parser = BloxParser::with_options(ParserOptions { indent: IndentPolicy::Common, ..ParserOptions::default() });
+        let doc = parser.parse_string(content).unwrap();
+        let paragraph = &doc.blocks[0].children[0];
+        assert_eq!(paragraph.content, "text\n  more");
+        assert_eq!(&content[paragraph.content_span.start.offset..paragraph.content_span.end.offset], "text\n          more");
+    }
+    
+    #[test]
+    fn test_metadata_blocks() {
+        let content = "#meta author=\"Ada, Grace\" version=\"0.1\" tags=blox\n#document \"Blox Example\" created=2024-05-01 modified=soon\n\n#section \"Welcome\"\nHello\n#meta note=kept\nThis block has content\n";
+        
+        let mut parser = BloxParser::new();
+        let output = parser.parse_with_diagnostics(content);
+        let document = &output.document;
+        
+        let keys: Vec<_> = document.metadata.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec!["author", "version", "tags", "created", "modified", "title"]);
+        assert_eq!(document.metadata.title(), Some("Blox Example"));
+        assert_eq!(document.metadata.authors(), vec!["Ada", "Grace"]);
+        assert_eq!(document.metadata.created().unwrap().to_string(), "2024-05-01");
+        
+        // The lifted blocks are gone; the one with content is kept
+        assert_eq!(document.blocks.len(), 2);
+        assert_eq!(document.blocks[1].block_type, BlockType::Custom("meta".to_string()));
+        
+        let codes: Vec<_> = output.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["invalid-metadata-date", "metadata-content"]);
+        assert_eq!(output.diagnostics[0].span.start.line, 2);
+    }
+    
+    #[test]
+    fn test_table_blocks() {
+        let content = "#tbl \"Scores\" header=true\nName, Note\n\"Smith, J.\", \"two\nlines\"\n{{cell colspan=2 Total}}\nLee, 3, extra\n";
+        
+        let mut parser = BloxParser::new();
+        let output = parser.parse_with_diagnostics(content);
+        let table = output.document.blocks[0].table.as_ref().unwrap();
+        
+        assert_eq!(table.caption.as_deref(), Some("Scores"));
+        assert!(table.header.as_ref().unwrap().cells[0].is_header);
+        assert_eq!(table.rows.len(), 3);
+        assert_eq!(table.rows[0].cells[1].content, "two\nlines");
+        assert_eq!(table.rows[1].cells[0].colspan, 2);
+        
+        assert_eq!(output.diagnostics.len(), 1);
+        let ragged = &output.diagnostics[0];
+        assert_eq!(ragged.code, "ragged-table-row");
+        assert_eq!(ragged.span.start.line, 6);
+        assert_eq!(&content[ragged.span.start.offset..ragged.span.end.offset], "Lee, 3, extra");
+        
+        // Column problems point at the attribute
+        let content = "#table types=text,money\na, b\n";
+        let output = BloxParser::new().parse_with_diagnostics(content);
+        let spec = &output.diagnostics[0];
+        assert_eq!(spec.code, "invalid-column-spec");
+        assert_eq!(&content[spec.span.start.offset..spec.span.end.offset], "text,money");
+    }
+    
+    #[test]
+    fn test_list_items() {
+        let content = "#list type=ordered start=3\n- One\n-- One.a\n--- One.a.i\n-- One.b\ncontinued\n- **Two**\n";
+        let document = BloxParser::new().parse_string(content).unwrap();
+        let items = &document.blocks[0].list_items;
+        
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].children.len(), 2);
+        assert_eq!(items[0].children[0].children[0].content, "One.a.i");
+        assert_eq!(items[0].children[0].children[0].level, 3);
+        assert_eq!(items[0].children[1].content, "One.b\ncontinued");
+        assert_eq!((items[0].number, items[1].number), (Some(3), Some(4)));
+        assert_eq!(items[0].children[1].number, Some(2));
+        assert!(matches!(&items[1].inline_elements[0], InlineElement::Bold(_)));
+        
+        // `item` blocks are items too, after any written with dashes
+        let content = "#list type=check\n- [x] Shorthand\n##item checked=true\nFirst\n- Sub\n###item\nSecond\n##item\n{{checked=true Third}}\n";
+        let document = BloxParser::new().parse_string(content).unwrap();
+        let items = &document.blocks[0].list_items;
+        
+        let summary: Vec<(&str, bool)> = items.iter().map(|item| (item.content.as_str(), item.item_type == ListItemType::Checked)).collect();
+        assert_eq!(summary, vec![("Shorthand", true), ("First", true), ("Third", true)]);
+        assert_eq!(items[1].children.iter().map(|item| item.content.as_str()).collect::<Vec<_>>(), vec!["Sub", "Second"]);
+        assert_eq!(items[1].children[1].level, 2);
+        assert_eq!(items[0].number, None);
+        
+        let content = "#list type=definition\n- Loom: A weaving frame\n";
+        let document = BloxParser::new().parse_string(content).unwrap();
+        assert_eq!(document.blocks[0].list_items[0].item_type, ListItemType::Definition { term: "Loom".to_string() });
+        
+        // A bad start is reported and the items are numbered from 1
+        let mut parser = BloxParser::new();
+        let output = parser.parse_with_diagnostics("#list type=ordered start=x\n- One\n- Two\n");
+        assert_eq!(output.diagnostics.len(), 1);
+        let numbers: Vec<_> = output.document.blocks[0].list_items.iter().map(|item| item.number).collect();
+        assert_eq!(numbers, vec![Some(1), Some(2)]);
+        
+        let document = BloxParser::new().parse_string("#list type=ordered start=18446744073709551615\n- One\n- Two\n").unwrap();
+        assert_eq!(document.blocks[0].list_items[1].number, Some(usize::MAX));
+    }
 }
@@ -0,0 +1,306 @@
+//! Quiz content (spec 3.2.5).
+//!
+//! A `#quiz` block holds questions written either as a line starting with
+//! `?` in its content or as `##question` child blocks. The lines after a
+//! question's prompt give its choices (`- [x] right`, `- [ ] wrong`),
+//! accepted answers (`= Paris`) and explanation (`> because`). A question's
+//! type comes from `type=` or is inferred from those lines, and is then
+//! checked against them.
+
+use crate::blox::ast::{Attribute, Block, QuestionType, Quiz, QuizChoice, QuizQuestion, Span};
+use crate::blox::attributes::AttributeScanner;
+
+/// A problem in a quiz, located in the source
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuizIssue {
+    pub code: &'static str,
+    pub span: Span,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedQuiz {
+    pub quiz: Quiz,
+    pub issues: Vec<QuizIssue>,
+}
+
+/// Read the questions in a quiz block's content, placing their spans with
+/// `place`, followed by its `question` children
+pub fn read_quiz(block: &Block, place: &impl Fn(Span) -> Span) -> ParsedQuiz {
+    let mut issues = Vec::new();
+    let mut description = Vec::new();
+    let mut drafts: Vec<Draft> = Vec::new();
+    let mut line_start = 0;
+    
+    for line in block.content.split('\n') {
+        let range = line_start..line_start + line.len();
+        line_start = range.end + 1;
+        
+        match line.trim_start().strip_prefix('?') {
+            Some(rest) => {
+                let span = place(Span::of_range(&block.content, range.start, range.end));
+                drafts.push(Draft::shorthand(rest.trim(), span));
+            }
+            None => match drafts.last_mut() {
+                Some(draft) => draft.read_line(line),
+                None if !line.trim().is_empty() => description.push(line.trim()),
+                None => {}
+            },
+        }
+    }
+    
+    for child in block.children.iter().filter(|child| child.is_quiz_question()) {
+        let mut draft = Draft::new(child.attributes.clone(), child.header_span);
+        for line in child.content.split('\n') {
+            draft.read_line(line);
+        }
+        drafts.push(draft);
+    }
+    
+    ParsedQuiz {
+        quiz: Quiz {
+            title: block.get_attribute("title").map(str::to_string),
+            description: description.join("\n"),
+            questions: drafts.into_iter().map(|draft| draft.finish(&mut issues)).collect(),
+        },
+        issues,
+    }
+}
+
+/// A question as its lines are read
+struct Draft {
+    attributes: Vec<Attribute>,
+    span: Span,
+    prompt: Vec<String>,
+    choices: Vec<QuizChoice>,
+    answers: Vec<String>,
+    explanation: Vec<String>,
+}
+
+impl Draft {
+    fn new(attributes: Vec<Attribute>, span: Span) -> Self {
+        Self {
+            attributes,
+            span,
+            prompt: Vec::new(),
+            choices: Vec::new(),
+            answers: Vec::new(),
+            explanation: Vec::new(),
+        }
+    }
+    
+    /// A question from the text after `?`, which may be a whole
+    /// `{{key=value prompt}}` giving its attributes
+    fn shorthand(text: &str, span: Span) -> Self {
+        let mut attributes = Vec::new();
+        let mut prompt = text;
+        if let Some(inner) = text.strip_prefix("{{").and_then(|rest| rest.strip_suffix("}}")) {
+            let (tokens, content_start) = AttributeScanner::keyed_prefix(inner);
+            if !tokens.is_empty() {
+                attributes = tokens.into_iter().filter_map(|token| Some(Attribute::new(token.key?.0, token.value))).collect();
+                prompt = inner[content_start..].trim();
+            }
+        }
+        
+        let mut draft = Self::new(attributes, span);
+        if !prompt.is_empty() {
+            draft.prompt.push(prompt.to_string());
+        }
+        draft
+    }
+    
+    fn read_line(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+        
+        if let Some(rest) = line.strip_prefix('-') {
+            let rest = rest.trim_start();
+            let choice = if let Some(text) = rest.strip_prefix("[x]").or_else(|| rest.strip_prefix("[X]")) {
+                QuizChoice { content: text.trim().to_string(), correct: true }
+            } else {
+                QuizChoice { content: rest.strip_prefix("[ ]").unwrap_or(rest).trim().to_string(), correct: false }
+            };
+            self.choices.push(choice);
+        } else if let Some(answer) = line.strip_prefix('=') {
+            self.answers.push(answer.trim().to_string());
+        } else if let Some(explanation) = line.strip_prefix('>') {
+            self.explanation.push(explanation.trim().to_string());
+        } else if !self.explanation.is_empty() {
+            self.explanation.push(line.to_string());
+        } else {
+            self.prompt.push(line.to_string());
+        }
+    }
+    
+    fn get_attribute(&self, key: &str) -> Option<&Attribute> {
+        self.attributes.iter().rev().find(|attr| attr.key == key)
+    }
+    
+    /// Where a problem with an attribute is reported: at its value when the
+    /// question is a block, otherwise at the question
+    fn attribute_span(&self, attribute: &Attribute) -> Span {
+        if attribute.value_span.is_empty() { self.span } else { attribute.value_span }
+    }
+    
+    fn finish(self, issues: &mut Vec<QuizIssue>) -> QuizQuestion {
+        let mut issue = |code: &'static str, span: Span, message: String| issues.push(QuizIssue { code, span, message });
+        
+        let declared = self.get_attribute("type").and_then(|attribute| {
+            let question_type = QuestionType::from_str(&attribute.value);
+            if question_type.is_none() {
+                issue(
+                    "unknown-question-type",
+                    self.attribute_span(attribute),
+                    format!("Unknown question type `{}`; expected single, multiple, truefalse or short", attribute.value),
+                );
+            }
+            question_type
+        });
+        let question_type = declared.unwrap_or_else(|| self.inferred_type());
+        
+        let points = match self.get_attribute("points") {
+            Some(attribute) => attribute.value.trim().parse().unwrap_or_else(|_| {
+                issue(
+                    "invalid-question-points",
+                    self.attribute_span(attribute),
+                    format!("`points={}` is not a whole number", attribute.value),
+                );
+                1
+            }),
+            None => 1,
+        };
+        
+        let mut answers = self.answers;
+        if question_type == QuestionType::TrueFalse {
+            answers.iter_mut().for_each(|answer| *answer = answer.to_lowercase());
+        }
+        let question = QuizQuestion {
+            question_type,
+            prompt: self.prompt.join("\n"),
+            choices: self.choices,
+            answers,
+            explanation: (!self.explanation.is_empty()).then(|| self.explanation.join("\n")),
+            points,
+            id: self.attributes.iter().rev().find(|attr| attr.key == "id").map(|attr| attr.value.clone()),
+            span: self.span,
+        };
+        for message in shape_problems(&question) {
+            issue("invalid-question", question.span, message);
+        }
+        question
+    }
+    
+    fn inferred_type(&self) -> QuestionType {
+        if !self.choices.is_empty() {
+            if self.choices.iter().filter(|choice| choice.correct).count() > 1 {
+                QuestionType::MultipleChoice
+            } else {
+                QuestionType::SingleChoice
+            }
+        } else if self.answers.len() == 1 && is_boolean(&self.answers[0]) {
+            QuestionType::TrueFalse
+        } else {
+            QuestionType::ShortAnswer
+        }
+    }
+}
+
+fn is_boolean(answer: &str) -> bool {
+    answer.eq_ignore_ascii_case("true") || answer.eq_ignore_ascii_case("false")
+}
+
+/// What is wrong with a question's prompt, choices and answers for its type
+pub(crate) fn shape_problems(question: &QuizQuestion) -> Vec<String> {
+    let mut problems = Vec::new();
+    if question.prompt.trim().is_empty() {
+        problems.push("Question has no prompt".to_string());
+    }
+    
+    let correct = question.choices.iter().filter(|choice| choice.correct).count();
+    let name = question.question_type.description();
+    match question.question_type {
+        QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+            if question.choices.len() < 2 {
+                problems.push(format!("A {} question needs at least two choices", name));
+            }
+            if question.question_type == QuestionType::SingleChoice && correct != 1 {
+                problems.push(format!("A {} question needs exactly one correct choice, found {}", name, correct));
+            }
+            if question.question_type == QuestionType::MultipleChoice && correct == 0 {
+                problems.push(format!("A {} question needs at least one correct choice", name));
+            }
+            if !question.answers.is_empty() {
+                problems.push(format!("A {} question takes `- [x]` choices, not `=` answers", name));
+            }
+        }
+        QuestionType::TrueFalse => {
+            if !question.choices.is_empty() {
+                problems.push(format!("A {} question takes `= true` or `= false`, not choices", name));
+            }
+            if question.answers.len() != 1 || !is_boolean(&question.answers[0]) {
+                problems.push(format!("A {} question needs one answer, `= true` or `= false`", name));
+            }
+        }
+        QuestionType::ShortAnswer => {
+            if !question.choices.is_empty() {
+                problems.push(format!("A {} question takes `=` answers, not choices", name));
+            }
+            if question.answers.is_empty() {
+                problems.push(format!("A {} question needs at least one `=` answer", name));
+            }
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::parser::BloxParser;
+    
+    #[test]
+    fn test_read_quiz() {
+        let source = "#quiz \"Basics\"\nAnswer every question.\n? Capital of France?\n- [ ] Lyon\n- [x] Paris\n> Paris has been the capital since 987.\n? {{points=2 id=primes Which are prime?}}\n- [x] 2\n- [ ] 4\n- [x] 5\n? The sun is a star.\n= True\n##question type=short\nName a noble gas.\n= Neon\n= Argon\n";
+        let document = BloxParser::new().parse_string(source).unwrap();
+        let quiz = document.blocks[0].quiz.as_ref().unwrap();
+        
+        assert_eq!(quiz.title.as_deref(), Some("Basics"));
+        assert_eq!(quiz.description, "Answer every question.");
+        let types: Vec<QuestionType> = quiz.questions.iter().map(|question| question.question_type).collect();
+        assert_eq!(types, vec![QuestionType::SingleChoice, QuestionType::MultipleChoice, QuestionType::TrueFalse, QuestionType::ShortAnswer]);
+        
+        let capital = &quiz.questions[0];
+        assert_eq!(capital.prompt, "Capital of France?");
+        assert_eq!(capital.choices[1], QuizChoice { content: "Paris".to_string(), correct: true });
+        assert_eq!(capital.explanation.as_deref(), Some("Paris has been the capital since 987."));
+        assert_eq!(&source[capital.span.start.offset..capital.span.end.offset], "? Capital of France?");
+        
+        assert_eq!(quiz.questions[1].points, 2);
+        assert_eq!(quiz.questions[1].id.as_deref(), Some("primes"));
+        assert_eq!(quiz.questions[2].answers, vec!["true"]);
+        assert_eq!(quiz.questions[3].answers, vec!["Neon", "Argon"]);
+        assert_eq!(quiz.questions[3].span.start.line, 13);
+        assert_eq!(quiz.total_points(), 5);
+    }
+    
+    #[test]
+    fn test_quiz_issues() {
+        let source = "#quiz\n? {{type=single Pick one}}\n- [x] A\n- [x] B\n? Lonely\n- [x] Only\n? {{type=essay points=x Why?}}\n= Because\n##question type=truefalse points=many\nTrue?\n= maybe\n";
+        let output = BloxParser::new().parse_with_diagnostics(source);
+        let issues: Vec<(&str, usize)> = output.diagnostics.iter().map(|diagnostic| (diagnostic.code.as_str(), diagnostic.span.start.line)).collect();
+        assert_eq!(issues, vec![
+            ("invalid-question", 2),
+            ("invalid-question", 5),
+            ("unknown-question-type", 7),
+            ("invalid-question-points", 7),
+            ("invalid-question-points", 9),
+            ("invalid-question", 9),
+        ]);
+        
+        let points = output.diagnostics.iter().find(|diagnostic| diagnostic.code == "invalid-question-points" && diagnostic.span.start.line == 9).unwrap();
+        assert_eq!(&source[points.span.start.offset..points.span.end.offset], "many");
+        assert!(output.diagnostics[0].message.contains("exactly one correct choice, found 2"));
+    }
+}
@@ -0,0 +1,484 @@
+//! Quiz export for learning management systems.
+//!
+//! The `#quiz` blocks of a document are written as Moodle GIFT text or as an
+//! IMS QTI 2.1 content package: a manifest, one assessment test per quiz
+//! and one assessment item per question. A question that a format cannot
+//! score, such as a single choice question without a correct choice, is
+//! exported as text only and reported as an `unsupported-question`
+//! diagnostic.
+
+use std::collections::HashSet;
+
+use crate::blox::ast::{Block, BlockType, Document, QuestionType, Quiz, QuizQuestion};
+use crate::blox::decoder::{block_quiz, html_escape, html_inline, markdown_inline, question_choices};
+use crate::blox::diagnostic::Diagnostic;
+use crate::blox::quiz::shape_problems;
+
+/// One file of a multi-file output
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageFile {
+    /// Path within the package, with `/` separators
+    pub path: String,
+    pub content: String,
+}
+
+/// The files an export produced and the problems found while writing them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Package {
+    pub files: Vec<PackageFile>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Package {
+    /// The files as an uncompressed zip archive
+    pub fn to_zip(&self) -> Vec<u8> {
+        let mut archive = Vec::new();
+        let mut directory = Vec::new();
+        for file in &self.files {
+            let offset = archive.len() as u32;
+            let name = file.path.as_bytes();
+            let data = file.content.as_bytes();
+            let crc = crc32(data);
+            
+            // Version 2.0, UTF-8 names, stored, dated 1980-01-01
+            let fields = |out: &mut Vec<u8>| {
+                for value in [20u16, 0x0800, 0, 0, 0x21] {
+                    out.extend(value.to_le_bytes());
+                }
+                for value in [crc, data.len() as u32, data.len() as u32] {
+                    out.extend(value.to_le_bytes());
+                }
+                out.extend((name.len() as u16).to_le_bytes());
+                out.extend(0u16.to_le_bytes());
+            };
+            
+            archive.extend(0x04034b50u32.to_le_bytes());
+            fields(&mut archive);
+            archive.extend(name);
+            archive.extend(data);
+            
+            directory.extend(0x02014b50u32.to_le_bytes());
+            directory.extend(20u16.to_le_bytes());
+            fields(&mut directory);
+            for value in [0u16, 0, 0] {
+                directory.extend(value.to_le_bytes());
+            }
+            directory.extend(0u32.to_le_bytes());
+            directory.extend(offset.to_le_bytes());
+            directory.extend(name);
+        }
+        
+        let directory_offset = archive.len() as u32;
+        archive.extend(&directory);
+        archive.extend(0x06054b50u32.to_le_bytes());
+        for value in [0u16, 0, self.files.len() as u16, self.files.len() as u16] {
+            archive.extend(value.to_le_bytes());
+        }
+        archive.extend((directory.len() as u32).to_le_bytes());
+        archive.extend(directory_offset.to_le_bytes());
+        archive.extend(0u16.to_le_bytes());
+        archive
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// Every quiz in the document, in document order
+fn quizzes(document: &Document) -> Vec<Quiz> {
+    fn collect(blocks: &[Block], quizzes: &mut Vec<Quiz>) {
+        for block in blocks {
+            if block.block_type == BlockType::Quiz {
+                quizzes.push(block_quiz(block));
+            }
+            collect(&block.children, quizzes);
+        }
+    }
+    
+    let mut quizzes = Vec::new();
+    collect(&document.blocks, &mut quizzes);
+    quizzes
+}
+
+/// Why a question cannot be scored in `format`, if it cannot
+fn unsupported(question: &QuizQuestion, format: &str) -> Option<Diagnostic> {
+    let mut problems = shape_problems(question);
+    let correct = question.choices.iter().filter(|choice| choice.correct).count();
+    if format == "GIFT" && question.question_type == QuestionType::MultipleChoice && correct > 10 {
+        problems.push("GIFT cannot share the marks of a question among more than 10 correct choices".to_string());
+    }
+    
+    let problem = problems.into_iter().next()?;
+    Some(Diagnostic::warning(
+        "unsupported-question",
+        question.span,
+        format!("{}; exported to {} as text without answers", problem, format),
+    ))
+}
+
+/// Names for the questions of every quiz, unique across the document: the
+/// question's `id` made a valid XML name, or `q` and its number
+fn question_names(quizzes: &[Quiz]) -> Vec<Vec<String>> {
+    let mut used = HashSet::new();
+    let mut number = 0;
+    quizzes
+        .iter()
+        .map(|quiz| {
+            quiz.questions
+                .iter()
+                .map(|question| {
+                    number += 1;
+                    let base = match &question.id {
+                        Some(id) => xml_name(id),
+                        None => format!("q{}", number),
+                    };
+                    let mut name = base.clone();
+                    let mut suffix = 1;
+                    while !used.insert(name.clone()) {
+                        suffix += 1;
+                        name = format!("{}-{}", base, suffix);
+                    }
+                    name
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn xml_name(id: &str) -> String {
+    let name: String = id
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '_' | '-' | '.') { c } else { '-' })
+        .collect();
+    match name.chars().next() {
+        Some(first) if first.is_alphabetic() || first == '_' => name,
+        _ => format!("q-{}", name),
+    }
+}
+
+/// The quizzes of `document` as Moodle GIFT text
+pub fn to_gift(document: &Document) -> Package {
+    let quizzes = quizzes(document);
+    let names = question_names(&quizzes);
+    let mut diagnostics = Vec::new();
+    let mut sections = Vec::new();
+    
+    for (quiz, names) in quizzes.iter().zip(&names) {
+        let mut lines = Vec::new();
+        if let Some(title) = &quiz.title {
+            lines.push(format!("$CATEGORY: {}", title));
+            lines.push(String::new());
+        }
+        if !quiz.description.is_empty() {
+            lines.extend(quiz.description.lines().map(|line| format!("// {}", line)));
+            lines.push(String::new());
+        }
+        
+        for (question, name) in quiz.questions.iter().zip(names) {
+            let prompt = format!("::{}::[markdown]{}", gift_escape(name), gift_escape(&markdown_inline(&question.prompt)));
+            if let Some(diagnostic) = unsupported(question, "GIFT") {
+                diagnostics.push(diagnostic);
+                lines.push(prompt);
+                lines.push(String::new());
+                continue;
+            }
+            
+            lines.push(format!("{} {{", prompt));
+            lines.extend(gift_answers(question));
+            if let Some(explanation) = &question.explanation {
+                lines.push(format!("####{}", gift_escape(&markdown_inline(explanation))));
+            }
+            lines.push("}".to_string());
+            lines.push(String::new());
+        }
+        sections.push(lines.join("\n"));
+    }
+    
+    Package {
+        files: vec![PackageFile { path: "quiz.gift".to_string(), content: sections.join("\n") }],
+        diagnostics,
+    }
+}
+
+fn gift_answers(question: &QuizQuestion) -> Vec<String> {
+    let choice = |text: &str| gift_escape(&markdown_inline(text));
+    match question.question_type {
+        QuestionType::SingleChoice => question.choices
+            .iter()
+            .map(|option| format!("{}{}", if option.correct { "=" } else { "~" }, choice(&option.content)))
+            .collect(),
+        QuestionType::MultipleChoice => {
+            let correct = question.choices.iter().filter(|option| option.correct).count();
+            let share = format!("{:.5}", 100.0 / correct as f64);
+            let share = share.trim_end_matches('0').trim_end_matches('.');
+            question.choices
+                .iter()
+                .map(|option| match option.correct {
+                    true => format!("~%{}%{}", share, choice(&option.content)),
+                    false => format!("~%-100%{}", choice(&option.content)),
+                })
+                .collect()
+        }
+        QuestionType::TrueFalse => vec![question.answers[0].to_uppercase()],
+        QuestionType::ShortAnswer => question.answers.iter().map(|answer| format!("={}", gift_escape(answer))).collect(),
+    }
+}
+
+/// Escape the characters GIFT gives a meaning to
+fn gift_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '~' | '=' | '#' | '{' | '}' | ':') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+const QTI_NAMESPACE: &str = "xmlns=\"http://www.imsglobal.org/xsd/imsqti_v2p1\" \
+    xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+    xsi:schemaLocation=\"http://www.imsglobal.org/xsd/imsqti_v2p1 http://www.imsglobal.org/xsd/qti/qtiv2p1/imsqti_v2p1.xsd\"";
+
+/// The quizzes of `document` as an IMS QTI 2.1 content package
+pub fn to_qti(document: &Document) -> Package {
+    let quizzes = quizzes(document);
+    let names = question_names(&quizzes);
+    let mut diagnostics = Vec::new();
+    let mut files = Vec::new();
+    let mut resources = Vec::new();
+    
+    for (i, (quiz, names)) in quizzes.iter().zip(&names).enumerate() {
+        let test = format!("quiz-{}", i + 1);
+        let mut refs = Vec::new();
+        for (question, name) in quiz.questions.iter().zip(names) {
+            let scored = match unsupported(question, "QTI") {
+                Some(diagnostic) => {
+                    diagnostics.push(diagnostic);
+                    false
+                }
+                None => true,
+            };
+            let path = format!("items/{}.xml", name);
+            files.push(PackageFile { path: path.clone(), content: qti_item(question, name, scored) });
+            resources.push(format!(
+                "    <resource identifier=\"{}\" type=\"imsqti_item_xmlv2p1\" href=\"{}\">\n      <file href=\"{}\"/>\n    </resource>",
+                name, path, path
+            ));
+            refs.push(format!("        <assessmentItemRef identifier=\"{}\" href=\"../{}\"/>", name, path));
+        }
+        
+        let path = format!("tests/{}.xml", test);
+        files.push(PackageFile { path: path.clone(), content: qti_test(quiz, &test, &refs) });
+        let dependencies: String = names.iter().map(|name| format!("\n      <dependency identifierref=\"{}\"/>", name)).collect();
+        resources.push(format!(
+            "    <resource identifier=\"{}\" type=\"imsqti_test_xmlv2p1\" href=\"{}\">\n      <file href=\"{}\"/>{}\n    </resource>",
+            test, path, path, dependencies
+        ));
+    }
+    
+    let manifest = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <manifest xmlns=\"http://www.imsglobal.org/xsd/imscp_v1p1\" identifier=\"manifest\">\n\
+         \x20 <metadata>\n    <schema>QTIv2.1 Package</schema>\n    <schemaversion>1.0.0</schemaversion>\n  </metadata>\n\
+         \x20 <organizations/>\n  <resources>\n{}\n  </resources>\n</manifest>\n",
+        resources.join("\n")
+    );
+    files.insert(0, PackageFile { path: "imsmanifest.xml".to_string(), content: manifest });
+    
+    Package { files, diagnostics }
+}
+
+fn qti_test(quiz: &Quiz, identifier: &str, refs: &[String]) -> String {
+    let title = html_escape(quiz.title.as_deref().unwrap_or(identifier));
+    let rubric = match quiz.description.is_empty() {
+        true => String::new(),
+        false => format!("        <rubricBlock view=\"candidate\"><p>{}</p></rubricBlock>\n", html_inline(&quiz.description)),
+    };
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <assessmentTest {} identifier=\"{}\" title=\"{}\">\n\
+         \x20 <outcomeDeclaration identifier=\"SCORE\" cardinality=\"single\" baseType=\"float\"/>\n\
+         \x20 <testPart identifier=\"part\" navigationMode=\"linear\" submissionMode=\"individual\">\n\
+         \x20   <assessmentSection identifier=\"section\" title=\"{}\" visible=\"true\">\n\
+         {}{}\n\
+         \x20   </assessmentSection>\n\
+         \x20 </testPart>\n\
+         \x20 <outcomeProcessing>\n\
+         \x20   <setOutcomeValue identifier=\"SCORE\"><sum><testVariables variableIdentifier=\"SCORE\"/></sum></setOutcomeValue>\n\
+         \x20 </outcomeProcessing>\n\
+         </assessmentTest>\n",
+        QTI_NAMESPACE, identifier, title, title, rubric, refs.join("\n")
+    )
+}
+
+fn qti_item(question: &QuizQuestion, identifier: &str, scored: bool) -> String {
+    let mut lines = vec![
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>".to_string(),
+        format!("<assessmentItem {} identifier=\"{}\" title=\"{}\" adaptive=\"false\" timeDependent=\"false\">", QTI_NAMESPACE, identifier, identifier),
+    ];
+    let prompt = html_inline(&question.prompt);
+    
+    if !scored {
+        lines.push(format!("  <itemBody>\n    <p>{}</p>\n  </itemBody>", prompt));
+        lines.push("</assessmentItem>".to_string());
+        return lines.join("\n") + "\n";
+    }
+    
+    let choices = question_choices(question);
+    let (declaration, interaction, matched) = if question.question_type == QuestionType::ShortAnswer {
+        let entries: String = question.answers
+            .iter()
+            .map(|answer| format!("\n      <mapEntry mapKey=\"{}\" mappedValue=\"1\"/>", html_escape(answer)))
+            .collect();
+        let declaration = format!(
+            "  <responseDeclaration identifier=\"RESPONSE\" cardinality=\"single\" baseType=\"string\">\n    <mapping defaultValue=\"0\">{}\n    </mapping>\n  </responseDeclaration>",
+            entries
+        );
+        let interaction = format!("    <p>{}</p>\n    <p><textEntryInteraction responseIdentifier=\"RESPONSE\"/></p>", prompt);
+        let matched = "<gt><mapResponse identifier=\"RESPONSE\"/><baseValue baseType=\"float\">0</baseValue></gt>";
+        (declaration, interaction, matched)
+    } else {
+        let multiple = question.question_type == QuestionType::MultipleChoice;
+        let choice_name = |j: usize| match question.question_type {
+            QuestionType::TrueFalse => if j == 0 { "true".to_string() } else { "false".to_string() },
+            _ => format!("choice-{}", j + 1),
+        };
+        let values: String = choices
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, correct))| *correct)
+            .map(|(j, _)| format!("\n      <value>{}</value>", choice_name(j)))
+            .collect();
+        let declaration = format!(
+            "  <responseDeclaration identifier=\"RESPONSE\" cardinality=\"{}\" baseType=\"identifier\">\n    <correctResponse>{}\n    </correctResponse>\n  </responseDeclaration>",
+            if multiple { "multiple" } else { "single" },
+            values
+        );
+        let options: String = choices
+            .iter()
+            .enumerate()
+            .map(|(j, (content, _))| format!("\n      <simpleChoice identifier=\"{}\">{}</simpleChoice>", choice_name(j), html_inline(content)))
+            .collect();
+        let interaction = format!(
+            "    <choiceInteraction responseIdentifier=\"RESPONSE\" shuffle=\"false\" maxChoices=\"{}\">\n      <prompt>{}</prompt>{}\n    </choiceInteraction>",
+            if multiple { 0 } else { 1 },
+            prompt,
+            options
+        );
+        (declaration, interaction, "<match><variable identifier=\"RESPONSE\"/><correct identifier=\"RESPONSE\"/></match>")
+    };
+    
+    lines.push(declaration);
+    lines.push("  <outcomeDeclaration identifier=\"SCORE\" cardinality=\"single\" baseType=\"float\">\n    <defaultValue><value>0</value></defaultValue>\n  </outcomeDeclaration>".to_string());
+    lines.push(format!(
+        "  <outcomeDeclaration identifier=\"MAXSCORE\" cardinality=\"single\" baseType=\"float\">\n    <defaultValue><value>{}</value></defaultValue>\n  </outcomeDeclaration>",
+        question.points
+    ));
+    if question.explanation.is_some() {
+        lines.push("  <outcomeDeclaration identifier=\"FEEDBACK\" cardinality=\"single\" baseType=\"identifier\"/>".to_string());
+    }
+    lines.push(format!("  <itemBody>\n{}\n  </itemBody>", interaction));
+    
+    let mut processing = format!(
+        "  <responseProcessing>\n    <responseCondition>\n      <responseIf>\n        {}\n        <setOutcomeValue identifier=\"SCORE\"><baseValue baseType=\"float\">{}</baseValue></setOutcomeValue>\n      </responseIf>\n    </responseCondition>",
+        matched, question.points
+    );
+    if question.explanation.is_some() {
+        processing.push_str("\n    <setOutcomeValue identifier=\"FEEDBACK\"><baseValue baseType=\"identifier\">EXPLANATION</baseValue></setOutcomeValue>");
+    }
+    processing.push_str("\n  </responseProcessing>");
+    lines.push(processing);
+    
+    if let Some(explanation) = &question.explanation {
+        lines.push(format!(
+            "  <modalFeedback outcomeIdentifier=\"FEEDBACK\" identifier=\"EXPLANATION\" showHide=\"show\">{}</modalFeedback>",
+            html_inline(explanation)
+        ));
+    }
+    lines.push("</assessmentItem>".to_string());
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::parser::BloxParser;
+    
+    const SOURCE: &str = "#quiz \"Basics\"\nAnswer all.\n? Capital of *France*?\n- [ ] Lyon\n- [x] Paris\n> Since 987.\n? {{id=primes points=2 Which are prime?}}\n- [x] 2\n- [ ] 4\n- [x] 5\n? The sun is a star.\n= true\n? Name a noble gas.\n= Neon\n= Argon\n? Broken\n- [ ] A\n- [ ] B\n";
+    
+    fn document() -> Document {
+        BloxParser::new().parse_string(SOURCE).unwrap()
+    }
+    
+    #[test]
+    fn test_gift() {
+        let package = to_gift(&document());
+        assert_eq!(package.files.len(), 1);
+        assert_eq!(
+            package.files[0].content,
+            "$CATEGORY: Basics\n\n// Answer all.\n\n\
+             ::q1::[markdown]Capital of *France*? {\n~Lyon\n=Paris\n####Since 987.\n}\n\n\
+             ::primes::[markdown]Which are prime? {\n~%50%2\n~%-100%4\n~%50%5\n}\n\n\
+             ::q3::[markdown]The sun is a star. {\nTRUE\n}\n\n\
+             ::q4::[markdown]Name a noble gas. {\n=Neon\n=Argon\n}\n\n\
+             ::q5::[markdown]Broken\n"
+        );
+        
+        assert_eq!(package.diagnostics.len(), 1);
+        assert_eq!(package.diagnostics[0].code, "unsupported-question");
+        assert_eq!(package.diagnostics[0].span.start.line, 16);
+        assert_eq!(gift_escape("a=b {c}: #d"), "a\\=b \\{c\\}\\: \\#d");
+    }
+    
+    #[test]
+    fn test_qti() {
+        let package = to_qti(&document());
+        let paths: Vec<&str> = package.files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, vec!["imsmanifest.xml", "items/q1.xml", "items/primes.xml", "items/q3.xml", "items/q4.xml", "items/q5.xml", "tests/quiz-1.xml"]);
+        
+        let manifest = &package.files[0].content;
+        assert!(manifest.contains("<resource identifier=\"primes\" type=\"imsqti_item_xmlv2p1\" href=\"items/primes.xml\">"));
+        assert!(manifest.contains("<dependency identifierref=\"q5\"/>"));
+        
+        let capital = &package.files[1].content;
+        assert!(capital.contains("<correctResponse>\n      <value>choice-2</value>\n    </correctResponse>"));
+        assert!(capital.contains("<prompt>Capital of <em>France</em>?</prompt>"));
+        assert!(capital.contains("<modalFeedback outcomeIdentifier=\"FEEDBACK\" identifier=\"EXPLANATION\" showHide=\"show\">Since 987.</modalFeedback>"));
+        
+        let primes = &package.files[2].content;
+        assert!(primes.contains("cardinality=\"multiple\""));
+        assert!(primes.contains("maxChoices=\"0\""));
+        assert!(primes.contains("<baseValue baseType=\"float\">2</baseValue>"));
+        assert!(package.files[3].content.contains("<value>true</value>"));
+        assert!(package.files[4].content.contains("<mapEntry mapKey=\"Argon\" mappedValue=\"1\"/>"));
+        assert!(!package.files[5].content.contains("responseDeclaration"));
+        assert!(package.files[6].content.contains("<assessmentItemRef identifier=\"q4\" href=\"../items/q4.xml\"/>"));
+        assert_eq!(package.diagnostics.len(), 1);
+    }
+    
+    #[test]
+    fn test_zip() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        
+        let package = Package {
+            files: vec![PackageFile { path: "a.txt".to_string(), content: "hello".to_string() }],
+            diagnostics: Vec::new(),
+        };
+        let zip = package.to_zip();
+        assert!(zip.starts_with(b"PK\x03\x04"));
+        assert_eq!(&zip[30..40], b"a.txthello");
+        let end = zip.len() - 22;
+        assert_eq!(&zip[end..end + 4], b"PK\x05\x06");
+        assert_eq!(u16::from_le_bytes([zip[end + 10], zip[end + 11]]), 1);
+        assert_eq!(u32::from_le_bytes(zip[end + 16..end + 20].try_into().unwrap()), 40);
+    }
+}
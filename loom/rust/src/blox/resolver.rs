@@ -0,0 +1,227 @@
+//! Block ids and `{{@id}}` / `{{ref id=...}}` references.
+//!
+//! The resolver indexes every `id=` attribute by the path to its block,
+//! gives headings without an id a slug of their title, and reports
+//! duplicate ids and references to ids that do not exist.
+
+use std::collections::HashMap;
+
+use crate::blox::ast::{Block, BlockType, Document, InlineElement, Span};
+use crate::blox::diagnostic::Diagnostic;
+
+/// The block an id refers to
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceTarget {
+    /// Indexes from `Document.blocks` down through `children`
+    pub path: Vec<usize>,
+    pub line_number: usize,
+    pub header_span: Span,
+    /// Whether the id is a slug made for a heading rather than written in the source
+    pub generated: bool,
+}
+
+/// Every id in a document and the block it names
+#[derive(Debug, Clone, Default)]
+pub struct IdIndex {
+    targets: HashMap<String, ReferenceTarget>,
+}
+
+impl IdIndex {
+    pub fn get(&self, id: &str) -> Option<&ReferenceTarget> {
+        self.targets.get(id)
+    }
+    
+    pub fn contains(&self, id: &str) -> bool {
+        self.targets.contains_key(id)
+    }
+    
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// The ids of a document, and the problems found with them
+pub struct Resolution {
+    pub index: IdIndex,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+pub struct Resolver {
+    generate_slugs: bool,
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Self { generate_slugs: true }
+    }
+    
+    /// Whether headings without an `id` get one made from their title
+    pub fn with_slugs(generate_slugs: bool) -> Self {
+        Self { generate_slugs }
+    }
+    
+    pub fn resolve(&self, document: &Document) -> Resolution {
+        let mut index = IdIndex::default();
+        let mut diagnostics = Vec::new();
+        let mut path = Vec::new();
+        
+        // Written ids first, so a generated slug never takes one of them
+        walk(&document.blocks, &mut path, &mut |block, path| {
+            let Some(attribute) = block.attributes.iter().find(|attr| attr.key == "id") else {
+                return;
+            };
+            
+            if let Some(existing) = index.get(&attribute.value) {
+                diagnostics.push(Diagnostic::error(
+                    "duplicate-id",
+                    attribute.value_span,
+                    format!("Id `{}` is already used on line {}", attribute.value, existing.line_number),
+                ));
+                return;
+            }
+            
+            index.targets.insert(attribute.value.clone(), target(block, path, false));
+        });
+        
+        if self.generate_slugs {
+            walk(&document.blocks, &mut path, &mut |block, path| {
+                if !is_heading(block) || block.get_attribute("id").is_some() {
+                    return;
+                }
+                let Some(title) = block.get_attribute("title") else {
+                    return;
+                };
+                
+                let base = slugify(title);
+                let mut slug = base.clone();
+                let mut suffix = 2;
+                while index.contains(&slug) {
+                    slug = format!("{}-{}", base, suffix);
+                    suffix += 1;
+                }
+                index.targets.insert(slug, target(block, path, true));
+            });
+        }
+        
+        walk(&document.blocks, &mut path, &mut |block, _| {
+            if block.is_verbatim() {
+                return;
+            }
+            
+            let mut elements: Vec<&InlineElement> = block.inline_elements.iter().rev().collect();
+            let mut spans = block.inline_spans.iter();
+            while let Some(element) = elements.pop() {
+                let span = spans.next().copied().unwrap_or_default();
+                match element {
+                    InlineElement::Reference { id, .. } if !index.contains(id) => {
+                        diagnostics.push(Diagnostic::warning(
+                            "unresolved-reference",
+                            span,
+                            format!("No block has the id `{}`", id),
+                        ));
+                    }
+                    _ => elements.extend(element.children().iter().rev()),
+                }
+            }
+        });
+        
+        Resolution { index, diagnostics }
+    }
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Visit every block in document order with its path
+fn walk(blocks: &[Block], path: &mut Vec<usize>, visit: &mut impl FnMut(&Block, &[usize])) {
+    for (i, block) in blocks.iter().enumerate() {
+        path.push(i);
+        visit(block, path);
+        walk(&block.children, path, visit);
+        path.pop();
+    }
+}
+
+fn target(block: &Block, path: &[usize], generated: bool) -> ReferenceTarget {
+    ReferenceTarget {
+        path: path.to_vec(),
+        line_number: block.line_number,
+        header_span: block.header_span,
+        generated,
+    }
+}
+
+fn is_heading(block: &Block) -> bool {
+    matches!(block.block_type.canonical(), BlockType::Section)
+}
+
+/// Make an id from a title: lowercase, with runs of anything but letters
+/// and digits turned into a single `-`
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() { "section".to_string() } else { slug.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::BloxParser;
+    
+    #[test]
+    fn test_resolve_ids() {
+        let content = r#"#section "Getting Started" id=start
+See {{@quote}} and {{@missing}}.
+##quote id=quote
+Quoted
+#section "Getting Started"
+**{{ref id=getting-started}}**
+#h2 "Émigré: 中文 Title!"
+#p id=start
+Duplicate
+"#;
+        let document = BloxParser::new().parse_string(content).unwrap();
+        let resolution = Resolver::new().resolve(&document);
+        let index = &resolution.index;
+        
+        assert_eq!(index.get("quote").unwrap().path, vec![0, 0]);
+        assert_eq!(document.block_at(&[0, 0]).unwrap().content, "Quoted");
+        assert_eq!(index.get("start").unwrap().line_number, 1);
+        assert!(index.get("getting-started").unwrap().generated);
+        assert_eq!(index.get("émigré-中文-title").unwrap().path, vec![2]);
+        assert_eq!(index.len(), 4);
+        
+        let codes: Vec<_> = resolution.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["duplicate-id", "unresolved-reference"]);
+        let dangling = &resolution.diagnostics[1];
+        assert_eq!(&content[dangling.span.start.offset..dangling.span.end.offset], "{{@missing}}");
+    }
+    
+    #[test]
+    fn test_slugs() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("  --  "), "section");
+        
+        let document = BloxParser::new().parse_string("#h1 \"A\"\n#h1 \"A\"\n#p id=a-2\n").unwrap();
+        let index = Resolver::new().resolve(&document).index;
+        assert_eq!(index.get("a").unwrap().path, vec![0]);
+        assert_eq!(index.get("a-3").unwrap().path, vec![1]);
+        
+        assert!(Resolver::with_slugs(false).resolve(&document).index.get("a").is_none());
+    }
+}
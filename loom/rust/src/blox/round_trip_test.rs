@@ -0,0 +1,323 @@
+//! Round-trip properties: encoding a document and parsing the result gives
+//! back the same document, for documents made up at random from pieces
+//! that collide with Blox syntax.
+
+use crate::blox::ast::*;
+use crate::blox::encoder::*;
+use crate::blox::error::ParseResult;
+use crate::blox::format::{AttributeOrder, FormatStyle, QuoteStyle};
+use crate::blox::metadata::{MetadataBlock, MetadataEntry};
+use crate::blox::parser::*;
+
+/// Number of documents each property is checked against
+const CASES: u64 = 300;
+
+/// A xorshift generator, so every failing case can be replayed from its seed
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+    
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+    
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+    
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+const KEYS: &[&str] = &["title", "id", "lang", "src", "alt", "author", "caption", "class", "x_1", "ключ"];
+
+const VALUES: &[&str] = &[
+    "", " ", "plain", "two words", "\"quoted\"", "say \"hi\"", "it's", "'lead", "back\\slash", "trail\\",
+    "\\\"", "=eq", "a=b", "{{bold x}}", "#h2", "// not a comment", "tab\there", "ünï 🙂", "true",
+];
+
+/// Content lines that mean something else at the start of a line
+const LINES: &[&str] = &[
+    "plain text", "#h2 header", "##p", "#", "#######", "# spaced", "#1st", "// comment", "  // indented",
+    "\\#escaped", "\\\\#twice", "\\// slashes", "  #indented", "```", "````", "~~~", "```rust", "- item",
+    "-- [x] nested", "{{bold x}}", "{{!var}}", "a,b,\"c\"", "| a | b |", "? question", "= answer",
+    "ends with \\", "tab\there", "ünï 🙂", "  leading", "trailing  ", "mid \\# and \\\\// x", "a #b // c \\",
+];
+
+/// Lines that only verbatim content keeps
+const VERBATIM_LINES: &[&str] = &["", "   ", "#include <x>", "#section inside", "#c", "~~~~"];
+
+/// Content with blank lines, which content that is not verbatim keeps as
+/// `\` lines, or `\r\n` line breaks, which no content keeps
+const LINE_BREAKS: &[&str] = &["a\n\nb", "\nlead", "trail\n", "  ", "a\r\nb", "cr\r"];
+
+fn block_type(rng: &mut Rng) -> BlockType {
+    match rng.below(14) {
+        0 => BlockType::Section,
+        1 => BlockType::H2,
+        2 => BlockType::Paragraph,
+        3 => BlockType::P,
+        4 => BlockType::Code,
+        5 => BlockType::M,
+        6 => BlockType::Quote,
+        7 => BlockType::List,
+        8 => BlockType::Table,
+        9 => BlockType::Image,
+        10 => BlockType::Comment,
+        11 => BlockType::Custom("note".to_string()),
+        12 => BlockType::Custom("item".to_string()),
+        _ => BlockType::Custom("meta".to_string()),
+    }
+}
+
+fn content(rng: &mut Rng, verbatim: bool) -> String {
+    if rng.chance(5) {
+        return rng.pick(LINE_BREAKS).to_string();
+    }
+    let count = rng.below(5);
+    let lines: Vec<&str> = (0..count)
+        .map(|_| if verbatim && rng.chance(25) { rng.pick(VERBATIM_LINES) } else { rng.pick(LINES) })
+        .collect();
+    lines.join("\n")
+}
+
+fn attributes(rng: &mut Rng) -> Vec<Attribute> {
+    let mut attributes: Vec<Attribute> = (0..rng.below(4))
+        .map(|_| Attribute::new(rng.pick(KEYS).to_string(), rng.pick(VALUES).to_string()))
+        .collect();
+    if rng.chance(10) {
+        attributes.push(Attribute::new("raw".to_string(), "true".to_string()));
+    }
+    attributes
+}
+
+/// Siblings under a parent at `parent_level`, each no deeper than the one before
+fn blocks(rng: &mut Rng, parent_level: usize, depth: usize) -> Vec<Block> {
+    let count = if depth == 0 { 1 + rng.below(4) } else { rng.below(3) };
+    let mut level = (parent_level + 1 + rng.below(2)).min(6);
+    let mut siblings = Vec::new();
+    
+    for _ in 0..count {
+        if level <= parent_level {
+            break;
+        }
+        let mut block = Block::new(block_type(rng), level, 0);
+        block.attributes = attributes(rng);
+        block.content = content(rng, block.is_verbatim());
+        if depth < 3 && level < 6 {
+            block.children = blocks(rng, level, depth + 1);
+        }
+        
+        // Header-only metadata blocks at the top are document metadata instead
+        if parent_level == 0 && MetadataBlock::of(&block.block_type).is_some() && block.content.is_empty() && block.children.is_empty() {
+            block.content = "kept".to_string();
+        }
+        siblings.push(block);
+        
+        if rng.chance(30) {
+            level -= 1;
+        }
+    }
+    siblings
+}
+
+fn document(rng: &mut Rng) -> Document {
+    let mut document = Document::new();
+    for _ in 0..rng.below(4) {
+        let block = if rng.chance(50) { MetadataBlock::Meta } else { MetadataBlock::Document };
+        let attribute = Attribute::new(rng.pick(KEYS).to_string(), rng.pick(VALUES).to_string());
+        document.metadata.entries.push(MetadataEntry { block, attribute });
+    }
+    document.blocks = blocks(rng, 0, 0);
+    document
+}
+
+/// What a document holds, leaving out what is read from it: spans, line
+/// numbers and the parsed models. Attributes and metadata entries with
+/// different keys may be reordered, but those with the same key keep their order.
+#[derive(Debug, PartialEq)]
+struct Shape {
+    block_type: BlockType,
+    level: usize,
+    attributes: Vec<(String, String)>,
+    content: String,
+    children: Vec<Shape>,
+}
+
+fn shape(block: &Block) -> Shape {
+    let mut attributes: Vec<(String, String)> = block.attributes.iter().map(|attr| (attr.key.clone(), attr.value.clone())).collect();
+    attributes.sort_by(|a, b| a.0.cmp(&b.0));
+    Shape {
+        block_type: block.block_type.canonical(),
+        level: block.level,
+        attributes,
+        content: block.content.clone(),
+        children: block.children.iter().map(shape).collect(),
+    }
+}
+
+/// A parser that reads the encoder's indented layout as well as its flat one
+fn parser() -> BloxParser {
+    BloxParser::with_options(ParserOptions { indent: IndentPolicy::Header, ..ParserOptions::default() })
+}
+
+fn assert_same(parsed: &Document, document: &Document, seed: u64, source: &str) {
+    let entries = |document: &Document| -> Vec<(MetadataBlock, String, String)> {
+        let mut entries: Vec<_> = document.metadata.entries.iter().map(|entry| (entry.block, entry.attribute.key.clone(), entry.attribute.value.clone())).collect();
+        entries.sort_by(|a, b| (a.0.to_str(), &a.1).cmp(&(b.0.to_str(), &b.1)));
+        entries
+    };
+    assert_eq!(entries(parsed), entries(document), "seed {}:\n{}", seed, source);
+    let parsed_shapes: Vec<Shape> = parsed.blocks.iter().map(shape).collect();
+    let shapes: Vec<Shape> = document.blocks.iter().map(shape).collect();
+    assert_eq!(parsed_shapes, shapes, "seed {}:\n{}", seed, source);
+}
+
+/// Whether some block's content has lines that would not be read back,
+/// those ending in `\r`
+fn has_lost_lines(blocks: &[Block]) -> bool {
+    blocks.iter().any(|block| block.content.split('\n').any(|line| line.ends_with('\r')) || has_lost_lines(&block.children))
+}
+
+/// The encoded source, or `None` when the document was refused for
+/// content that would not be read back
+fn encoded(result: ParseResult<String>, document: &Document, seed: u64) -> Option<String> {
+    match result {
+        Ok(source) => Some(source),
+        Err(error) => {
+            assert_eq!(error.code(), "unencodable", "seed {}: {}", seed, error);
+            assert!(has_lost_lines(&document.blocks), "seed {}: {}", seed, error);
+            None
+        }
+    }
+}
+
+#[test]
+fn test_encode_round_trip() {
+    let encoders = [
+        BloxEncoder::new(),
+        BloxEncoder::with_options(0, false),
+        BloxEncoder::with_options(2, true),
+        BloxEncoder::with_options(4, false),
+        BloxEncoder::with_style(FormatStyle {
+            attribute_order: AttributeOrder::Sorted,
+            quote_style: QuoteStyle::Single,
+            blank_lines: 0,
+            ..FormatStyle::new()
+        }),
+        BloxEncoder::with_style(FormatStyle { quote_style: QuoteStyle::Double, ..FormatStyle::new() }),
+    ];
+    
+    for seed in 0..CASES {
+        let document = document(&mut Rng::new(seed));
+        for encoder in &encoders {
+            let Some(source) = encoded(encoder.encode(&document), &document, seed) else {
+                continue;
+            };
+            let parsed = parser().parse_string(&source).unwrap();
+            assert_same(&parsed, &document, seed, &source);
+        }
+        
+        // Flat output reads back under the default policy too
+        let Some(source) = encoded(BloxEncoder::new().encode(&document), &document, seed) else {
+            continue;
+        };
+        assert_same(&BloxParser::new().parse_string(&source).unwrap(), &document, seed, &source);
+    }
+}
+
+#[test]
+fn test_preserving_round_trip_after_edits() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        // Every other source is indented, with edits written to match
+        let encoder = BloxEncoder::with_options(2 * (seed as usize % 2), false);
+        let document = document(&mut rng);
+        let Some(source) = encoded(encoder.encode(&document), &document, seed) else {
+            continue;
+        };
+        let tree = parser().parse_tree(&source);
+        
+        // Edit the content and attributes of some blocks, leaving their place in the source
+        let mut edited = tree.document().clone();
+        let mut pending = edited.blocks.iter_mut().collect::<Vec<_>>();
+        while let Some(block) = pending.pop() {
+            if rng.chance(30) {
+                block.content = content(&mut rng, block.is_verbatim());
+            }
+            if rng.chance(20) {
+                block.attributes = attributes(&mut rng);
+                // A block that turns verbatim keeps only what verbatim content can hold
+                block.content = content(&mut rng, false);
+            }
+            pending.extend(block.children.iter_mut());
+        }
+        for block in &mut edited.blocks {
+            if MetadataBlock::of(&block.block_type).is_some() && block.content.is_empty() && block.children.is_empty() {
+                block.content = "kept".to_string();
+            }
+        }
+        
+        let Some(written) = encoded(encoder.encode_preserving(&tree, &edited), &edited, seed) else {
+            continue;
+        };
+        let parsed = parser().parse_string(&written).unwrap();
+        assert_same(&parsed, &edited, seed, &written);
+    }
+}
+
+#[test]
+fn test_unencodable_documents() {
+    let encoder = BloxEncoder::new();
+    let encode = |blocks: Vec<Block>| {
+        let mut document = Document::new();
+        document.blocks = blocks;
+        encoder.encode(&document)
+    };
+    
+    let mut multiline = Block::new(BlockType::Paragraph, 1, 0);
+    multiline.add_attribute("title".to_string(), "two\nlines".to_string());
+    let deeper_sibling = vec![Block::new(BlockType::Paragraph, 1, 0), Block::new(BlockType::Paragraph, 2, 0)];
+    let mut shallow_child = Block::new(BlockType::Section, 2, 0);
+    shallow_child.children.push(Block::new(BlockType::Paragraph, 2, 0));
+    let with_content = |block_type: BlockType, content: &str| {
+        let mut block = Block::new(block_type, 1, 0);
+        block.content = content.to_string();
+        block
+    };
+    
+    for blocks in [
+        vec![multiline],
+        deeper_sibling,
+        vec![shallow_child],
+        vec![Block::new(BlockType::Custom("no spaces".to_string()), 1, 0)],
+        vec![Block::new(BlockType::Custom("section".to_string()), 1, 0)],
+        vec![Block::new(BlockType::Custom("meta".to_string()), 1, 0)],
+        vec![Block::new(BlockType::Paragraph, 7, 0)],
+        vec![with_content(BlockType::Paragraph, "a\r\nb")],
+        vec![with_content(BlockType::Code, "a\r\nb")],
+    ] {
+        let error = encode(blocks).unwrap_err();
+        assert_eq!(error.code(), "unencodable", "{}", error);
+    }
+    
+    // Blank lines are fenced in verbatim content and escaped elsewhere
+    for block_type in [BlockType::Code, BlockType::Paragraph, BlockType::List] {
+        for content in ["a\n\nb", "\nlead", "trail\n", "  ", "\\\n\\\\"] {
+            let source = encode(vec![with_content(block_type.clone(), content)]).unwrap();
+            assert_eq!(parser().parse_string(&source).unwrap().blocks[0].content, content, "{}", source);
+        }
+    }
+    assert_eq!(encode(vec![with_content(BlockType::Paragraph, "a\n\nb")]).unwrap(), "#p\na\n\\\nb\n\n");
+}
@@ -56,7 +56,7 @@ Test | 123
     assert!(has_italic, "Should have italic inline element");
 
     let has_code = h1_block.inline_elements.iter()
-        .any(|elem| matches!(elem, InlineElement::Code(_)));
+        .any(|elem| matches!(elem, InlineElement::Code { .. }));
     assert!(has_code, "Should have code inline element");
 
     let has_link = h1_block.inline_elements.iter()
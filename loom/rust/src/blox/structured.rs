@@ -0,0 +1,581 @@
+//! Content written from the structured model: list items, tables and
+//! inline elements serialised back into the Blox source they are read from.
+//!
+//! `synced` rewrites a block's content from its model when the model says
+//! something the content does not, which is how edits made through
+//! `list_items`, `table` or `inline_elements` are saved. Lists are written
+//! as dash lines, tables in the block's format with `{{cell}}` for spans,
+//! and inline elements in the `{{type ...}}` form, apart from code and
+//! math spans, which keep their backtick and dollar forms when they can.
+//! Text is written as it is with its braces escaped, so shorthand markers
+//! in it that pair up are read back as markup.
+
+use std::borrow::Cow;
+
+use crate::blox::ast::{Attribute, Block, BlockType, InlineElement, ListItem, ListItemType, ListType, Table, TableCell, TableRow};
+use crate::blox::attributes;
+use crate::blox::encoder::same_attributes;
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::escape::ESCAPABLE;
+use crate::blox::inline::parse_inline;
+use crate::blox::table::{read_table, TableFormat};
+use crate::blox::table_edit::quote_field;
+
+/// The block with its content written from its model, or `None` if the
+/// model is empty or reads the same as the content.
+///
+/// Lists are written from `list_items`, replacing any `item` children,
+/// and tables from `table`, setting the `header` and `caption` attributes
+/// to match; column attributes are left as they are. Other blocks that
+/// are not verbatim are written from `inline_elements`. An item's text is
+/// its `content`, whatever its `inline_elements` hold.
+///
+/// Lists and tables that would not read back as their model, such as an
+/// item line starting with a dash or a pipe cell holding `|`, are
+/// `Unencodable` rather than written changed.
+pub fn synced(block: &Block) -> ParseResult<Option<Block>> {
+    if block.is_verbatim() || block.is_list_item() || block.is_quiz_question() {
+        return Ok(None);
+    }
+    match block.block_type.canonical() {
+        BlockType::List => synced_list(block),
+        BlockType::Table => synced_table(block),
+        BlockType::Quiz => Ok(None),
+        _ => Ok(synced_inline(block)),
+    }
+}
+
+/// Whether the model `synced` writes `block` from was changed from the
+/// one read into `original`, rather than left as it was read
+pub fn model_edited(block: &Block, original: &Block) -> bool {
+    match block.block_type.canonical() {
+        BlockType::List => !same_items(&block.list_items, &original.list_items),
+        BlockType::Table => match (&block.table, &original.table) {
+            (Some(table), Some(original)) => !same_table(table, original),
+            (table, _) => table.is_some(),
+        },
+        _ => block.inline_elements != original.inline_elements,
+    }
+}
+
+fn synced_list(block: &Block) -> ParseResult<Option<Block>> {
+    if block.list_items.is_empty() {
+        return Ok(None);
+    }
+    if same_items(&read_items(block), &block.list_items) {
+        return Ok(None);
+    }
+    
+    // Text before the first item is not part of the model, so it is kept
+    let mut lines: Vec<&str> = block.content
+        .split('\n')
+        .take_while(|line| !line.starts_with('-'))
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let items = list_source(&block.list_items, &block.list_type());
+    lines.push(&items);
+    
+    let mut synced = block.clone();
+    synced.content = lines.join("\n");
+    synced.children.retain(|child| !child.is_list_item());
+    if !same_written_items(&read_items(&synced), &block.list_items) {
+        return Err(unencodable("list items with lines that start with a dash or are blank cannot be written as dash lines"));
+    }
+    Ok(Some(synced))
+}
+
+/// The items `block` reads back as; a bad `start=` still leaves them to compare
+fn read_items(block: &Block) -> Vec<ListItem> {
+    let mut read = block.clone();
+    let _ = read.parse_list_items();
+    read.list_items
+}
+
+fn synced_table(block: &Block) -> ParseResult<Option<Block>> {
+    let Some(table) = block.table.as_ref() else {
+        return Ok(None);
+    };
+    if same_table(table, &read_table(block).table) {
+        return Ok(None);
+    }
+    
+    let mut synced = block.clone();
+    if (block.get_attribute("header") == Some("true")) != table.header.is_some() {
+        synced.attributes.retain(|attr| attr.key != "header");
+        if table.header.is_some() {
+            synced.add_attribute("header".to_string(), "true".to_string());
+        }
+    }
+    if block.get_attribute("caption") != table.caption.as_deref() {
+        synced.attributes.retain(|attr| attr.key != "caption");
+        if let Some(caption) = &table.caption {
+            synced.add_attribute("caption".to_string(), caption.clone());
+        }
+    }
+    
+    let format = match block.get_attribute("format").and_then(TableFormat::from_str) {
+        Some(format) => format,
+        None => TableFormat::detect(&block.content),
+    };
+    synced.content = table_source(table, format);
+    
+    // Cell text can make the rows look like another format
+    if synced.get_attribute("format").and_then(TableFormat::from_str).is_none() && TableFormat::detect(&synced.content) != format {
+        synced.attributes.retain(|attr| attr.key != "format");
+        synced.add_attribute("format".to_string(), format.to_str().to_string());
+    }
+    if !same_table(&read_table(&synced).table, table) {
+        return Err(unencodable(format!("the edited table cannot be written in {} format", format.to_str())));
+    }
+    Ok(Some(synced))
+}
+
+fn unencodable(message: impl Into<String>) -> BloxError {
+    BloxError::Unencodable { message: message.into() }
+}
+
+fn synced_inline(block: &Block) -> Option<Block> {
+    if block.inline_elements.is_empty() || parse_inline(&block.content).elements == block.inline_elements {
+        return None;
+    }
+    let mut synced = block.clone();
+    synced.content = inline_source(&block.inline_elements);
+    Some(synced)
+}
+
+/// Whether two item trees hold the same items, leaving out what is read
+/// from elsewhere: levels, numbers, spans and inline elements
+fn same_items(a: &[ListItem], b: &[ListItem]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| {
+        a.item_type == b.item_type
+            && a.content == b.content
+            && same_attributes(&a.attributes, &b.attributes)
+            && same_items(&a.children, &b.children)
+    })
+}
+
+/// Whether `read` holds the items written from `items`, whose item types
+/// take the place of any `term` or `checked` attributes that disagree
+fn same_written_items(read: &[ListItem], items: &[ListItem]) -> bool {
+    let other = |attributes: &[Attribute]| -> Vec<Attribute> {
+        attributes.iter().filter(|attr| attr.key != "term" && attr.key != "checked").cloned().collect()
+    };
+    read.len() == items.len() && read.iter().zip(items).all(|(a, b)| {
+        a.item_type == b.item_type
+            && a.content == b.content
+            && same_attributes(&other(&a.attributes), &other(&b.attributes))
+            && same_written_items(&a.children, &b.children)
+    })
+}
+
+fn same_table(a: &Table, b: &Table) -> bool {
+    a.caption == b.caption
+        && a.header.as_ref().map(row_cells) == b.header.as_ref().map(row_cells)
+        && a.rows.iter().map(row_cells).eq(b.rows.iter().map(row_cells))
+}
+
+fn row_cells(row: &TableRow) -> Vec<(&str, usize, usize)> {
+    row.cells.iter().map(|cell| (cell.content.as_str(), cell.colspan, cell.rowspan)).collect()
+}
+
+/// Dash lines for `items`, one dash per level.
+///
+/// Lines of item content that start with a dash, and blank lines in it,
+/// have no place in a dash line: they would read back as items or be
+/// dropped, so `synced` refuses to write them.
+pub fn list_source(items: &[ListItem], list_type: &ListType) -> String {
+    let mut lines = Vec::new();
+    write_items(&mut lines, items, list_type, 1);
+    lines.join("\n")
+}
+
+fn write_items(lines: &mut Vec<String>, items: &[ListItem], list_type: &ListType, depth: usize) {
+    let dashes = "-".repeat(depth);
+    for item in items {
+        let text = item_text(item, list_type);
+        let mut text_lines = text.split('\n');
+        match text_lines.next() {
+            Some(first) if !first.is_empty() => lines.push(format!("{} {}", dashes, first)),
+            _ => lines.push(dashes.clone()),
+        }
+        lines.extend(text_lines.map(str::to_string));
+        write_items(lines, &item.children, list_type, depth + 1);
+    }
+}
+
+/// An item's text after its dashes: a `[x]` or `[ ]` marker, `term: ` in
+/// definition lists, and `{{key=value ...}}` around the first line when
+/// the item has attributes
+fn item_text(item: &ListItem, list_type: &ListType) -> String {
+    let mut attributes = item.attributes.clone();
+    if attribute_type(&attributes).is_some_and(|item_type| item_type != item.item_type) {
+        attributes.retain(|attr| attr.key != "term" && attr.key != "checked");
+    }
+    
+    let first_line = item.content.split('\n').next().unwrap_or("");
+    let mut marker = "";
+    let mut body = Cow::Borrowed(item.content.as_str());
+    if attribute_type(&attributes).is_none() {
+        match &item.item_type {
+            ListItemType::Checked => marker = "[x] ",
+            ListItemType::Unchecked => {
+                // Text that would be read as a marker or term needs a marker of its own
+                let misread = ["[x] ", "[X] ", "[ ] "].iter().any(|prefix| first_line.starts_with(prefix))
+                    || (*list_type == ListType::Definition && first_line.contains(": "));
+                if *list_type == ListType::Check || misread {
+                    marker = "[ ] ";
+                }
+            }
+            ListItemType::Definition { term } => {
+                let inline_term = *list_type == ListType::Definition
+                    && !term.is_empty()
+                    && !term.contains(": ")
+                    && !term.contains('\n')
+                    && !term.starts_with(['[', '{']);
+                if inline_term {
+                    body = Cow::Owned(format!("{}: {}", term, item.content));
+                } else {
+                    attributes.push(Attribute::new("term".to_string(), term.clone()));
+                }
+            }
+        }
+    }
+    
+    let (first, rest) = match body.split_once('\n') {
+        Some((first, rest)) => (first, Some(rest)),
+        None => (body.as_ref(), None),
+    };
+    let mut text = marker.to_string();
+    if attributes.is_empty() {
+        text.push_str(first);
+    } else {
+        let pairs: Vec<String> = attributes.iter().map(|attr| format!("{}={}", attr.key, attributes::quote(&attr.value))).collect();
+        text.push_str("{{");
+        text.push_str(&pairs.join(" "));
+        if !first.is_empty() {
+            text.push(' ');
+            text.push_str(first);
+        }
+        text.push_str("}}");
+    }
+    if let Some(rest) = rest {
+        text.push('\n');
+        text.push_str(rest);
+    }
+    text
+}
+
+/// The item type `checked` and `term` attributes give, as `ListItem` reads them
+fn attribute_type(attributes: &[Attribute]) -> Option<ListItemType> {
+    let get = |key: &str| attributes.iter().find(|attr| attr.key == key).map(|attr| attr.value.as_str());
+    match (get("term"), get("checked")) {
+        (Some(term), _) => Some(ListItemType::Definition { term: term.to_string() }),
+        (None, Some("true")) => Some(ListItemType::Checked),
+        (None, Some(_)) => Some(ListItemType::Unchecked),
+        (None, None) => None,
+    }
+}
+
+/// Table rows in `format`, the header row first. Pipe tables get a
+/// separator row after their header.
+pub fn table_source(table: &Table, format: TableFormat) -> String {
+    let mut lines = Vec::new();
+    if let Some(header) = &table.header {
+        lines.push(row_source(header, format));
+        if format == TableFormat::Pipe {
+            let columns: usize = header.cells.iter().map(|cell| cell.colspan).sum();
+            lines.push(format!("|{}", " --- |".repeat(columns.max(1))));
+        }
+    }
+    lines.extend(table.rows.iter().map(|row| row_source(row, format)));
+    lines.join("\n")
+}
+
+fn row_source(row: &TableRow, format: TableFormat) -> String {
+    let fields: Vec<String> = row.cells.iter().map(|cell| cell_source(cell, format)).collect();
+    match format {
+        TableFormat::Pipe => format!("| {} |", fields.join(" | ")),
+        TableFormat::Csv | TableFormat::Tsv => fields.join(&format.delimiter().to_string()),
+    }
+}
+
+fn cell_source(cell: &TableCell, format: TableFormat) -> String {
+    if cell.colspan == 1 && cell.rowspan == 1 {
+        // Quoted, a field starting with `{{` is not read for spans
+        if format != TableFormat::Pipe && cell.content.starts_with("{{") {
+            return format!("\"{}\"", cell.content.replace('"', "\"\""));
+        }
+        return field(&cell.content, format);
+    }
+    
+    let mut spans = String::new();
+    if cell.colspan != 1 {
+        spans.push_str(&format!(" colspan={}", cell.colspan));
+    }
+    if cell.rowspan != 1 {
+        spans.push_str(&format!(" rowspan={}", cell.rowspan));
+    }
+    // Span content is read as written, up to the closing braces
+    let content = match format {
+        TableFormat::Pipe => field(&cell.content, format),
+        TableFormat::Csv | TableFormat::Tsv => cell.content.clone(),
+    };
+    if content.is_empty() {
+        format!("{{{{cell{}}}}}", spans)
+    } else {
+        format!("{{{{cell{} {}}}}}", spans, content)
+    }
+}
+
+/// A cell's content as a field. Pipe cells are read as written, `\|`
+/// included, so they are written as they are; a `|` or line break in one
+/// does not read back, which `synced_table` reports.
+fn field(content: &str, format: TableFormat) -> String {
+    match format {
+        TableFormat::Pipe => content.to_string(),
+        TableFormat::Csv | TableFormat::Tsv => quote_field(content, format),
+    }
+}
+
+/// Inline markup that parses back into `elements`
+pub fn inline_source(elements: &[InlineElement]) -> String {
+    let mut output = String::new();
+    for element in elements {
+        write_inline(&mut output, element);
+    }
+    output
+}
+
+fn write_inline(output: &mut String, element: &InlineElement) {
+    match element {
+        InlineElement::Text(text) => output.push_str(&escape_text(text)),
+        InlineElement::Link { content, url } => write_markup(output, "link", &[("href", url)], &children_source(content)),
+        InlineElement::Bold(children) => write_markup(output, "bold", &[], &children_source(children)),
+        InlineElement::Italic(children) => write_markup(output, "italic", &[], &children_source(children)),
+        InlineElement::Strikethrough(children) => write_markup(output, "strike", &[], &children_source(children)),
+        InlineElement::Highlight(children) => write_markup(output, "highlight", &[], &children_source(children)),
+        InlineElement::Subscript(children) => write_markup(output, "sub", &[], &children_source(children)),
+        InlineElement::Superscript(children) => write_markup(output, "sup", &[], &children_source(children)),
+        InlineElement::Code { code, lang: None } if !code.is_empty() && !code.contains('`') => {
+            output.push('`');
+            output.push_str(code);
+            output.push('`');
+        }
+        InlineElement::Code { code, lang } => {
+            let attributes: Vec<(&str, &str)> = lang.iter().map(|lang| ("lang", lang.as_str())).collect();
+            write_markup(output, "code", &attributes, &literal_source(code));
+        }
+        InlineElement::Math(math) if !math.is_empty() && !math.contains('$') => {
+            output.push('$');
+            output.push_str(math);
+            output.push('$');
+        }
+        InlineElement::Math(math) => write_markup(output, "math", &[], &literal_source(math)),
+        InlineElement::Reference { id, relation: None } if !id.is_empty() && !id.contains(char::is_whitespace) => {
+            output.push_str("{{@");
+            output.push_str(id);
+            output.push_str("}}");
+        }
+        InlineElement::Reference { id, relation } => {
+            let mut attributes = vec![("id", id.as_str())];
+            attributes.extend(relation.iter().map(|relation| ("relation", relation.as_str())));
+            write_markup(output, "ref", &attributes, "");
+        }
+        InlineElement::Footnote { id, content } => {
+            let attributes: Vec<(&str, &str)> = if id.is_empty() { Vec::new() } else { vec![("id", id.as_str())] };
+            write_markup(output, "footnote", &attributes, &children_source(content));
+        }
+        InlineElement::Variable(name) => {
+            output.push_str("{{!");
+            output.push_str(name);
+            output.push_str("}}");
+        }
+        InlineElement::Custom { element_type, attributes, content } => {
+            let attributes: Vec<(&str, &str)> = attributes.iter().map(|attr| (attr.key.as_str(), attr.value.as_str())).collect();
+            write_markup(output, element_type, &attributes, content);
+        }
+    }
+}
+
+fn write_markup(output: &mut String, element_type: &str, attributes: &[(&str, &str)], content: &str) {
+    output.push_str("{{");
+    output.push_str(element_type);
+    for (key, value) in attributes {
+        output.push(' ');
+        output.push_str(key);
+        output.push('=');
+        output.push_str(&attributes::quote(value));
+    }
+    if !content.is_empty() {
+        output.push(' ');
+        output.push_str(content);
+    }
+    output.push_str("}}");
+}
+
+/// Content of an element that nests. A lone text child is written bare
+/// when it is plain words, and quoted, so it is read as written, when it
+/// can be.
+fn children_source(children: &[InlineElement]) -> String {
+    match children {
+        [InlineElement::Text(text)] if is_plain(text) => text.clone(),
+        [InlineElement::Text(text)] => literal_source(text),
+        _ => inline_source(children),
+    }
+}
+
+/// Content of code and math elements, which is taken literally: quoted
+/// unless braces in it would close the element early
+fn literal_source(text: &str) -> String {
+    if text.contains("{{") || text.contains("}}") {
+        escape_text(text)
+    } else {
+        attributes::quote_with(text, '"')
+    }
+}
+
+/// Whether text reads the same as bare element content: words and
+/// punctuation that no markup starts with
+fn is_plain(text: &str) -> bool {
+    !text.is_empty()
+        && text.trim() == text
+        && !text.starts_with('\'')
+        && text.chars().all(|c| c.is_alphanumeric() || c == ' ' || ",.;:?!'-".contains(c))
+}
+
+/// Escape braces, and the characters after a backslash that
+/// `unescape_inline` would take it as escaping
+fn escape_text(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut previous = None;
+    for c in text.chars() {
+        if c == '{' || c == '}' || (previous == Some('\\') && ESCAPABLE.contains(&c)) {
+            output.push('\\');
+        }
+        output.push(c);
+        previous = Some(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::ast::CellValue;
+    use crate::blox::parser::BloxParser;
+    
+    fn parse(source: &str) -> Block {
+        BloxParser::new().parse_string(source).unwrap().blocks.remove(0)
+    }
+    
+    #[test]
+    fn test_inline_source() {
+        let elements = parse_inline(
+            "Say **hi** to [the *docs*](https://example.com/a b) with `x{}` and $a^2$, {{@kay}} {{ref id=k relation=\"sup ports\"}} \
+             {{fn id=n1 see {{i below}}}} {{!name}} {{cell colspan=2 raw}} {{code lang=rs \"fn {{}}\"}} \\{\\{ literal \\}\\} C:\\\\#",
+        ).elements;
+        let source = inline_source(&elements);
+        assert_eq!(parse_inline(&source).elements, elements);
+        assert!(source.starts_with("Say {{bold hi}} to {{link href=\"https://example.com/a b\" the {{italic docs}}}} with `x{}`"));
+        
+        let edited = vec![
+            InlineElement::Text("a {b} \\# ".to_string()),
+            InlineElement::Code { code: "`tick`".to_string(), lang: None },
+            InlineElement::Math(String::new()),
+            InlineElement::Bold(vec![InlineElement::Text(" key=value \"quoted\" ".to_string())]),
+        ];
+        assert_eq!(parse_inline(&inline_source(&edited)).elements, edited);
+    }
+    
+    #[test]
+    fn test_synced_list() {
+        let mut block = parse("#list type=check\nIntro\n- [ ] one\n-- [x] nested\n##item checked=true\nTwo\n");
+        assert!(synced(&block).unwrap().is_none());
+        
+        block.list_items[0].content = "first: [x] still text".to_string();
+        block.list_items[0].children[0].item_type = ListItemType::Unchecked;
+        block.list_items[1].attributes.push(Attribute::new("id".to_string(), "two b".to_string()));
+        let mut added = block.list_items[0].clone();
+        added.content = "[x] literal\ncontinued".to_string();
+        added.children.clear();
+        block.list_items.push(added);
+        
+        let written = synced(&block).unwrap().unwrap();
+        assert_eq!(
+            written.content,
+            "Intro\n- [ ] first: [x] still text\n-- [ ] nested\n- {{checked=true id=\"two b\" Two}}\n- [ ] [x] literal\ncontinued",
+        );
+        assert!(written.children.is_empty());
+        
+        let mut read = written.clone();
+        read.parse_list_items().unwrap();
+        assert!(same_items(&read.list_items, &block.list_items));
+        
+        // Definitions use `term: ` in definition lists and an attribute elsewhere
+        for list in ["#list type=definition\n- a: one\n", "#list\n- {{term=a one}}\n"] {
+            let mut block = parse(list);
+            block.list_items[0].item_type = ListItemType::Definition { term: "b".to_string() };
+            let written = synced(&block).unwrap().unwrap();
+            let mut read = written.clone();
+            read.parse_list_items().unwrap();
+            assert_eq!(read.list_items[0].item_type, block.list_items[0].item_type, "{}", written.content);
+            assert_eq!(read.list_items[0].content, "one");
+        }
+        
+        // A line that would read back as an item is refused, not written as one
+        let mut block = parse("#list\n- one\n");
+        block.list_items[0].content = "one\n- two".to_string();
+        assert_eq!(synced(&block).unwrap_err().code(), "unencodable");
+    }
+    
+    #[test]
+    fn test_synced_table() {
+        for source in ["#table header=true\nName,Notes\nAda,\"x, y\"\n", "#table format=pipe header=true\n| Name | Notes |\n|---|---|\n| Ada | x |\n"] {
+            let mut block = parse(source);
+            assert!(synced(&block).unwrap().is_none());
+            
+            let table = block.table.as_mut().unwrap();
+            table.rows[0].cells[1].content = "b, c".to_string();
+            table.rows.push(TableRow { cells: vec![TableCell { colspan: 2, ..table.rows[0].cells[0].clone() }] });
+            table.caption = Some("People".to_string());
+            
+            let written = synced(&block).unwrap().unwrap();
+            assert_eq!(written.get_attribute("caption"), Some("People"));
+            assert!(same_table(&read_table(&written).table, block.table.as_ref().unwrap()), "{}", written.content);
+        }
+        
+        let mut block = parse("#table\na,b\n");
+        let table = block.table.as_mut().unwrap();
+        table.header = Some(table.rows[0].clone());
+        let written = synced(&block).unwrap().unwrap();
+        assert_eq!(written.content, "a,b\na,b");
+        assert_eq!(written.get_attribute("header"), Some("true"));
+        
+        // Span text that would be detected as pipes keeps the format explicit
+        let mut block = parse("#table\na,b\nc,d\n");
+        let table = block.table.as_mut().unwrap();
+        table.rows[0].cells = vec![TableCell { colspan: 2, content: "x | y | z".to_string(), ..table.rows[0].cells[0].clone() }];
+        let written = synced(&block).unwrap().unwrap();
+        assert_eq!(written.get_attribute("format"), Some("csv"));
+        assert!(same_table(&read_table(&written).table, block.table.as_ref().unwrap()), "{}", written.content);
+        
+        // Cells that cannot be written so they read back are refused
+        let cell = |content: &str, colspan: usize| TableCell {
+            content: content.to_string(),
+            colspan,
+            rowspan: 1,
+            is_header: false,
+            value: CellValue::Empty,
+        };
+        let edits = [
+            ("#table\na,b\n", cell("p}}q", 2)),
+            ("#table format=pipe\n| a | b |\n", cell("m|n", 1)),
+            ("#table format=pipe\n| a | b |\n", cell("two\nlines", 2)),
+        ];
+        for (source, cell) in edits {
+            let mut block = parse(source);
+            block.table.as_mut().unwrap().rows[0].cells = vec![cell];
+            assert_eq!(synced(&block).unwrap_err().code(), "unencodable", "{}", source);
+        }
+    }
+}
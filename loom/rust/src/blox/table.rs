@@ -0,0 +1,805 @@
+//! Table content (spec 3.2.4).
+//!
+//! Rows are CSV by default, with TSV and pipe-separated rows chosen by a
+//! `format=` attribute or detected from the first row. CSV and TSV fields
+//! can be quoted to hold the delimiter, `""` or a line break. A field that is
+//! a single `{{cell colspan=2 rowspan=2 ...}}` sets the spans of its cell.
+//!
+//! Columns are described by comma-separated `align`, `width`, `types` and
+//! `decimals` attributes, and each body cell is read as a value of its
+//! column's type so it can be sorted and formatted. Cells starting with `=`
+//! are formulas, evaluated by `blox::formula`.
+
+use std::ops::Range;
+
+use crate::blox::ast::{Block, CellValue, ColumnAlign, ColumnSpec, ColumnType, InlineElement, Table, TableCell, TableRow};
+use crate::blox::formula;
+use crate::blox::metadata::MetadataDate;
+use crate::blox::inline::parse_inline;
+
+/// Widest colspan or rowspan read; larger spans are reported and read as 1
+const MAX_CELL_SPAN: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Csv,
+    Tsv,
+    Pipe,
+}
+
+impl TableFormat {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "tsv" => Some(Self::Tsv),
+            "pipe" => Some(Self::Pipe),
+            _ => None,
+        }
+    }
+    
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Pipe => "pipe",
+        }
+    }
+    
+    /// Guess the format from the first row: tabs mean TSV, and more pipes
+    /// than commas mean pipe-separated
+    pub fn detect(content: &str) -> Self {
+        let first = content.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+        if first.contains('\t') {
+            Self::Tsv
+        } else if first.matches('|').count() > first.matches(',').count() {
+            Self::Pipe
+        } else {
+            Self::Csv
+        }
+    }
+    
+    pub fn delimiter(&self) -> char {
+        match self {
+            Self::Csv => ',',
+            Self::Tsv => '\t',
+            Self::Pipe => '|',
+        }
+    }
+}
+
+/// A problem in a table, located by a byte range of the content
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableIssue {
+    pub code: &'static str,
+    pub range: Range<usize>,
+    /// The table attribute the problem is in, in which case `range` is empty
+    pub attribute: Option<&'static str>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedTable {
+    pub table: Table,
+    pub issues: Vec<TableIssue>,
+}
+
+/// One field as written, before `{{cell}}` is applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub text: String,
+    pub quoted: bool,
+    pub range: Range<usize>,
+}
+
+/// The fields of one row and the range of content it was read from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub fields: Vec<Field>,
+    pub range: Range<usize>,
+}
+
+/// Read the table in a block's content, using its `format`, `header`,
+/// `caption` and column attributes
+pub fn read_table(block: &Block) -> ParsedTable {
+    let mut issues = Vec::new();
+    
+    let format = match block.get_attribute("format") {
+        Some(name) => TableFormat::from_str(name).unwrap_or_else(|| {
+            issues.push(TableIssue {
+                code: "unknown-table-format",
+                range: 0..0,
+                attribute: Some("format"),
+                message: format!("Unknown table format `{}`; expected csv, tsv or pipe", name),
+            });
+            TableFormat::detect(&block.content)
+        }),
+        None => TableFormat::detect(&block.content),
+    };
+    
+    let records = split_records(&block.content, format, &mut issues);
+    let has_header = block.get_attribute("header") == Some("true");
+    
+    let mut rows: Vec<TableRow> = Vec::new();
+    let mut ranges = Vec::new();
+    for (i, record) in records.iter().enumerate() {
+        let is_header = has_header && i == 0;
+        let cells = record.fields.iter().map(|field| read_cell(field, is_header, &mut issues)).collect();
+        rows.push(TableRow { cells });
+        ranges.push(record.range.clone());
+    }
+    
+    let layout = layout(&rows);
+    check_shape(&layout, &ranges, &mut issues);
+    let columns = column_specs(block, layout.column_count(), &mut issues);
+    assign_values(&mut rows, &layout, &columns, has_header, &ranges, &mut issues);
+    
+    for problem in formula::evaluate(&mut rows, &layout, &columns) {
+        let cell = &rows[problem.row].cells[problem.cell];
+        issues.push(TableIssue {
+            code: "formula-error",
+            range: records[problem.row].fields[problem.cell].range.clone(),
+            attribute: None,
+            message: format!("`{}` gives {}", cell.content, problem.error),
+        });
+    }
+    issues.sort_by_key(|issue| issue.range.start);
+    
+    let header = if has_header && !rows.is_empty() { Some(rows.remove(0)) } else { None };
+    ParsedTable {
+        table: Table {
+            caption: block.get_attribute("caption").map(str::to_string),
+            columns,
+            header,
+            rows,
+        },
+        issues,
+    }
+}
+
+/// Split content into rows of fields
+pub fn split_records(content: &str, format: TableFormat, issues: &mut Vec<TableIssue>) -> Vec<Record> {
+    match format {
+        TableFormat::Pipe => split_pipe_records(content),
+        TableFormat::Csv | TableFormat::Tsv => split_delimited_records(content, format.delimiter(), issues),
+    }
+}
+
+fn split_delimited_records(content: &str, delimiter: char, issues: &mut Vec<TableIssue>) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut record_start = 0;
+    let mut pos = 0;
+    
+    let is_blank = |c: char| c == ' ' || (c == '\t' && delimiter != '\t');
+    
+    while pos <= content.len() {
+        // Skip leading blanks of the field
+        pos += content[pos..].len() - content[pos..].trim_start_matches(is_blank).len();
+        let field_start = pos;
+        let rest = &content[pos..];
+        
+        let field = if rest.starts_with('"') {
+            let (text, consumed, closed) = read_quoted(rest);
+            if !closed {
+                issues.push(TableIssue {
+                    code: "unclosed-quote",
+                    range: field_start..content.len(),
+                    attribute: None,
+                    message: "Quoted field is not closed".to_string(),
+                });
+            }
+            pos += consumed;
+            
+            // Anything between the closing quote and the delimiter is kept
+            let tail_len = field_end(&content[pos..], delimiter);
+            let tail = content[pos..pos + tail_len].trim_end();
+            if !tail.is_empty() {
+                issues.push(TableIssue {
+                    code: "text-after-quote",
+                    range: pos..pos + tail_len,
+                    attribute: None,
+                    message: "Text after a closing quote is added to the field".to_string(),
+                });
+            }
+            pos += tail_len;
+            Field { text: text + tail, quoted: true, range: field_start..pos }
+        } else {
+            let len = field_end(rest, delimiter);
+            pos += len;
+            let text = rest[..len].trim_end_matches(is_blank);
+            Field { text: text.to_string(), quoted: false, range: field_start..field_start + text.len() }
+        };
+        fields.push(field);
+        
+        match content[pos..].chars().next() {
+            Some(c) if c == delimiter => pos += c.len_utf8(),
+            next => {
+                // End of the row: a lone empty field is a blank line
+                let blank = fields.len() == 1 && !fields[0].quoted && fields[0].text.is_empty();
+                if !blank {
+                    records.push(Record { fields: std::mem::take(&mut fields), range: record_start..pos });
+                }
+                fields.clear();
+                pos += 1;
+                record_start = pos;
+                if next.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+    
+    records
+}
+
+/// Read a quoted field, returning its text, the bytes consumed and whether it was closed
+fn read_quoted(text: &str) -> (String, usize, bool) {
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1).peekable();
+    
+    while let Some((i, c)) = chars.next() {
+        if c == '"' {
+            if chars.peek().is_some_and(|&(_, next)| next == '"') {
+                value.push('"');
+                chars.next();
+                continue;
+            }
+            return (value, i + 1, true);
+        }
+        value.push(c);
+    }
+    
+    (value, text.len(), false)
+}
+
+/// Length of an unquoted field: up to the delimiter or line end, skipping
+/// over escapes and delimiters inside `{{ }}`
+fn field_end(text: &str, delimiter: char) -> usize {
+    let mut depth = 0usize;
+    let mut chars = text.char_indices().peekable();
+    
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' if text[i..].starts_with("{{") => {
+                depth += 1;
+                chars.next();
+            }
+            '}' if depth > 0 && text[i..].starts_with("}}") => {
+                depth -= 1;
+                chars.next();
+            }
+            '\n' => return i,
+            c if c == delimiter && depth == 0 => return i,
+            _ => {}
+        }
+    }
+    
+    text.len()
+}
+
+fn split_pipe_records(content: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut offset = 0;
+    
+    for line in content.split('\n') {
+        let line_start = offset;
+        offset += line.len() + 1;
+        
+        let trimmed = line.trim();
+        // Skip blank lines and Markdown `|---|:--:|` separator rows
+        if trimmed.is_empty() || (trimmed.contains('-') && trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))) {
+            continue;
+        }
+        
+        let inner_start = line_start + (line.len() - line.trim_start().len()) + usize::from(trimmed.starts_with('|'));
+        let inner_end = line_start + line.trim_end().len() - usize::from(trimmed.len() > 1 && trimmed.ends_with('|') && !trimmed.ends_with("\\|"));
+        
+        let mut fields = Vec::new();
+        let mut pos = inner_start;
+        loop {
+            let len = field_end(&content[pos..inner_end], '|');
+            let raw = &content[pos..pos + len];
+            let start = pos + (raw.len() - raw.trim_start().len());
+            fields.push(Field { text: raw.trim().to_string(), quoted: false, range: start..start + raw.trim().len() });
+            
+            pos += len + 1;
+            if pos > inner_end {
+                break;
+            }
+        }
+        
+        records.push(Record { fields, range: line_start..line_start + line.len() });
+    }
+    
+    records
+}
+
+/// Build a cell from a field, reading spans from `{{cell}}`
+fn read_cell(field: &Field, is_header: bool, issues: &mut Vec<TableIssue>) -> TableCell {
+    let mut cell = TableCell {
+        content: field.text.clone(),
+        colspan: 1,
+        rowspan: 1,
+        is_header,
+        value: CellValue::Empty,
+    };
+    if field.quoted || !field.text.starts_with("{{") {
+        return cell;
+    }
+    
+    let parsed = parse_inline(&field.text);
+    let [InlineElement::Custom { element_type, attributes, content }] = parsed.elements.as_slice() else {
+        return cell;
+    };
+    if element_type != "cell" || parsed.ranges[0] != (0..field.text.len()) {
+        return cell;
+    }
+    
+    cell.content = content.clone();
+    for attribute in attributes {
+        let span = match attribute.key.as_str() {
+            "colspan" => &mut cell.colspan,
+            "rowspan" => &mut cell.rowspan,
+            _ => continue,
+        };
+        let message = match attribute.value.parse::<usize>() {
+            Ok(value) if (1..=MAX_CELL_SPAN).contains(&value) => {
+                *span = value;
+                continue;
+            }
+            Ok(value) if value > 0 => format!("`{}={}` is more than {}", attribute.key, attribute.value, MAX_CELL_SPAN),
+            _ => format!("`{}={}` is not a positive number", attribute.key, attribute.value),
+        };
+        issues.push(TableIssue {
+            code: "invalid-cell-span",
+            range: field.range.clone(),
+            attribute: None,
+            message,
+        });
+    }
+    
+    cell
+}
+
+/// Where each cell sits once row spans from above are counted
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    /// The column each cell starts in, per row
+    pub columns: Vec<Vec<usize>>,
+    /// The columns each row fills, counting those covered from above
+    pub widths: Vec<usize>,
+    /// Whether a cell spans past the last row
+    pub overflows: bool,
+}
+
+impl Layout {
+    /// The number of columns, from the widest row
+    pub fn column_count(&self) -> usize {
+        self.widths.iter().copied().max().unwrap_or(0)
+    }
+}
+
+pub fn layout<'a>(rows: impl IntoIterator<Item = &'a TableRow>) -> Layout {
+    // Rows still covered in each column, counting the current row
+    let mut covered: Vec<usize> = Vec::new();
+    let mut columns = Vec::new();
+    let mut widths = Vec::new();
+    
+    for row in rows {
+        let mut next: Vec<usize> = covered.iter().map(|rows| rows.saturating_sub(1)).collect();
+        let mut starts = Vec::new();
+        let mut column = 0;
+        
+        for cell in &row.cells {
+            while covered.get(column).is_some_and(|&rows| rows > 0) {
+                column += 1;
+            }
+            starts.push(column);
+            for c in column..column + cell.colspan {
+                if next.len() <= c {
+                    next.resize(c + 1, 0);
+                }
+                next[c] = cell.rowspan.saturating_sub(1);
+            }
+            column += cell.colspan;
+        }
+        
+        widths.push(covered.iter().filter(|&&rows| rows > 0).count() + row.cells.iter().map(|cell| cell.colspan).sum::<usize>());
+        columns.push(starts);
+        covered = next;
+    }
+    
+    Layout {
+        columns,
+        widths,
+        overflows: covered.iter().any(|&rows| rows > 0),
+    }
+}
+
+/// Report rows that do not fill the same number of columns as the first
+fn check_shape(layout: &Layout, ranges: &[Range<usize>], issues: &mut Vec<TableIssue>) {
+    let Some(&expected) = layout.widths.first() else {
+        return;
+    };
+    
+    for (&width, range) in layout.widths.iter().zip(ranges) {
+        if width != expected {
+            issues.push(TableIssue {
+                code: "ragged-table-row",
+                range: range.clone(),
+                attribute: None,
+                message: format!("Row has {} columns, expected {}", width, expected),
+            });
+        }
+    }
+    
+    if layout.overflows {
+        if let Some(last) = ranges.last() {
+            issues.push(TableIssue {
+                code: "ragged-table-row",
+                range: last.clone(),
+                attribute: None,
+                message: "A cell spans past the last row".to_string(),
+            });
+        }
+    }
+}
+
+/// Read the column attributes: comma-separated lists with one entry per
+/// column, where an empty entry keeps the default
+fn column_specs(block: &Block, count: usize, issues: &mut Vec<TableIssue>) -> Vec<ColumnSpec> {
+    let list = |key: &str| -> Vec<String> {
+        block.get_attribute(key)
+            .map(|value| value.split(',').map(|entry| entry.trim().to_string()).collect())
+            .unwrap_or_default()
+    };
+    let (aligns, widths, types, decimals) = (list("align"), list("width"), list("types"), list("decimals"));
+    let count = [count, aligns.len(), widths.len(), types.len(), decimals.len()].into_iter().max().unwrap_or(0);
+    
+    let mut invalid = |attribute: &'static str, entry: &str, expected: &str| {
+        issues.push(TableIssue {
+            code: "invalid-column-spec",
+            range: 0..0,
+            attribute: Some(attribute),
+            message: format!("`{}` in `{}` is not {}", entry, attribute, expected),
+        });
+    };
+    let entry = |list: &[String], i: usize| list.get(i).filter(|entry| !entry.is_empty() && *entry != "*").cloned();
+    
+    (0..count)
+        .map(|i| {
+            let column_type = entry(&types, i).map_or(ColumnType::Text, |name| {
+                ColumnType::from_str(&name).unwrap_or_else(|| {
+                    invalid("types", &name, "text, number, date or currency");
+                    ColumnType::Text
+                })
+            });
+            let align = entry(&aligns, i).and_then(|name| {
+                let align = ColumnAlign::from_str(&name);
+                if align.is_none() {
+                    invalid("align", &name, "l, c or r");
+                }
+                align
+            });
+            let width = entry(&widths, i).filter(|width| {
+                let valid = is_length(width);
+                if !valid {
+                    invalid("width", width, "a length such as 12, 20% or 120px");
+                }
+                valid
+            });
+            let decimals = entry(&decimals, i).and_then(|places| {
+                let parsed = places.parse::<usize>().ok().filter(|&places| places <= 20);
+                if parsed.is_none() {
+                    invalid("decimals", &places, "a number of places");
+                }
+                parsed
+            });
+            
+            ColumnSpec {
+                align: align.unwrap_or_else(|| column_type.default_align()),
+                width,
+                column_type,
+                decimals,
+            }
+        })
+        .collect()
+}
+
+/// A number with an optional `%`, `px`, `em` or `ch` unit
+fn is_length(width: &str) -> bool {
+    let number = ["%", "px", "em", "ch"].iter().find_map(|unit| width.strip_suffix(unit)).unwrap_or(width);
+    !number.is_empty() && number.chars().all(|c| c.is_ascii_digit() || c == '.') && number.parse::<f64>().is_ok()
+}
+
+/// Read every body cell as the type of the column it starts in
+fn assign_values(rows: &mut [TableRow], layout: &Layout, columns: &[ColumnSpec], has_header: bool, ranges: &[Range<usize>], issues: &mut Vec<TableIssue>) {
+    for (i, row) in rows.iter_mut().enumerate() {
+        for (cell, &column) in row.cells.iter_mut().zip(&layout.columns[i]) {
+            // Formulas get their value when the table is evaluated
+            if formula::is_formula(&cell.content) {
+                continue;
+            }
+            
+            let column_type = if has_header && i == 0 {
+                ColumnType::Text
+            } else {
+                columns.get(column).map_or(ColumnType::Text, |spec| spec.column_type)
+            };
+            
+            cell.value = match parse_value(&cell.content, column_type) {
+                Some(value) => value,
+                None => {
+                    issues.push(TableIssue {
+                        code: "invalid-cell-value",
+                        range: ranges[i].clone(),
+                        attribute: None,
+                        message: format!("`{}` is not a {}", cell.content, column_type.to_str()),
+                    });
+                    CellValue::Text(cell_text(&cell.content))
+                }
+            };
+        }
+    }
+}
+
+/// The plain text of a cell's inline markup
+fn cell_text(content: &str) -> String {
+    parse_inline(content).elements.iter().map(InlineElement::plain_text).collect()
+}
+
+/// Read cell content as `column_type`, or `None` if it is not one.
+///
+/// Cells still holding a `{{!variable}}` are left as text until they are interpolated.
+pub fn parse_value(content: &str, column_type: ColumnType) -> Option<CellValue> {
+    let parsed = parse_inline(content);
+    let text: String = parsed.elements.iter().map(InlineElement::plain_text).collect();
+    let text = text.trim();
+    
+    if text.is_empty() {
+        return Some(CellValue::Empty);
+    }
+    if parsed.elements.iter().any(|element| matches!(element, InlineElement::Variable(_))) {
+        return Some(CellValue::Text(text.to_string()));
+    }
+    
+    match column_type {
+        ColumnType::Text => Some(CellValue::Text(text.to_string())),
+        ColumnType::Number => parse_number(text).map(CellValue::Number),
+        ColumnType::Date => MetadataDate::parse(text).map(CellValue::Date),
+        ColumnType::Currency => parse_currency(text),
+    }
+}
+
+/// Read a decimal number, allowing `,` and `_` between digits
+pub(crate) fn parse_number(text: &str) -> Option<f64> {
+    let digits: String = text.chars().filter(|&c| c != ',' && c != '_').collect();
+    let unsigned = digits.strip_prefix(['-', '+']).unwrap_or(&digits);
+    
+    let valid = unsigned.chars().any(|c| c.is_ascii_digit())
+        && unsigned.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && unsigned.matches('.').count() <= 1;
+    if valid { digits.parse().ok() } else { None }
+}
+
+/// Read an amount with an optional symbol or code before or after it, as
+/// in `$1,200.50`, `-€3` or `12.00 CHF`
+fn parse_currency(text: &str) -> Option<CellValue> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, text),
+    };
+    
+    let is_symbol = |c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '_' | '-' | '+'));
+    let number_start = text.find(|c: char| !is_symbol(c))?;
+    let number_end = text[number_start..].find(is_symbol).map_or(text.len(), |i| number_start + i);
+    let (prefix, number, suffix) = (text[..number_start].trim(), &text[number_start..number_end], text[number_end..].trim());
+    
+    let symbol = match (prefix.is_empty(), suffix.is_empty()) {
+        (true, true) => None,
+        (false, true) => Some(prefix.to_string()),
+        (true, false) => Some(suffix.to_string()),
+        (false, false) => return None,
+    };
+    if symbol.as_ref().is_some_and(|symbol| symbol.chars().any(char::is_whitespace)) {
+        return None;
+    }
+    
+    let amount = parse_number(number)?;
+    Some(CellValue::Currency {
+        amount: if negative { -amount } else { amount },
+        symbol,
+    })
+}
+
+/// How a cell's value is shown in its column, or `None` to show its content as written
+pub fn format_value(value: &CellValue, column: Option<&ColumnSpec>) -> Option<String> {
+    let decimals = column.and_then(|spec| spec.decimals);
+    match value {
+        CellValue::Number(number) => decimals.map(|places| format_number(*number, places)),
+        CellValue::Currency { amount, symbol } => {
+            let number = format_number(amount.abs(), decimals.unwrap_or(2));
+            let sign = if *amount < 0.0 { "-" } else { "" };
+            Some(match symbol {
+                Some(code) if code.chars().all(char::is_alphabetic) => format!("{}{} {}", sign, number, code),
+                Some(symbol) => format!("{}{}{}", sign, symbol, number),
+                None => format!("{}{}", sign, number),
+            })
+        }
+        CellValue::Date(date) => Some(date.to_string()),
+        CellValue::Error(error) => Some(error.code().to_string()),
+        CellValue::Empty | CellValue::Text(_) => None,
+    }
+}
+
+/// How a cell is shown: its formatted value, a formula's result, or `None`
+/// to show its content as written
+pub fn display_value(cell: &TableCell, column: Option<&ColumnSpec>) -> Option<String> {
+    format_value(&cell.value, column).or_else(|| {
+        formula::is_formula(&cell.content).then(|| match &cell.value {
+            CellValue::Number(number) => formula::format_general(*number),
+            CellValue::Text(text) => text.clone(),
+            _ => String::new(),
+        })
+    })
+}
+
+/// Format with a fixed number of decimal places and `,` between thousands
+pub fn format_number(number: f64, decimals: usize) -> String {
+    let fixed = format!("{:.*}", decimals, number.abs());
+    let (whole, fraction) = fixed.split_once('.').map_or((fixed.as_str(), None), |(whole, fraction)| (whole, Some(fraction)));
+    
+    let mut grouped = String::new();
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push('.');
+        grouped.push_str(fraction);
+    }
+    
+    // No sign on a value that rounds to zero
+    if number < 0.0 && fixed.chars().any(|c| matches!(c, '1'..='9')) {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::ast::BlockType;
+    
+    fn table(content: &str, attributes: &[(&str, &str)]) -> ParsedTable {
+        let mut block = Block::new(BlockType::Table, 1, 1);
+        block.content = content.to_string();
+        for (key, value) in attributes {
+            block.add_attribute(key.to_string(), value.to_string());
+        }
+        read_table(&block)
+    }
+    
+    fn texts(row: &TableRow) -> Vec<&str> {
+        row.cells.iter().map(|cell| cell.content.as_str()).collect()
+    }
+    
+    #[test]
+    fn test_spec_csv() {
+        let parsed = table("姓名, 年龄, 职业\n张三, 28, 工程师\n李四, 32, 设计师", &[("header", "true")]);
+        assert!(parsed.issues.is_empty());
+        assert_eq!(texts(parsed.table.header.as_ref().unwrap()), vec!["姓名", "年龄", "职业"]);
+        assert_eq!(texts(&parsed.table.rows[1]), vec!["李四", "32", "设计师"]);
+        
+        let parsed = table("{{cell colspan=2 全标题}}, 另一个标题\n内容1, 内容2, 内容3", &[]);
+        assert!(parsed.issues.is_empty());
+        let title = &parsed.table.rows[0].cells[0];
+        assert_eq!((title.content.as_str(), title.colspan), ("全标题", 2));
+    }
+    
+    #[test]
+    fn test_quoted_fields() {
+        let content = "name, note\n\"Smith, J.\", \"said \"\"hi\"\"\nthen left\"\n{{b a, b}}, plain";
+        let parsed = table(content, &[]);
+        assert!(parsed.issues.is_empty(), "{:?}", parsed.issues);
+        assert_eq!(parsed.table.rows.len(), 3);
+        assert_eq!(texts(&parsed.table.rows[1]), vec!["Smith, J.", "said \"hi\"\nthen left"]);
+        assert_eq!(texts(&parsed.table.rows[2]), vec!["{{b a, b}}", "plain"]);
+        
+        let field = &split_records(content, TableFormat::Csv, &mut Vec::new())[1].fields[0];
+        assert_eq!(&content[field.range.clone()], "\"Smith, J.\"");
+    }
+    
+    #[test]
+    fn test_formats() {
+        assert_eq!(TableFormat::detect("a\tb, c"), TableFormat::Tsv);
+        assert_eq!(TableFormat::detect("| a | b |"), TableFormat::Pipe);
+        assert_eq!(TableFormat::detect("a, b | c"), TableFormat::Csv);
+        
+        let parsed = table("| Name | Value |\n|------|------:|\n| a \\| b | 1 |", &[("header", "true")]);
+        assert_eq!(texts(parsed.table.header.as_ref().unwrap()), vec!["Name", "Value"]);
+        assert_eq!(texts(&parsed.table.rows[0]), vec!["a \\| b", "1"]);
+        
+        let parsed = table("a, b\tc\nd, e\tf", &[("format", "tsv")]);
+        assert_eq!(texts(&parsed.table.rows[1]), vec!["d, e", "f"]);
+    }
+    
+    #[test]
+    fn test_spans_and_ragged_rows() {
+        let content = "{{cell rowspan=2 A}}, B, C\nD, E\nF, G\nH, I, J, K\n{{cell colspan=x L}}, M, N\n{{cell colspan=18446744073709551615 rowspan=300000000 O}}, P, Q";
+        let parsed = table(content, &[]);
+        
+        let issues: Vec<_> = parsed.issues.iter().map(|issue| (issue.code, &content[issue.range.clone()])).collect();
+        assert_eq!(issues, vec![
+            ("ragged-table-row", "F, G"),
+            ("ragged-table-row", "H, I, J, K"),
+            ("invalid-cell-span", "{{cell colspan=x L}}"),
+            ("invalid-cell-span", "{{cell colspan=18446744073709551615 rowspan=300000000 O}}"),
+            ("invalid-cell-span", "{{cell colspan=18446744073709551615 rowspan=300000000 O}}"),
+        ]);
+        assert_eq!(parsed.table.rows[0].cells[0].rowspan, 2);
+        
+        // Spans too large to lay out fall back to 1
+        let last = &parsed.table.rows[5].cells[0];
+        assert_eq!((last.colspan, last.rowspan), (1, 1));
+    }
+    
+    #[test]
+    fn test_column_specs() {
+        let content = "Item, Price, Qty, Sold\nTea, \"$1,200.5\", 3, 2024-05-01\nCake, 12.00 CHF, x, \nPie, -€3, {{!n}}, soon";
+        let attributes = [("header", "true"), ("types", "text,currency,number,date"), ("align", ",,c"), ("width", "20%,,8,wide"), ("decimals", ",0")];
+        let parsed = table(content, &attributes);
+        let columns = &parsed.table.columns;
+        
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns[0], ColumnSpec { width: Some("20%".to_string()), ..ColumnSpec::default() });
+        assert_eq!((columns[1].align, columns[1].decimals), (ColumnAlign::Right, Some(0)));
+        assert_eq!((columns[2].align, columns[2].column_type), (ColumnAlign::Center, ColumnType::Number));
+        assert_eq!(columns[3].width, None);
+        
+        let values: Vec<Vec<&CellValue>> = parsed.table.rows.iter().map(|row| row.cells.iter().map(|cell| &cell.value).collect()).collect();
+        assert_eq!(values[0][1], &CellValue::Currency { amount: 1200.5, symbol: Some("$".to_string()) });
+        assert_eq!(values[0][2], &CellValue::Number(3.0));
+        assert_eq!(values[0][3], &CellValue::Date(MetadataDate { year: 2024, month: 5, day: 1 }));
+        assert_eq!(values[1][1], &CellValue::Currency { amount: 12.0, symbol: Some("CHF".to_string()) });
+        assert_eq!(values[1][3], &CellValue::Empty);
+        assert_eq!(values[2][1], &CellValue::Currency { amount: -3.0, symbol: Some("€".to_string()) });
+        assert_eq!(values[2][2], &CellValue::Text("{{!n}}".to_string()));
+        assert_eq!(parsed.table.header.as_ref().unwrap().cells[1].value, CellValue::Text("Price".to_string()));
+        
+        let issues: Vec<_> = parsed.issues.iter().map(|issue| (issue.code, issue.attribute)).collect();
+        assert_eq!(issues, vec![
+            ("invalid-column-spec", Some("width")),
+            ("invalid-cell-value", None),
+            ("invalid-cell-value", None),
+        ]);
+        assert_eq!(&content[parsed.issues[1].range.clone()], "Cake, 12.00 CHF, x, ");
+    }
+    
+    #[test]
+    fn test_value_formatting() {
+        assert_eq!(format_number(1234567.891, 2), "1,234,567.89");
+        assert_eq!(format_number(-999.5, 0), "-1,000");
+        assert_eq!(format_number(-0.001, 2), "0.00");
+        assert_eq!(format_number(12.0, 0), "12");
+        
+        let spec = ColumnSpec { decimals: Some(1), ..ColumnSpec::default() };
+        assert_eq!(format_value(&CellValue::Number(3.0), None), None);
+        assert_eq!(format_value(&CellValue::Number(3.0), Some(&spec)).as_deref(), Some("3.0"));
+        
+        let euros = CellValue::Currency { amount: -1500.0, symbol: Some("€".to_string()) };
+        assert_eq!(format_value(&euros, None).as_deref(), Some("-€1,500.00"));
+        let francs = CellValue::Currency { amount: 12.0, symbol: Some("CHF".to_string()) };
+        assert_eq!(format_value(&francs, Some(&spec)).as_deref(), Some("12.0 CHF"));
+        
+        assert!(parse_value("1e5", ColumnType::Number).is_none());
+        assert!(parse_value("$ 5 USD", ColumnType::Currency).is_none());
+        assert_eq!(parse_value("**42**", ColumnType::Number), Some(CellValue::Number(42.0)));
+    }
+}
@@ -0,0 +1,643 @@
+//! Editing tables in their source: inserting, deleting and moving rows and
+//! columns, sorting and filtering.
+//!
+//! Edits work on the table as written, so rows they do not change keep
+//! their formatting. Each returns the smallest text edits that make the
+//! change, in source order, along with the block parsed again from the
+//! edited source. Formulas are not rewritten when the cells they refer to move.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::blox::ast::{Block, BlockType, CellValue, Span, Table, TableCell, TableRow};
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::escape::escape_line;
+use crate::blox::parser::BloxParser;
+use crate::blox::table::{self, Layout, Record, TableFormat};
+
+/// Table attributes that hold one entry per column
+const COLUMN_ATTRIBUTES: &[&str] = &["align", "width", "types", "decimals"];
+
+/// Replace the source in `span` with `replacement`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TextEdit {
+    pub span: Span,
+    pub replacement: String,
+}
+
+/// The result of editing a table
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableEdit {
+    /// The table block, parsed from the edited source
+    pub block: Block,
+    /// Edits that turn the old source into the new one, in source order
+    pub edits: Vec<TextEdit>,
+}
+
+/// A change to a table. Row indexes count body rows, leaving out the
+/// header row; column indexes count grid columns from zero.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableOp {
+    InsertRow { index: usize, cells: Vec<String> },
+    DeleteRow { index: usize },
+    MoveRow { from: usize, to: usize },
+    /// Insert an empty column, with `header` in the header row if there is one
+    InsertColumn { index: usize, header: Option<String> },
+    DeleteColumn { index: usize },
+    MoveColumn { from: usize, to: usize },
+    /// Sort body rows by their values in `column`; empty cells stay last
+    Sort { column: usize, descending: bool },
+}
+
+/// Apply `op` to the table block at `path` in `source`
+pub fn edit_table(source: &str, path: &[usize], op: &TableOp) -> ParseResult<TableEdit> {
+    let mut editor = Editor::load(source, path)?;
+    
+    match op {
+        TableOp::InsertRow { index, cells } => {
+            editor.check_row_index(*index, true)?;
+            let text = editor.new_row(cells);
+            editor.insert_record(editor.body_start() + index, text);
+        }
+        TableOp::DeleteRow { index } => {
+            editor.check_row_index(*index, false)?;
+            editor.check_row_unmerged(*index)?;
+            editor.remove_record(editor.body_start() + index);
+        }
+        TableOp::MoveRow { from, to } => {
+            editor.check_row_index(*from, false)?;
+            editor.check_row_index(*to, false)?;
+            editor.check_row_unmerged(*from)?;
+            editor.check_row_unmerged(*to)?;
+            let text = editor.remove_record(editor.body_start() + from);
+            editor.insert_record(editor.body_start() + to, text);
+        }
+        TableOp::InsertColumn { index, header } => {
+            editor.check_column_index(*index, true)?;
+            editor.check_column_unmerged(*index, true)?;
+            let header = header.as_deref().unwrap_or("");
+            editor.edit_columns(|fields, is_header| {
+                let text = if is_header { header } else { "" };
+                fields.insert(*index, text.to_string());
+            });
+            editor.edit_column_attributes(|entries| {
+                if *index <= entries.len() {
+                    entries.insert(*index, String::new());
+                }
+            });
+        }
+        TableOp::DeleteColumn { index } => {
+            editor.check_column_index(*index, false)?;
+            editor.check_column_unmerged(*index, false)?;
+            editor.edit_columns(|fields, _| {
+                fields.remove(*index);
+            });
+            editor.edit_column_attributes(|entries| {
+                if *index < entries.len() {
+                    entries.remove(*index);
+                }
+            });
+        }
+        TableOp::MoveColumn { from, to } => {
+            editor.check_column_index(*from, false)?;
+            editor.check_column_index(*to, false)?;
+            editor.check_column_unmerged(*from, false)?;
+            editor.check_column_unmerged(*to, false)?;
+            editor.edit_columns(|fields, _| {
+                let field = fields.remove(*from);
+                fields.insert(*to, field);
+            });
+            editor.edit_column_attributes(|entries| {
+                if entries.len() <= (*from).max(*to) {
+                    entries.resize((*from).max(*to) + 1, String::new());
+                }
+                let entry = entries.remove(*from);
+                entries.insert(*to, entry);
+            });
+        }
+        TableOp::Sort { column, descending } => {
+            editor.check_column_index(*column, false)?;
+            editor.check_body_unmerged()?;
+            
+            let mut order: Vec<usize> = (0..editor.table.rows.len()).collect();
+            order.sort_by(|&a, &b| {
+                let (a, b) = (editor.value_at(a, *column), editor.value_at(b, *column));
+                compare_values(a, b, *descending)
+            });
+            
+            let start = editor.body_start();
+            let texts: Vec<String> = order.iter().map(|&row| editor.records[start + row].clone()).collect();
+            editor.records.splice(start.., texts);
+        }
+    }
+    
+    editor.finish(path)
+}
+
+/// Keep the body rows for which `keep` returns `true`, deleting the others
+pub fn filter_table(source: &str, path: &[usize], keep: impl Fn(&TableRow) -> bool) -> ParseResult<TableEdit> {
+    let mut editor = Editor::load(source, path)?;
+    
+    let removed: Vec<usize> = (0..editor.table.rows.len()).filter(|&row| !keep(&editor.table.rows[row])).collect();
+    if !removed.is_empty() {
+        editor.check_body_unmerged()?;
+    }
+    for row in removed.into_iter().rev() {
+        editor.remove_record(editor.body_start() + row);
+    }
+    
+    editor.finish(path)
+}
+
+/// Apply edits given in source order
+pub fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let mut output = source.to_string();
+    for edit in edits.iter().rev() {
+        output.replace_range(edit.span.start.offset..edit.span.end.offset, &edit.replacement);
+    }
+    output
+}
+
+/// Order two cell values, numbers before dates before text, with errors
+/// and then empty cells last whichever way the rest are sorted
+fn compare_values(a: &CellValue, b: &CellValue, descending: bool) -> Ordering {
+    fn rank(value: &CellValue) -> u8 {
+        match value {
+            CellValue::Number(_) | CellValue::Currency { .. } => 0,
+            CellValue::Date(_) => 1,
+            CellValue::Text(_) => 2,
+            CellValue::Error(_) => 3,
+            CellValue::Empty => 4,
+        }
+    }
+    
+    let (rank_a, rank_b) = (rank(a), rank(b));
+    if rank_a != rank_b || rank_a >= 3 {
+        return rank_a.cmp(&rank_b);
+    }
+    
+    let ordering = match (a, b) {
+        (CellValue::Date(a), CellValue::Date(b)) => a.cmp(b),
+        (CellValue::Text(a), CellValue::Text(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+        _ => amount(a).total_cmp(&amount(b)),
+    };
+    if descending { ordering.reverse() } else { ordering }
+}
+
+fn amount(value: &CellValue) -> f64 {
+    match value {
+        CellValue::Number(number) | CellValue::Currency { amount: number, .. } => *number,
+        _ => 0.0,
+    }
+}
+
+/// A table's content split into records and the text between them
+struct Editor<'a> {
+    source: &'a str,
+    block: Block,
+    table: Table,
+    layout: Layout,
+    format: TableFormat,
+    /// The content's range in the source
+    region: Range<usize>,
+    /// Each record's text as written, header first
+    records: Vec<String>,
+    /// Text before, between and after the records; one more than `records`
+    gaps: Vec<String>,
+    /// The fields of each record, for rebuilding it
+    fields: Vec<Vec<String>>,
+    /// Text before the first field, between fields and after the last, per record
+    styles: Vec<(String, String, String)>,
+    attribute_edits: Vec<TextEdit>,
+}
+
+impl<'a> Editor<'a> {
+    fn load(source: &'a str, path: &[usize]) -> ParseResult<Self> {
+        let document = BloxParser::new().parse_string(source)?;
+        let block = document.block_at(path).cloned().ok_or_else(|| BloxError::TableEdit {
+            line: 0,
+            message: format!("No block at {:?}", path),
+        })?;
+        if block.block_type.canonical() != BlockType::Table {
+            return Err(BloxError::TableEdit { line: block.line_number, message: "Block is not a table".to_string() });
+        }
+        
+        let region = if block.content.trim().is_empty() {
+            block.header_span.end.offset..block.header_span.end.offset
+        } else {
+            block.content_span.start.offset..block.content_span.end.offset
+        };
+        let text = &source[region.clone()];
+        
+        // Comment lines are not part of the table
+        let masked: String = text
+            .split('\n')
+            .map(|line| if line.trim_start().starts_with("//") { " ".repeat(line.len()) } else { line.to_string() })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let format = block.get_attribute("format").and_then(TableFormat::from_str).unwrap_or_else(|| TableFormat::detect(&masked));
+        let found = table::split_records(&masked, format, &mut Vec::new());
+        
+        let table = block.table.clone().unwrap_or(Table { caption: None, columns: Vec::new(), header: None, rows: Vec::new() });
+        if found.len() != table.all_rows().count() {
+            return Err(BloxError::TableEdit {
+                line: block.line_number,
+                message: "The table's rows could not be matched to its source".to_string(),
+            });
+        }
+        
+        let mut editor = Self {
+            source,
+            layout: table::layout(table.all_rows()),
+            block,
+            table,
+            format,
+            region,
+            records: Vec::new(),
+            gaps: Vec::new(),
+            fields: Vec::new(),
+            styles: Vec::new(),
+            attribute_edits: Vec::new(),
+        };
+        editor.split(text, &found);
+        Ok(editor)
+    }
+    
+    fn split(&mut self, text: &str, found: &[Record]) {
+        let mut last = 0;
+        for record in found {
+            self.gaps.push(text[last..record.range.start].to_string());
+            self.records.push(text[record.range.clone()].to_string());
+            last = record.range.end;
+            
+            let fields: Vec<&Range<usize>> = record.fields.iter().map(|field| &field.range).collect();
+            let first = fields.first().map_or(record.range.start, |range| range.start);
+            let end = fields.last().map_or(record.range.end, |range| range.end);
+            let separator = match fields.as_slice() {
+                [a, b, ..] => text[a.end..b.start].to_string(),
+                _ => self.default_separator().to_string(),
+            };
+            self.styles.push((text[record.range.start..first].to_string(), separator, text[end..record.range.end].to_string()));
+            self.fields.push(fields.iter().map(|range| text[(*range).clone()].to_string()).collect());
+        }
+        self.gaps.push(text[last..].to_string());
+    }
+    
+    fn default_separator(&self) -> &'static str {
+        match self.format {
+            TableFormat::Csv => ", ",
+            TableFormat::Tsv => "\t",
+            TableFormat::Pipe => " | ",
+        }
+    }
+    
+    fn error(&self, message: String) -> BloxError {
+        BloxError::TableEdit { line: self.block.line_number, message }
+    }
+    
+    fn body_start(&self) -> usize {
+        usize::from(self.table.header.is_some())
+    }
+    
+    fn check_row_index(&self, index: usize, inserting: bool) -> ParseResult<()> {
+        let count = self.table.rows.len();
+        if index < count || (inserting && index == count) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Row {} is out of range; the table has {} rows", index, count)))
+        }
+    }
+    
+    fn check_column_index(&self, index: usize, inserting: bool) -> ParseResult<()> {
+        let count = self.layout.column_count();
+        if index < count || (inserting && index == count) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Column {} is out of range; the table has {} columns", index, count)))
+        }
+    }
+    
+    /// Cells with their grid row and column, header row first
+    fn placed_cells(&self) -> impl Iterator<Item = (usize, usize, &TableCell)> {
+        self.table.all_rows().zip(&self.layout.columns).enumerate().flat_map(|(row, (cells, starts))| {
+            cells.cells.iter().zip(starts).map(move |(cell, &column)| (row, column, cell))
+        })
+    }
+    
+    fn check_row_unmerged(&self, index: usize) -> ParseResult<()> {
+        let row = self.body_start() + index;
+        let merged = self.placed_cells().any(|(start, _, cell)| cell.rowspan > 1 && (start..start + cell.rowspan).contains(&row));
+        if merged { Err(self.error(format!("Row {} is part of a merged cell", index))) } else { Ok(()) }
+    }
+    
+    fn check_body_unmerged(&self) -> ParseResult<()> {
+        let merged = self.placed_cells().any(|(row, _, cell)| row >= self.body_start() && cell.rowspan > 1);
+        if merged { Err(self.error("Rows with merged cells cannot be reordered".to_string())) } else { Ok(()) }
+    }
+    
+    /// Check that no merged cell covers `column`, or, when inserting, spans
+    /// across the boundary before it
+    fn check_column_unmerged(&self, column: usize, inserting: bool) -> ParseResult<()> {
+        let merged = self.placed_cells().any(|(_, start, cell)| {
+            if inserting {
+                start < column && column < start + cell.colspan
+            } else {
+                (cell.colspan > 1 || cell.rowspan > 1) && (start..start + cell.colspan).contains(&column)
+            }
+        });
+        if merged { Err(self.error(format!("Column {} is part of a merged cell", column))) } else { Ok(()) }
+    }
+    
+    fn value_at(&self, row: usize, column: usize) -> &CellValue {
+        let starts = &self.layout.columns[self.body_start() + row];
+        starts.iter()
+            .position(|&start| start == column)
+            .map_or(&CellValue::Empty, |i| &self.table.rows[row].cells[i].value)
+    }
+    
+    /// Write a new row in the style of the existing ones
+    fn new_row(&self, cells: &[String]) -> String {
+        let (prefix, separator, suffix) = self.styles.first().cloned().unwrap_or_else(|| match self.format {
+            TableFormat::Pipe => ("| ".to_string(), " | ".to_string(), " |".to_string()),
+            _ => (String::new(), self.default_separator().to_string(), String::new()),
+        });
+        let fields: Vec<String> = cells.iter().map(|cell| quote_field(cell, self.format)).collect();
+        escape_line(&format!("{}{}{}", prefix, fields.join(&separator), suffix))
+    }
+    
+    fn insert_record(&mut self, index: usize, text: String) {
+        if self.records.is_empty() {
+            // The content starts on the line after the header
+            self.gaps = vec!["\n".to_string(), String::new()];
+        } else if index == self.records.len() {
+            self.gaps.insert(index, "\n".to_string());
+        } else {
+            self.gaps.insert(index + 1, "\n".to_string());
+        }
+        self.records.insert(index, text);
+        self.fields.insert(index, Vec::new());
+        self.styles.insert(index, Default::default());
+    }
+    
+    fn remove_record(&mut self, index: usize) -> String {
+        let last = self.records.len() - 1;
+        let before = &self.gaps[index];
+        let after = &self.gaps[index + 1];
+        
+        // Keep the gap holding other text, such as a `|---|` rule
+        let mut gap = if !before.trim().is_empty() {
+            before.clone()
+        } else if !after.trim().is_empty() || (index != 0 && index != last) {
+            after.clone()
+        } else if index == 0 {
+            before.clone()
+        } else {
+            after.clone()
+        };
+        if index == last {
+            gap = gap.trim_end_matches('\n').to_string();
+        }
+        
+        self.gaps.splice(index..index + 2, [gap]);
+        self.fields.remove(index);
+        self.styles.remove(index);
+        self.records.remove(index)
+    }
+    
+    /// Rebuild every record with `edit` applied to its fields, placed by grid column
+    fn edit_columns(&mut self, edit: impl Fn(&mut Vec<String>, bool)) {
+        let width = self.layout.column_count();
+        
+        for (row, starts) in self.layout.columns.iter().enumerate() {
+            // Lay the fields out by column, leaving columns covered from above empty
+            let mut grid: Vec<Option<String>> = vec![None; width.max(starts.len())];
+            for (field, &start) in self.fields[row].iter().zip(starts) {
+                grid[start] = Some(field.clone());
+            }
+            let mut fields: Vec<String> = grid.iter().map(|field| field.clone().unwrap_or_default()).collect();
+            edit(&mut fields, row == 0 && self.table.header.is_some());
+            
+            // Drop the columns that stay covered from above again
+            let covered: Vec<bool> = grid.iter().map(Option::is_none).collect();
+            let mut kept_covered = covered.clone();
+            edit_flags(&mut kept_covered, &edit);
+            let fields: Vec<String> = fields.into_iter().zip(kept_covered).filter(|(_, covered)| !covered).map(|(field, _)| field).collect();
+            
+            let (prefix, separator, suffix) = &self.styles[row];
+            self.records[row] = format!("{}{}{}", prefix, fields.join(separator), suffix);
+        }
+        
+        if self.format == TableFormat::Pipe {
+            for gap in &mut self.gaps {
+                *gap = gap.split('\n').map(|line| edit_rule(line, &edit)).collect::<Vec<_>>().join("\n");
+            }
+        }
+    }
+    
+    /// Edit the comma-separated column attributes in place
+    fn edit_column_attributes(&mut self, edit: impl Fn(&mut Vec<String>)) {
+        for attribute in &self.block.attributes {
+            if !COLUMN_ATTRIBUTES.contains(&attribute.key.as_str()) {
+                continue;
+            }
+            
+            let mut entries: Vec<String> = attribute.value.split(',').map(|entry| entry.trim().to_string()).collect();
+            edit(&mut entries);
+            while entries.last().is_some_and(|entry| entry.is_empty()) {
+                entries.pop();
+            }
+            
+            let mut value = entries.join(",");
+            let start = attribute.value_span.start.offset;
+            let quoted = self.source[..start].ends_with('"');
+            if value.is_empty() && !quoted {
+                value = "\"\"".to_string();
+            }
+            if value != attribute.value {
+                self.attribute_edits.push(TextEdit { span: attribute.value_span, replacement: value });
+            }
+        }
+    }
+    
+    fn finish(self, path: &[usize]) -> ParseResult<TableEdit> {
+        let mut text = String::new();
+        for (gap, record) in self.gaps.iter().zip(&self.records) {
+            text.push_str(gap);
+            text.push_str(record);
+        }
+        text.push_str(self.gaps.last().map_or("", String::as_str));
+        
+        let mut edits = self.attribute_edits;
+        if let Some(edit) = minimal_edit(self.source, self.region.clone(), &text) {
+            edits.push(edit);
+        }
+        edits.sort_by_key(|edit| edit.span.start.offset);
+        
+        let edited = apply_edits(self.source, &edits);
+        let document = BloxParser::new().parse_string(&edited)?;
+        let block = document.block_at(path).cloned().ok_or_else(|| BloxError::TableEdit {
+            line: self.block.line_number,
+            message: "The edited table could not be read back".to_string(),
+        })?;
+        
+        Ok(TableEdit { block, edits })
+    }
+}
+
+/// Apply a column edit to flags kept alongside the fields
+fn edit_flags(flags: &mut Vec<bool>, edit: &impl Fn(&mut Vec<String>, bool)) {
+    let mut marked: Vec<String> = flags.iter().map(|&flag| if flag { "1" } else { "" }.to_string()).collect();
+    edit(&mut marked, false);
+    *flags = marked.iter().map(|mark| mark == "1").collect();
+}
+
+/// Apply a column edit to a Markdown `|---|:---:|` rule, leaving other lines alone
+fn edit_rule(line: &str, edit: &impl Fn(&mut Vec<String>, bool)) -> String {
+    let trimmed = line.trim();
+    let is_rule = trimmed.contains('-') && trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '));
+    if !is_rule {
+        return line.to_string();
+    }
+    
+    let inner = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let inner = inner.strip_suffix('|').unwrap_or(inner);
+    let mut cells: Vec<String> = inner.split('|').map(|cell| cell.trim().to_string()).collect();
+    edit(&mut cells, false);
+    let cells: Vec<String> = cells.into_iter().map(|cell| if cell.is_empty() { "---".to_string() } else { cell }).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Write `text` as a field, quoting it if it holds a delimiter, quote or line break
+pub fn quote_field(text: &str, format: TableFormat) -> String {
+    match format {
+        TableFormat::Pipe => text.replace('|', "\\|").replace('\n', " "),
+        TableFormat::Csv | TableFormat::Tsv => {
+            let special = text.contains([format.delimiter(), '"', '\n'])
+                || text.starts_with(char::is_whitespace)
+                || text.ends_with(char::is_whitespace);
+            if special { format!("\"{}\"", text.replace('"', "\"\"")) } else { text.to_string() }
+        }
+    }
+}
+
+/// The edit replacing `region` of `source` with `text`, trimmed to the part that changes
+fn minimal_edit(source: &str, region: Range<usize>, text: &str) -> Option<TextEdit> {
+    let old = &source[region.clone()];
+    if old == text {
+        return None;
+    }
+    
+    let prefix = old.char_indices()
+        .zip(text.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(old.len().min(text.len()), |((i, _), _)| i);
+    let prefix = (0..=prefix).rev().find(|&i| old.is_char_boundary(i) && text.is_char_boundary(i)).unwrap_or(0);
+    
+    let max_suffix = (old.len() - prefix).min(text.len() - prefix);
+    let mut suffix = old.bytes().rev().zip(text.bytes().rev()).take(max_suffix).take_while(|(a, b)| a == b).count();
+    while !old.is_char_boundary(old.len() - suffix) || !text.is_char_boundary(text.len() - suffix) {
+        suffix -= 1;
+    }
+    
+    let start = region.start + prefix;
+    let end = region.end - suffix;
+    Some(TextEdit {
+        span: Span::of_range(source, start, end),
+        replacement: text[prefix..text.len() - suffix].to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn edit(source: &str, op: TableOp) -> (String, TableEdit) {
+        let result = edit_table(source, &[0], &op).unwrap();
+        (apply_edits(source, &result.edits), result)
+    }
+    
+    fn cells(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+    
+    #[test]
+    fn test_row_edits() {
+        let source = "#table header=true\nName,  Qty\n// stock\nTea,   2\nCake,  10\n#p\nAfter\n";
+        
+        let (edited, result) = edit(source, TableOp::InsertRow { index: 1, cells: cells(&["Pie, large", "3"]) });
+        assert_eq!(edited, "#table header=true\nName,  Qty\n// stock\nTea,   2\n\"Pie, large\",  3\nCake,  10\n#p\nAfter\n");
+        assert_eq!(result.edits.len(), 1);
+        assert_eq!(result.edits[0].replacement, "\"Pie, large\",  3\n");
+        assert_eq!(result.block.table.as_ref().unwrap().rows[1].cells[0].content, "Pie, large");
+        
+        let (edited, _) = edit(source, TableOp::InsertRow { index: 2, cells: cells(&["#1", "1"]) });
+        assert!(edited.contains("Cake,  10\n\\#1,  1\n#p"));
+        
+        let (edited, result) = edit(source, TableOp::DeleteRow { index: 1 });
+        assert_eq!(edited, "#table header=true\nName,  Qty\n// stock\nTea,   2\n#p\nAfter\n");
+        assert_eq!(result.block.table.as_ref().unwrap().rows.len(), 1);
+        
+        let (edited, _) = edit(source, TableOp::MoveRow { from: 1, to: 0 });
+        assert_eq!(edited, "#table header=true\nName,  Qty\n// stock\nCake,  10\nTea,   2\n#p\nAfter\n");
+        
+        assert!(matches!(edit_table(source, &[0], &TableOp::DeleteRow { index: 2 }), Err(BloxError::TableEdit { line: 1, .. })));
+        assert!(edit_table(source, &[1], &TableOp::DeleteRow { index: 0 }).is_err());
+        
+        let (edited, _) = edit("#table\n", TableOp::InsertRow { index: 0, cells: cells(&["a", "b"]) });
+        assert_eq!(edited, "#table\na, b\n");
+    }
+    
+    #[test]
+    fn test_column_edits() {
+        let source = "#table header=true align=\"l,r\" types=text,number\n| Name | Qty |\n|------|----:|\n| Tea | 2 |\n| Cake | 10 |\n";
+        
+        let (edited, result) = edit(source, TableOp::InsertColumn { index: 1, header: Some("Note".to_string()) });
+        assert_eq!(edited, "#table header=true align=\"l,,r\" types=text,,number\n| Name | Note | Qty |\n| ------ | --- | ----: |\n| Tea |  | 2 |\n| Cake |  | 10 |\n");
+        assert_eq!(result.edits.len(), 3);
+        let table = result.block.table.unwrap();
+        assert_eq!(table.header.unwrap().cells[1].content, "Note");
+        assert_eq!(table.rows[1].cells[2].value, CellValue::Number(10.0));
+        
+        let (edited, _) = edit(source, TableOp::DeleteColumn { index: 0 });
+        assert_eq!(edited, "#table header=true align=\"r\" types=number\n| Qty |\n| ----: |\n| 2 |\n| 10 |\n");
+        
+        let (edited, _) = edit(source, TableOp::MoveColumn { from: 1, to: 0 });
+        assert_eq!(edited, "#table header=true align=\"r,l\" types=number,text\n| Qty | Name |\n| ----: | ------ |\n| 2 | Tea |\n| 10 | Cake |\n");
+        
+        let merged = "#table\n{{cell colspan=2 a}}, b\nc, d, e\n";
+        assert!(edit_table(merged, &[0], &TableOp::DeleteColumn { index: 1 }).is_err());
+        assert!(edit_table(merged, &[0], &TableOp::InsertColumn { index: 1, header: None }).is_err());
+        let (edited, _) = edit(merged, TableOp::DeleteColumn { index: 2 });
+        assert_eq!(edited, "#table\n{{cell colspan=2 a}}\nc, d\n");
+    }
+    
+    #[test]
+    fn test_sort_and_filter() {
+        let source = "#table header=true types=text,currency\nItem, Price\nTea, $3\nFree, \nCake, $12.50\nPie, $4\n";
+        
+        let (edited, result) = edit(source, TableOp::Sort { column: 1, descending: false });
+        assert_eq!(edited, "#table header=true types=text,currency\nItem, Price\nTea, $3\nPie, $4\nCake, $12.50\nFree, \n");
+        assert_eq!(result.edits[0].replacement, "Pie, $4\nCake, $12.50\nFree, ");
+        
+        let (edited, _) = edit(source, TableOp::Sort { column: 1, descending: true });
+        assert!(edited.ends_with("Cake, $12.50\nPie, $4\nTea, $3\nFree, \n"));
+        
+        let (edited, _) = edit(source, TableOp::Sort { column: 0, descending: false });
+        assert!(edited.ends_with("Item, Price\nCake, $12.50\nFree, \nPie, $4\nTea, $3\n"));
+        
+        let result = filter_table(source, &[0], |row| row.cells.iter().any(|cell| matches!(cell.value, CellValue::Currency { amount, .. } if amount > 3.5))).unwrap();
+        assert_eq!(apply_edits(source, &result.edits), "#table header=true types=text,currency\nItem, Price\nCake, $12.50\nPie, $4\n");
+        assert_eq!(result.block.table.unwrap().rows.len(), 2);
+        
+        let merged = "#table\n{{cell rowspan=2 a}}, 1\n2\n";
+        assert!(edit_table(merged, &[0], &TableOp::Sort { column: 1, descending: false }).is_err());
+    }
+    
+    #[test]
+    fn test_quote_field() {
+        assert_eq!(quote_field("plain", TableFormat::Csv), "plain");
+        assert_eq!(quote_field("a \"b\", c", TableFormat::Csv), "\"a \"\"b\"\", c\"");
+        assert_eq!(quote_field("a, b", TableFormat::Tsv), "a, b");
+        assert_eq!(quote_field("a | b", TableFormat::Pipe), "a \\| b");
+    }
+}
@@ -0,0 +1,462 @@
+//! Tables that load their rows from a file: `#table src=data.csv`.
+//!
+//! Loading is a separate pass over a parsed document that reads each
+//! `src` through a `TableFiles`, so callers decide where files come from
+//! and tests can supply them from memory. `columns=`, `where=` and
+//! `limit=` pick what is shown; the other table attributes apply to the
+//! result as they would to rows written in the block.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::blox::ast::{Attribute, Block, BlockType, Document, Span};
+use crate::blox::diagnostic::Diagnostic;
+use crate::blox::table::{self, TableFormat};
+use crate::blox::table_edit::quote_field;
+
+lazy_static! {
+    /// Matches one `where=` condition: column, operator and value
+    static ref CONDITION: Regex = Regex::new(
+        r"^\s*(.+?)\s*(!=|<=|>=|=|<|>|~)\s*(.*?)\s*$"
+    ).unwrap();
+}
+
+/// Where `src` files are read from
+pub trait TableFiles {
+    /// Read the file at `path`, as written in the document
+    fn read(&self, path: &str) -> io::Result<String>;
+}
+
+/// Files on disk, relative to the document's directory. Paths that lead
+/// outside it, through `..`, an absolute path or a link, are refused.
+pub struct DirectoryFiles {
+    root: PathBuf,
+}
+
+impl DirectoryFiles {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl TableFiles for DirectoryFiles {
+    fn read(&self, path: &str) -> io::Result<String> {
+        let outside = || io::Error::new(io::ErrorKind::PermissionDenied, format!("`{}` is outside the document's directory", path));
+        if !Path::new(path).components().all(|component| matches!(component, Component::Normal(_) | Component::CurDir)) {
+            return Err(outside());
+        }
+        let file = self.root.join(path).canonicalize()?;
+        if !file.starts_with(self.root.canonicalize()?) {
+            return Err(outside());
+        }
+        std::fs::read_to_string(file)
+    }
+}
+
+/// Files held in memory, keyed by path
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFiles {
+    files: HashMap<String, String>,
+}
+
+impl MemoryFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn insert(&mut self, path: &str, contents: &str) {
+        self.files.insert(normalize(path), contents.to_string());
+    }
+    
+    pub fn with_file(mut self, path: &str, contents: &str) -> Self {
+        self.insert(path, contents);
+        self
+    }
+}
+
+impl TableFiles for MemoryFiles {
+    fn read(&self, path: &str) -> io::Result<String> {
+        self.files
+            .get(&normalize(path))
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no such file"))
+    }
+}
+
+fn normalize(path: &str) -> String {
+    path.trim_start_matches("./").to_string()
+}
+
+/// A document with its `src` tables loaded, and the problems found loading them
+pub struct LoadedTables {
+    pub document: Document,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+pub struct TableLoader<'a> {
+    files: &'a dyn TableFiles,
+}
+
+impl<'a> TableLoader<'a> {
+    pub fn new(files: &'a dyn TableFiles) -> Self {
+        Self { files }
+    }
+    
+    /// Fill in `table` for every table block with a `src`, leaving the
+    /// content as written so the document encodes unchanged
+    pub fn load(&self, document: &Document) -> LoadedTables {
+        let mut document = document.clone();
+        let mut diagnostics = Vec::new();
+        
+        for block in &mut document.blocks {
+            self.load_block(block, &mut diagnostics);
+        }
+        
+        LoadedTables { document, diagnostics }
+    }
+    
+    fn load_block(&self, block: &mut Block, diagnostics: &mut Vec<Diagnostic>) {
+        if block.block_type.canonical() == BlockType::Table {
+            if let Some(src) = block.attributes.iter().find(|attr| attr.key == "src").cloned() {
+                self.load_table(block, &src, diagnostics);
+            }
+        }
+        
+        for child in &mut block.children {
+            self.load_block(child, diagnostics);
+        }
+    }
+    
+    fn load_table(&self, block: &mut Block, src: &Attribute, diagnostics: &mut Vec<Diagnostic>) {
+        if !block.content.trim().is_empty() {
+            diagnostics.push(Diagnostic::warning(
+                "table-content-ignored",
+                block.content_span,
+                format!("Rows are loaded from `{}`, so the rows written here are ignored", src.value),
+            ));
+        }
+        if Path::new(&src.value).is_absolute() {
+            diagnostics.push(Diagnostic::warning(
+                "invalid-table-source",
+                src.value_span,
+                format!("`{}` should be relative to the document", src.value),
+            ));
+            return;
+        }
+        
+        let text = match self.files.read(&src.value) {
+            Ok(text) => text,
+            Err(error) => {
+                diagnostics.push(Diagnostic::warning(
+                    "table-source-missing",
+                    src.value_span,
+                    format!("Cannot read `{}`: {}", src.value, error),
+                ));
+                return;
+            }
+        };
+        let mut data = match read_data(&src.value, block, &text) {
+            Ok(data) => data,
+            Err(message) => {
+                diagnostics.push(Diagnostic::warning("invalid-table-source", src.value_span, message));
+                return;
+            }
+        };
+        
+        let mut report = |key: &str, message: String| {
+            let span = block.attributes.iter().find(|attr| attr.key == key).map_or(src.value_span, |attr| attr.value_span);
+            diagnostics.push(Diagnostic::warning("invalid-table-option", span, message));
+        };
+        if let Some(conditions) = block.get_attribute("where") {
+            data.filter(conditions, &mut report);
+        }
+        if let Some(columns) = block.get_attribute("columns") {
+            data.select(columns, &mut report);
+        }
+        if let Some(limit) = block.get_attribute("limit") {
+            match limit.trim().parse::<usize>() {
+                Ok(limit) => data.rows.truncate(limit),
+                Err(_) => report("limit", format!("`{}` is not a number of rows", limit)),
+            }
+        }
+        
+        // Read the rows as a table written in the block, so its own
+        // attributes apply to them
+        let mut source = block.clone();
+        source.content = data.to_csv();
+        source.attributes.retain(|attr| attr.key != "format" && attr.key != "header");
+        for (key, value) in [("format", "csv"), ("header", if data.header.is_some() { "true" } else { "false" })] {
+            source.attributes.push(Attribute {
+                key: key.to_string(),
+                value: value.to_string(),
+                key_span: Span::default(),
+                value_span: Span::default(),
+            });
+        }
+        
+        let parsed = table::read_table(&source);
+        for issue in parsed.issues {
+            let attribute = issue.attribute.and_then(|key| block.attributes.iter().find(|attr| attr.key == key));
+            let span = attribute.map_or(src.value_span, |attr| attr.value_span);
+            diagnostics.push(Diagnostic::warning(issue.code, span, issue.message));
+        }
+        block.table = Some(parsed.table);
+    }
+}
+
+/// Rows read from a file, with the header row apart
+struct Data {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Data {
+    /// Find a column by its header, ignoring case, or by its letter as in formulas
+    fn column(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        let by_header = self.header.as_ref().and_then(|header| header.iter().position(|cell| cell.trim().eq_ignore_ascii_case(name)));
+        by_header.or_else(|| {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return None;
+            }
+            let width = self.header.iter().chain(&self.rows).map(Vec::len).max().unwrap_or(0);
+            let column = name.to_ascii_uppercase().bytes().try_fold(0usize, |column, letter| {
+                column.checked_mul(26)?.checked_add(usize::from(letter - b'A') + 1)
+            })?;
+            (column <= width).then(|| column - 1)
+        })
+    }
+    
+    /// Keep the rows that meet every `;`-separated condition
+    fn filter(&mut self, conditions: &str, report: &mut impl FnMut(&str, String)) {
+        let mut tests = Vec::new();
+        for condition in conditions.split(';').filter(|condition| !condition.trim().is_empty()) {
+            let Some(captures) = CONDITION.captures(condition) else {
+                report("where", format!("`{}` is not a condition such as `Qty > 5`", condition.trim()));
+                continue;
+            };
+            match self.column(&captures[1]) {
+                Some(column) => tests.push((column, captures[2].to_string(), captures[3].to_string())),
+                None => report("where", format!("No column named `{}`", &captures[1])),
+            }
+        }
+        
+        self.rows.retain(|row| {
+            tests.iter().all(|(column, operator, value)| {
+                let cell = row.get(*column).map_or("", String::as_str);
+                matches(cell, operator, value)
+            })
+        });
+    }
+    
+    /// Keep only the named columns, in the order given
+    fn select(&mut self, columns: &str, report: &mut impl FnMut(&str, String)) {
+        let mut picked = Vec::new();
+        for name in columns.split(',') {
+            match self.column(name) {
+                Some(column) => picked.push(column),
+                None => report("columns", format!("No column named `{}`", name.trim())),
+            }
+        }
+        
+        let pick = |row: &Vec<String>| -> Vec<String> {
+            picked.iter().map(|&column| row.get(column).cloned().unwrap_or_default()).collect()
+        };
+        self.header = self.header.as_ref().map(pick);
+        self.rows = self.rows.iter().map(pick).collect();
+    }
+    
+    fn to_csv(&self) -> String {
+        self.header
+            .iter()
+            .chain(&self.rows)
+            .map(|row| match row.as_slice() {
+                // A lone empty field would read as a blank line
+                [cell] if cell.is_empty() => "\"\"".to_string(),
+                _ => row.iter().map(|cell| quote_field(cell, TableFormat::Csv)).collect::<Vec<_>>().join(","),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Compare a cell with a `where=` value: as numbers when both are, otherwise
+/// as text ignoring case
+fn matches(cell: &str, operator: &str, value: &str) -> bool {
+    let (cell, value) = (cell.trim(), value.trim());
+    if operator == "~" {
+        return cell.to_lowercase().contains(&value.to_lowercase());
+    }
+    
+    let ordering = match (table::parse_number(cell), table::parse_number(value)) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        _ => cell.to_lowercase().cmp(&value.to_lowercase()),
+    };
+    match operator {
+        "=" => ordering.is_eq(),
+        "!=" => ordering.is_ne(),
+        "<" => ordering.is_lt(),
+        "<=" => ordering.is_le(),
+        ">" => ordering.is_gt(),
+        _ => ordering.is_ge(),
+    }
+}
+
+/// Read a file's rows: JSON by its extension, otherwise delimited text in
+/// the block's `format` or the one its extension or first line suggests
+fn read_data(path: &str, block: &Block, text: &str) -> Result<Data, String> {
+    let extension = Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or("").to_ascii_lowercase();
+    if extension == "json" {
+        return read_json(text).map_err(|message| format!("`{}` is not a table: {}", path, message));
+    }
+    
+    let format = block
+        .get_attribute("format")
+        .and_then(TableFormat::from_str)
+        .or_else(|| TableFormat::from_str(&extension))
+        .unwrap_or_else(|| TableFormat::detect(text));
+    let mut rows: Vec<Vec<String>> = table::split_records(text, format, &mut Vec::new())
+        .into_iter()
+        .map(|record| record.fields.into_iter().map(|field| field.text).collect())
+        .collect();
+    
+    let header = (block.get_attribute("header") == Some("true") && !rows.is_empty()).then(|| rows.remove(0));
+    Ok(Data { header, rows })
+}
+
+/// Read an array of objects, whose keys become the header, or an array of arrays
+fn read_json(text: &str) -> Result<Data, String> {
+    use serde_json::Value;
+    
+    let value: Value = serde_json::from_str(text).map_err(|error| error.to_string())?;
+    let Value::Array(items) = value else {
+        return Err("expected an array of rows".to_string());
+    };
+    
+    let cell = |value: &Value| match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    
+    if items.iter().all(Value::is_object) && !items.is_empty() {
+        let mut header: Vec<String> = Vec::new();
+        for key in items.iter().filter_map(Value::as_object).flat_map(|object| object.keys()) {
+            if !header.contains(key) {
+                header.push(key.clone());
+            }
+        }
+        let rows = items
+            .iter()
+            .filter_map(Value::as_object)
+            .map(|object| header.iter().map(|key| object.get(key).map_or(String::new(), cell)).collect())
+            .collect();
+        return Ok(Data { header: Some(header), rows });
+    }
+    
+    let rows = items
+        .iter()
+        .map(|item| match item {
+            Value::Array(cells) => Ok(cells.iter().map(cell).collect()),
+            _ => Err("expected every row to be an array, or every row an object".to_string()),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Data { header: None, rows })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::ast::CellValue;
+    use crate::blox::parser::BloxParser;
+    
+    const SALES: &str = "Region,Month,Amount\nNorth,2024-01,120\nSouth,2024-01,80\nNorth,2024-02,150\nEast,2024-02,\"1,020\"\n";
+    
+    fn load(source: &str, files: &MemoryFiles) -> LoadedTables {
+        let document = BloxParser::new().parse_string(source).unwrap();
+        TableLoader::new(files).load(&document)
+    }
+    
+    fn cells(block: &Block) -> Vec<Vec<String>> {
+        let table = block.table.as_ref().unwrap();
+        table.all_rows().map(|row| row.cells.iter().map(|cell| cell.content.clone()).collect()).collect()
+    }
+    
+    #[test]
+    fn test_csv_source() {
+        let files = MemoryFiles::new().with_file("data/sales.csv", SALES);
+        let source = "#table src=./data/sales.csv header=true types=,,number columns=Region,amount where=\"Amount >= 100; Region != south\" limit=2\n";
+        
+        let loaded = load(source, &files);
+        assert!(loaded.diagnostics.is_empty(), "{:?}", loaded.diagnostics);
+        let block = &loaded.document.blocks[0];
+        assert_eq!(cells(block), vec![vec!["Region", "Amount"], vec!["North", "120"], vec!["North", "150"]]);
+        assert_eq!(block.content, "");
+        
+        // Column types count the selected columns
+        let table = block.table.as_ref().unwrap();
+        assert_eq!(table.rows[0].cells[1].value, CellValue::Text("120".to_string()));
+        
+        let loaded = load("#table src=data/sales.csv header=true types=,,number where=\"Region ~ ea\"\n", &files);
+        let table = loaded.document.blocks[0].table.clone().unwrap();
+        assert_eq!(table.rows.len(), 1);
+        assert_eq!(table.rows[0].cells[2].value, CellValue::Number(1020.0));
+    }
+    
+    #[test]
+    fn test_json_and_tsv_sources() {
+        let files = MemoryFiles::new()
+            .with_file("people.json", r#"[{"name": "Ada", "age": 36}, {"name": "Alan", "age": null, "field": "logic"}]"#)
+            .with_file("grid.json", r#"[[1, "a, b"], [2, true]]"#)
+            .with_file("list.tsv", "x\ty\n1\t2\n");
+        
+        let loaded = load("#table src=people.json columns=name,field,age\n", &files);
+        assert_eq!(cells(&loaded.document.blocks[0]), vec![vec!["name", "field", "age"], vec!["Ada", "", "36"], vec!["Alan", "logic", ""]]);
+        assert!(loaded.document.blocks[0].table.as_ref().unwrap().header.is_some());
+        
+        let loaded = load("#section Data\n##table src=grid.json where=\"B = true\"\n", &files);
+        assert_eq!(cells(&loaded.document.blocks[0].children[0]), vec![vec!["2", "true"]]);
+        
+        let loaded = load("#table src=list.tsv\n", &files);
+        assert_eq!(cells(&loaded.document.blocks[0]), vec![vec!["x", "y"], vec!["1", "2"]]);
+    }
+    
+    #[test]
+    fn test_source_problems() {
+        let files = MemoryFiles::new().with_file("sales.csv", SALES).with_file("bad.json", "{\"a\": 1}");
+        
+        let source = "#table src=missing.csv\n#table src=bad.json\n#table src=sales.csv header=true columns=Region,Total where=\"Amount\" limit=few\nA, B\n";
+        let loaded = load(source, &files);
+        let codes: Vec<&str> = loaded.diagnostics.iter().map(|diagnostic| diagnostic.code.as_str()).collect();
+        assert_eq!(codes, vec!["table-source-missing", "invalid-table-source", "table-content-ignored", "invalid-table-option", "invalid-table-option", "invalid-table-option"]);
+        
+        let span = loaded.diagnostics[0].span;
+        assert_eq!(&source[span.start.offset..span.end.offset], "missing.csv");
+        let span = loaded.diagnostics[4].span;
+        assert_eq!(&source[span.start.offset..span.end.offset], "Region,Total");
+        assert!(loaded.document.blocks[0].table.is_none());
+        assert_eq!(loaded.document.blocks[2].table.as_ref().unwrap().rows.len(), 4);
+    }
+    
+    #[test]
+    fn test_directory_stays_inside_root() {
+        let root = std::env::temp_dir().join(format!("loom-tables-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("notes/data")).unwrap();
+        std::fs::write(root.join("notes/data/sales.csv"), SALES).unwrap();
+        std::fs::write(root.join("secret.csv"), "a,b\n").unwrap();
+        
+        let files = DirectoryFiles::new(root.join("notes"));
+        let inside = files.read("./data/sales.csv");
+        let parent = files.read("../secret.csv");
+        let nested = files.read("data/../../secret.csv");
+        std::fs::remove_dir_all(&root).unwrap();
+        
+        assert_eq!(inside.unwrap(), SALES);
+        assert_eq!(parent.unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(nested.unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+    }
+}
@@ -0,0 +1,289 @@
+//! Tasks: the items of check lists, gathered across documents.
+//!
+//! Any item of a `type=check` list is a task, whether written as
+//! `- [ ] text`, `- {{checked=false due=2024-06-01 text}}` or as an
+//! `##item` block. `due` and `assignee` are read from the item's
+//! attributes. Toggling a task changes only the line it is written on.
+
+use std::path::{Path, PathBuf};
+
+use crate::blox::ast::{Attribute, Block, BlockType, Document, ListItem, ListItemType, ListType, Span};
+use crate::blox::error::{BloxError, ParseResult};
+use crate::blox::parser::BloxParser;
+use crate::blox::table_edit::{apply_edits, TextEdit};
+
+/// A check list item and where it is
+#[derive(Debug, Clone, PartialEq)]
+pub struct Task {
+    pub text: String,
+    pub checked: bool,
+    /// Span of the item's line, or of its `item` block header
+    pub span: Span,
+    /// Titles of the sections around the list, outermost first
+    pub section_path: Vec<String>,
+    pub due: Option<String>,
+    pub assignee: Option<String>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// A task and the file it was found in
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileTask {
+    pub file: PathBuf,
+    pub task: Task,
+}
+
+/// Every task in `document`, in document order, nested tasks after their parent
+pub fn extract_tasks(document: &Document) -> Vec<Task> {
+    let mut tasks = Vec::new();
+    collect_blocks(&document.blocks, &mut Vec::new(), &mut tasks);
+    tasks
+}
+
+fn collect_blocks(blocks: &[Block], sections: &mut Vec<String>, tasks: &mut Vec<Task>) {
+    for block in blocks {
+        let title = match block.block_type.canonical() {
+            BlockType::Section => block.get_attribute("title"),
+            _ => None,
+        };
+        if let Some(title) = title {
+            sections.push(title.to_string());
+        }
+        
+        if block.block_type.canonical() == BlockType::List && block.list_type() == ListType::Check {
+            collect_items(&block.list_items, sections, tasks);
+        }
+        collect_blocks(&block.children, sections, tasks);
+        
+        if title.is_some() {
+            sections.pop();
+        }
+    }
+}
+
+fn collect_items(items: &[ListItem], sections: &[String], tasks: &mut Vec<Task>) {
+    for item in items.iter().filter(|item| item.is_task()) {
+        tasks.push(Task {
+            text: item.content.clone(),
+            checked: item.item_type == ListItemType::Checked,
+            span: item.span,
+            section_path: sections.to_vec(),
+            due: item.get_attribute("due").map(str::to_string),
+            assignee: item.get_attribute("assignee").map(str::to_string),
+            attributes: item.attributes.clone(),
+        });
+        collect_items(&item.children, sections, tasks);
+    }
+}
+
+/// Check or uncheck the task at `span`, as given by `extract_tasks` for
+/// `source`, returning the edit to the task's line and the task afterwards
+pub fn toggle_task(source: &str, span: Span) -> ParseResult<(TextEdit, Task)> {
+    let document = BloxParser::new().parse_string(source)?;
+    let task = extract_tasks(&document)
+        .into_iter()
+        .find(|task| task.span == span)
+        .ok_or(BloxError::TaskNotFound { line: span.start.line })?;
+    
+    let edit = toggle_edit(source, &task);
+    let edited = apply_edits(source, std::slice::from_ref(&edit));
+    let document = BloxParser::new().parse_string(&edited)?;
+    let toggled = extract_tasks(&document)
+        .into_iter()
+        .find(|toggled| toggled.span.start == span.start)
+        .ok_or(BloxError::TaskNotFound { line: span.start.line })?;
+    
+    Ok((edit, toggled))
+}
+
+/// The edit flipping a task's checkbox, `checked=` attribute or, when it
+/// has neither, adding one
+fn toggle_edit(source: &str, task: &Task) -> TextEdit {
+    let to = if task.checked { "false" } else { "true" };
+    let line = &source[task.span.start.offset..task.span.end.offset];
+    let at = |start: usize, end: usize, replacement: String| TextEdit {
+        span: Span::of_range(source, task.span.start.offset + start, task.span.start.offset + end),
+        replacement,
+    };
+    
+    // An `item` block. Attributes from its header carry their spans; a
+    // `checked` from `{{...}}` around its content, which wins, does not.
+    if line.trim_start().starts_with('#') {
+        let from_header = |attr: &Attribute| {
+            attr.key_span.start.offset < attr.key_span.end.offset
+                && (task.span.start.offset..=task.span.end.offset).contains(&attr.key_span.start.offset)
+        };
+        return match task.attributes.iter().find(|attr| attr.key == "checked") {
+            Some(attribute) if from_header(attribute) => TextEdit { span: attribute.value_span, replacement: to.to_string() },
+            Some(_) => {
+                let (start, content) = first_content_line(source, task.span.end.offset);
+                wrapped_checked_edit(source, start, content, to).unwrap_or_else(|| {
+                    let end = line.trim_end().len();
+                    at(end, end, format!(" checked={}", to))
+                })
+            }
+            None => {
+                let end = line.trim_end().len();
+                at(end, end, " checked=true".to_string())
+            }
+        };
+    }
+    
+    let text_start = line.len() - line.trim_start_matches('-').trim_start().len();
+    let text = &line[text_start..];
+    if text.starts_with("[x]") || text.starts_with("[X]") || text.starts_with("[ ]") {
+        let mark = if task.checked { "[ ]" } else { "[x]" };
+        return at(text_start, text_start + 3, mark.to_string());
+    }
+    
+    // `{{checked=false text}}`
+    let line_start = task.span.start.offset + text_start;
+    wrapped_checked_edit(source, line_start, text, to).unwrap_or_else(|| at(text_start, text_start, "[x] ".to_string()))
+}
+
+/// The first line of content after the header ending at `header_end`,
+/// past blank and comment lines, with its offset
+fn first_content_line(source: &str, header_end: usize) -> (usize, &str) {
+    let mut offset = source[header_end..].find('\n').map_or(source.len(), |i| header_end + i + 1);
+    while offset < source.len() {
+        let end = source[offset..].find('\n').map_or(source.len(), |i| offset + i);
+        let text = source[offset..end].trim_end_matches('\r');
+        if !text.trim().is_empty() && !text.trim_start().starts_with("//") {
+            return (offset, text);
+        }
+        offset = end + 1;
+    }
+    (source.len(), "")
+}
+
+/// The edit setting `checked=` in `{{checked=... text}}` at the start of
+/// `text`, which starts at byte `start` of `source`
+fn wrapped_checked_edit(source: &str, start: usize, text: &str, to: &str) -> Option<TextEdit> {
+    let key = text.starts_with("{{").then(|| text.find("checked="))??;
+    let value = key + "checked=".len();
+    let len = text[value..].find(|c: char| c.is_whitespace() || c == '}').unwrap_or(text.len() - value);
+    Some(TextEdit {
+        span: Span::of_range(source, start + value, start + value + len),
+        replacement: to.to_string(),
+    })
+}
+
+/// Every task in the `.blox` files at `paths`, searching directories for them
+pub fn scan_files<P: AsRef<Path>>(paths: &[P]) -> ParseResult<Vec<FileTask>> {
+    let mut files = Vec::new();
+    for path in paths {
+        blox_files(path.as_ref(), &mut files)?;
+    }
+    
+    let mut tasks = Vec::new();
+    for file in files {
+        let source = std::fs::read_to_string(&file)?;
+        let document = BloxParser::new().parse_string(&source)?;
+        tasks.extend(extract_tasks(&document).into_iter().map(|task| FileTask { file: file.clone(), task }));
+    }
+    Ok(tasks)
+}
+
+fn blox_files(path: &Path, files: &mut Vec<PathBuf>) -> ParseResult<()> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect::<Result<_, _>>()?;
+    entries.sort();
+    for entry in entries {
+        // Linked directories are skipped, so a link back up cannot loop
+        let linked = entry.symlink_metadata().is_ok_and(|metadata| metadata.file_type().is_symlink());
+        let wanted = if entry.is_dir() { !linked } else { entry.extension().is_some_and(|ext| ext == "blox") };
+        if wanted {
+            blox_files(&entry, files)?;
+        }
+    }
+    Ok(())
+}
+
+/// Toggle the task at `span` in the file at `path`, writing the file back
+pub fn toggle_task_in_file(path: &Path, span: Span) -> ParseResult<Task> {
+    let source = std::fs::read_to_string(path)?;
+    let (edit, task) = toggle_task(&source, span)?;
+    std::fs::write(path, apply_edits(&source, &[edit]))?;
+    Ok(task)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SOURCE: &str = "#section title=Launch\n##list type=check\n- [x] Draft post\n- {{checked=false due=2024-06-01 assignee=ana Review post}}\n-- Fix typos\n###item assignee=li\nBook venue\n#list\n- [ ] Not a task list\n";
+    
+    fn read_tasks(source: &str) -> Vec<Task> {
+        extract_tasks(&BloxParser::new().parse_string(source).unwrap())
+    }
+    
+    #[test]
+    fn test_extract_tasks() {
+        let tasks = read_tasks(SOURCE);
+        let summary: Vec<(&str, bool)> = tasks.iter().map(|task| (task.text.as_str(), task.checked)).collect();
+        assert_eq!(summary, vec![("Draft post", true), ("Review post", false), ("Fix typos", false), ("Book venue", false)]);
+        
+        assert_eq!(tasks[1].due.as_deref(), Some("2024-06-01"));
+        assert_eq!(tasks[1].assignee.as_deref(), Some("ana"));
+        assert_eq!(tasks[3].assignee.as_deref(), Some("li"));
+        assert_eq!(tasks[0].section_path, vec!["Launch"]);
+        
+        let span = tasks[2].span;
+        assert_eq!(&SOURCE[span.start.offset..span.end.offset], "-- Fix typos");
+        assert_eq!(span.start.line, 5);
+        let span = tasks[3].span;
+        assert_eq!(&SOURCE[span.start.offset..span.end.offset], "###item assignee=li");
+    }
+    
+    #[test]
+    fn test_toggle_task() {
+        let tasks = read_tasks(SOURCE);
+        let toggle = |task: &Task| {
+            let (edit, toggled) = toggle_task(SOURCE, task.span).unwrap();
+            assert_ne!(toggled.checked, task.checked);
+            let edited = apply_edits(SOURCE, &[edit]);
+            edited.lines().nth(task.span.start.line - 1).unwrap().to_string()
+        };
+        
+        assert_eq!(toggle(&tasks[0]), "- [ ] Draft post");
+        assert_eq!(toggle(&tasks[1]), "- {{checked=true due=2024-06-01 assignee=ana Review post}}");
+        assert_eq!(toggle(&tasks[2]), "-- [x] Fix typos");
+        assert_eq!(toggle(&tasks[3]), "###item assignee=li checked=true");
+        
+        let (edit, _) = toggle_task(SOURCE, tasks[3].span).unwrap();
+        let edited = apply_edits(SOURCE, &[edit]);
+        let again = read_tasks(&edited);
+        let (edit, toggled) = toggle_task(&edited, again[3].span).unwrap();
+        assert!(!toggled.checked);
+        assert_eq!(edit.replacement, "false");
+        
+        assert!(matches!(toggle_task(SOURCE, Span::default()), Err(BloxError::TaskNotFound { .. })));
+        
+        // `checked` read from `{{...}}` around an item block's content is toggled there
+        let source = "#list type=check\n##item\n// note\n{{checked=false Book venue}}\n##item checked=true\n{{checked=false Wrapped wins}}\n";
+        for (task, line) in read_tasks(source).iter().zip(["{{checked=true Book venue}}", "{{checked=true Wrapped wins}}"]) {
+            let (edit, toggled) = toggle_task(source, task.span).unwrap();
+            assert!(toggled.checked);
+            assert!(apply_edits(source, &[edit]).lines().any(|edited| edited == line));
+        }
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_skips_linked_directories() {
+        let root = std::env::temp_dir().join(format!("loom-tasks-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("notes")).unwrap();
+        std::fs::write(root.join("notes/plan.blox"), "#list type=check\n- [ ] Plan\n").unwrap();
+        std::os::unix::fs::symlink(&root, root.join("notes/loop")).unwrap();
+        
+        let tasks = scan_files(&[&root]);
+        std::fs::remove_dir_all(&root).unwrap();
+        let tasks = tasks.unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].task.text, "Plan");
+    }
+}
@@ -0,0 +1,246 @@
+//! `#var` definitions and `{{!name}}` interpolation.
+//!
+//! A `#var` block defines variables for its parent and everything inside
+//! it, or for the whole document when it is at the top level; inner
+//! definitions shadow outer ones. Interpolation is a separate pass over a
+//! parsed document, so the raw text stays available alongside the result.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::blox::ast::{Block, BlockType, Document, InlineElement, ListItem};
+use crate::blox::diagnostic::Diagnostic;
+use crate::blox::escape::escape_inline;
+use crate::blox::inline::parse_inline;
+
+/// A document with its variables replaced, and a diagnostic for each one left undefined
+pub struct Interpolated {
+    pub document: Document,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+pub struct Interpolator {
+    external: HashMap<String, String>,
+}
+
+impl Interpolator {
+    pub fn new() -> Self {
+        Self::with_values(HashMap::new())
+    }
+    
+    /// Use `values` ahead of the document's own definitions, so a build
+    /// can set values that the document gives defaults for
+    pub fn with_values(values: HashMap<String, String>) -> Self {
+        Self { external: values }
+    }
+    
+    /// Replace every `{{!name}}` that has a value.
+    ///
+    /// Replaced variables become `Text` elements that keep the span of the
+    /// `{{!name}}` they came from; `content` gets the value with inline
+    /// escapes added. Verbatim blocks are left as written.
+    pub fn interpolate(&self, document: &Document) -> Interpolated {
+        let mut document = document.clone();
+        let mut diagnostics = Vec::new();
+        let mut scopes = vec![definitions(&document.blocks)];
+        
+        for block in &mut document.blocks {
+            self.interpolate_block(block, &mut scopes, &mut diagnostics);
+        }
+        
+        Interpolated { document, diagnostics }
+    }
+    
+    fn lookup(&self, name: &str, scopes: &[HashMap<String, String>]) -> Option<String> {
+        self.external
+            .get(name)
+            .or_else(|| scopes.iter().rev().find_map(|scope| scope.get(name)))
+            .cloned()
+    }
+    
+    fn interpolate_block(&self, block: &mut Block, scopes: &mut Vec<HashMap<String, String>>, diagnostics: &mut Vec<Diagnostic>) {
+        scopes.push(definitions(&block.children));
+        
+        if !block.is_verbatim() && !is_definition(block) {
+            let lookup = |name: &str| self.lookup(name, scopes);
+            
+            let mut index = 0;
+            for element in &mut block.inline_elements {
+                resolve(element, &lookup, &block.inline_spans, &mut index, diagnostics);
+            }
+            
+            let content = substitute(&block.content, &lookup);
+            if content != block.content {
+                block.content = content;
+                
+                // Items and cells are read from the content, so read them again
+                let result = match block.block_type.canonical() {
+                    BlockType::List => {
+                        let items = std::mem::take(&mut block.list_items);
+                        let result = block.parse_list_items();
+                        keep_item_spans(&items, &mut block.list_items);
+                        result
+                    }
+                    BlockType::Table => block.parse_table(),
+                    BlockType::Quiz => {
+                        let questions = block.quiz.take().map(|quiz| quiz.questions).unwrap_or_default();
+                        let result = block.parse_quiz();
+                        if let Some(quiz) = &mut block.quiz {
+                            for (old, new) in questions.iter().zip(&mut quiz.questions) {
+                                new.span = old.span;
+                            }
+                        }
+                        result
+                    }
+                    _ => Ok(()),
+                };
+                if let Err(message) = result {
+                    diagnostics.push(Diagnostic::warning("interpolation-failed", block.content_span, message));
+                }
+            }
+        }
+        
+        for child in &mut block.children {
+            self.interpolate_block(child, scopes, diagnostics);
+        }
+        
+        scopes.pop();
+    }
+}
+
+impl Default for Interpolator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Give items read again the source spans of the items they replace
+fn keep_item_spans(old: &[ListItem], new: &mut [ListItem]) {
+    for (old, new) in old.iter().zip(new) {
+        new.span = old.span;
+        keep_item_spans(&old.children, &mut new.children);
+    }
+}
+
+/// Whether `block` is a `#var` block defining variables
+pub fn is_definition(block: &Block) -> bool {
+    matches!(&block.block_type, BlockType::Custom(name) if name.eq_ignore_ascii_case("var"))
+}
+
+/// Variables defined by the `#var` blocks among `blocks`, later ones winning
+fn definitions(blocks: &[Block]) -> HashMap<String, String> {
+    blocks
+        .iter()
+        .filter(|block| is_definition(block))
+        .flat_map(|block| &block.attributes)
+        .map(|attribute| (attribute.key.clone(), attribute.value.clone()))
+        .collect()
+}
+
+/// Replace the variables in `element` and its children, counting elements
+/// in pre-order to find each one's span
+fn resolve(
+    element: &mut InlineElement,
+    lookup: &impl Fn(&str) -> Option<String>,
+    spans: &[crate::blox::ast::Span],
+    index: &mut usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let span = spans.get(*index).copied().unwrap_or_default();
+    *index += 1;
+    
+    match element {
+        InlineElement::Variable(name) => match lookup(name) {
+            Some(value) => *element = InlineElement::Text(value),
+            None => diagnostics.push(Diagnostic::warning(
+                "undefined-variable",
+                span,
+                format!("Variable `{}` is not defined", name),
+            )),
+        },
+        InlineElement::Custom { content, .. } => {
+            *content = substitute(content, lookup);
+        }
+        _ => {
+            for child in element.children_mut() {
+                resolve(child, lookup, spans, index, diagnostics);
+            }
+        }
+    }
+}
+
+/// Replace the variables in inline markup with their escaped values
+fn substitute(text: &str, lookup: &impl Fn(&str) -> Option<String>) -> String {
+    let parsed = parse_inline(text);
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    
+    let mut ranges = parsed.ranges.iter();
+    let mut stack: Vec<&InlineElement> = parsed.elements.iter().rev().collect();
+    while let (Some(element), Some(range)) = (stack.pop(), ranges.next()) {
+        match element {
+            InlineElement::Variable(name) => {
+                if let Some(value) = lookup(name) {
+                    replacements.push((range.clone(), escape_inline(&value)));
+                }
+            }
+            InlineElement::Custom { content, .. } => {
+                // Custom content ends just before the closing `}}`
+                let inner = substitute(content, lookup);
+                if inner != *content {
+                    let content_end = text[..range.end - 2].trim_end().len();
+                    replacements.push((content_end - content.len()..content_end, inner));
+                }
+            }
+            _ => stack.extend(element.children().iter().rev()),
+        }
+    }
+    
+    let mut output = text.to_string();
+    for (range, value) in replacements.into_iter().rev() {
+        output.replace_range(range, &value);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blox::BloxParser;
+    
+    #[test]
+    fn test_scoped_interpolation() {
+        let content = "#var project=Loom version=0.1\n#p\nWelcome to {{!project}} {{!version}}!\n#section \"Notes\"\n##var project=\"Sub {{project}}\"\n##p\n**{{!project}}** and {{!missing}}\n##c\n{{!project}}\n#p\nBack to {{!project}}\n";
+        let document = BloxParser::new().parse_string(content).unwrap();
+        
+        // The raw document is untouched
+        assert!(document.blocks[1].content.contains("{{!project}}"));
+        
+        let result = Interpolator::new().interpolate(&document);
+        let blocks = &result.document.blocks;
+        assert_eq!(blocks[1].content, "Welcome to Loom 0.1!");
+        assert_eq!(blocks[1].inline_elements, vec![InlineElement::Text("Welcome to ".to_string()), InlineElement::Text("Loom".to_string()), InlineElement::Text(" ".to_string()), InlineElement::Text("0.1".to_string()), InlineElement::Text("!".to_string())]);
+        
+        let section = &blocks[2];
+        assert_eq!(section.children[1].content, "**Sub \\{\\{project\\}\\}** and {{!missing}}");
+        assert_eq!(section.children[2].content, "{{!project}}");
+        assert_eq!(blocks[3].content, "Back to Loom");
+        
+        assert_eq!(result.diagnostics.len(), 1);
+        let undefined = &result.diagnostics[0];
+        assert_eq!(undefined.code, "undefined-variable");
+        assert_eq!(&content[undefined.span.start.offset..undefined.span.end.offset], "{{!missing}}");
+    }
+    
+    #[test]
+    fn test_external_values() {
+        let content = "#var version=dev\n#list\n- Version {{!version}}\n- {{note {{!version}}}}\n";
+        let document = BloxParser::new().parse_string(content).unwrap();
+        
+        let values = HashMap::from([("version".to_string(), "1.2.0".to_string())]);
+        let result = Interpolator::with_values(values).interpolate(&document);
+        let list = &result.document.blocks[1];
+        assert_eq!(list.content, "- Version 1.2.0\n- {{note 1.2.0}}");
+        assert_eq!(list.list_items.last().unwrap().content, "{{note 1.2.0}}");
+        assert!(result.diagnostics.is_empty());
+    }
+}
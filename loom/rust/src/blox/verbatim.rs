@@ -0,0 +1,138 @@
+//! Verbatim content for code, math and `raw=true` blocks.
+//!
+//! Lines in a verbatim block are taken as written: `//` is not a comment and
+//! `# text` is not a header. The block ends at the next header at the same or
+//! higher level, written with the type right after the `#`s (`#section`, not
+//! `# section`) and, when headers may be indented, indented no further than
+//! the block's own header. With `raw=true` only a header naming a known block type ends
+//! it, so lines such as `#include` stay in the content. Content that must
+//! contain any header can be wrapped in a ``` or ~~~ fence, which only the
+//! matching closing fence ends; lines after it are read as Blox again, so
+//! a fenced block can have children.
+
+use crate::blox::ast::Block;
+use crate::blox::parser::KNOWN_BLOCK_TYPES;
+
+/// A ``` or ~~~ fence opening verbatim content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fence {
+    pub marker: char,
+    pub len: usize,
+}
+
+impl Fence {
+    /// Read a fence opener: three or more backticks or tildes, then an
+    /// optional info string, which is ignored
+    pub fn open(line: &str) -> Option<Self> {
+        let trimmed = line.trim_start();
+        let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+        let len = trimmed.chars().take_while(|&c| c == marker).count();
+        
+        if len < 3 || (marker == '`' && trimmed[len..].contains('`')) {
+            return None;
+        }
+        Some(Self { marker, len })
+    }
+    
+    /// Whether `line` closes this fence
+    pub fn closes(&self, line: &str) -> bool {
+        let trimmed = line.trim();
+        let len = trimmed.chars().take_while(|&c| c == self.marker).count();
+        len >= self.len && len == trimmed.chars().count()
+    }
+    
+    /// A fence that `content` cannot close early
+    pub fn for_content(content: &str) -> Self {
+        let longest = content
+            .lines()
+            .map(|line| line.trim().chars().take_while(|&c| c == '`').count())
+            .max()
+            .unwrap_or(0);
+        Self { marker: '`', len: longest.max(2) + 1 }
+    }
+}
+
+impl std::fmt::Display for Fence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.marker.to_string().repeat(self.len))
+    }
+}
+
+/// Whether `line` ends a verbatim block at `level`.
+///
+/// `known_types` restricts this to headers naming one of the given block
+/// types, as for `raw=true` blocks.
+pub fn ends_block(line: &str, level: usize, known_types: Option<&[&str]>) -> bool {
+    let hashes = line.chars().take_while(|&c| c == '#').count();
+    if hashes == 0 || hashes > level {
+        return false;
+    }
+    
+    let rest = &line[hashes..];
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return false;
+    }
+    
+    let type_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(rest.len());
+    known_types.is_none_or(|known| known.contains(&rest[..type_len].to_lowercase().as_str()))
+}
+
+/// The header types that end `block`'s content, or `None` for any header
+pub fn ending_types(block: &Block) -> Option<&'static [&'static str]> {
+    block.is_raw().then_some(KNOWN_BLOCK_TYPES)
+}
+
+/// Whether `content` has to be fenced to read back unchanged in a verbatim block
+pub fn needs_fence(content: &str, level: usize, known_types: Option<&[&str]>) -> bool {
+    let first = content.split('\n').next().unwrap_or("");
+    let last = content.rsplit('\n').next().unwrap_or("");
+    
+    // Blank lines at either end are dropped, and a leading fence would be read as one
+    first.trim().is_empty()
+        || last.trim().is_empty()
+        || Fence::open(first).is_some()
+        || content.lines().any(|line| {
+            ends_block(line, level, known_types) || crate::blox::escape::unescape_line_start(line).is_some()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_block_end() {
+        assert!(ends_block("#section", 2, None));
+        assert!(ends_block("##code python", 2, None));
+        assert!(!ends_block("###quote", 2, None));
+        assert!(!ends_block("# compute total", 2, None));
+        assert!(!ends_block("#!/bin/sh", 1, None));
+        assert!(!ends_block("  #section", 1, None));
+        
+        let known: &[&str] = &["section", "code"];
+        assert!(!ends_block("#include <stdio.h>", 1, Some(known)));
+        assert!(ends_block("#Section", 1, Some(known)));
+    }
+    
+    #[test]
+    fn test_fences() {
+        let fence = Fence::open("```python").unwrap();
+        assert_eq!(fence, Fence { marker: '`', len: 3 });
+        assert!(fence.closes("````"));
+        assert!(!fence.closes("~~~"));
+        assert!(!fence.closes("``` x"));
+        assert!(Fence::open("``").is_none());
+        assert!(Fence::open("``` `x`").is_none());
+        
+        assert_eq!(Fence::for_content("a\n````\nb").to_string(), "`````");
+        assert_eq!(Fence::for_content("plain").to_string(), "```");
+        
+        assert!(!needs_fence("x = 1\n# comment", 1, None));
+        assert!(needs_fence("#define X 1", 1, None));
+        assert!(needs_fence("\\# escaped", 1, None));
+        assert!(needs_fence("\nleading blank", 1, None));
+        assert!(needs_fence("trailing newline\n", 1, None));
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1149422544;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1065454388;
 
 // Section: executor
 
@@ -45,6 +45,163 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__blox_api__blox_flashcards_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "blox_flashcards",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::blox_flashcards(api_file_path)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__blox_indent_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "blox_indent_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::blox_api::BloxIndentPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__blox_api__blox_parser_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "blox_parser_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::blox_api::BloxParserOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__blox_api__check_blox_format_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "check_blox_format",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_style = <crate::api::blox_api::BloxFormatStyle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::check_blox_format(api_content, api_style)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__decode_blox_answer_key_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "decode_blox_answer_key",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::decode_blox_answer_key(api_document)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__blox_api__decode_blox_document_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -78,14 +235,14 @@ fn wire__crate__api__blox_api__decode_blox_document_impl(
         },
     )
 }
-fn wire__crate__api__blox_api__encode_blox_document_impl(
+fn wire__crate__api__blox_api__decode_blox_package_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "encode_blox_document",
+            debug_name: "decode_blox_package",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -100,24 +257,25 @@ fn wire__crate__api__blox_api__encode_blox_document_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
-            let api_use_shorthand = <bool>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::blox_api::BloxOutputFormat>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
                 let output_ok =
-                    crate::api::blox_api::encode_blox_document(api_document, api_use_shorthand)?;
+                    crate::api::blox_api::decode_blox_package(api_document, api_format)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__blox_api__due_blox_flashcards_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "due_blox_flashcards",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -131,26 +289,27 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_today = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::blox_api::due_blox_flashcards(api_file_path, api_today)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__blox_api__edit_blox_table_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "edit_blox_table",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -162,29 +321,28 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_path = <Vec<usize>>::sse_decode(&mut deserializer);
+            let api_op = <crate::api::blox_api::BloxTableOp>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::blox_api::edit_blox_table(api_content, api_path, api_op)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__blox_api__parse_blox_file_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__blox_api__encode_blox_document_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_blox_file",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "encode_blox_document",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -196,29 +354,59 @@ fn wire__crate__api__blox_api__parse_blox_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_use_shorthand = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::blox_api::parse_blox_file(api_file_path).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::blox_api::encode_blox_document(api_document, api_use_shorthand)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__encode_blox_document_from_models_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "encode_blox_document_from_models",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_use_shorthand = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::encode_blox_document_from_models(
+                    api_document,
+                    api_use_shorthand,
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__blox_api__parse_blox_string_impl(
+fn wire__crate__api__blox_api__encode_blox_document_preserving_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_blox_string",
+            debug_name: "encode_blox_document_preserving",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -232,23 +420,29 @@ fn wire__crate__api__blox_api__parse_blox_string_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_source = <String>::sse_decode(&mut deserializer);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_use_shorthand = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::blox_api::parse_blox_string(api_content)?;
+                let output_ok = crate::api::blox_api::encode_blox_document_preserving(
+                    api_source,
+                    api_document,
+                    api_use_shorthand,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__blox_api__validate_blox_syntax_impl(
+fn wire__crate__api__blox_api__filter_blox_table_rows_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_blox_syntax",
+            debug_name: "filter_blox_table_rows",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -263,612 +457,2927 @@ fn wire__crate__api__blox_api__validate_blox_syntax_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_content = <String>::sse_decode(&mut deserializer);
+            let api_path = <Vec<usize>>::sse_decode(&mut deserializer);
+            let api_column = <Option<usize>>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::blox_api::validate_blox_syntax(api_content)?;
+                let output_ok = crate::api::blox_api::filter_blox_table_rows(
+                    api_content,
+                    api_path,
+                    api_column,
+                    api_query,
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for std::collections::HashMap<String, String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
-        return inner.into_iter().collect();
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__blox_api__format_blox_string_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "format_blox_string",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_style = <crate::api::blox_api::BloxFormatStyle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::format_blox_string(api_content, api_style)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__blox_api__interpolate_blox_variables_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "interpolate_blox_variables",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_variables =
+                <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::blox_api::interpolate_blox_variables(api_document, api_variables),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__load_blox_tables_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_blox_tables",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_base_dir = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::blox_api::load_blox_tables(
+                    api_document,
+                    api_base_dir,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__parse_blox_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_blox_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::blox_api::parse_blox_file(api_file_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__blox_api__parse_blox_string_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_blox_string",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::parse_blox_string(api_content)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__parse_blox_string_with_diagnostics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_blox_string_with_diagnostics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::blox_api::BloxParserOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::blox_api::parse_blox_string_with_diagnostics(
+                        api_content,
+                        api_options,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__resolve_reference_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "resolve_reference",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_document = <crate::api::blox_api::BloxDocument>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::blox_api::resolve_reference(
+                    api_document,
+                    api_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__review_blox_flashcard_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "review_blox_flashcard",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_card_id = <String>::sse_decode(&mut deserializer);
+            let api_grade = <u8>::sse_decode(&mut deserializer);
+            let api_today = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::review_blox_flashcard(
+                    api_file_path,
+                    api_card_id,
+                    api_grade,
+                    api_today,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__scan_blox_tasks_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_blox_tasks",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::scan_blox_tasks(api_paths)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__toggle_blox_task_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "toggle_blox_task",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_file = <String>::sse_decode(&mut deserializer);
+            let api_span = <crate::api::blox_api::BloxSpan>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::toggle_blox_task(api_file, api_span)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__blox_api__validate_blox_syntax_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_blox_syntax",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::blox_api::validate_blox_syntax(api_content)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxAttributeOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::blox_api::BloxAttributeOrder::Source,
+            1 => crate::api::blox_api::BloxAttributeOrder::Sorted,
+            _ => unreachable!("Invalid variant for BloxAttributeOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxAttributeSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_keySpan = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_valueSpan = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxAttributeSpan {
+            key: var_key,
+            key_span: var_keySpan,
+            value_span: var_valueSpan,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxBlock {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blockType = <String>::sse_decode(deserializer);
+        let mut var_level = <usize>::sse_decode(deserializer);
+        let mut var_attributes =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_children = <Vec<crate::api::blox_api::BloxBlock>>::sse_decode(deserializer);
+        let mut var_lineNumber = <usize>::sse_decode(deserializer);
+        let mut var_headerSpan = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_contentSpan = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_attributeSpans =
+            <Vec<crate::api::blox_api::BloxAttributeSpan>>::sse_decode(deserializer);
+        let mut var_inlineElements =
+            <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+        let mut var_inlineSpans = <Vec<crate::api::blox_api::BloxSpan>>::sse_decode(deserializer);
+        let mut var_listItems = <Vec<crate::api::blox_api::BloxListItem>>::sse_decode(deserializer);
+        let mut var_table = <Option<crate::api::blox_api::BloxTable>>::sse_decode(deserializer);
+        let mut var_quiz = <Option<crate::api::blox_api::BloxQuiz>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxBlock {
+            block_type: var_blockType,
+            level: var_level,
+            attributes: var_attributes,
+            content: var_content,
+            children: var_children,
+            line_number: var_lineNumber,
+            header_span: var_headerSpan,
+            content_span: var_contentSpan,
+            attribute_spans: var_attributeSpans,
+            inline_elements: var_inlineElements,
+            inline_spans: var_inlineSpans,
+            list_items: var_listItems,
+            table: var_table,
+            quiz: var_quiz,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxCellValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::blox_api::BloxCellValue::Empty;
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxCellValue::Text(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxCellValue::Number(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxCellValue::Date(var_field0);
+            }
+            4 => {
+                let mut var_amount = <f64>::sse_decode(deserializer);
+                let mut var_symbol = <Option<String>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxCellValue::Currency {
+                    amount: var_amount,
+                    symbol: var_symbol,
+                };
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxCellValue::Error(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxColumnSpec {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_align = <String>::sse_decode(deserializer);
+        let mut var_width = <Option<String>>::sse_decode(deserializer);
+        let mut var_columnType = <String>::sse_decode(deserializer);
+        let mut var_decimals = <Option<usize>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxColumnSpec {
+            align: var_align,
+            width: var_width,
+            column_type: var_columnType,
+            decimals: var_decimals,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxDiagnostic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_severity = <crate::api::blox_api::BloxSeverity>::sse_decode(deserializer);
+        let mut var_code = <String>::sse_decode(deserializer);
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        let mut var_fix = <Option<crate::api::blox_api::BloxFix>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxDiagnostic {
+            severity: var_severity,
+            code: var_code,
+            span: var_span,
+            message: var_message,
+            fix: var_fix,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxDocument {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_blocks = <Vec<crate::api::blox_api::BloxBlock>>::sse_decode(deserializer);
+        let mut var_metadata = <crate::api::blox_api::BloxMetadata>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxDocument {
+            blocks: var_blocks,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxFix {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_replacement = <String>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxFix {
+            span: var_span,
+            replacement: var_replacement,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxFlashcard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_front = <String>::sse_decode(deserializer);
+        let mut var_back = <String>::sse_decode(deserializer);
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_due = <Option<String>>::sse_decode(deserializer);
+        let mut var_repetitions = <u32>::sse_decode(deserializer);
+        let mut var_intervalDays = <u32>::sse_decode(deserializer);
+        let mut var_ease = <f64>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxFlashcard {
+            id: var_id,
+            kind: var_kind,
+            front: var_front,
+            back: var_back,
+            span: var_span,
+            due: var_due,
+            repetitions: var_repetitions,
+            interval_days: var_intervalDays,
+            ease: var_ease,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxFormatStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_useShorthand = <bool>::sse_decode(deserializer);
+        let mut var_attributeOrder =
+            <crate::api::blox_api::BloxAttributeOrder>::sse_decode(deserializer);
+        let mut var_quoteStyle = <crate::api::blox_api::BloxQuoteStyle>::sse_decode(deserializer);
+        let mut var_blankLines = <usize>::sse_decode(deserializer);
+        let mut var_alignTables = <bool>::sse_decode(deserializer);
+        let mut var_normalizeLists = <bool>::sse_decode(deserializer);
+        let mut var_wrapWidth = <Option<usize>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxFormatStyle {
+            use_shorthand: var_useShorthand,
+            attribute_order: var_attributeOrder,
+            quote_style: var_quoteStyle,
+            blank_lines: var_blankLines,
+            align_tables: var_alignTables,
+            normalize_lists: var_normalizeLists,
+            wrap_width: var_wrapWidth,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxIndentPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::blox_api::BloxIndentPolicy::Flat,
+            1 => crate::api::blox_api::BloxIndentPolicy::Header,
+            2 => crate::api::blox_api::BloxIndentPolicy::Common,
+            _ => unreachable!("Invalid variant for BloxIndentPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxInlineElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Text(var_field0);
+            }
+            1 => {
+                let mut var_content =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Link {
+                    content: var_content,
+                    url: var_url,
+                };
+            }
+            2 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Bold(var_field0);
+            }
+            3 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Italic(var_field0);
+            }
+            4 => {
+                let mut var_code = <String>::sse_decode(deserializer);
+                let mut var_lang = <Option<String>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Code {
+                    code: var_code,
+                    lang: var_lang,
+                };
+            }
+            5 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Math(var_field0);
+            }
+            6 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Strikethrough(var_field0);
+            }
+            7 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Highlight(var_field0);
+            }
+            8 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Subscript(var_field0);
+            }
+            9 => {
+                let mut var_field0 =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Superscript(var_field0);
+            }
+            10 => {
+                let mut var_id = <String>::sse_decode(deserializer);
+                let mut var_relation = <Option<String>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Reference {
+                    id: var_id,
+                    relation: var_relation,
+                };
+            }
+            11 => {
+                let mut var_id = <String>::sse_decode(deserializer);
+                let mut var_content =
+                    <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Footnote {
+                    id: var_id,
+                    content: var_content,
+                };
+            }
+            12 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Variable(var_field0);
+            }
+            13 => {
+                let mut var_elementType = <String>::sse_decode(deserializer);
+                let mut var_attributes =
+                    <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+                let mut var_content = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxInlineElement::Custom {
+                    element_type: var_elementType,
+                    attributes: var_attributes,
+                    content: var_content,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_itemType = <crate::api::blox_api::BloxListItemType>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_children = <Vec<crate::api::blox_api::BloxListItem>>::sse_decode(deserializer);
+        let mut var_level = <usize>::sse_decode(deserializer);
+        let mut var_number = <Option<usize>>::sse_decode(deserializer);
+        let mut var_inlineElements =
+            <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
+        let mut var_attributes =
+            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxListItem {
+            item_type: var_itemType,
+            content: var_content,
+            children: var_children,
+            level: var_level,
+            number: var_number,
+            inline_elements: var_inlineElements,
+            attributes: var_attributes,
+            span: var_span,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxListItemType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::blox_api::BloxListItemType::Unchecked;
+            }
+            1 => {
+                return crate::api::blox_api::BloxListItemType::Checked;
+            }
+            2 => {
+                let mut var_term = <String>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxListItemType::Definition { term: var_term };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_authors = <Vec<String>>::sse_decode(deserializer);
+        let mut var_created = <Option<String>>::sse_decode(deserializer);
+        let mut var_modified = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_entries =
+            <Vec<crate::api::blox_api::BloxMetadataEntry>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxMetadata {
+            title: var_title,
+            authors: var_authors,
+            created: var_created,
+            modified: var_modified,
+            tags: var_tags,
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxMetadataEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_block = <String>::sse_decode(deserializer);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxMetadataEntry {
+            block: var_block,
+            key: var_key,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::blox_api::BloxOutputFormat::Html,
+            1 => crate::api::blox_api::BloxOutputFormat::Markdown,
+            2 => crate::api::blox_api::BloxOutputFormat::Json,
+            3 => crate::api::blox_api::BloxOutputFormat::PlainText,
+            4 => crate::api::blox_api::BloxOutputFormat::Gift,
+            5 => crate::api::blox_api::BloxOutputFormat::Qti,
+            _ => unreachable!("Invalid variant for BloxOutputFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxPackage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_files = <Vec<crate::api::blox_api::BloxPackageFile>>::sse_decode(deserializer);
+        let mut var_archive = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_diagnostics =
+            <Vec<crate::api::blox_api::BloxDiagnostic>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxPackage {
+            files: var_files,
+            archive: var_archive,
+            diagnostics: var_diagnostics,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxPackageFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxPackageFile {
+            path: var_path,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxParseResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_document = <crate::api::blox_api::BloxDocument>::sse_decode(deserializer);
+        let mut var_diagnostics =
+            <Vec<crate::api::blox_api::BloxDiagnostic>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxParseResult {
+            document: var_document,
+            diagnostics: var_diagnostics,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxParserOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_strictNesting = <bool>::sse_decode(deserializer);
+        let mut var_indent = <crate::api::blox_api::BloxIndentPolicy>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxParserOptions {
+            strict_nesting: var_strictNesting,
+            indent: var_indent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_line = <usize>::sse_decode(deserializer);
+        let mut var_column = <usize>::sse_decode(deserializer);
+        let mut var_offset = <usize>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxPosition {
+            line: var_line,
+            column: var_column,
+            offset: var_offset,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxQuiz {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_questions =
+            <Vec<crate::api::blox_api::BloxQuizQuestion>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxQuiz {
+            title: var_title,
+            description: var_description,
+            questions: var_questions,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxQuizChoice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_correct = <bool>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxQuizChoice {
+            content: var_content,
+            correct: var_correct,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxQuizQuestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_questionType = <String>::sse_decode(deserializer);
+        let mut var_prompt = <String>::sse_decode(deserializer);
+        let mut var_choices = <Vec<crate::api::blox_api::BloxQuizChoice>>::sse_decode(deserializer);
+        let mut var_answers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_explanation = <Option<String>>::sse_decode(deserializer);
+        let mut var_points = <usize>::sse_decode(deserializer);
+        let mut var_id = <Option<String>>::sse_decode(deserializer);
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxQuizQuestion {
+            question_type: var_questionType,
+            prompt: var_prompt,
+            choices: var_choices,
+            answers: var_answers,
+            explanation: var_explanation,
+            points: var_points,
+            id: var_id,
+            span: var_span,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxQuoteStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::blox_api::BloxQuoteStyle::Minimal,
+            1 => crate::api::blox_api::BloxQuoteStyle::Double,
+            2 => crate::api::blox_api::BloxQuoteStyle::Single,
+            _ => unreachable!("Invalid variant for BloxQuoteStyle: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxReferenceTarget {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <Vec<usize>>::sse_decode(deserializer);
+        let mut var_lineNumber = <usize>::sse_decode(deserializer);
+        let mut var_headerSpan = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_generated = <bool>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxReferenceTarget {
+            path: var_path,
+            line_number: var_lineNumber,
+            header_span: var_headerSpan,
+            generated: var_generated,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxSeverity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::blox_api::BloxSeverity::Error,
+            1 => crate::api::blox_api::BloxSeverity::Warning,
+            2 => crate::api::blox_api::BloxSeverity::Info,
+            3 => crate::api::blox_api::BloxSeverity::Hint,
+            _ => unreachable!("Invalid variant for BloxSeverity: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <crate::api::blox_api::BloxPosition>::sse_decode(deserializer);
+        let mut var_end = <crate::api::blox_api::BloxPosition>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxSpan {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTable {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_caption = <Option<String>>::sse_decode(deserializer);
+        let mut var_columns = <Vec<crate::api::blox_api::BloxColumnSpec>>::sse_decode(deserializer);
+        let mut var_header = <Option<crate::api::blox_api::BloxTableRow>>::sse_decode(deserializer);
+        let mut var_rows = <Vec<crate::api::blox_api::BloxTableRow>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTable {
+            caption: var_caption,
+            columns: var_columns,
+            header: var_header,
+            rows: var_rows,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTableCell {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_colspan = <usize>::sse_decode(deserializer);
+        let mut var_rowspan = <usize>::sse_decode(deserializer);
+        let mut var_isHeader = <bool>::sse_decode(deserializer);
+        let mut var_value = <crate::api::blox_api::BloxCellValue>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTableCell {
+            content: var_content,
+            colspan: var_colspan,
+            rowspan: var_rowspan,
+            is_header: var_isHeader,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTableEditResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_block = <crate::api::blox_api::BloxBlock>::sse_decode(deserializer);
+        let mut var_edits = <Vec<crate::api::blox_api::BloxTextEdit>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTableEditResult {
+            block: var_block,
+            edits: var_edits,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTableOp {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_index = <usize>::sse_decode(deserializer);
+                let mut var_cells = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::InsertRow {
+                    index: var_index,
+                    cells: var_cells,
+                };
+            }
+            1 => {
+                let mut var_index = <usize>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::DeleteRow { index: var_index };
+            }
+            2 => {
+                let mut var_from = <usize>::sse_decode(deserializer);
+                let mut var_to = <usize>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::MoveRow {
+                    from: var_from,
+                    to: var_to,
+                };
+            }
+            3 => {
+                let mut var_index = <usize>::sse_decode(deserializer);
+                let mut var_header = <Option<String>>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::InsertColumn {
+                    index: var_index,
+                    header: var_header,
+                };
+            }
+            4 => {
+                let mut var_index = <usize>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::DeleteColumn { index: var_index };
+            }
+            5 => {
+                let mut var_from = <usize>::sse_decode(deserializer);
+                let mut var_to = <usize>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::MoveColumn {
+                    from: var_from,
+                    to: var_to,
+                };
+            }
+            6 => {
+                let mut var_column = <usize>::sse_decode(deserializer);
+                let mut var_descending = <bool>::sse_decode(deserializer);
+                return crate::api::blox_api::BloxTableOp::Sort {
+                    column: var_column,
+                    descending: var_descending,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTableRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cells = <Vec<crate::api::blox_api::BloxTableCell>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTableRow { cells: var_cells };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTask {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_file = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_checked = <bool>::sse_decode(deserializer);
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_sectionPath = <Vec<String>>::sse_decode(deserializer);
+        let mut var_due = <Option<String>>::sse_decode(deserializer);
+        let mut var_assignee = <Option<String>>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTask {
+            file: var_file,
+            text: var_text,
+            checked: var_checked,
+            span: var_span,
+            section_path: var_sectionPath,
+            due: var_due,
+            assignee: var_assignee,
+        };
+    }
+}
+
+impl SseDecode for crate::api::blox_api::BloxTextEdit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_span = <crate::api::blox_api::BloxSpan>::sse_decode(deserializer);
+        let mut var_replacement = <String>::sse_decode(deserializer);
+        return crate::api::blox_api::BloxTextEdit {
+            span: var_span,
+            replacement: var_replacement,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxAttributeSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxAttributeSpan>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxBlock> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxBlock>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxColumnSpec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxColumnSpec>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxDiagnostic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxDiagnostic>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxFlashcard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxFlashcard>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxInlineElement> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxInlineElement>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxListItem> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxListItem>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxMetadataEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxMetadataEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxPackageFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxPackageFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxQuizChoice> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxQuizChoice>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxQuizQuestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxQuizQuestion>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxSpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxTableCell> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxTableCell>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxTableRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxTableRow>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxTask>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::blox_api::BloxTextEdit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::blox_api::BloxTextEdit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<usize>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::blox_api::BloxFix> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::blox_api::BloxFix>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::blox_api::BloxQuiz> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::blox_api::BloxQuiz>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::blox_api::BloxReferenceTarget> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::blox_api::BloxReferenceTarget>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::blox_api::BloxTable> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::blox_api::BloxTable>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::blox_api::BloxTableRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::blox_api::BloxTableRow>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__blox_api__blox_indent_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__blox_api__blox_parser_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__blox_api__parse_blox_file_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__blox_api__blox_flashcards_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__blox_api__check_blox_format_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__blox_api__decode_blox_answer_key_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__blox_api__decode_blox_document_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__blox_api__decode_blox_package_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__blox_api__due_blox_flashcards_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__blox_api__edit_blox_table_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__blox_api__encode_blox_document_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__blox_api__encode_blox_document_from_models_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__blox_api__encode_blox_document_preserving_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__blox_api__filter_blox_table_rows_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__blox_api__format_blox_string_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        17 => {
+            wire__crate__api__blox_api__interpolate_blox_variables_impl(ptr, rust_vec_len, data_len)
+        }
+        18 => wire__crate__api__blox_api__load_blox_tables_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__blox_api__parse_blox_string_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__blox_api__parse_blox_string_with_diagnostics_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__blox_api__resolve_reference_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__blox_api__review_blox_flashcard_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__blox_api__scan_blox_tasks_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__blox_api__toggle_blox_task_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__blox_api__validate_blox_syntax_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxAttributeOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Source => 0.into_dart(),
+            Self::Sorted => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxAttributeOrder
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxAttributeOrder>
+    for crate::api::blox_api::BloxAttributeOrder
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxAttributeOrder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxAttributeSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.key_span.into_into_dart().into_dart(),
+            self.value_span.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxAttributeSpan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxAttributeSpan>
+    for crate::api::blox_api::BloxAttributeSpan
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxAttributeSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxBlock {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block_type.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+            self.attributes.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+            self.line_number.into_into_dart().into_dart(),
+            self.header_span.into_into_dart().into_dart(),
+            self.content_span.into_into_dart().into_dart(),
+            self.attribute_spans.into_into_dart().into_dart(),
+            self.inline_elements.into_into_dart().into_dart(),
+            self.inline_spans.into_into_dart().into_dart(),
+            self.list_items.into_into_dart().into_dart(),
+            self.table.into_into_dart().into_dart(),
+            self.quiz.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxBlock
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxBlock>
+    for crate::api::blox_api::BloxBlock
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxBlock {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxCellValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::blox_api::BloxCellValue::Empty => [0.into_dart()].into_dart(),
+            crate::api::blox_api::BloxCellValue::Text(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxCellValue::Number(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxCellValue::Date(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxCellValue::Currency { amount, symbol } => [
+                4.into_dart(),
+                amount.into_into_dart().into_dart(),
+                symbol.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxCellValue::Error(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxCellValue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxCellValue>
+    for crate::api::blox_api::BloxCellValue
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxCellValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxColumnSpec {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.align.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.column_type.into_into_dart().into_dart(),
+            self.decimals.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxColumnSpec
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxColumnSpec>
+    for crate::api::blox_api::BloxColumnSpec
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxColumnSpec {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxDiagnostic {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.severity.into_into_dart().into_dart(),
+            self.code.into_into_dart().into_dart(),
+            self.span.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+            self.fix.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxDiagnostic
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxDiagnostic>
+    for crate::api::blox_api::BloxDiagnostic
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxDiagnostic {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxDocument {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.blocks.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxDocument
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxDocument>
+    for crate::api::blox_api::BloxDocument
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxDocument {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxFix {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.span.into_into_dart().into_dart(),
+            self.replacement.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::blox_api::BloxFix {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxFix>
+    for crate::api::blox_api::BloxFix
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxFix {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxFlashcard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.front.into_into_dart().into_dart(),
+            self.back.into_into_dart().into_dart(),
+            self.span.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+            self.repetitions.into_into_dart().into_dart(),
+            self.interval_days.into_into_dart().into_dart(),
+            self.ease.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxFlashcard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxFlashcard>
+    for crate::api::blox_api::BloxFlashcard
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxFlashcard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxFormatStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.use_shorthand.into_into_dart().into_dart(),
+            self.attribute_order.into_into_dart().into_dart(),
+            self.quote_style.into_into_dart().into_dart(),
+            self.blank_lines.into_into_dart().into_dart(),
+            self.align_tables.into_into_dart().into_dart(),
+            self.normalize_lists.into_into_dart().into_dart(),
+            self.wrap_width.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxFormatStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxFormatStyle>
+    for crate::api::blox_api::BloxFormatStyle
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxFormatStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxIndentPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Flat => 0.into_dart(),
+            Self::Header => 1.into_dart(),
+            Self::Common => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxIndentPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxIndentPolicy>
+    for crate::api::blox_api::BloxIndentPolicy
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxIndentPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxInlineElement {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::blox_api::BloxInlineElement::Text(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Link { content, url } => [
+                1.into_dart(),
+                content.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxInlineElement::Bold(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Italic(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Code { code, lang } => [
+                4.into_dart(),
+                code.into_into_dart().into_dart(),
+                lang.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxInlineElement::Math(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Strikethrough(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Highlight(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Subscript(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Superscript(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Reference { id, relation } => [
+                10.into_dart(),
+                id.into_into_dart().into_dart(),
+                relation.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxInlineElement::Footnote { id, content } => [
+                11.into_dart(),
+                id.into_into_dart().into_dart(),
+                content.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxInlineElement::Variable(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::blox_api::BloxInlineElement::Custom {
+                element_type,
+                attributes,
+                content,
+            } => [
+                13.into_dart(),
+                element_type.into_into_dart().into_dart(),
+                attributes.into_into_dart().into_dart(),
+                content.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxInlineElement
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxInlineElement>
+    for crate::api::blox_api::BloxInlineElement
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxInlineElement {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxListItem {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.item_type.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+            self.number.into_into_dart().into_dart(),
+            self.inline_elements.into_into_dart().into_dart(),
+            self.attributes.into_into_dart().into_dart(),
+            self.span.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxListItem
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxListItem>
+    for crate::api::blox_api::BloxListItem
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxListItem {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxListItemType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::blox_api::BloxListItemType::Unchecked => [0.into_dart()].into_dart(),
+            crate::api::blox_api::BloxListItemType::Checked => [1.into_dart()].into_dart(),
+            crate::api::blox_api::BloxListItemType::Definition { term } => {
+                [2.into_dart(), term.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxListItemType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxListItemType>
+    for crate::api::blox_api::BloxListItemType
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxListItemType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.authors.into_into_dart().into_dart(),
+            self.created.into_into_dart().into_dart(),
+            self.modified.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxMetadata>
+    for crate::api::blox_api::BloxMetadata
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxMetadata {
+        self
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxBlock {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_blockType = <String>::sse_decode(deserializer);
-        let mut var_level = <usize>::sse_decode(deserializer);
-        let mut var_attributes =
-            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_children = <Vec<crate::api::blox_api::BloxBlock>>::sse_decode(deserializer);
-        let mut var_lineNumber = <usize>::sse_decode(deserializer);
-        let mut var_inlineElements =
-            <Vec<crate::api::blox_api::BloxInlineElement>>::sse_decode(deserializer);
-        let mut var_listItems = <Vec<crate::api::blox_api::BloxListItem>>::sse_decode(deserializer);
-        let mut var_table = <Option<crate::api::blox_api::BloxTable>>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxBlock {
-            block_type: var_blockType,
-            level: var_level,
-            attributes: var_attributes,
-            content: var_content,
-            children: var_children,
-            line_number: var_lineNumber,
-            inline_elements: var_inlineElements,
-            list_items: var_listItems,
-            table: var_table,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxMetadataEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxMetadataEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxMetadataEntry>
+    for crate::api::blox_api::BloxMetadataEntry
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxMetadataEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxOutputFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Html => 0.into_dart(),
+            Self::Markdown => 1.into_dart(),
+            Self::Json => 2.into_dart(),
+            Self::PlainText => 3.into_dart(),
+            Self::Gift => 4.into_dart(),
+            Self::Qti => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxOutputFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxOutputFormat>
+    for crate::api::blox_api::BloxOutputFormat
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxOutputFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxPackage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.files.into_into_dart().into_dart(),
+            self.archive.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxPackage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxPackage>
+    for crate::api::blox_api::BloxPackage
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxPackage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxPackageFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxPackageFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxPackageFile>
+    for crate::api::blox_api::BloxPackageFile
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxPackageFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxParseResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.document.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxParseResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxParseResult>
+    for crate::api::blox_api::BloxParseResult
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxParseResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxParserOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.strict_nesting.into_into_dart().into_dart(),
+            self.indent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxParserOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxParserOptions>
+    for crate::api::blox_api::BloxParserOptions
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxParserOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxPosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.line.into_into_dart().into_dart(),
+            self.column.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxPosition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxPosition>
+    for crate::api::blox_api::BloxPosition
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxPosition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxQuiz {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.title.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.questions.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxQuiz
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxQuiz>
+    for crate::api::blox_api::BloxQuiz
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxQuiz {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxQuizChoice {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.correct.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxQuizChoice
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxQuizChoice>
+    for crate::api::blox_api::BloxQuizChoice
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxQuizChoice {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxQuizQuestion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.question_type.into_into_dart().into_dart(),
+            self.prompt.into_into_dart().into_dart(),
+            self.choices.into_into_dart().into_dart(),
+            self.answers.into_into_dart().into_dart(),
+            self.explanation.into_into_dart().into_dart(),
+            self.points.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.span.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxQuizQuestion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxQuizQuestion>
+    for crate::api::blox_api::BloxQuizQuestion
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxQuizQuestion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxQuoteStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Minimal => 0.into_dart(),
+            Self::Double => 1.into_dart(),
+            Self::Single => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxQuoteStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxQuoteStyle>
+    for crate::api::blox_api::BloxQuoteStyle
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxQuoteStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxReferenceTarget {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.line_number.into_into_dart().into_dart(),
+            self.header_span.into_into_dart().into_dart(),
+            self.generated.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxReferenceTarget
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxReferenceTarget>
+    for crate::api::blox_api::BloxReferenceTarget
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxReferenceTarget {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxSeverity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Error => 0.into_dart(),
+            Self::Warning => 1.into_dart(),
+            Self::Info => 2.into_dart(),
+            Self::Hint => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxSeverity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxSeverity>
+    for crate::api::blox_api::BloxSeverity
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxSeverity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxSpan
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxSpan>
+    for crate::api::blox_api::BloxSpan
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTable {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.caption.into_into_dart().into_dart(),
+            self.columns.into_into_dart().into_dart(),
+            self.header.into_into_dart().into_dart(),
+            self.rows.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTable
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTable>
+    for crate::api::blox_api::BloxTable
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTable {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableCell {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.colspan.into_into_dart().into_dart(),
+            self.rowspan.into_into_dart().into_dart(),
+            self.is_header.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxDocument {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_blocks = <Vec<crate::api::blox_api::BloxBlock>>::sse_decode(deserializer);
-        let mut var_metadata =
-            <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxDocument {
-            blocks: var_blocks,
-            metadata: var_metadata,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTableCell
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableCell>
+    for crate::api::blox_api::BloxTableCell
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTableCell {
+        self
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxInlineElement {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Text(var_field0);
-            }
-            1 => {
-                let mut var_text = <String>::sse_decode(deserializer);
-                let mut var_url = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Link {
-                    text: var_text,
-                    url: var_url,
-                };
-            }
-            2 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Bold(var_field0);
-            }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Italic(var_field0);
-            }
-            4 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Code(var_field0);
-            }
-            5 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Math(var_field0);
-            }
-            6 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Strikethrough(var_field0);
-            }
-            7 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Highlight(var_field0);
-            }
-            8 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Subscript(var_field0);
-            }
-            9 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Superscript(var_field0);
-            }
-            10 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Reference(var_field0);
-            }
-            11 => {
-                let mut var_id = <String>::sse_decode(deserializer);
-                let mut var_text = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Footnote {
-                    id: var_id,
-                    text: var_text,
-                };
-            }
-            12 => {
-                let mut var_elementType = <String>::sse_decode(deserializer);
-                let mut var_attributes =
-                    <std::collections::HashMap<String, String>>::sse_decode(deserializer);
-                let mut var_content = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxInlineElement::Custom {
-                    element_type: var_elementType,
-                    attributes: var_attributes,
-                    content: var_content,
-                };
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableEditResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.block.into_into_dart().into_dart(),
+            self.edits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxListItem {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_itemType = <crate::api::blox_api::BloxListItemType>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_children = <Vec<crate::api::blox_api::BloxListItem>>::sse_decode(deserializer);
-        let mut var_level = <usize>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxListItem {
-            item_type: var_itemType,
-            content: var_content,
-            children: var_children,
-            level: var_level,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTableEditResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableEditResult>
+    for crate::api::blox_api::BloxTableEditResult
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTableEditResult {
+        self
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxListItemType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::api::blox_api::BloxListItemType::Unchecked;
-            }
-            1 => {
-                return crate::api::blox_api::BloxListItemType::Checked;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableOp {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::blox_api::BloxTableOp::InsertRow { index, cells } => [
+                0.into_dart(),
+                index.into_into_dart().into_dart(),
+                cells.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxTableOp::DeleteRow { index } => {
+                [1.into_dart(), index.into_into_dart().into_dart()].into_dart()
             }
-            2 => {
-                let mut var_term = <String>::sse_decode(deserializer);
-                return crate::api::blox_api::BloxListItemType::Definition { term: var_term };
+            crate::api::blox_api::BloxTableOp::MoveRow { from, to } => [
+                2.into_dart(),
+                from.into_into_dart().into_dart(),
+                to.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxTableOp::InsertColumn { index, header } => [
+                3.into_dart(),
+                index.into_into_dart().into_dart(),
+                header.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxTableOp::DeleteColumn { index } => {
+                [4.into_dart(), index.into_into_dart().into_dart()].into_dart()
             }
+            crate::api::blox_api::BloxTableOp::MoveColumn { from, to } => [
+                5.into_dart(),
+                from.into_into_dart().into_dart(),
+                to.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::blox_api::BloxTableOp::Sort { column, descending } => [
+                6.into_dart(),
+                column.into_into_dart().into_dart(),
+                descending.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
             _ => {
                 unimplemented!("");
             }
         }
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxOutputFormat {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::blox_api::BloxOutputFormat::Html,
-            1 => crate::api::blox_api::BloxOutputFormat::Markdown,
-            2 => crate::api::blox_api::BloxOutputFormat::Json,
-            3 => crate::api::blox_api::BloxOutputFormat::PlainText,
-            _ => unreachable!("Invalid variant for BloxOutputFormat: {}", inner),
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTableOp
+{
 }
-
-impl SseDecode for crate::api::blox_api::BloxTable {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_caption = <Option<String>>::sse_decode(deserializer);
-        let mut var_header = <Option<crate::api::blox_api::BloxTableRow>>::sse_decode(deserializer);
-        let mut var_rows = <Vec<crate::api::blox_api::BloxTableRow>>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxTable {
-            caption: var_caption,
-            header: var_header,
-            rows: var_rows,
-        };
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableOp>
+    for crate::api::blox_api::BloxTableOp
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTableOp {
+        self
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxTableCell {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_colspan = <usize>::sse_decode(deserializer);
-        let mut var_rowspan = <usize>::sse_decode(deserializer);
-        let mut var_isHeader = <bool>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxTableCell {
-            content: var_content,
-            colspan: var_colspan,
-            rowspan: var_rowspan,
-            is_header: var_isHeader,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.cells.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::api::blox_api::BloxTableRow {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_cells = <Vec<crate::api::blox_api::BloxTableCell>>::sse_decode(deserializer);
-        return crate::api::blox_api::BloxTableRow { cells: var_cells };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTableRow
+{
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableRow>
+    for crate::api::blox_api::BloxTableRow
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTableRow {
+        self
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTask {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.checked.into_into_dart().into_dart(),
+            self.span.into_into_dart().into_dart(),
+            self.section_path.into_into_dart().into_dart(),
+            self.due.into_into_dart().into_dart(),
+            self.assignee.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTask
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTask>
+    for crate::api::blox_api::BloxTask
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTask {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::api::blox_api::BloxBlock> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::blox_api::BloxBlock>::sse_decode(deserializer));
-        }
-        return ans_;
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTextEdit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.span.into_into_dart().into_dart(),
+            self.replacement.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Vec<crate::api::blox_api::BloxInlineElement> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::blox_api::BloxInlineElement>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::blox_api::BloxTextEdit
+{
 }
-
-impl SseDecode for Vec<crate::api::blox_api::BloxListItem> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::blox_api::BloxListItem>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTextEdit>
+    for crate::api::blox_api::BloxTextEdit
+{
+    fn into_into_dart(self) -> crate::api::blox_api::BloxTextEdit {
+        self
     }
 }
 
-impl SseDecode for Vec<crate::api::blox_api::BloxTableCell> {
+impl SseEncode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::blox_api::BloxTableCell>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
     }
 }
 
-impl SseDecode for Vec<crate::api::blox_api::BloxTableRow> {
+impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::blox_api::BloxTableRow>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for crate::api::blox_api::BloxAttributeOrder {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::blox_api::BloxAttributeOrder::Source => 0,
+                crate::api::blox_api::BloxAttributeOrder::Sorted => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for Vec<(String, String)> {
+impl SseEncode for crate::api::blox_api::BloxAttributeSpan {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.key_span, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.value_span, serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::api::blox_api::BloxBlock {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.block_type, serializer);
+        <usize>::sse_encode(self.level, serializer);
+        <std::collections::HashMap<String, String>>::sse_encode(self.attributes, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<crate::api::blox_api::BloxBlock>>::sse_encode(self.children, serializer);
+        <usize>::sse_encode(self.line_number, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.header_span, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.content_span, serializer);
+        <Vec<crate::api::blox_api::BloxAttributeSpan>>::sse_encode(
+            self.attribute_spans,
+            serializer,
+        );
+        <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(
+            self.inline_elements,
+            serializer,
+        );
+        <Vec<crate::api::blox_api::BloxSpan>>::sse_encode(self.inline_spans, serializer);
+        <Vec<crate::api::blox_api::BloxListItem>>::sse_encode(self.list_items, serializer);
+        <Option<crate::api::blox_api::BloxTable>>::sse_encode(self.table, serializer);
+        <Option<crate::api::blox_api::BloxQuiz>>::sse_encode(self.quiz, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::blox_api::BloxTable> {
+impl SseEncode for crate::api::blox_api::BloxCellValue {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::blox_api::BloxTable>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::blox_api::BloxCellValue::Empty => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::blox_api::BloxCellValue::Text(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::blox_api::BloxCellValue::Number(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::blox_api::BloxCellValue::Date(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::blox_api::BloxCellValue::Currency { amount, symbol } => {
+                <i32>::sse_encode(4, serializer);
+                <f64>::sse_encode(amount, serializer);
+                <Option<String>>::sse_encode(symbol, serializer);
+            }
+            crate::api::blox_api::BloxCellValue::Error(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseDecode for Option<crate::api::blox_api::BloxTableRow> {
+impl SseEncode for crate::api::blox_api::BloxColumnSpec {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::blox_api::BloxTableRow>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.align, serializer);
+        <Option<String>>::sse_encode(self.width, serializer);
+        <String>::sse_encode(self.column_type, serializer);
+        <Option<usize>>::sse_encode(self.decimals, serializer);
     }
 }
 
-impl SseDecode for (String, String) {
+impl SseEncode for crate::api::blox_api::BloxDiagnostic {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::blox_api::BloxSeverity>::sse_encode(self.severity, serializer);
+        <String>::sse_encode(self.code, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
+        <String>::sse_encode(self.message, serializer);
+        <Option<crate::api::blox_api::BloxFix>>::sse_encode(self.fix, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::blox_api::BloxDocument {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::blox_api::BloxBlock>>::sse_encode(self.blocks, serializer);
+        <crate::api::blox_api::BloxMetadata>::sse_encode(self.metadata, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::blox_api::BloxFix {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
+        <String>::sse_encode(self.replacement, serializer);
+        <String>::sse_encode(self.message, serializer);
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::blox_api::BloxFlashcard {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.front, serializer);
+        <String>::sse_encode(self.back, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
+        <Option<String>>::sse_encode(self.due, serializer);
+        <u32>::sse_encode(self.repetitions, serializer);
+        <u32>::sse_encode(self.interval_days, serializer);
+        <f64>::sse_encode(self.ease, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__blox_api__parse_blox_file_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::blox_api::BloxFormatStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.use_shorthand, serializer);
+        <crate::api::blox_api::BloxAttributeOrder>::sse_encode(self.attribute_order, serializer);
+        <crate::api::blox_api::BloxQuoteStyle>::sse_encode(self.quote_style, serializer);
+        <usize>::sse_encode(self.blank_lines, serializer);
+        <bool>::sse_encode(self.align_tables, serializer);
+        <bool>::sse_encode(self.normalize_lists, serializer);
+        <Option<usize>>::sse_encode(self.wrap_width, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__blox_api__decode_blox_document_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__blox_api__encode_blox_document_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__blox_api__parse_blox_string_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__blox_api__validate_blox_syntax_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::blox_api::BloxIndentPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::blox_api::BloxIndentPolicy::Flat => 0,
+                crate::api::blox_api::BloxIndentPolicy::Header => 1,
+                crate::api::blox_api::BloxIndentPolicy::Common => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxBlock {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.block_type.into_into_dart().into_dart(),
-            self.level.into_into_dart().into_dart(),
-            self.attributes.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.children.into_into_dart().into_dart(),
-            self.line_number.into_into_dart().into_dart(),
-            self.inline_elements.into_into_dart().into_dart(),
-            self.list_items.into_into_dart().into_dart(),
-            self.table.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxBlock
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxBlock>
-    for crate::api::blox_api::BloxBlock
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxBlock {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxDocument {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.blocks.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxDocument
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxDocument>
-    for crate::api::blox_api::BloxDocument
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxDocument {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxInlineElement {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+impl SseEncode for crate::api::blox_api::BloxInlineElement {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
             crate::api::blox_api::BloxInlineElement::Text(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::blox_api::BloxInlineElement::Link { content, url } => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(content, serializer);
+                <String>::sse_encode(url, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Link { text, url } => [
-                1.into_dart(),
-                text.into_into_dart().into_dart(),
-                url.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
             crate::api::blox_api::BloxInlineElement::Bold(field0) => {
-                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(2, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Italic(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(3, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Code(field0) => {
-                [4.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            crate::api::blox_api::BloxInlineElement::Code { code, lang } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(code, serializer);
+                <Option<String>>::sse_encode(lang, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Math(field0) => {
-                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field0, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Strikethrough(field0) => {
-                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(6, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Highlight(field0) => {
-                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(7, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Subscript(field0) => {
-                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(8, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
             crate::api::blox_api::BloxInlineElement::Superscript(field0) => {
-                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(9, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(field0, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Reference(field0) => {
-                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            crate::api::blox_api::BloxInlineElement::Reference { id, relation } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(id, serializer);
+                <Option<String>>::sse_encode(relation, serializer);
+            }
+            crate::api::blox_api::BloxInlineElement::Footnote { id, content } => {
+                <i32>::sse_encode(11, serializer);
+                <String>::sse_encode(id, serializer);
+                <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(content, serializer);
+            }
+            crate::api::blox_api::BloxInlineElement::Variable(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(field0, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Footnote { id, text } => [
-                11.into_dart(),
-                id.into_into_dart().into_dart(),
-                text.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
             crate::api::blox_api::BloxInlineElement::Custom {
                 element_type,
                 attributes,
                 content,
-            } => [
-                12.into_dart(),
-                element_type.into_into_dart().into_dart(),
-                attributes.into_into_dart().into_dart(),
-                content.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
+            } => {
+                <i32>::sse_encode(13, serializer);
+                <String>::sse_encode(element_type, serializer);
+                <std::collections::HashMap<String, String>>::sse_encode(attributes, serializer);
+                <String>::sse_encode(content, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxInlineElement
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxInlineElement>
-    for crate::api::blox_api::BloxInlineElement
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxInlineElement {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxListItem {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.item_type.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.children.into_into_dart().into_dart(),
-            self.level.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxListItem
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxListItem>
-    for crate::api::blox_api::BloxListItem
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxListItem {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxListItem {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::blox_api::BloxListItemType>::sse_encode(self.item_type, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<crate::api::blox_api::BloxListItem>>::sse_encode(self.children, serializer);
+        <usize>::sse_encode(self.level, serializer);
+        <Option<usize>>::sse_encode(self.number, serializer);
+        <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(
+            self.inline_elements,
+            serializer,
+        );
+        <std::collections::HashMap<String, String>>::sse_encode(self.attributes, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxListItemType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::api::blox_api::BloxListItemType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::blox_api::BloxListItemType::Unchecked => [0.into_dart()].into_dart(),
-            crate::api::blox_api::BloxListItemType::Checked => [1.into_dart()].into_dart(),
+            crate::api::blox_api::BloxListItemType::Unchecked => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::blox_api::BloxListItemType::Checked => {
+                <i32>::sse_encode(1, serializer);
+            }
             crate::api::blox_api::BloxListItemType::Definition { term } => {
-                [2.into_dart(), term.into_into_dart().into_dart()].into_dart()
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(term, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -876,206 +3385,239 @@ impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxListItemType {
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxListItemType
-{
+
+impl SseEncode for crate::api::blox_api::BloxMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Vec<String>>::sse_encode(self.authors, serializer);
+        <Option<String>>::sse_encode(self.created, serializer);
+        <Option<String>>::sse_encode(self.modified, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Vec<crate::api::blox_api::BloxMetadataEntry>>::sse_encode(self.entries, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxListItemType>
-    for crate::api::blox_api::BloxListItemType
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxListItemType {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxMetadataEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.block, serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxOutputFormat {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Html => 0.into_dart(),
-            Self::Markdown => 1.into_dart(),
-            Self::Json => 2.into_dart(),
-            Self::PlainText => 3.into_dart(),
-            _ => unreachable!(),
-        }
+
+impl SseEncode for crate::api::blox_api::BloxOutputFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::blox_api::BloxOutputFormat::Html => 0,
+                crate::api::blox_api::BloxOutputFormat::Markdown => 1,
+                crate::api::blox_api::BloxOutputFormat::Json => 2,
+                crate::api::blox_api::BloxOutputFormat::PlainText => 3,
+                crate::api::blox_api::BloxOutputFormat::Gift => 4,
+                crate::api::blox_api::BloxOutputFormat::Qti => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxOutputFormat
-{
+
+impl SseEncode for crate::api::blox_api::BloxPackage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::blox_api::BloxPackageFile>>::sse_encode(self.files, serializer);
+        <Vec<u8>>::sse_encode(self.archive, serializer);
+        <Vec<crate::api::blox_api::BloxDiagnostic>>::sse_encode(self.diagnostics, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxOutputFormat>
-    for crate::api::blox_api::BloxOutputFormat
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxOutputFormat {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxPackageFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <String>::sse_encode(self.content, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTable {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.caption.into_into_dart().into_dart(),
-            self.header.into_into_dart().into_dart(),
-            self.rows.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::blox_api::BloxParseResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::blox_api::BloxDocument>::sse_encode(self.document, serializer);
+        <Vec<crate::api::blox_api::BloxDiagnostic>>::sse_encode(self.diagnostics, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxTable
-{
+
+impl SseEncode for crate::api::blox_api::BloxParserOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.strict_nesting, serializer);
+        <crate::api::blox_api::BloxIndentPolicy>::sse_encode(self.indent, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTable>
-    for crate::api::blox_api::BloxTable
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxTable {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxPosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.line, serializer);
+        <usize>::sse_encode(self.column, serializer);
+        <usize>::sse_encode(self.offset, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableCell {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.content.into_into_dart().into_dart(),
-            self.colspan.into_into_dart().into_dart(),
-            self.rowspan.into_into_dart().into_dart(),
-            self.is_header.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::blox_api::BloxQuiz {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<crate::api::blox_api::BloxQuizQuestion>>::sse_encode(self.questions, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxTableCell
-{
+
+impl SseEncode for crate::api::blox_api::BloxQuizChoice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <bool>::sse_encode(self.correct, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableCell>
-    for crate::api::blox_api::BloxTableCell
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxTableCell {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxQuizQuestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.question_type, serializer);
+        <String>::sse_encode(self.prompt, serializer);
+        <Vec<crate::api::blox_api::BloxQuizChoice>>::sse_encode(self.choices, serializer);
+        <Vec<String>>::sse_encode(self.answers, serializer);
+        <Option<String>>::sse_encode(self.explanation, serializer);
+        <usize>::sse_encode(self.points, serializer);
+        <Option<String>>::sse_encode(self.id, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::blox_api::BloxTableRow {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.cells.into_into_dart().into_dart()].into_dart()
+
+impl SseEncode for crate::api::blox_api::BloxQuoteStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::blox_api::BloxQuoteStyle::Minimal => 0,
+                crate::api::blox_api::BloxQuoteStyle::Double => 1,
+                crate::api::blox_api::BloxQuoteStyle::Single => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::blox_api::BloxTableRow
-{
+
+impl SseEncode for crate::api::blox_api::BloxReferenceTarget {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<usize>>::sse_encode(self.path, serializer);
+        <usize>::sse_encode(self.line_number, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.header_span, serializer);
+        <bool>::sse_encode(self.generated, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::blox_api::BloxTableRow>
-    for crate::api::blox_api::BloxTableRow
-{
-    fn into_into_dart(self) -> crate::api::blox_api::BloxTableRow {
-        self
+
+impl SseEncode for crate::api::blox_api::BloxSeverity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::blox_api::BloxSeverity::Error => 0,
+                crate::api::blox_api::BloxSeverity::Warning => 1,
+                crate::api::blox_api::BloxSeverity::Info => 2,
+                crate::api::blox_api::BloxSeverity::Hint => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for std::collections::HashMap<String, String> {
+impl SseEncode for crate::api::blox_api::BloxSpan {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+        <crate::api::blox_api::BloxPosition>::sse_encode(self.start, serializer);
+        <crate::api::blox_api::BloxPosition>::sse_encode(self.end, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::blox_api::BloxTable {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <Option<String>>::sse_encode(self.caption, serializer);
+        <Vec<crate::api::blox_api::BloxColumnSpec>>::sse_encode(self.columns, serializer);
+        <Option<crate::api::blox_api::BloxTableRow>>::sse_encode(self.header, serializer);
+        <Vec<crate::api::blox_api::BloxTableRow>>::sse_encode(self.rows, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxBlock {
+impl SseEncode for crate::api::blox_api::BloxTableCell {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.block_type, serializer);
-        <usize>::sse_encode(self.level, serializer);
-        <std::collections::HashMap<String, String>>::sse_encode(self.attributes, serializer);
         <String>::sse_encode(self.content, serializer);
-        <Vec<crate::api::blox_api::BloxBlock>>::sse_encode(self.children, serializer);
-        <usize>::sse_encode(self.line_number, serializer);
-        <Vec<crate::api::blox_api::BloxInlineElement>>::sse_encode(
-            self.inline_elements,
-            serializer,
-        );
-        <Vec<crate::api::blox_api::BloxListItem>>::sse_encode(self.list_items, serializer);
-        <Option<crate::api::blox_api::BloxTable>>::sse_encode(self.table, serializer);
+        <usize>::sse_encode(self.colspan, serializer);
+        <usize>::sse_encode(self.rowspan, serializer);
+        <bool>::sse_encode(self.is_header, serializer);
+        <crate::api::blox_api::BloxCellValue>::sse_encode(self.value, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxDocument {
+impl SseEncode for crate::api::blox_api::BloxTableEditResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::blox_api::BloxBlock>>::sse_encode(self.blocks, serializer);
-        <std::collections::HashMap<String, String>>::sse_encode(self.metadata, serializer);
+        <crate::api::blox_api::BloxBlock>::sse_encode(self.block, serializer);
+        <Vec<crate::api::blox_api::BloxTextEdit>>::sse_encode(self.edits, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxInlineElement {
+impl SseEncode for crate::api::blox_api::BloxTableOp {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            crate::api::blox_api::BloxInlineElement::Text(field0) => {
+            crate::api::blox_api::BloxTableOp::InsertRow { index, cells } => {
                 <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
+                <usize>::sse_encode(index, serializer);
+                <Vec<String>>::sse_encode(cells, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Link { text, url } => {
+            crate::api::blox_api::BloxTableOp::DeleteRow { index } => {
                 <i32>::sse_encode(1, serializer);
-                <String>::sse_encode(text, serializer);
-                <String>::sse_encode(url, serializer);
+                <usize>::sse_encode(index, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Bold(field0) => {
+            crate::api::blox_api::BloxTableOp::MoveRow { from, to } => {
                 <i32>::sse_encode(2, serializer);
-                <String>::sse_encode(field0, serializer);
+                <usize>::sse_encode(from, serializer);
+                <usize>::sse_encode(to, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Italic(field0) => {
+            crate::api::blox_api::BloxTableOp::InsertColumn { index, header } => {
                 <i32>::sse_encode(3, serializer);
-                <String>::sse_encode(field0, serializer);
+                <usize>::sse_encode(index, serializer);
+                <Option<String>>::sse_encode(header, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Code(field0) => {
+            crate::api::blox_api::BloxTableOp::DeleteColumn { index } => {
                 <i32>::sse_encode(4, serializer);
-                <String>::sse_encode(field0, serializer);
+                <usize>::sse_encode(index, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Math(field0) => {
+            crate::api::blox_api::BloxTableOp::MoveColumn { from, to } => {
                 <i32>::sse_encode(5, serializer);
-                <String>::sse_encode(field0, serializer);
+                <usize>::sse_encode(from, serializer);
+                <usize>::sse_encode(to, serializer);
             }
-            crate::api::blox_api::BloxInlineElement::Strikethrough(field0) => {
+            crate::api::blox_api::BloxTableOp::Sort { column, descending } => {
                 <i32>::sse_encode(6, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Highlight(field0) => {
-                <i32>::sse_encode(7, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Subscript(field0) => {
-                <i32>::sse_encode(8, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Superscript(field0) => {
-                <i32>::sse_encode(9, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Reference(field0) => {
-                <i32>::sse_encode(10, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Footnote { id, text } => {
-                <i32>::sse_encode(11, serializer);
-                <String>::sse_encode(id, serializer);
-                <String>::sse_encode(text, serializer);
-            }
-            crate::api::blox_api::BloxInlineElement::Custom {
-                element_type,
-                attributes,
-                content,
-            } => {
-                <i32>::sse_encode(12, serializer);
-                <String>::sse_encode(element_type, serializer);
-                <std::collections::HashMap<String, String>>::sse_encode(attributes, serializer);
-                <String>::sse_encode(content, serializer);
+                <usize>::sse_encode(column, serializer);
+                <bool>::sse_encode(descending, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -1084,111 +3626,111 @@ impl SseEncode for crate::api::blox_api::BloxInlineElement {
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxListItem {
+impl SseEncode for crate::api::blox_api::BloxTableRow {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::api::blox_api::BloxListItemType>::sse_encode(self.item_type, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <Vec<crate::api::blox_api::BloxListItem>>::sse_encode(self.children, serializer);
-        <usize>::sse_encode(self.level, serializer);
+        <Vec<crate::api::blox_api::BloxTableCell>>::sse_encode(self.cells, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxListItemType {
+impl SseEncode for crate::api::blox_api::BloxTask {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::blox_api::BloxListItemType::Unchecked => {
-                <i32>::sse_encode(0, serializer);
-            }
-            crate::api::blox_api::BloxListItemType::Checked => {
-                <i32>::sse_encode(1, serializer);
-            }
-            crate::api::blox_api::BloxListItemType::Definition { term } => {
-                <i32>::sse_encode(2, serializer);
-                <String>::sse_encode(term, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        <String>::sse_encode(self.file, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <bool>::sse_encode(self.checked, serializer);
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
+        <Vec<String>>::sse_encode(self.section_path, serializer);
+        <Option<String>>::sse_encode(self.due, serializer);
+        <Option<String>>::sse_encode(self.assignee, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxOutputFormat {
+impl SseEncode for crate::api::blox_api::BloxTextEdit {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::blox_api::BloxOutputFormat::Html => 0,
-                crate::api::blox_api::BloxOutputFormat::Markdown => 1,
-                crate::api::blox_api::BloxOutputFormat::Json => 2,
-                crate::api::blox_api::BloxOutputFormat::PlainText => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <crate::api::blox_api::BloxSpan>::sse_encode(self.span, serializer);
+        <String>::sse_encode(self.replacement, serializer);
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxTable {
+impl SseEncode for bool {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.caption, serializer);
-        <Option<crate::api::blox_api::BloxTableRow>>::sse_encode(self.header, serializer);
-        <Vec<crate::api::blox_api::BloxTableRow>>::sse_encode(self.rows, serializer);
+        serializer.cursor.write_u8(self as _).unwrap();
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxTableCell {
+impl SseEncode for f64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.content, serializer);
-        <usize>::sse_encode(self.colspan, serializer);
-        <usize>::sse_encode(self.rowspan, serializer);
-        <bool>::sse_encode(self.is_header, serializer);
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for crate::api::blox_api::BloxTableRow {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::blox_api::BloxTableCell>>::sse_encode(self.cells, serializer);
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for Vec<crate::api::blox_api::BloxAttributeSpan> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxAttributeSpan>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for Vec<crate::api::blox_api::BloxBlock> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <String>::sse_encode(item, serializer);
+            <crate::api::blox_api::BloxBlock>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::blox_api::BloxBlock> {
+impl SseEncode for Vec<crate::api::blox_api::BloxColumnSpec> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::api::blox_api::BloxBlock>::sse_encode(item, serializer);
+            <crate::api::blox_api::BloxColumnSpec>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxDiagnostic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxDiagnostic>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxFlashcard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxFlashcard>::sse_encode(item, serializer);
         }
     }
 }
@@ -1213,6 +3755,56 @@ impl SseEncode for Vec<crate::api::blox_api::BloxListItem> {
     }
 }
 
+impl SseEncode for Vec<crate::api::blox_api::BloxMetadataEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxMetadataEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxPackageFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxPackageFile>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxQuizChoice> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxQuizChoice>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxQuizQuestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxQuizQuestion>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::blox_api::BloxTableCell> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1233,6 +3825,26 @@ impl SseEncode for Vec<crate::api::blox_api::BloxTableRow> {
     }
 }
 
+impl SseEncode for Vec<crate::api::blox_api::BloxTask> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxTask>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::blox_api::BloxTextEdit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::blox_api::BloxTextEdit>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1243,6 +3855,16 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <usize>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1263,6 +3885,36 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<crate::api::blox_api::BloxFix> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::blox_api::BloxFix>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::blox_api::BloxQuiz> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::blox_api::BloxQuiz>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::blox_api::BloxReferenceTarget> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::blox_api::BloxReferenceTarget>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::blox_api::BloxTable> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1283,6 +3935,16 @@ impl SseEncode for Option<crate::api::blox_api::BloxTableRow> {
     }
 }
 
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1291,6 +3953,13 @@ impl SseEncode for (String, String) {
     }
 }
 
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {